@@ -26,12 +26,23 @@ extern crate criterion;
 extern crate roots;
 use criterion::{criterion_group, criterion_main, Criterion};
 use roots::find_root_brent;
+use roots::find_root_chandrupatla;
+use roots::find_root_dekker;
+use roots::find_root_halley;
 use roots::find_root_newton_raphson;
+use roots::find_root_newton_raphson_combined;
+use roots::find_root_ostrowski;
 use roots::find_root_regula_falsi;
+use roots::find_root_ridders;
 use roots::find_root_secant;
+#[cfg(feature = "num-complex")]
+use roots::find_roots_aberth;
 use roots::find_roots_biquadratic;
+use roots::find_roots_eigen;
 use roots::find_roots_quadratic;
 use roots::find_roots_quartic;
+use roots::find_roots_quartic_batch;
+use roots::find_smallest_positive_root_quartic;
 
 fn x2_min_1(x: f64) -> f64 {
     x * x - 1f64
@@ -49,6 +60,28 @@ fn x4_min_1_derivative(x: f64) -> f64 {
     4f64 * x * x * x
 }
 
+fn ln_sin_min_1(x: f64) -> f64 {
+    (x.sin() + 2f64).ln() - 1f64
+}
+
+fn ln_sin_min_1_derivative(x: f64) -> f64 {
+    x.cos() / (x.sin() + 2f64)
+}
+
+fn ln_sin_min_1_combined(x: f64) -> (f64, f64) {
+    let (sin, cos) = x.sin_cos();
+    let shared = sin + 2f64;
+    (shared.ln() - 1f64, cos / shared)
+}
+
+fn x2_min_1_second_derivative(_x: f64) -> f64 {
+    2f64
+}
+
+fn x4_min_1_second_derivative(x: f64) -> f64 {
+    12f64 * x * x
+}
+
 fn secant_x2_min_1_x1000(c: &mut Criterion) {
     c.bench_function("simple", |b| b.iter(|| find_root_secant(0f64, 10f64, &x2_min_1, &mut 1e-15f64)));
 }
@@ -73,6 +106,30 @@ fn brent_x4_min_1_x1000(c: &mut Criterion) {
     c.bench_function("simple", |b| b.iter(|| find_root_brent(0f64, 10f64, &x4_min_1, &mut 1e-15f64)));
 }
 
+fn ridders_x2_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_ridders(0f64, 10f64, &x2_min_1, &mut 1e-15f64)));
+}
+
+fn ridders_x4_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_ridders(0f64, 10f64, &x4_min_1, &mut 1e-15f64)));
+}
+
+fn chandrupatla_x2_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_chandrupatla(0f64, 10f64, &x2_min_1, &mut 1e-15f64)));
+}
+
+fn chandrupatla_x4_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_chandrupatla(0f64, 10f64, &x4_min_1, &mut 1e-15f64)));
+}
+
+fn dekker_x2_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_dekker(0f64, 10f64, &x2_min_1, &mut 1e-15f64)));
+}
+
+fn dekker_x4_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_dekker(0f64, 10f64, &x4_min_1, &mut 1e-15f64)));
+}
+
 fn newton_raphson_x2_min_1_x1000(c: &mut Criterion) {
     c.bench_function("simple", |b| b.iter(|| find_root_newton_raphson(0.5f64, &x2_min_1, &x2_min_1_derivative, &mut 1e-15f64)));
 }
@@ -81,6 +138,45 @@ fn newton_raphson_x4_min_1_x1000(c: &mut Criterion) {
     c.bench_function("simple", |b| b.iter(|| find_root_newton_raphson(0.5f64, &x4_min_1, &x4_min_1_derivative, &mut 1e-15f64)));
 }
 
+fn newton_raphson_ln_sin_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_newton_raphson(0f64, &ln_sin_min_1, &ln_sin_min_1_derivative, &mut 1e-15f64)));
+}
+
+fn newton_raphson_combined_ln_sin_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_newton_raphson_combined(0f64, &ln_sin_min_1_combined, &mut 1e-15f64)));
+}
+
+fn ostrowski_x2_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_ostrowski(0.5f64, &x2_min_1, &x2_min_1_derivative, &mut 1e-15f64)));
+}
+
+fn ostrowski_x4_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_ostrowski(0.5f64, &x4_min_1, &x4_min_1_derivative, &mut 1e-15f64)));
+}
+
+fn halley_x2_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_halley(0.5f64, &x2_min_1, &x2_min_1_derivative, &x2_min_1_second_derivative, &mut 1e-15f64)));
+}
+
+fn halley_x4_min_1_x1000(c: &mut Criterion) {
+    c.bench_function("simple", |b| b.iter(|| find_root_halley(0.5f64, &x4_min_1, &x4_min_1_derivative, &x4_min_1_second_derivative, &mut 1e-15f64)));
+}
+
+fn polynom_degree_100() -> Vec<f64> {
+    (0..100).map(|i| (f64::from(i) * 1.7f64).sin()).collect()
+}
+
+fn eigen_degree_100(c: &mut Criterion) {
+    let polynom = polynom_degree_100();
+    c.bench_function("eigen_degree_100", |b| b.iter(|| find_roots_eigen(&polynom).count()));
+}
+
+#[cfg(feature = "num-complex")]
+fn aberth_degree_100(c: &mut Criterion) {
+    let polynom = polynom_degree_100();
+    c.bench_function("aberth_degree_100", |b| b.iter(|| find_roots_aberth(&polynom, &mut 1e-15f64)));
+}
+
 fn quadratic_x2_min_1_x1000(c: &mut Criterion) {
     c.bench_function("simple", |b| b.iter(|| find_roots_quadratic(1f64, 0f64, -1f64)));
 }
@@ -93,19 +189,111 @@ fn quartic_x4_min_1_x1000(c: &mut Criterion) {
     c.bench_function("simple", |b| b.iter(|| find_roots_quartic(1f64, 0f64, 0f64, 0f64, -1f64)));
 }
 
+fn quartic_batch_coeffs() -> Vec<[f64; 5]> {
+    (0..1000)
+        .map(|i| {
+            let x = f64::from(i) / 1000f64;
+            [1f64, -x, -5f64, x, 4f64]
+        })
+        .collect()
+}
+
+fn quartic_x1000_collected(c: &mut Criterion) {
+    let coeffs = quartic_batch_coeffs();
+    c.bench_function("quartic_x1000_collected", |b| {
+        b.iter(|| {
+            coeffs
+                .iter()
+                .map(|a| find_roots_quartic(a[0], a[1], a[2], a[3], a[4]).as_ref().to_vec())
+                .collect::<Vec<Vec<f64>>>()
+        })
+    });
+}
+
+fn quartic_x1000_batch(c: &mut Criterion) {
+    let coeffs = quartic_batch_coeffs();
+    let mut roots = vec![[0f64; 4]; coeffs.len()];
+    let mut counts = vec![0u8; coeffs.len()];
+    c.bench_function("quartic_x1000_batch", |b| {
+        b.iter(|| find_roots_quartic_batch(&coeffs, &mut roots, &mut counts))
+    });
+}
+
+fn quartic_no_positive_roots_filtered(c: &mut Criterion) {
+    c.bench_function("quartic_no_positive_roots_filtered", |b| {
+        b.iter(|| {
+            find_roots_quartic(1f64, 10f64, 35f64, 50f64, 24f64)
+                .as_ref()
+                .iter()
+                .find(|x| **x > 0f64)
+                .cloned()
+        })
+    });
+}
+
+fn quartic_no_positive_roots_smallest(c: &mut Criterion) {
+    c.bench_function("quartic_no_positive_roots_smallest", |b| {
+        b.iter(|| find_smallest_positive_root_quartic(1f64, 10f64, 35f64, 50f64, 24f64, 0f64))
+    });
+}
+
+// (x+1)(x+2)(x+3)(x-4) has one positive root
+fn quartic_one_positive_root_filtered(c: &mut Criterion) {
+    c.bench_function("quartic_one_positive_root_filtered", |b| {
+        b.iter(|| {
+            find_roots_quartic(1f64, 2f64, -13f64, -38f64, -24f64)
+                .as_ref()
+                .iter()
+                .find(|x| **x > 0f64)
+                .cloned()
+        })
+    });
+}
+
+fn quartic_one_positive_root_smallest(c: &mut Criterion) {
+    c.bench_function("quartic_one_positive_root_smallest", |b| {
+        b.iter(|| find_smallest_positive_root_quartic(1f64, 2f64, -13f64, -38f64, -24f64, 0f64))
+    });
+}
+
 criterion_group!(
     benches,
     quadratic_x2_min_1_x1000,
     biquadratic_x4_min_1_x1000,
     quartic_x4_min_1_x1000,
+    quartic_x1000_collected,
+    quartic_x1000_batch,
+    quartic_no_positive_roots_filtered,
+    quartic_no_positive_roots_smallest,
+    quartic_one_positive_root_filtered,
+    quartic_one_positive_root_smallest,
     secant_x2_min_1_x1000,
     secant_x4_min_1_x1000,
     regula_falsi_x2_min_1_x1000,
     regula_falsi_x4_min_1_x1000,
     brent_x2_min_1_x1000,
     brent_x4_min_1_x1000,
+    ridders_x2_min_1_x1000,
+    ridders_x4_min_1_x1000,
+    chandrupatla_x2_min_1_x1000,
+    chandrupatla_x4_min_1_x1000,
+    dekker_x2_min_1_x1000,
+    dekker_x4_min_1_x1000,
     newton_raphson_x2_min_1_x1000,
-    newton_raphson_x4_min_1_x1000
+    newton_raphson_x4_min_1_x1000,
+    newton_raphson_ln_sin_min_1_x1000,
+    newton_raphson_combined_ln_sin_min_1_x1000,
+    ostrowski_x2_min_1_x1000,
+    ostrowski_x4_min_1_x1000,
+    halley_x2_min_1_x1000,
+    halley_x4_min_1_x1000,
+    eigen_degree_100
 );
 
+#[cfg(feature = "num-complex")]
+criterion_group!(complex_benches, aberth_degree_100);
+
+#[cfg(not(feature = "num-complex"))]
 criterion_main!(benches);
+#[cfg(feature = "num-complex")]
+criterion_main!(benches, complex_benches);
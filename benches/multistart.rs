@@ -0,0 +1,55 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+extern crate criterion;
+extern crate rayon;
+extern crate roots;
+use criterion::{criterion_group, criterion_main, Criterion};
+use roots::find_all_roots;
+use roots::find_roots_multistart;
+
+/// A function expensive enough for the parallel search to pay off, with about 320 roots on [0, 1000]
+fn slow_sin(x: f64) -> f64 {
+    (0..100)
+        .fold(x, |y, i| y + 1e-3f64 * (y * f64::from(i)).cos() * 1e-6f64)
+        .sin()
+}
+
+fn sequential_slow_sin(c: &mut Criterion) {
+    c.bench_function("sequential_slow_sin", |b| {
+        b.iter(|| find_all_roots(slow_sin, 0f64, 1000f64, 1000, &mut 1e-12f64))
+    });
+}
+
+fn multistart_slow_sin(c: &mut Criterion) {
+    for &threads in &[1, 2, 4, 8] {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        c.bench_function(&format!("multistart_slow_sin_{}_threads", threads), |b| {
+            b.iter(|| pool.install(|| find_roots_multistart(slow_sin, 0f64, 1000f64, 1000, &1e-12f64)))
+        });
+    }
+}
+
+criterion_group!(benches, sequential_slow_sin, multistart_slow_sin);
+criterion_main!(benches);
@@ -0,0 +1,131 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use std::error::Error;
+use std::fmt;
+
+/// Possible errors of solving equations in batches
+#[derive(Debug, PartialEq)]
+pub enum BatchError {
+    /// Output slices are shorter than the slice of coefficients
+    OutputTooShort,
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BatchError::OutputTooShort => write!(f, "Output Too Short Error"),
+        }
+    }
+}
+impl Error for BatchError {
+    fn description(&self) -> &str {
+        match self {
+            BatchError::OutputTooShort => "Output slices are shorter than the slice of coefficients",
+        }
+    }
+}
+
+/// Solves quartic equations a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 given as [a4, a3, a2, a1, a0]
+/// and writes results into the caller-provided buffers without allocating memory.
+///
+/// For each equation, out_counts gets the number of roots, and the first elements of out_roots get the roots
+/// returned by find_roots_quartic. Other elements of out_roots are left unchanged.
+/// Returns BatchError::OutputTooShort before solving anything if an output slice is shorter than coeffs.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_quartic_batch;
+///
+/// let coeffs = [[1f64, 0f64, 0f64, 0f64, -1f64], [1f64, -10f64, 35f64, -50f64, 24f64]];
+/// let mut roots = [[0f64; 4]; 2];
+/// let mut counts = [0u8; 2];
+/// find_roots_quartic_batch(&coeffs, &mut roots, &mut counts).unwrap();
+/// // counts are [2, 4]; roots are [[-1, 1, 0, 0], [1, 2, 3, 4]]
+/// ```
+pub fn find_roots_quartic_batch<F: FloatType>(
+    coeffs: &[[F; 5]],
+    out_roots: &mut [[F; 4]],
+    out_counts: &mut [u8],
+) -> Result<(), BatchError> {
+    if out_roots.len() < coeffs.len() || out_counts.len() < coeffs.len() {
+        return Err(BatchError::OutputTooShort);
+    }
+    for ((a, roots), count) in coeffs.iter().zip(out_roots.iter_mut()).zip(out_counts.iter_mut()) {
+        let found = super::quartic::find_roots_quartic(a[0], a[1], a[2], a[3], a[4]);
+        let found = found.as_ref();
+        roots[..found.len()].copy_from_slice(found);
+        *count = found.len() as u8;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_roots_quartic_batch() {
+        let mut seed = 1u32;
+        let mut random = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            f64::from(seed >> 8) / f64::from(1u32 << 24) * 20f64 - 10f64
+        };
+        let mut coeffs = [[0f64; 5]; 1000];
+        for a in coeffs.iter_mut() {
+            *a = [random(), random(), random(), random(), random()];
+        }
+        coeffs[0] = [1f64, 0f64, 0f64, 0f64, -1f64];
+        coeffs[1] = [0f64, 0f64, 0f64, 0f64, 0f64];
+
+        let mut roots = [[0f64; 4]; 1000];
+        let mut counts = [0u8; 1000];
+        assert_eq!(find_roots_quartic_batch(&coeffs, &mut roots, &mut counts), Ok(()));
+        for i in 0..coeffs.len() {
+            let a = coeffs[i];
+            assert_eq!(
+                &roots[i][..counts[i] as usize],
+                find_roots_quartic(a[0], a[1], a[2], a[3], a[4]).as_ref()
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_roots_quartic_batch_output_too_short() {
+        let coeffs = [[1f32, 0f32, 0f32, 0f32, -1f32]; 3];
+        let mut roots = [[0f32; 4]; 3];
+        let mut counts = [0u8; 3];
+        assert_eq!(
+            find_roots_quartic_batch(&coeffs, &mut roots[..2], &mut counts),
+            Err(BatchError::OutputTooShort)
+        );
+        assert_eq!(
+            find_roots_quartic_batch(&coeffs, &mut roots, &mut counts[..2]),
+            Err(BatchError::OutputTooShort)
+        );
+        assert_eq!(counts, [0u8; 3]);
+    }
+}
@@ -28,6 +28,8 @@ use super::super::Roots;
 /// Solves a bi-quadratic equation a4*x^4 + a2*x^2 + a0 = 0.
 ///
 /// Returned roots are arranged in the increasing order.
+/// Roots of the corresponding quadratic equation a4*y^2 + a2*y + a0 = 0 which are within
+/// the relative epsilon of zero (|y| <= epsilon*|a2/a4|) give the root 0.
 ///
 /// # Examples
 ///
@@ -52,14 +54,17 @@ pub fn find_roots_biquadratic<F: FloatType>(a4: F, a2: F, a0: F) -> Roots<F> {
         // a0 = 0; a4*x^4 + a2*x^2 = 0; solve quadratic equation and add zero root
         super::quadratic::find_roots_quadratic(a4, F::zero(), a2).add_new_root(F::zero())
     } else {
-        // solve the corresponding quadratic equation and order roots
+        // solve the corresponding quadratic equation and order roots.
+        // Rounding errors may turn the zero root of the quadratic equation into a tiny negative value;
+        // roots within the relative epsilon of zero are treated as zero.
+        let zero_tolerance = F::epsilon() * (a2 / a4).abs();
         let mut roots = Roots::No([]);
         for x in super::quadratic::find_roots_quadratic(a4, a2, a0).as_ref().iter() {
-            if *x > F::zero() {
+            if x.abs() <= zero_tolerance {
+                roots = roots.add_new_root(F::zero());
+            } else if *x > F::zero() {
                 let sqrt_x = x.sqrt();
                 roots = roots.add_new_root(-sqrt_x).add_new_root(sqrt_x);
-            } else if *x == F::zero() {
-                roots = roots.add_new_root(F::zero());
             }
         }
         roots
@@ -80,4 +85,36 @@ mod test {
             Roots::Four([-2f64, -1f64, 1f64, 2f64])
         );
     }
+
+    #[test]
+    fn test_find_roots_biquadratic_rounded_zero_root() {
+        // (x^2 - 2)(x^2 - s), where s = 0.3 - 0.1 - 0.2 is a rounded zero
+        let s = 0.3f64 - 0.1f64 - 0.2f64;
+        assert!(s < 0f64);
+        match find_roots_biquadratic(1f64, -(2f64 + s), 2f64 * s) {
+            Roots::Three(x) => {
+                assert_float_array_eq!(1e-15, x, [-2f64.sqrt(), 0f64, 2f64.sqrt()]);
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+
+        // -3(x^2 + 5)(x^2 - s) has only the root 0
+        assert_eq!(
+            find_roots_biquadratic(-3f64, -3f64 * (5f64 - s), 15f64 * s),
+            Roots::One([0f64])
+        );
+
+        // In f32, the rounded zero is tiny positive
+        let s = 0.3f32 - 0.1f32 - 0.2f32;
+        match find_roots_biquadratic(1f32, -(2f32 + s), 2f32 * s) {
+            Roots::Three(x) => {
+                assert_float_array_eq!(1e-6, x, [-2f32.sqrt(), 0f32, 2f32.sqrt()]);
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
 }
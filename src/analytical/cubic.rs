@@ -25,6 +25,27 @@
 use super::super::FloatType;
 use super::super::Roots;
 
+/// Calculates the discriminant 18*a3*a2*a1*a0 - 4*a2^3*a0 + a2^2*a1^2 - 4*a3*a1^3 - 27*a3^2*a0^2
+/// of the cubic equation a3*x^3 + a2*x^2 + a1*x + a0 = 0.
+///
+/// The discriminant is positive for three distinct real roots, negative for one real root and two complex ones,
+/// and zero for multiple roots. find_roots_cubic uses the same value in the general case.
+///
+/// # Examples
+///
+/// ```
+/// use roots::discriminant_cubic;
+///
+/// let discriminant = discriminant_cubic(1f64, 0f64, -1f64, 0f64);
+/// // Returns 4f64 for 'x^3 - x = 0' with roots -1, 0, and 1
+/// ```
+pub fn discriminant_cubic<F: FloatType>(a3: F, a2: F, a1: F, a0: F) -> F {
+    let _4 = F::from(4i16);
+    let _18 = F::from(18i16);
+    let _27 = F::from(27i16);
+    _18 * a3 * a2 * a1 * a0 - _4 * a2 * a2 * a2 * a0 + a2 * a2 * a1 * a1 - _4 * a3 * a1 * a1 * a1 - _27 * a3 * a3 * a0 * a0
+}
+
 /// Solves a cubic equation a3*x^3 + a2*x^2 + a1*x + a0 = 0.
 ///
 /// General formula (complex numbers) is implemented for three roots.
@@ -58,7 +79,7 @@ use super::super::Roots;
 /// ```
 pub fn find_roots_cubic<F: FloatType>(a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
     // Handle non-standard cases
-    if a3 == F::zero() {
+    let roots = if a3 == F::zero() {
         // a3 = 0; a2*x^2+a1*x+a0=0; solve quadratic equation
         super::quadratic::find_roots_quadratic(a2, a1, a0)
     } else if a2 == F::zero() {
@@ -72,13 +93,10 @@ pub fn find_roots_cubic<F: FloatType>(a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
         let _3 = F::from(3i16);
         let _4 = F::from(4i16);
         let _9 = F::from(9i16);
-        let _18 = F::from(18i16);
         let _27 = F::from(27i16);
 
         // standard case
-        let d = _18 * a3 * a2 * a1 * a0 - _4 * a2 * a2 * a2 * a0 + a2 * a2 * a1 * a1
-            - _4 * a3 * a1 * a1 * a1
-            - _27 * a3 * a3 * a0 * a0;
+        let d = discriminant_cubic(a3, a2, a1, a0);
         let d0 = a2 * a2 - _3 * a3 * a1;
         let d1 = _2 * a2 * a2 * a2 - _9 * a3 * a2 * a1 + _27 * a3 * a3 * a0;
         if d < F::zero() {
@@ -121,9 +139,126 @@ pub fn find_roots_cubic<F: FloatType>(a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
 
             Roots::One([x0_real]).add_new_root(x1_real).add_new_root(x2_real)
         }
+    };
+
+    #[cfg(all(feature = "debug-exact", debug_assertions))]
+    super::exact::assert_classification(
+        "find_roots_cubic",
+        &[a3.to_f64(), a2.to_f64(), a1.to_f64(), a0.to_f64()],
+        roots.as_ref().len(),
+        super::exact::count_real_roots_cubic_exact(a3.to_f64(), a2.to_f64(), a1.to_f64(), a0.to_f64()),
+    );
+    roots
+}
+
+/// Solves a cubic equation keeping the exact zero root when the free term is zero
+pub fn find_roots_cubic_or_deflated<F: FloatType>(a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
+    if a0 == F::zero() {
+        super::quadratic::find_roots_quadratic(a3, a2, a1).add_new_root(F::zero())
+    } else {
+        find_roots_cubic(a3, a2, a1, a0)
+    }
+}
+
+/// Counts distinct real roots of a depressed cubic equation x^3 + a1*x + a0 = 0 like find_roots_cubic_depressed.
+fn count_real_roots_cubic_depressed<F: FloatType>(a1: F, a0: F) -> usize {
+    if a1 == F::zero() {
+        1
+    } else if a0 == F::zero() {
+        if a1 < F::zero() {
+            3
+        } else {
+            1
+        }
+    } else {
+        let d = a0 * a0 / F::from(4i16) + a1 * a1 * a1 / F::from(27i16);
+        if d < F::zero() {
+            3
+        } else if d == F::zero() {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+/// Counts distinct real roots of a normalized cubic equation x^3 + a2*x^2 + a1*x + a0 = 0 like find_roots_cubic_normalized.
+fn count_real_roots_cubic_normalized<F: FloatType>(a2: F, a1: F, a0: F) -> usize {
+    let _2 = F::from(2i16);
+    let _3 = F::from(3i16);
+    let _9 = F::from(9i16);
+    let _27 = F::from(27i16);
+    let _54 = F::from(54i16);
+
+    let q = (_3 * a1 - a2 * a2) / _9;
+    let r = (_9 * a2 * a1 - _27 * a0 - _2 * a2 * a2 * a2) / _54;
+    let d = q * q * q + r * r;
+    if d < F::zero() {
+        3
+    } else {
+        // Close roots merge when cubic roots are equal, so compare them the same way
+        let sqrt_d = d.sqrt();
+        let (s, t) = ((r + sqrt_d).cbrt(), (r - sqrt_d).cbrt());
+        if s == t && s + t != F::zero() {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+/// Counts distinct real roots of a cubic equation a3*x^3 + a2*x^2 + a1*x + a0 = 0 without finding them.
+///
+/// Only the sign of the discriminant is calculated. The result is the same as the length of roots
+/// returned by find_roots_cubic unless the discriminant is close to zero, where rounding errors decide.
+///
+/// # Examples
+///
+/// ```
+/// use roots::count_real_roots_cubic;
+///
+/// let count = count_real_roots_cubic(1f64, 0f64, -1f64, 0f64);
+/// // Returns 3 as 'x^3 - x = 0' has roots -1, 0, and 1
+/// ```
+pub fn count_real_roots_cubic<F: FloatType>(a3: F, a2: F, a1: F, a0: F) -> usize {
+    if a3 == F::zero() {
+        super::quadratic::count_real_roots_quadratic(a2, a1, a0)
+    } else if a2 == F::zero() {
+        count_real_roots_cubic_depressed(a1 / a3, a0 / a3)
+    } else if a3 == F::one() {
+        count_real_roots_cubic_normalized(a2, a1, a0)
+    } else {
+        let d = discriminant_cubic(a3, a2, a1, a0);
+        if d < F::zero() {
+            1
+        } else if d == F::zero() {
+            if a2 * a2 - F::from(3i16) * a3 * a1 == F::zero() {
+                1
+            } else {
+                2
+            }
+        } else {
+            3
+        }
     }
 }
 
+/// Checks if a cubic equation a3*x^3 + a2*x^2 + a1*x + a0 = 0 has real roots without finding them.
+///
+/// Every cubic equation with a3 != 0 has at least one real root.
+///
+/// # Examples
+///
+/// ```
+/// use roots::has_real_roots_cubic;
+///
+/// let has_roots = has_real_roots_cubic(0f64, 1f64, 0f64, 1f64);
+/// // Returns false as 'x^2 + 1 = 0' has no real roots
+/// ```
+pub fn has_real_roots_cubic<F: FloatType>(a3: F, a2: F, a1: F, a0: F) -> bool {
+    a3 != F::zero() || super::quadratic::has_real_roots_quadratic(a2, a1, a0)
+}
+
 #[cfg(test)]
 mod test {
     use super::super::super::*;
@@ -162,4 +297,47 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_discriminant_cubic() {
+        assert_eq!(discriminant_cubic(1f64, 0f64, -1f64, 0f64), 4f64);
+        // (x-1)^2*(x+2)
+        assert_eq!(discriminant_cubic(1f64, 0f64, -3f64, 2f64), 0f64);
+        assert_eq!(discriminant_cubic(1f64, 0f64, 1f64, 1f64), -31f64);
+        assert_eq!(discriminant_cubic(2f32, -6f32, 4f32, 3f32), -908f32);
+
+        // The discriminant scales by the fourth power of the coefficient scale
+        for scale in [2f64.powi(-20), 0.5f64, 1024f64].iter() {
+            let s = *scale;
+            assert_eq!(
+                discriminant_cubic(2f64 * s, -6f64 * s, 4f64 * s, 3f64 * s),
+                -908f64 * s * s * s * s
+            );
+            assert_eq!(discriminant_cubic(s, 0f64, -3f64 * s, 2f64 * s), 0f64);
+        }
+    }
+
+    #[test]
+    fn test_count_real_roots_cubic() {
+        assert_eq!(count_real_roots_cubic(1f32, 0f32, 0f32, 0f32), 1);
+        assert_eq!(count_real_roots_cubic(1f64, 0f64, -3f64, 2f64), 2);
+        assert_eq!(count_real_roots_cubic(2f64, -6f64, 4f64, 0f64), 3);
+        assert!(!has_real_roots_cubic(0f64, 1f64, 0f64, 1f64));
+        assert!(has_real_roots_cubic(1f64, 0f64, 1f64, 1f64));
+
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            f64::from(seed >> 8) / f64::from(1u32 << 24) * 20f64 - 10f64
+        };
+        for i in 0..3000 {
+            // Cover normalized and depressed cubic equations too
+            let a3 = if i % 3 == 0 { 1f64 } else { next() };
+            let a2 = if i % 3 == 1 { 0f64 } else { next() };
+            let (a1, a0) = (next(), next());
+            let count = find_roots_cubic(a3, a2, a1, a0).as_ref().len();
+            assert_eq!(count_real_roots_cubic(a3, a2, a1, a0), count);
+            assert_eq!(has_real_roots_cubic(a3, a2, a1, a0), count > 0);
+        }
+    }
 }
@@ -0,0 +1,160 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::numerical::eigen::find_roots_eigen;
+use super::super::FloatType;
+
+/// Improves the root y of the polynomial c[0]*y^n + c[1]*y^(n-1) + ... + c[n] by the Newton-Raphson method.
+fn polish_root<F: FloatType>(c: &[F], y: F) -> F {
+    let mut y = y;
+    for _ in 0..2 {
+        let (value, derivative) = c.iter().fold((F::zero(), F::zero()), |(value, derivative), a| {
+            (value * y + *a, derivative * y + value)
+        });
+        if derivative == F::zero() {
+            break;
+        }
+        y = y - value / derivative;
+    }
+    y
+}
+
+/// Solves an even polynomial equation c[0]*x^(2n) + c[1]*x^(2n-2) + ... + c[n-1]*x^2 + c[n] = 0
+/// given coefficients of the polynomial in y = x^2.
+///
+/// The polynomial equation in y is solved by analytical methods for n <= 4 and by find_roots_eigen otherwise.
+/// Every non-negative root y gives roots -sqrt(y) and sqrt(y). Like find_roots_biquadratic does,
+/// roots y within the relative epsilon of zero give the single root 0.
+///
+/// Returned roots are arranged in the increasing order.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_even_polynomial;
+///
+/// let roots = find_roots_even_polynomial(&[1f64, -14f64, 49f64, -36f64]);
+/// // Returns vec![-3f64, -2f64, -1f64, 1f64, 2f64, 3f64] as 'x^6 - 14x^4 + 49x^2 - 36 = 0' has these roots
+/// ```
+pub fn find_roots_even_polynomial<F: FloatType>(coeffs_of_y: &[F]) -> Vec<F> {
+    // Skip leading zeros
+    let c: &[F] = match coeffs_of_y.iter().position(|c| *c != F::zero()) {
+        Some(first) => &coeffs_of_y[first..],
+        None => &coeffs_of_y[coeffs_of_y.len().saturating_sub(1)..],
+    };
+
+    // Zero constant terms give the root y = 0; remove them to find other roots
+    let mut y_roots: Vec<F> = Vec::new();
+    let mut c = c;
+    while c.len() > 1 && c[c.len() - 1] == F::zero() {
+        c = &c[..c.len() - 1];
+        if y_roots.is_empty() {
+            y_roots.push(F::zero());
+        }
+    }
+
+    y_roots.extend(match c.len() {
+        0 => Vec::new(),
+        1..=3 => {
+            // Up to the bi-quadratic equation; let it expand roots
+            let mut padded = [F::zero(); 3];
+            padded[3 - c.len()..].copy_from_slice(c);
+            let mut roots = super::biquadratic::find_roots_biquadratic(padded[0], padded[1], padded[2]);
+            if !y_roots.is_empty() {
+                roots = roots.add_new_root(F::zero());
+            }
+            return roots.as_ref().to_vec();
+        }
+        4 => super::cubic::find_roots_cubic(c[0], c[1], c[2], c[3]).as_ref().to_vec(),
+        5 => super::quartic::find_roots_quartic(c[0], c[1], c[2], c[3], c[4])
+            .as_ref()
+            .to_vec(),
+        _ => {
+            // Eigenvalues are found in f64 and polished by the Newton-Raphson method
+            let normalized: Vec<f64> = c[1..].iter().map(|a| (*a / c[0]).to_f64()).collect();
+            find_roots_eigen(&normalized)
+                .map(|y| polish_root(c, F::from_f64(y)))
+                .collect::<Vec<F>>()
+        }
+    });
+
+    let zero_tolerance = F::epsilon()
+        * y_roots
+            .iter()
+            .fold(F::zero(), |max, y| if y.abs() > max { y.abs() } else { max });
+    let mut roots = Vec::new();
+    for y in y_roots.iter() {
+        if y.abs() <= zero_tolerance {
+            roots.push(F::zero());
+        } else if *y > F::zero() {
+            let sqrt_y = y.sqrt();
+            roots.push(-sqrt_y);
+            roots.push(sqrt_y);
+        }
+    }
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots.dedup();
+    roots
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_roots_even_polynomial() {
+        assert_eq!(find_roots_even_polynomial::<f64>(&[]), Vec::<f64>::new());
+        assert_eq!(find_roots_even_polynomial(&[0f64, 0f64]), vec![0f64]);
+        assert_eq!(find_roots_even_polynomial(&[1f64, 0f64, -1f64]), vec![-1f64, 1f64]);
+        assert_eq!(
+            find_roots_even_polynomial(&[0f32, 1f32, -5f32, 4f32]),
+            find_roots_biquadratic(1f32, -5f32, 4f32).as_ref().to_vec()
+        );
+
+        // (x^2 - 1)(x^2 - 4)(x^2 - 9)
+        assert_float_array_eq!(
+            1e-14,
+            find_roots_even_polynomial(&[1f64, -14f64, 49f64, -36f64]),
+            [-3f64, -2f64, -1f64, 1f64, 2f64, 3f64]
+        );
+        // x^2 (x^2 + 1)(x^2 - 2)
+        assert_float_array_eq!(
+            1e-15,
+            find_roots_even_polynomial(&[1f64, -1f64, -2f64, 0f64]),
+            [-2f64.sqrt(), 0f64, 2f64.sqrt()]
+        );
+        // (x^2 - 1)(x^2 - 4)(x^2 - 9)(x^2 + 1)
+        assert_float_array_eq!(
+            1e-14,
+            find_roots_even_polynomial(&[1f64, -13f64, 35f64, 13f64, -36f64]),
+            [-3f64, -2f64, -1f64, 1f64, 2f64, 3f64]
+        );
+        // (x^2 - 0.25)(x^2 - 1)(x^2 - 4)(x^2 - 9)(x^2 + 4) is solved via eigenvalues
+        assert_float_array_eq!(
+            1e-15,
+            find_roots_even_polynomial(&[1f64, -10.25f64, -4.5f64, 161.75f64, -184f64, 36f64]),
+            [-3f64, -2f64, -1f64, -0.5f64, 0.5f64, 1f64, 2f64, 3f64]
+        );
+    }
+}
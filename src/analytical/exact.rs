@@ -0,0 +1,243 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Exact cross-check of the classification of roots, enabled by the `debug-exact` feature.
+//
+// Floating-point values are exact binary fractions, so discriminants and other invariants of
+// equations can be calculated exactly. In debug builds, analytical solvers compare the number
+// of real roots they have found with the exact number and panic on mismatch.
+
+use num_bigint::BigInt;
+use std::cmp::Ordering;
+
+/// Mantissa and exponent of a finite floating-point number x = m * 2^e
+fn decompose(x: f64) -> Option<(i64, i32)> {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = (bits & ((1u64 << 52) - 1)) as i64;
+    let (mantissa, exponent) = match exponent {
+        0x7ff => return None,
+        0 => (fraction, -1074),
+        _ => (fraction | (1i64 << 52), exponent - 1075),
+    };
+    Some((if x < 0f64 { -mantissa } else { mantissa }, exponent))
+}
+
+/// Exact values of coefficients multiplied by the common power of two which makes all of them integers.
+/// Invariants of equations are homogeneous polynomials of coefficients, so their signs are not changed.
+fn exact(coeffs: &[f64]) -> Option<Vec<BigInt>> {
+    let decomposed = coeffs.iter().map(|c| decompose(*c)).collect::<Option<Vec<(i64, i32)>>>()?;
+    let min_exponent = decomposed.iter().map(|&(_, e)| e).min().unwrap_or(0);
+    Some(
+        decomposed
+            .iter()
+            .map(|&(m, e)| BigInt::from(m) << ((e - min_exponent) as usize))
+            .collect(),
+    )
+}
+
+fn int(n: i64) -> BigInt {
+    BigInt::from(n)
+}
+
+fn sign(x: &BigInt) -> Ordering {
+    x.cmp(&int(0))
+}
+
+fn is_zero(x: &BigInt) -> bool {
+    sign(x) == Ordering::Equal
+}
+
+fn is_negative(x: &BigInt) -> bool {
+    sign(x) == Ordering::Less
+}
+
+fn count_linear(a1: &BigInt, a0: &BigInt) -> usize {
+    // Like find_roots_linear, 0 = 0 has the single root 0
+    if !is_zero(a1) || is_zero(a0) {
+        1
+    } else {
+        0
+    }
+}
+
+fn count_quadratic(a2: &BigInt, a1: &BigInt, a0: &BigInt) -> usize {
+    if is_zero(a2) {
+        count_linear(a1, a0)
+    } else {
+        match sign(&(a1 * a1 - int(4) * a2 * a0)) {
+            Ordering::Less => 0,
+            Ordering::Equal => 1,
+            Ordering::Greater => 2,
+        }
+    }
+}
+
+fn count_cubic(a3: &BigInt, a2: &BigInt, a1: &BigInt, a0: &BigInt) -> usize {
+    if is_zero(a3) {
+        count_quadratic(a2, a1, a0)
+    } else {
+        let d = int(18) * a3 * a2 * a1 * a0 - int(4) * a2 * a2 * a2 * a0 + a2 * a2 * a1 * a1
+            - int(4) * a3 * a1 * a1 * a1
+            - int(27) * a3 * a3 * a0 * a0;
+        match sign(&d) {
+            Ordering::Less => 1,
+            Ordering::Equal if is_zero(&(a2 * a2 - int(3) * a3 * a1)) => 1,
+            Ordering::Equal => 2,
+            Ordering::Greater => 3,
+        }
+    }
+}
+
+fn count_quartic(a: &BigInt, b: &BigInt, c: &BigInt, d: &BigInt, e: &BigInt) -> usize {
+    if is_zero(a) {
+        return count_cubic(b, c, d, e);
+    }
+    let discriminant = int(256) * a * a * a * e * e * e - int(192) * a * a * b * d * e * e - int(128) * a * a * c * c * e * e
+        + int(144) * a * a * c * d * d * e
+        - int(27) * a * a * d * d * d * d
+        + int(144) * a * b * b * c * e * e
+        - int(6) * a * b * b * d * d * e
+        - int(80) * a * b * c * c * d * e
+        + int(18) * a * b * c * d * d * d
+        + int(16) * a * c * c * c * c * e
+        - int(4) * a * c * c * c * d * d
+        - int(27) * b * b * b * b * e * e
+        + int(18) * b * b * b * c * d * e
+        - int(4) * b * b * b * d * d * d
+        - int(4) * b * b * c * c * c * e
+        + b * b * c * c * d * d;
+    let pp = int(8) * a * c - int(3) * b * b;
+    let rr = b * b * b + int(8) * a * a * d - int(4) * a * b * c;
+    let dd = int(64) * a * a * a * e - int(16) * a * a * c * c + int(16) * a * b * b * c
+        - int(16) * a * a * b * d
+        - int(3) * b * b * b * b;
+    let delta0 = c * c - int(3) * b * d + int(12) * a * e;
+
+    // See https://en.wikipedia.org/wiki/Quartic_function#Nature_of_the_roots
+    match sign(&discriminant) {
+        Ordering::Greater if is_negative(&pp) && is_negative(&dd) => 4,
+        Ordering::Greater => 0,
+        Ordering::Less => 2,
+        Ordering::Equal if is_zero(&delta0) && is_zero(&dd) => 1,
+        Ordering::Equal if is_zero(&delta0) => 2,
+        Ordering::Equal if is_zero(&dd) && is_negative(&pp) => 2,
+        Ordering::Equal if is_zero(&dd) && sign(&pp) == Ordering::Greater && is_zero(&rr) => 0,
+        Ordering::Equal if is_negative(&pp) && is_negative(&dd) => 3,
+        Ordering::Equal => 1,
+    }
+}
+
+/// Counts distinct real roots of a2*x^2 + a1*x + a0 = 0 in exact arithmetic. Returns None for non-finite coefficients.
+pub fn count_real_roots_quadratic_exact(a2: f64, a1: f64, a0: f64) -> Option<usize> {
+    let c = exact(&[a2, a1, a0])?;
+    Some(count_quadratic(&c[0], &c[1], &c[2]))
+}
+
+/// Counts distinct real roots of a3*x^3 + a2*x^2 + a1*x + a0 = 0 in exact arithmetic. Returns None for non-finite coefficients.
+pub fn count_real_roots_cubic_exact(a3: f64, a2: f64, a1: f64, a0: f64) -> Option<usize> {
+    let c = exact(&[a3, a2, a1, a0])?;
+    Some(count_cubic(&c[0], &c[1], &c[2], &c[3]))
+}
+
+/// Counts distinct real roots of a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 in exact arithmetic.
+/// Returns None for non-finite coefficients.
+pub fn count_real_roots_quartic_exact(a4: f64, a3: f64, a2: f64, a1: f64, a0: f64) -> Option<usize> {
+    let c = exact(&[a4, a3, a2, a1, a0])?;
+    Some(count_quartic(&c[0], &c[1], &c[2], &c[3], &c[4]))
+}
+
+/// Panics if the number of found roots differs from the exact number of distinct real roots
+pub fn assert_classification(solver: &str, coeffs: &[f64], found: usize, exact: Option<usize>) {
+    if let Some(exact) = exact {
+        assert!(
+            found == exact,
+            "{} misclassified {:?}: found {} real roots, exact arithmetic gives {}",
+            solver,
+            coeffs,
+            found,
+            exact
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_count_real_roots_exact() {
+        assert_eq!(count_real_roots_quadratic_exact(1f64, -2f64, 1f64), Some(1));
+        assert_eq!(count_real_roots_quadratic_exact(0f64, 0f64, 0f64), Some(1));
+        assert_eq!(count_real_roots_quadratic_exact(1f64, f64::NAN, 1f64), None);
+        // Coefficients of very different magnitudes are scaled exactly
+        assert_eq!(count_real_roots_quadratic_exact(1e300f64, 0f64, -5e-324f64), Some(2));
+        assert_eq!(count_real_roots_quadratic_exact(-1e300f64, 0f64, -5e-324f64), Some(0));
+        assert_eq!(count_real_roots_cubic_exact(1f64, -3f64, 3f64, -1f64), Some(1));
+        assert_eq!(count_real_roots_cubic_exact(1f64, 0f64, -3f64, 2f64), Some(2));
+        // Luecke's quartic has two double roots, and its exact coefficients keep them
+        assert_eq!(
+            count_real_roots_quartic_exact(-14.0625f64, -3.75f64, 29.75f64, 4f64, -16f64),
+            Some(2)
+        );
+        assert_eq!(count_real_roots_quartic_exact(1f64, -10f64, 35f64, -50f64, 24f64), Some(4));
+        assert_eq!(count_real_roots_quartic_exact(1f64, 0f64, 2f64, 0f64, 1f64), Some(0));
+        assert_eq!(count_real_roots_quartic_exact(1f64, -2f64, 2f64, -2f64, 1f64), Some(1));
+    }
+
+    #[test]
+    fn test_find_roots_quadratic_exact() {
+        // (x - 0.1)^2 with rounded coefficients has two close roots, and the compensated discriminant finds both
+        assert_eq!(find_roots_quadratic(1f64, -0.2f64, 0.01f64).as_ref().len(), 2);
+        assert_eq!(find_roots_quadratic(1f64, -0.6f64, 0.09f64).as_ref().len(), 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "find_roots_cubic misclassified")]
+    fn test_find_roots_cubic_misclassified() {
+        // (x - 0.1)(x - 0.2)^2 with rounded coefficients has one real root and two complex ones,
+        // but the floating-point discriminant is positive, and three real roots are found
+        find_roots_cubic(1f64, -0.5f64, 0.08f64, -0.004f64);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "find_roots_quartic misclassified")]
+    fn test_find_roots_quartic_misclassified_double_root() {
+        // (x - 1)(x + 1)(x - 0.1)^2 with rounded coefficients has four real roots,
+        // but the double root is lost as a pair of complex ones
+        find_roots_quartic(1f64, -0.2f64, -0.99f64, 0.2f64, -0.01f64);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "find_roots_quartic misclassified")]
+    fn test_find_roots_quartic_misclassified_two_double_roots() {
+        // (x - 0.1)^2 (x - 0.2)^2 with rounded coefficients has no real roots,
+        // but two double roots are found
+        find_roots_quartic(1f64, -0.6f64, 0.13f64, -0.012f64, 0.0004f64);
+    }
+}
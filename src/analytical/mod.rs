@@ -22,12 +22,25 @@
 // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod batch;
 pub mod biquadratic;
 pub mod cubic;
 pub mod cubic_depressed;
 pub mod cubic_normalized;
+pub mod even_polynomial;
+#[cfg(feature = "debug-exact")]
+pub mod exact;
 pub mod linear;
+pub mod palindromic;
+pub mod polynomial;
 pub mod quadratic;
 pub mod quartic;
+#[cfg(feature = "num-complex")]
+pub mod quartic_complex;
 pub mod quartic_depressed;
+pub mod quartic_refined;
+pub mod quintic;
+pub mod rational;
 pub mod roots;
+pub mod spline;
+pub mod verify;
@@ -0,0 +1,265 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::double_double::DoubleDouble;
+use super::super::FloatType;
+use super::super::Roots;
+
+/// Adds roots of x^2 - z*x + sign = 0 for every given z
+fn add_roots_of_reciprocal_pairs<F: FloatType>(roots: Roots<F>, z_roots: &Roots<F>, sign: F) -> Roots<F> {
+    let mut roots = roots;
+    for z in z_roots.as_ref().iter() {
+        for x in super::quadratic::find_roots_quadratic(F::one(), -*z, sign).as_ref().iter() {
+            roots = roots.add_new_root(*x);
+        }
+    }
+    roots
+}
+
+/// Finds roots x and 1/x of x^2 - z*x + 1 = 0 given the discriminant z^2 - 4
+fn reciprocal_pair<F: FloatType>(z: F, discriminant: F) -> Option<(F, F)> {
+    if discriminant < F::zero() {
+        None
+    } else {
+        let sqrt_discriminant = discriminant.sqrt();
+        // The greatest absolute value does not lose precision
+        let x = if z < F::zero() {
+            (z - sqrt_discriminant) / F::from(2i16)
+        } else {
+            (z + sqrt_discriminant) / F::from(2i16)
+        };
+        Some((x, F::one() / x))
+    }
+}
+
+/// Finds all roots x of x^2 - z*x + 1 = 0 where z = w + 2 for every w >= -2 and z = v - 2 for every v < 2
+fn reciprocal_pairs<F: FloatType>(w_roots: &[F], v_roots: &[F]) -> Vec<(F, F)> {
+    let _2 = F::from(2i16);
+    let _4 = F::from(4i16);
+    // z^2 - 4 = w*(w + 4) = v*(v - 4) does not lose precision when z is close to 2 or -2
+    let w_pairs = w_roots
+        .iter()
+        .filter(|w| **w >= -_2)
+        .filter_map(|w| reciprocal_pair(*w + _2, *w * (*w + _4)));
+    let v_pairs = v_roots
+        .iter()
+        .filter(|v| **v < _2)
+        .filter_map(|v| reciprocal_pair(*v - _2, *v * (*v - _4)));
+    w_pairs.chain(v_pairs).collect()
+}
+
+/// Solves a palindromic quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a3*x + a4 = 0.
+///
+/// Roots come in pairs (r, 1/r). The substitution z = x + 1/x gives the quadratic equation
+/// a4*z^2 + a3*z + (a2 - 2*a4) = 0, and every its root z gives roots of x^2 - z*x + 1 = 0.
+/// That is much better conditioned than the general quartic solver for roots near 1 or -1.
+///
+/// Returned roots are ordered.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_quartic_palindromic;
+///
+/// let four_roots = find_roots_quartic_palindromic(1f64, -6.75f64, 12.625f64);
+/// // Returns Roots::Four([0.25f64, 0.5f64, 2f64, 4f64])
+/// ```
+pub fn find_roots_quartic_palindromic<F: FloatType>(a4: F, a3: F, a2: F) -> Roots<F> {
+    if a4 == F::zero() {
+        // x*(a3*x^2 + a2*x + a3) = 0
+        super::quadratic::find_roots_quadratic(a3, a2, a3).add_new_root(F::zero())
+    } else {
+        let _4 = F::from(4i16);
+        // Roots are close to 1 or -1 when z is close to 2 or -2, so the equation is solved for w = z - 2 and for v = z + 2.
+        // Free terms are values at x = 1 and x = -1; they are calculated in double-double precision to avoid cancellation
+        let (dd_a4, dd_a3, dd_a2) = (DoubleDouble::from(a4), DoubleDouble::from(a3), DoubleDouble::from(a2));
+        let w_a0 = (DoubleDouble::from(2i16) * (dd_a4 + dd_a3) + dd_a2).value();
+        let v_a0 = (DoubleDouble::from(2i16) * (dd_a4 - dd_a3) + dd_a2).value();
+        let w_roots = super::quadratic::find_roots_quadratic(a4, _4 * a4 + a3, w_a0);
+        let v_roots = super::quadratic::find_roots_quadratic(a4, a3 - _4 * a4, v_a0);
+        let mut roots = Roots::No([]);
+        for (x1, x2) in reciprocal_pairs(w_roots.as_ref(), v_roots.as_ref()) {
+            roots = roots.add_new_root(x1).add_new_root(x2);
+        }
+        roots
+    }
+}
+
+/// Solves an anti-palindromic quartic equation a4*x^4 + a3*x^3 + a2*x^2 - a3*x + a4 = 0.
+///
+/// Roots come in pairs (r, -1/r). The substitution z = x - 1/x gives the quadratic equation
+/// a4*z^2 + a3*z + (a2 + 2*a4) = 0, and every its root z gives roots of x^2 - z*x - 1 = 0.
+///
+/// Returned roots are ordered.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_quartic_anti_palindromic;
+///
+/// let four_roots = find_roots_quartic_anti_palindromic(1f64, -2.25f64, -7.625f64);
+/// // Returns Roots::Four([-2f64, -0.25f64, 0.5f64, 4f64])
+/// ```
+pub fn find_roots_quartic_anti_palindromic<F: FloatType>(a4: F, a3: F, a2: F) -> Roots<F> {
+    let _2 = F::from(2i16);
+    if a4 == F::zero() {
+        // x*(a3*x^2 + a2*x - a3) = 0
+        super::quadratic::find_roots_quadratic(a3, a2, -a3).add_new_root(F::zero())
+    } else {
+        let z_roots = super::quadratic::find_roots_quadratic(a4, a3, a2 + _2 * a4);
+        add_roots_of_reciprocal_pairs(Roots::No([]), &z_roots, -F::one())
+    }
+}
+
+/// Solves a palindromic sextic equation a6*x^6 + a5*x^5 + a4*x^4 + a3*x^3 + a4*x^2 + a5*x + a6 = 0.
+///
+/// Roots come in pairs (r, 1/r). The substitution z = x + 1/x gives the cubic equation
+/// a6*z^3 + a5*z^2 + (a4 - 3*a6)*z + (a3 - 2*a5) = 0, and every its root z gives roots of x^2 - z*x + 1 = 0.
+///
+/// Returns a vector of ordered roots.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_sextic_palindromic;
+///
+/// let roots = find_roots_sextic_palindromic(1f64, -8.75f64, 27.125f64, -38.75f64);
+/// // Returns vec![0.25f64, 0.5f64, 1f64, 2f64, 4f64] as z = x + 1/x is 2.5, 4.25, or 2
+/// ```
+pub fn find_roots_sextic_palindromic<F: FloatType>(a6: F, a5: F, a4: F, a3: F) -> Vec<F> {
+    let mut roots = Vec::new();
+    if a6 == F::zero() {
+        // x*(a5*x^4 + a4*x^3 + a3*x^2 + a4*x + a5) = 0
+        roots.push(F::zero());
+        roots.extend_from_slice(find_roots_quartic_palindromic(a5, a4, a3).as_ref());
+    } else {
+        let _4 = F::from(4i16);
+        let _6 = F::from(6i16);
+        let _9 = F::from(9i16);
+        // The cubic equation is solved for w = z - 2 and for v = z + 2 as in the quartic case
+        let (dd_a6, dd_a5, dd_a4) = (DoubleDouble::from(a6), DoubleDouble::from(a5), DoubleDouble::from(a4));
+        let w_a0 = (DoubleDouble::from(2i16) * (dd_a6 + dd_a5 + dd_a4) + DoubleDouble::from(a3)).value();
+        let v_a0 = (DoubleDouble::from(2i16) * (dd_a5 - dd_a6 - dd_a4) + DoubleDouble::from(a3)).value();
+        let w_roots = super::cubic::find_roots_cubic_or_deflated(a6, _6 * a6 + a5, _9 * a6 + _4 * a5 + a4, w_a0);
+        let v_roots = super::cubic::find_roots_cubic_or_deflated(a6, a5 - _6 * a6, _9 * a6 - _4 * a5 + a4, v_a0);
+        for (x1, x2) in reciprocal_pairs(w_roots.as_ref(), v_roots.as_ref()) {
+            roots.push(x1);
+            roots.push(x2);
+        }
+    }
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots.dedup();
+    roots
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_roots_quartic_palindromic() {
+        assert_eq!(
+            find_roots_quartic_palindromic(1f64, -6.75f64, 12.625f64),
+            Roots::Four([0.25f64, 0.5f64, 2f64, 4f64])
+        );
+        assert_eq!(find_roots_quartic_palindromic(1f64, 1f64, 1f64), Roots::No([]));
+        // (x - 1)^4
+        assert_eq!(find_roots_quartic_palindromic(1f64, -4f64, 6f64), Roots::One([1f64]));
+        // x*(x^2 - 2.5x + 1)
+        assert_eq!(
+            find_roots_quartic_palindromic(0f64, 1f64, -2.5f64),
+            Roots::Three([0f64, 0.5f64, 2f64])
+        );
+        assert_eq!(
+            find_roots_quartic_palindromic(1f32, -6.75f32, 12.625f32),
+            Roots::Four([0.25f32, 0.5f32, 2f32, 4f32])
+        );
+    }
+
+    #[test]
+    fn test_find_roots_quartic_palindromic_close_to_one() {
+        // Reference roots are calculated with 128-bit precision
+        match find_roots_quartic_palindromic(1f64, -9.89001999407435e-7f64, -2.000002018000008f64) {
+            Roots::Four(x) => {
+                assert_float_array_eq!(
+                    1e-15f64,
+                    x,
+                    [
+                        -1.000099999998477635f64,
+                        -0.99990001000052216058f64,
+                        0.99900099900089805212f64,
+                        1.0010000000001011509f64
+                    ]
+                );
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+        match find_roots_quartic_palindromic(1f64, -5.333333333433332f64, 8.666666666999998f64) {
+            Roots::Four(x) => {
+                assert_float_array_eq!(
+                    1e-15f64,
+                    x,
+                    [
+                        0.33333333333333348136f64,
+                        0.99999000004958617729f64,
+                        1.000010000050413831f64,
+                        2.9999999999999986677f64
+                    ]
+                );
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_roots_quartic_anti_palindromic() {
+        assert_eq!(
+            find_roots_quartic_anti_palindromic(1f64, -2.25f64, -7.625f64),
+            Roots::Four([-2f64, -0.25f64, 0.5f64, 4f64])
+        );
+        // x*(x^2 - 1.5x - 1)
+        assert_eq!(
+            find_roots_quartic_anti_palindromic(0f64, 1f64, -1.5f64),
+            Roots::Three([-0.5f64, 0f64, 2f64])
+        );
+    }
+
+    #[test]
+    fn test_find_roots_sextic_palindromic() {
+        assert_eq!(
+            find_roots_sextic_palindromic(1f64, -8.75f64, 27.125f64, -38.75f64),
+            vec![0.25f64, 0.5f64, 1f64, 2f64, 4f64]
+        );
+        // (x - 1)^6
+        assert_eq!(find_roots_sextic_palindromic(1f64, -6f64, 15f64, -20f64), vec![1f64]);
+        assert_eq!(
+            find_roots_sextic_palindromic(0f64, 1f64, -6.75f64, 12.625f64),
+            vec![0f64, 0.25f64, 0.5f64, 2f64, 4f64]
+        );
+    }
+}
@@ -0,0 +1,286 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::numerical::eigen::find_roots_eigen;
+use super::super::numerical::refine::refine_root;
+use super::super::numerical::Convergency;
+use super::super::FloatType;
+use super::rational::extract_rational_roots;
+use std::cmp::Ordering;
+
+/// Calculates coefficients of the polynomial scale*(x - r[0])*(x - r[1])*...*(x - r[n-1]) from its roots.
+///
+/// Coefficients are arranged like the ones accepted by solvers: c[0]*x^n + c[1]*x^(n-1) + ... + c[n].
+/// Linear factors are multiplied one by one, starting from roots of the smallest magnitude,
+/// and every coefficient is updated by a fused multiply-add with a single rounding.
+/// This is handy for synthesizing equations with known roots, e.g. to check solvers with verify_roots.
+///
+/// # Examples
+///
+/// ```
+/// use roots::polynomial_from_roots;
+///
+/// let coeffs = polynomial_from_roots(&[1f64, 2f64, 3f64], 2f64);
+/// // Returns vec![2f64, -12f64, 22f64, -12f64] as 2(x-1)(x-2)(x-3) = 2x^3 - 12x^2 + 22x - 12
+/// ```
+pub fn polynomial_from_roots<F: FloatType>(roots: &[F], scale: F) -> Vec<F> {
+    let mut sorted = roots.to_vec();
+    sorted.sort_by(|a, b| a.abs().partial_cmp(&b.abs()).unwrap_or(Ordering::Equal));
+
+    let mut c = Vec::with_capacity(roots.len() + 1);
+    c.push(scale);
+    for r in sorted.iter() {
+        c.push(F::zero());
+        for i in (1..c.len()).rev() {
+            c[i] = (-*r).mul_add(c[i - 1], c[i]);
+        }
+    }
+    c
+}
+
+/// Checks if x is an integer which is exactly representable by both f64 and i64
+fn is_integer(x: f64) -> bool {
+    x.fract() == 0f64 && x.abs() <= 9007199254740992f64
+}
+
+/// Solves a polynomial equation c[0]*x^n + c[1]*x^(n-1) + ... + c[n-1]*x + c[n] = 0 of any degree.
+///
+/// Leading zero coefficients are skipped, and trailing zero coefficients give the root 0.
+/// When all coefficients are integers, rational roots are extracted exactly by extract_rational_roots first.
+/// Equations up to the quartic one are solved by analytical methods. Roots of higher degree polynomials
+/// are found by find_roots_eigen in f64 and refined by refine_root with the given convergency.
+/// Complex roots are not returned; eigenvalues of close real roots may be complex as well.
+///
+/// When all coefficients are zero, every x is a root, and the single root 0 is returned like find_roots_linear does.
+/// Returned roots are arranged in the increasing order, without duplicates.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_polynomial;
+///
+/// let roots = find_roots_polynomial(&[0f64, 2f64, -6f64, 4f64], &mut 1e-15f64);
+/// // Returns vec![1f64, 2f64] as '2x^2 - 6x + 4 = 0' has roots 1 and 2
+///
+/// let roots = find_roots_polynomial(&[1f64, 0f64, -5f64, 0f64, 4f64, 0f64], &mut 1e-15f64);
+/// // Returns vec![-2f64, -1f64, 0f64, 1f64, 2f64] as 'x^5 - 5x^3 + 4x = 0' has these roots
+/// ```
+pub fn find_roots_polynomial<F: FloatType>(coeffs: &[F], convergency: &mut (impl Convergency<F> + ?Sized)) -> Vec<F> {
+    // Skip leading zeros
+    let mut c: &[F] = match coeffs.iter().position(|c| *c != F::zero()) {
+        Some(first) => &coeffs[first..],
+        None if coeffs.is_empty() => return Vec::new(),
+        None => return vec![F::zero()],
+    };
+
+    // Zero constant terms give the root x = 0; remove them to find other roots
+    let mut roots: Vec<F> = Vec::new();
+    while c.len() > 1 && c[c.len() - 1] == F::zero() {
+        c = &c[..c.len() - 1];
+        if roots.is_empty() {
+            roots.push(F::zero());
+        }
+    }
+
+    // Exact rational roots of integer polynomials leave a lower-degree remainder for numerical solvers
+    let deflated: Vec<F>;
+    if c.len() > 2 && c.iter().all(|a| is_integer(a.to_f64())) {
+        let integers: Vec<i64> = c.iter().map(|a| a.to_f64() as i64).collect();
+        let (rational, remainder) = extract_rational_roots(&integers);
+        if !rational.is_empty() {
+            roots.extend(rational.iter().map(|&(p, q)| F::from_f64(p as f64 / q as f64)));
+            deflated = remainder.iter().map(|a| F::from_f64(*a)).collect();
+            c = &deflated;
+        }
+    }
+
+    roots.extend(match c.len() {
+        1 => Vec::new(),
+        2 => super::linear::find_roots_linear(c[0], c[1]).as_ref().to_vec(),
+        3 => super::quadratic::find_roots_quadratic(c[0], c[1], c[2]).as_ref().to_vec(),
+        4 => super::cubic::find_roots_cubic(c[0], c[1], c[2], c[3]).as_ref().to_vec(),
+        5 => super::quartic::find_roots_quartic(c[0], c[1], c[2], c[3], c[4])
+            .as_ref()
+            .to_vec(),
+        _ => {
+            // Eigenvalues are found in f64 and refined on the original polynomial
+            let normalized: Vec<f64> = c[1..].iter().map(|a| (*a / c[0]).to_f64()).collect();
+            let value = |x: F| c.iter().fold(F::zero(), |value, a| value * x + *a);
+            let derivative = |x: F| {
+                c.iter()
+                    .fold((F::zero(), F::zero()), |(value, derivative), a| {
+                        (value * x + *a, derivative * x + value)
+                    })
+                    .1
+            };
+            find_roots_eigen(&normalized)
+                .map(|x| {
+                    let x = F::from_f64(x);
+                    refine_root(x, &value, &derivative, convergency).unwrap_or(x)
+                })
+                .collect()
+        }
+    });
+
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots.dedup();
+    roots
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_roots_polynomial_edge_cases() {
+        assert_eq!(find_roots_polynomial::<f64>(&[], &mut 1e-15f64), Vec::<f64>::new());
+        assert_eq!(find_roots_polynomial(&[0f64, 0f64, 0f64], &mut 1e-15f64), vec![0f64]);
+        assert_eq!(find_roots_polynomial(&[0f64, 0f64, 3f64], &mut 1e-15f64), Vec::<f64>::new());
+        assert_eq!(
+            find_roots_polynomial(&[0f32, 0f32, 2f32, -6f32, 4f32], &mut 1e-7f32),
+            vec![1f32, 2f32]
+        );
+        // x^3*(x - 1)
+        assert_eq!(
+            find_roots_polynomial(&[1f64, -1f64, 0f64, 0f64, 0f64], &mut 1e-15f64),
+            vec![0f64, 1f64]
+        );
+        // x^2*(x^2 + 1)
+        assert_eq!(
+            find_roots_polynomial(&[1f64, 0f64, 1f64, 0f64, 0f64], &mut 1e-15f64),
+            vec![0f64]
+        );
+    }
+
+    #[test]
+    fn test_find_roots_polynomial() {
+        let cases: [&[f64]; 9] = [
+            &[],
+            &[-3f64],
+            &[-3f64, 0.5f64],
+            &[-3f64, 0.5f64, 2f64],
+            &[-3f64, -1f64, 0.5f64, 2f64],
+            &[-3f64, -1f64, 0.25f64, 0.5f64, 2f64],
+            &[-3f64, -1.5f64, -1f64, 0.25f64, 0.5f64, 2f64],
+            &[-3f64, -1.5f64, -1f64, 0.25f64, 0.5f64, 2f64, 4f64],
+            &[-3f64, -1.5f64, -1f64, 0.125f64, 0.25f64, 0.5f64, 2f64, 4f64],
+        ];
+        for roots in cases.iter() {
+            let c = polynomial_from_roots(roots, -2f64);
+            assert_float_array_eq!(1e-13f64, find_roots_polynomial(&c, &mut 1e-15f64), roots);
+
+            // (x^2 + 1) adds complex roots only
+            let mut c = polynomial_from_roots(roots, 0.5f64);
+            c.push(0f64);
+            c.push(0f64);
+            for i in (2..c.len()).rev() {
+                c[i] = c[i] + c[i - 2];
+            }
+            assert_float_array_eq!(1e-13f64, find_roots_polynomial(&c, &mut 1e-15f64), roots);
+        }
+    }
+    #[test]
+    fn test_find_roots_polynomial_rational() {
+        // Rational roots of integer polynomials are exact
+        assert_eq!(
+            find_roots_polynomial(&[6f64, -11f64, 6f64, -1f64], &mut 1e-15f64),
+            vec![1f64 / 3f64, 0.5f64, 1f64]
+        );
+        assert_eq!(
+            find_roots_polynomial(&[6f32, -11f32, 6f32, -1f32], &mut 1e-7f32),
+            vec![1f32 / 3f32, 0.5f32, 1f32]
+        );
+
+        // (3x - 1)(2x + 5)(x - 4)(7x - 3)(x^2 - 2)(x^2 + x + 1): the remainder is a quartic
+        let roots = find_roots_polynomial(
+            &[42f64, -53f64, -503f64, -44f64, 723f64, 551f64, 170f64, -502f64, 120f64],
+            &mut 1e-15f64,
+        );
+        assert_eq!(roots.len(), 6);
+        assert_eq!(roots[0], -2.5f64);
+        assert_float_eq!(1e-15f64, roots[1], -2f64.sqrt());
+        assert_eq!(roots[2], 1f64 / 3f64);
+        assert_eq!(roots[3], 3f64 / 7f64);
+        assert_float_eq!(1e-15f64, roots[4], 2f64.sqrt());
+        assert_eq!(roots[5], 4f64);
+
+        // (3x - 1)^3 (x^2 - 2)(x^2 + 1): the triple root is exact instead of being spread by rounding
+        let roots = find_roots_polynomial(&[27f64, -27f64, -18f64, 26f64, -63f64, 55f64, -18f64, 2f64], &mut 1e-15f64);
+        assert_eq!(roots.len(), 3);
+        assert_float_eq!(1e-15f64, roots[0], -2f64.sqrt());
+        assert_eq!(roots[1], 1f64 / 3f64);
+        assert_float_eq!(1e-15f64, roots[2], 2f64.sqrt());
+    }
+
+    #[test]
+    fn test_polynomial_from_roots() {
+        assert_eq!(polynomial_from_roots::<f64>(&[], 3f64), vec![3f64]);
+        assert_eq!(polynomial_from_roots(&[-2f32], 0.5f32), vec![0.5f32, 1f32]);
+        // Repeated roots
+        assert_eq!(
+            polynomial_from_roots(&[1f64, 1f64, 1f64], 2f64),
+            vec![2f64, -6f64, 6f64, -2f64]
+        );
+        assert_eq!(
+            polynomial_from_roots(&[-3f64, 2f64, -3f64, 2f64], 1f64),
+            vec![1f64, 2f64, -11f64, -12f64, 36f64]
+        );
+        // Powers of two spanning several magnitudes give exact coefficients
+        let (a, b) = (2f64.powi(-10), 2f64.powi(20));
+        assert_eq!(
+            polynomial_from_roots(&[b, 1f64, a], 1f64),
+            vec![1f64, -(a + 1f64 + b), a + a * b + b, -a * b]
+        );
+    }
+
+    #[test]
+    fn test_polynomial_from_roots_round_trip() {
+        let cases: [&[f64]; 4] = [
+            &[-1e-4f64, 0.03f64, 7f64, 1e5f64],
+            &[0.1f64, 0.1f64, 0.1f64, 250f64],
+            &[-123.456f64, -0.5f64, 1e-3f64, 2f64, 3e3f64, 1e6f64],
+            &[1e-8f64, 1e-4f64, 1f64, 1e4f64, 1e8f64],
+        ];
+        for roots in cases.iter() {
+            let c = polynomial_from_roots(roots, -1.5f64);
+            for check in verify_roots(&c, roots).iter() {
+                assert!(check.relative_residual < 1e-15f64, "{:?} {:?}", c, check);
+            }
+        }
+
+        // Roots of synthesized quartic equations are found back
+        let roots = [-1e-4f64, 0.03f64, 7f64, 1e5f64];
+        let c = polynomial_from_roots(&roots, 2f64);
+        match find_roots_quartic(c[0], c[1], c[2], c[3], c[4]) {
+            Roots::Four(x) => {
+                for (found, expected) in x.iter().zip(roots.iter()) {
+                    assert_float_eq!(1e-14f64 * expected.abs(), *found, *expected);
+                }
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+}
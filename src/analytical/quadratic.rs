@@ -25,6 +25,33 @@
 use super::super::FloatType;
 use super::super::Roots;
 
+/// Calculates the discriminant a1^2 - 4*a2*a0 of the quadratic equation a2*x^2 + a1*x + a0 = 0.
+///
+/// When a1^2 is close to 4*a2*a0, the difference is recalculated using fused multiply-add,
+/// which keeps exact rounding errors of both products (see Kahan, "On the Cost of Floating-Point Computation
+/// Without Extra-Precise Arithmetic"). find_roots_quadratic uses the same value to count roots.
+///
+/// # Examples
+///
+/// ```
+/// use roots::discriminant_quadratic;
+///
+/// let discriminant = discriminant_quadratic(1f64, -3f64, 2f64);
+/// // Returns 1f64 for 'x^2 - 3x + 2 = 0'
+/// ```
+pub fn discriminant_quadratic<F: FloatType>(a2: F, a1: F, a0: F) -> F {
+    let a2x4 = F::from(4i16) * a2;
+    let product = a2x4 * a0;
+    let a1_pow_2 = a1 * a1;
+    let discriminant = a1_pow_2 - product;
+    if F::from(3i16) * discriminant.abs() >= a1_pow_2 + product.abs() {
+        discriminant
+    } else {
+        let product_error = a2x4.mul_add(a0, -product);
+        a1.mul_add(a1, -product) - product_error
+    }
+}
+
 /// Solves a quadratic equation a2*x^2 + a1*x + a0 = 0.
 ///
 /// In case two roots are present, the first returned root is less than the second one.
@@ -46,15 +73,14 @@ use super::super::Roots;
 /// ```
 pub fn find_roots_quadratic<F: FloatType>(a2: F, a1: F, a0: F) -> Roots<F> {
     // Handle non-standard cases
-    if a2 == F::zero() {
+    let roots = if a2 == F::zero() {
         // a2 = 0; a1*x+a0=0; solve linear equation
         super::linear::find_roots_linear(a1, a0)
     } else {
         let _2 = F::from(2i16);
         let _4 = F::from(4i16);
 
-        // Rust lacks a simple way to convert an integer constant to generic type F
-        let discriminant = a1 * a1 - _4 * a2 * a0;
+        let discriminant = discriminant_quadratic(a2, a1, a0);
         if discriminant < F::zero() {
             Roots::No([])
         } else {
@@ -94,9 +120,86 @@ pub fn find_roots_quadratic<F: FloatType>(a2: F, a1: F, a0: F) -> Roots<F> {
                 }
             }
         }
+    };
+
+    #[cfg(all(feature = "debug-exact", debug_assertions))]
+    super::exact::assert_classification(
+        "find_roots_quadratic",
+        &[a2.to_f64(), a1.to_f64(), a0.to_f64()],
+        roots.as_ref().len(),
+        super::exact::count_real_roots_quadratic_exact(a2.to_f64(), a1.to_f64(), a0.to_f64()),
+    );
+    roots
+}
+
+/// Solves a normalized quadratic equation x^2 + a1*x + a0 = 0.
+///
+/// Unlike find_roots_quadratic, the smaller root is always calculated from the product of roots a0,
+/// so it keeps precision when a1^2 is much greater than |a0|. Used to solve quadratic factors of quartics.
+pub fn find_roots_quadratic_normalized<F: FloatType>(a1: F, a0: F) -> Roots<F> {
+    let two = F::from(2i16);
+    let discriminant = discriminant_quadratic(F::one(), a1, a0);
+    if discriminant < F::zero() {
+        Roots::No([])
+    } else if discriminant == F::zero() {
+        Roots::One([-a1 / two])
+    } else {
+        let sq = discriminant.sqrt();
+        let same_sign = if a1 < F::zero() { -a1 + sq } else { -a1 - sq };
+        let (x1, x2) = (same_sign / two, (two * a0) / same_sign);
+        if x1 < x2 {
+            Roots::Two([x1, x2])
+        } else {
+            Roots::Two([x2, x1])
+        }
+    }
+}
+
+/// Counts distinct real roots of a quadratic equation a2*x^2 + a1*x + a0 = 0 without finding them.
+///
+/// The result is the same as the length of roots returned by find_roots_quadratic.
+///
+/// # Examples
+///
+/// ```
+/// use roots::count_real_roots_quadratic;
+///
+/// let count = count_real_roots_quadratic(1f64, 0f64, -1f64);
+/// // Returns 2 as 'x^2 - 1 = 0' has roots -1 and 1
+/// ```
+pub fn count_real_roots_quadratic<F: FloatType>(a2: F, a1: F, a0: F) -> usize {
+    if a2 == F::zero() {
+        if a1 != F::zero() || a0 == F::zero() {
+            1
+        } else {
+            0
+        }
+    } else {
+        let discriminant = discriminant_quadratic(a2, a1, a0);
+        if discriminant < F::zero() {
+            0
+        } else if discriminant == F::zero() {
+            1
+        } else {
+            2
+        }
     }
 }
 
+/// Checks if a quadratic equation a2*x^2 + a1*x + a0 = 0 has real roots without finding them.
+///
+/// # Examples
+///
+/// ```
+/// use roots::has_real_roots_quadratic;
+///
+/// let has_roots = has_real_roots_quadratic(1f64, 0f64, 1f64);
+/// // Returns false as 'x^2 + 1 = 0' has no real roots
+/// ```
+pub fn has_real_roots_quadratic<F: FloatType>(a2: F, a1: F, a0: F) -> bool {
+    count_real_roots_quadratic(a2, a1, a0) > 0
+}
+
 #[cfg(test)]
 mod test {
     use super::super::super::*;
@@ -128,4 +231,62 @@ mod test {
         assert_eq!(find_roots_quadratic(1f32, -1e15f32, -1f32), Roots::Two([-1e-15f32, 1e15f32]));
         assert_eq!(find_roots_quadratic(-1f32, 1e15f32, 1f32), Roots::Two([-1e-15f32, 1e15f32]));
     }
+
+    #[test]
+    fn test_find_roots_quadratic_normalized() {
+        assert_eq!(super::find_roots_quadratic_normalized(0f64, 1f64), Roots::No([]));
+        assert_eq!(super::find_roots_quadratic_normalized(-2f64, 1f64), Roots::One([1f64]));
+        assert_eq!(super::find_roots_quadratic_normalized(-3f64, 2f64), Roots::Two([1f64, 2f64]));
+        assert_eq!(
+            super::find_roots_quadratic_normalized(-1e8f64, 1f64),
+            Roots::Two([1e-8f64, 1e8f64])
+        );
+        assert_eq!(
+            super::find_roots_quadratic_normalized(1e8f32, 1f32),
+            Roots::Two([-1e8f32, -1e-8f32])
+        );
+    }
+
+    #[test]
+    fn test_discriminant_quadratic() {
+        assert_eq!(discriminant_quadratic(1f64, -3f64, 2f64), 1f64);
+        assert_eq!(discriminant_quadratic(2f64, 4f64, 2f64), 0f64);
+        assert_eq!(discriminant_quadratic(1f32, 0f32, 1f32), -4f32);
+
+        // a1^2 = 1 + 2^-29 + 2^-60 is rounded, but the exact difference 2^-60 is kept
+        let a1 = 1f64 + 2f64.powi(-30);
+        let a0 = 0.25f64 + 2f64.powi(-31);
+        assert_eq!(discriminant_quadratic(1f64, a1, a0), 2f64.powi(-60));
+
+        // The discriminant scales by the square of the coefficient scale
+        for scale in [2f64.powi(-20), 0.5f64, 1024f64].iter() {
+            let s = *scale;
+            assert_eq!(discriminant_quadratic(s, -3f64 * s, 2f64 * s), s * s);
+            assert_eq!(discriminant_quadratic(s, a1 * s, a0 * s), 2f64.powi(-60) * s * s);
+        }
+    }
+
+    #[test]
+    fn test_count_real_roots_quadratic() {
+        assert_eq!(count_real_roots_quadratic(0f32, 0f32, 0f32), 1);
+        assert_eq!(count_real_roots_quadratic(0f32, 0f32, 1f32), 0);
+        assert_eq!(count_real_roots_quadratic(1f64, 2f64, 1f64), 1);
+        assert!(!has_real_roots_quadratic(1f64, 0f64, 1f64));
+
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            f64::from(seed >> 8) / f64::from(1u32 << 24) * 20f64 - 10f64
+        };
+        for _ in 0..1000 {
+            let (a2, a1, a0) = (next(), next(), next());
+            let count = find_roots_quadratic(a2, a1, a0).as_ref().len();
+            assert_eq!(count_real_roots_quadratic(a2, a1, a0), count);
+            assert_eq!(has_real_roots_quadratic(a2, a1, a0), count > 0);
+            // Small integer coefficients give exact zero discriminants
+            let (a2, a1, a0) = (a2.round() / 4f64, a1.round() / 4f64, a0.round() / 4f64);
+            let count = find_roots_quadratic(a2, a1, a0).as_ref().len();
+            assert_eq!(count_real_roots_quadratic(a2, a1, a0), count);
+        }
+    }
 }
@@ -22,48 +22,195 @@
 // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use super::super::double_double::DoubleDouble;
 use super::super::FloatType;
 use super::super::Roots;
+use super::verify::root_condition;
+use std::ops::Add;
+use std::ops::Mul;
+use std::ops::Sub;
 
-/// Solves a quartic equation a4*x^4 + a4*x^3 + a2*x^2 + a1*x + a0 = 0.
-/// pp, rr, and dd are already computed while searching for multiple roots
-fn find_roots_via_depressed_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F, pp: F, rr: F, dd: F) -> Roots<F> {
-    // Depressed quartic
-    // https://en.wikipedia.org/wiki/Quartic_function#Converting_to_a_depressed_quartic
+/// Discriminant of the quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0.
+/// https://en.wikipedia.org/wiki/Quartic_function#Nature_of_the_roots
+/// Partially simplifed to keep intermediate values smaller (to minimize rounding errors).
+fn discriminant<T>(a4: T, a3: T, a2: T, a1: T, a0: T) -> T
+where
+    T: Copy + From<i16> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let _4 = T::from(4i16);
+    let _6 = T::from(6i16);
+    let _16 = T::from(16i16);
+    let _18 = T::from(18i16);
+    let _27 = T::from(27i16);
+    let _80 = T::from(80i16);
+    let _128 = T::from(128i16);
+    let _144 = T::from(144i16);
+    let _192 = T::from(192i16);
+    let _256 = T::from(256i16);
 
-    let _2 = F::from(2i16);
-    let _3 = F::from(3i16);
+    a4 * a0 * a4 * (_256 * a4 * a0 * a0 + a1 * (_144 * a2 * a1 - _192 * a3 * a0))
+        + a4 * a0 * a2 * a2 * (_16 * a2 * a2 - _80 * a3 * a1 - _128 * a4 * a0)
+        + (a3
+            * a3
+            * (a4 * a0 * (_144 * a2 * a0 - _6 * a1 * a1)
+                + (a0 * (_18 * a3 * a2 * a1 - _27 * a3 * a3 * a0 - _4 * a2 * a2 * a2) + a1 * a1 * (a2 * a2 - _4 * a3 * a1))))
+        + a4 * a1 * a1 * (_18 * a3 * a2 * a1 - _27 * a4 * a1 * a1 - _4 * a2 * a2 * a2)
+}
+
+/// Sum of absolute values of all terms of the expanded discriminant.
+/// Rounding errors of the discriminant are proportional to it.
+fn discriminant_magnitude<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> F {
+    let (a, b, c, d, e) = (a4.abs(), a3.abs(), a2.abs(), a1.abs(), a0.abs());
     let _4 = F::from(4i16);
     let _6 = F::from(6i16);
+    let _16 = F::from(16i16);
+    let _18 = F::from(18i16);
+    let _27 = F::from(27i16);
+    let _80 = F::from(80i16);
+    let _128 = F::from(128i16);
+    let _144 = F::from(144i16);
+    let _192 = F::from(192i16);
+    let _256 = F::from(256i16);
+
+    a * a * (_256 * a * e * e * e + _192 * b * d * e * e + _128 * c * c * e * e + _144 * c * d * d * e + _27 * d * d * d * d)
+        + a * (_144 * b * b * c * e * e + _6 * b * b * d * d * e + _80 * b * c * c * d * e + _18 * b * c * d * d * d)
+        + a * (_16 * c * c * c * c * e + _4 * c * c * c * d * d)
+        + b * b * (_27 * b * b * e * e + _18 * b * c * d * e + _4 * b * d * d * d + _4 * c * c * c * e + c * c * d * d)
+}
+
+/// Calculates the discriminant of the quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0.
+///
+/// When the value is within rounding errors from zero, its sign cannot be trusted.
+/// Then it is recalculated using double-double arithmetic,
+/// and values within rounding errors of the recalculation are considered to be exactly zero.
+/// The discriminant is calculated in f64 for any type of the coefficients, as find_roots_quartic
+/// uses the same value to choose how to solve the equation.
+///
+/// # Examples
+///
+/// ```
+/// use roots::discriminant_quartic;
+///
+/// let discriminant = discriminant_quartic(1f64, 0f64, -5f64, 0f64, 4f64);
+/// // Returns 5184f64 for '(x+2)(x+1)(x-1)(x-2) = 0'; positive as all four roots are real and distinct
+/// ```
+pub fn discriminant_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> F {
+    let (a4, a3, a2, a1, a0) = (a4.to_f64(), a3.to_f64(), a2.to_f64(), a1.to_f64(), a0.to_f64());
+    F::from_f64(discriminant_rechecked(a4, a3, a2, a1, a0))
+}
+
+/// The discriminant recalculated in double-double precision when its sign cannot be trusted
+fn discriminant_rechecked<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> F {
+    let value = discriminant(a4, a3, a2, a1, a0);
+    let error_bound = F::from(64i16) * F::epsilon() * discriminant_magnitude(a4, a3, a2, a1, a0);
+    if value.abs() > error_bound {
+        value
+    } else {
+        let precise = discriminant(
+            DoubleDouble::from(a4),
+            DoubleDouble::from(a3),
+            DoubleDouble::from(a2),
+            DoubleDouble::from(a1),
+            DoubleDouble::from(a0),
+        )
+        .value();
+        if precise.abs() > error_bound * F::epsilon() {
+            precise
+        } else {
+            F::zero()
+        }
+    }
+}
+
+/// Converts a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 to the depressed quartic equation
+/// y^4 + p*y^2 + q*y + r = 0, where x = y + shift.
+///
+/// Returns (p, q, r, shift). a4 must not be zero.
+/// See https://en.wikipedia.org/wiki/Quartic_function#Converting_to_a_depressed_quartic
+///
+/// # Examples
+///
+/// ```
+/// use roots::depress_quartic;
+///
+/// let (p, q, r, shift) = depress_quartic(1f64, -4f64, 0f64, 0f64, 0f64);
+/// // Returns (-6f64, -8f64, -3f64, 1f64) as 'x^4 - 4x^3 = 0' becomes 'y^4 - 6y^2 - 8y - 3 = 0' for x = y + 1
+/// ```
+pub fn depress_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> (F, F, F, F) {
+    let _3 = F::from(3i16);
+    let _4 = F::from(4i16);
     let _8 = F::from(8i16);
     let _12 = F::from(12i16);
     let _16 = F::from(16i16);
     let _256 = F::from(256i16);
 
-    // a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 => y^4 + p*y^2 + q*y + r.
+    let (pp, rr, dd) = depressed_invariants(a4, a3, a2, a1, a0);
     let a4_pow_2 = a4 * a4;
     let a4_pow_3 = a4_pow_2 * a4;
     let a4_pow_4 = a4_pow_2 * a4_pow_2;
-    // Re-use pre-calculated values
     let p = pp / (_8 * a4_pow_2);
     let q = rr / (_8 * a4_pow_3);
     let r = (dd + _16 * a4_pow_2 * (_12 * a0 * a4 - _3 * a1 * a3 + a2 * a2)) / (_256 * a4_pow_4);
+    (p, q, r, -a3 / (_4 * a4))
+}
+
+/// Returns coefficients (b2, b1, b0) of the resolvent cubic u^3 + b2*u^2 + b1*u + b0 = 0
+/// used to solve the quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0.
+///
+/// The resolvent is built for the depressed quartic y^4 + p*y^2 + q*y + r = 0 returned by depress_quartic:
+/// u^3 + (5/2)*p*u^2 + (2*p^2 - r)*u + (p^3/2 - p*r/2 - q^2/8) = 0.
+/// For any of its roots u with p + 2u > 0, the depressed quartic is the product of two quadratics
+/// (y^2 + s*y + p + u - q/(2s)) and (y^2 - s*y + p + u + q/(2s)), where s = sqrt(p + 2u).
+/// a4 must not be zero.
+///
+/// # Examples
+///
+/// ```
+/// use roots::resolvent_cubic;
+///
+/// let (b2, b1, b0) = resolvent_cubic(1f64, 0f64, -5f64, 0f64, 4f64);
+/// // Returns (-12.5f64, 46f64, -52.5f64) for 'x^4 - 5x^2 + 4 = 0'
+/// ```
+pub fn resolvent_cubic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> (F, F, F) {
+    let (p, q, r, _) = depress_quartic(a4, a3, a2, a1, a0);
+    super::quartic_depressed::auxiliary_equation(p, q, r)
+}
+
+/// Solves a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 via the depressed quartic.
+fn find_roots_via_depressed_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
+    let (p, q, r, shift) = depress_quartic(a4, a3, a2, a1, a0);
 
     let mut roots = Roots::No([]);
     for y in super::quartic_depressed::find_roots_quartic_depressed(p, q, r)
         .as_ref()
         .iter()
     {
-        roots = roots.add_new_root(*y - a3 / (_4 * a4));
+        roots = roots.add_new_root(*y + shift);
     }
     roots
 }
 
+/// Checks if a3*x^3 is too small to change any root of the quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0.
+/// Then the shift of roots a3/(4*a4) done by converting to the depressed quartic only adds rounding errors.
+fn is_shift_negligible<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> bool {
+    // Cauchy's lower bound of absolute values of roots: |x| >= |a0| / (|a0| + max(|a1|, |a2|, |a3|, |a4|))
+    let mut max = a4.abs();
+    for a in [a3, a2, a1].iter() {
+        if a.abs() > max {
+            max = a.abs();
+        }
+    }
+    let lower_bound = a0.abs() / (a0.abs() + max);
+    (a3 / a4).abs() < F::epsilon() * lower_bound
+}
+
 /// Solves a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0.
 ///
 /// Returned roots are ordered.
 /// Precision is about 5e-15 for f64, 5e-7 for f32.
-/// WARNING: f32 is often not enough to find multiple roots.
+///
+/// Calculations are always done in f64: coefficients of other types (e.g. f32) are promoted to f64,
+/// and found roots are rounded back. Roots which become equal after rounding are returned once.
 ///
 /// # Examples
 ///
@@ -79,54 +226,569 @@ fn find_roots_via_depressed_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0
 /// let multiple_roots = find_roots_quartic(-14.0625f64, -3.75f64, 29.75f64, 4.0f64, -16.0f64);
 /// // Returns Roots::Two([-1.1016116464173349f64, 0.9682783130840016f64])
 ///
-/// let multiple_roots_not_found = find_roots_quartic(-14.0625f32, -3.75f32, 29.75f32, 4.0f32, -16.0f32);
-/// // Returns Roots::No([]) because of f32 rounding errors when trying to calculate the discriminant
+/// let multiple_roots_f32 = find_roots_quartic(-14.0625f32, -3.75f32, 29.75f32, 4.0f32, -16.0f32);
+/// // Returns Roots::Two([-1.1016117f32, 0.96827835f32]) because calculations are done in f64
 /// ```
 pub fn find_roots_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
+    let (a4, a3, a2, a1, a0) = (a4.to_f64(), a3.to_f64(), a2.to_f64(), a1.to_f64(), a0.to_f64());
+    let solved = solve_quartic(a4, a3, a2, a1, a0).0;
+
+    #[cfg(all(feature = "debug-exact", debug_assertions))]
+    super::exact::assert_classification(
+        "find_roots_quartic",
+        &[a4, a3, a2, a1, a0],
+        solved.as_ref().len(),
+        super::exact::count_real_roots_quartic_exact(a4, a3, a2, a1, a0),
+    );
+
+    let mut roots = Roots::No([]);
+    for x in solved.as_ref().iter() {
+        roots = roots.add_new_root(F::from_f64(*x));
+    }
+    roots
+}
+
+/// Finds the smallest root of a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 which is greater than t_min.
+///
+/// The polynomial is shifted by t_min, and the signs of its coefficients are counted (the Descartes' rule of signs).
+/// Without sign changes there are no roots greater than t_min, and the equation is not solved.
+/// With exactly one sign change there is exactly one such root. It is isolated between t_min
+/// and the Fujiwara's bound of positive roots and polished by the Newton-Raphson method without finding other roots.
+/// Otherwise the roots are found by find_roots_quartic and filtered.
+/// In all cases the result is the same as filtering roots returned by find_roots_quartic up to rounding errors,
+/// but equations with at most one root greater than t_min are solved several times faster (see the benchmarks).
+/// This is useful for ray tracing and collision detection.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_smallest_positive_root_quartic;
+///
+/// // (x+1)(x-1)(x-2)(x-3) = x^4 - 5x^3 + 5x^2 + 5x - 6
+/// let root = find_smallest_positive_root_quartic(1f64, -5f64, 5f64, 5f64, -6f64, 1.5f64);
+/// // Returns Some(2f64)
+///
+/// // (x+1)(x+2)(x+3)(x+4) = x^4 + 10x^3 + 35x^2 + 50x + 24
+/// let no_root = find_smallest_positive_root_quartic(1f64, 10f64, 35f64, 50f64, 24f64, 0f64);
+/// // Returns None without solving the equation as the shifted polynomial has no sign changes
+/// ```
+pub fn find_smallest_positive_root_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F, t_min: F) -> Option<F> {
+    // Shift the polynomial by t_min using the Horner's scheme: p(t_min + t) = b4*t^4 + b3*t^3 + b2*t^2 + b1*t + b0
+    let mut b = [a4, a3, a2, a1, a0];
+    for i in 0..4 {
+        for j in 1..(5 - i) {
+            b[j] = b[j] + t_min * b[j - 1];
+        }
+    }
+
+    // The number of positive roots of the shifted polynomial does not exceed the number of sign changes of its coefficients
+    let mut sign_changes = 0;
+    let mut previous = F::zero();
+    for x in b.iter().filter(|x| **x != F::zero()) {
+        if previous * *x < F::zero() {
+            sign_changes += 1;
+        }
+        previous = *x;
+    }
+
+    if sign_changes == 0 && previous != F::zero() {
+        None
+    } else if sign_changes == 1 && b[0] != F::zero() && b[4] != F::zero() {
+        // The only root greater than t_min lies below t_min + the bound of positive roots of the shifted polynomial
+        let upper = t_min + positive_roots_bound(&b);
+        Some(polish_isolated_root_quartic(
+            &[a4, a3, a2, a1, a0],
+            t_min,
+            upper,
+            b[4] < F::zero(),
+        ))
+    } else {
+        find_roots_quartic(a4, a3, a2, a1, a0)
+            .as_ref()
+            .iter()
+            .find(|x| **x > t_min)
+            .cloned()
+    }
+}
+
+/// Returns the Fujiwara's bound of positive roots of the polynomial b[0]*t^4 + b[1]*t^3 + b[2]*t^2 + b[3]*t + b[4].
+///
+/// Only coefficients of the sign opposite to b[0] are taken into account, as others cannot make the polynomial vanish.
+fn positive_roots_bound<F: FloatType>(b: &[F; 5]) -> F {
+    let two = F::from(2i16);
+    let mut bound = F::zero();
+    for (i, x) in b.iter().enumerate().skip(1) {
+        let ratio = -*x / b[0];
+        if ratio > F::zero() {
+            let root = match i {
+                1 => ratio,
+                2 => ratio.sqrt(),
+                3 => ratio.cbrt(),
+                _ => (ratio / two).sqrt().sqrt(),
+            };
+            if root > bound {
+                bound = root;
+            }
+        }
+    }
+    two * bound
+}
+
+/// Finds the only root of the quartic a[0]*x^4 + a[1]*x^3 + a[2]*x^2 + a[3]*x + a[4] = 0 between lower and upper.
+/// The polynomial is negative at lower if lower_negative, and it has the opposite sign at upper.
+///
+/// Newton-Raphson steps are made from upper; the bisection is used when a step leaves the bracket.
+fn polish_isolated_root_quartic<F: FloatType>(a: &[F; 5], mut lower: F, mut upper: F, lower_negative: bool) -> F {
+    let mut x = upper;
+    for _ in 0..MAX_POLISHING_STEPS {
+        // Evaluate the value and the derivative using the Horner's scheme
+        let (mut y, mut derivative) = (a[0], F::zero());
+        for c in a.iter().skip(1) {
+            derivative = derivative * x + y;
+            y = y * x + *c;
+        }
+        if y == F::zero() {
+            return x;
+        }
+        if (y < F::zero()) == lower_negative {
+            lower = x;
+        } else {
+            upper = x;
+        }
+
+        let newton = x - y / derivative;
+        let next = if newton > lower && newton < upper {
+            newton
+        } else {
+            (lower + upper) / F::from(2i16)
+        };
+        if (next - x).abs() <= F::epsilon() * next.abs() || next == lower || next == upper {
+            return next;
+        }
+        x = next;
+    }
+    x
+}
+
+/// Newton-Raphson steps converge in a few iterations, and bisections narrow the bracket down to one ulp of f64 in ~2100
+const MAX_POLISHING_STEPS: usize = 100;
+
+/// The way the quartic equation has been solved
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum QuarticBranch {
+    /// a4 = 0; the cubic equation is solved
+    Cubic,
+    /// a0 = 0; the zero root is added to roots of the cubic equation
+    ZeroRoot,
+    /// a3 = 0 and a1 = 0; the bi-quadratic equation is solved
+    Biquadratic,
+    /// a0 = a4 and a1 = a3; the quadratic equation in z = x + 1/x is solved
+    Palindromic,
+    /// a0 = a4 and a1 = -a3; the quadratic equation in z = x - 1/x is solved
+    AntiPalindromic,
+    /// a3 and a1 are small; roots of the bi-quadratic equation are corrected by the Newton-Raphson method
+    NearlyBiquadratic,
+    /// a3 = 0 (or too small to change roots); the equation is solved as a depressed quartic
+    Depressed,
+    /// All four roots are equal
+    QuadrupleRoot,
+    /// At least three roots are equal
+    TripleRoot,
+    /// Two complex conjugate double roots
+    ComplexDoubleRoots,
+    /// Two pairs of complex conjugate roots
+    ComplexRoots,
+    /// General case; roots are shifted to solve the depressed quartic
+    ShiftedDepressed,
+    /// Exactly one double real root; found among the roots of the derivative
+    DoubleRoot,
+    /// Roots of the depressed quartic had big residuals; the equation is solved by find_roots_quartic_refined
+    Refined,
+}
+
+/// Values calculated while solving the quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0.
+/// See https://en.wikipedia.org/wiki/Quartic_function#Nature_of_the_roots
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct QuarticDiagnostics<F>
+where
+    F: FloatType,
+{
+    /// The way the equation has been solved
+    pub branch: QuarticBranch,
+    /// Discriminant (zero if it is within rounding errors from zero)
+    pub discriminant: F,
+    /// 8*a4*a2 - 3*a3^2
+    pub pp: F,
+    /// a3^3 + 8*a4^2*a1 - 4*a4*a3*a2
+    pub rr: F,
+    /// 64*a4^3*a0 - 16*a4^2*a2^2 + 16*a4*a3^2*a2 - 16*a4^2*a3*a1 - 3*a3^4
+    pub dd: F,
+    /// a2^2 - 3*a3*a1 + 12*a4*a0
+    pub delta0: F,
+}
+
+/// Solves a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 like find_roots_quartic does,
+/// and tells how the equation has been solved.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_quartic_diagnostics;
+/// use roots::QuarticBranch;
+///
+/// let (roots, diagnostics) = find_roots_quartic_diagnostics(2401f64, 2744f64, 1176f64, 224f64, 16f64);
+/// // Returns Roots::One([-0.2857142857142857f64]) and diagnostics.branch == QuarticBranch::QuadrupleRoot
+/// ```
+pub fn find_roots_quartic_diagnostics<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> (Roots<F>, QuarticDiagnostics<F>) {
+    let (a4, a3, a2, a1, a0) = (a4.to_f64(), a3.to_f64(), a2.to_f64(), a1.to_f64(), a0.to_f64());
+    let (solved, branch) = solve_quartic(a4, a3, a2, a1, a0);
+    let (discriminant, pp, rr, dd, delta0) = quartic_invariants(a4, a3, a2, a1, a0);
+
+    let mut roots = Roots::No([]);
+    for x in solved.as_ref().iter() {
+        roots = roots.add_new_root(F::from_f64(*x));
+    }
+    (
+        roots,
+        QuarticDiagnostics {
+            branch,
+            discriminant: F::from_f64(discriminant),
+            pp: F::from_f64(pp),
+            rr: F::from_f64(rr),
+            dd: F::from_f64(dd),
+            delta0: F::from_f64(delta0),
+        },
+    )
+}
+
+/// Finds real roots of a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 together with their condition numbers.
+///
+/// Roots are the same as returned by find_roots_quartic; each one is paired with its condition number
+/// estimated by root_condition. Roots with big condition numbers (e.g. close or multiple roots)
+/// cannot be found precisely by any method, so the condition number tells how much a root can be trusted.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_quartic_with_condition;
+///
+/// // Two double roots: the found roots are precise to about 8 digits only
+/// let roots = find_roots_quartic_with_condition(-14.0625f64, -3.75f64, 29.75f64, 4.0f64, -16.0f64);
+/// assert!(roots.iter().all(|&(_, condition)| condition > 1e7f64));
+///
+/// // Simple well-separated roots 1, 2, 3, 4
+/// let roots = find_roots_quartic_with_condition(1f64, -10f64, 35f64, -50f64, 24f64);
+/// // Returns [(1f64, 20f64), (2f64, 90f64), (3f64, 140f64), (4f64, 70f64)]
+/// ```
+pub fn find_roots_quartic_with_condition<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Vec<(F, F)> {
+    let coeffs = [a4, a3, a2, a1, a0];
+    find_roots_quartic(a4, a3, a2, a1, a0)
+        .as_ref()
+        .iter()
+        .map(|x| (*x, root_condition(&coeffs, *x)))
+        .collect()
+}
+
+/// Counts distinct real roots of a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 without finding them.
+///
+/// The nature of roots is told by the discriminant and the invariants P, R, D, and delta0;
+/// see https://en.wikipedia.org/wiki/Quartic_function#Nature_of_the_roots
+/// Like find_roots_quartic, calculations are done in f64. The result is the same as the length of roots
+/// returned by find_roots_quartic unless the discriminant is close to zero, where rounding errors decide,
+/// or the equation has a double root, which find_roots_quartic may split into two close roots.
+///
+/// # Examples
+///
+/// ```
+/// use roots::count_real_roots_quartic;
+///
+/// let count = count_real_roots_quartic(1f64, -10f64, 35f64, -50f64, 24f64);
+/// // Returns 4 as '(x-1)(x-2)(x-3)(x-4) = 0' has roots 1, 2, 3, and 4
+/// ```
+pub fn count_real_roots_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> usize {
+    let (a4, a3, a2, a1, a0) = (a4.to_f64(), a3.to_f64(), a2.to_f64(), a1.to_f64(), a0.to_f64());
+    if a4 == 0f64 {
+        super::cubic::count_real_roots_cubic(a3, a2, a1, a0)
+    } else if a0 == 0f64 {
+        // The root 0 is added unless the cubic has it already
+        super::cubic::count_real_roots_cubic(a4, a3, a2, a1) + if a1 == 0f64 { 0 } else { 1 }
+    } else {
+        let (discriminant, pp, rr, dd, delta0) = quartic_invariants(a4, a3, a2, a1, a0);
+        if discriminant > 0f64 {
+            // Four real roots or two pairs of complex conjugate roots
+            if pp > 0f64 || dd > 0f64 {
+                0
+            } else {
+                4
+            }
+        } else if discriminant < 0f64 {
+            // Two real roots and a pair of complex conjugate roots
+            2
+        } else if delta0 == 0f64 {
+            // A quadruple root, or a triple root and a simple root
+            if dd == 0f64 {
+                1
+            } else {
+                2
+            }
+        } else if dd == 0f64 && pp < 0f64 {
+            // Two real double roots
+            2
+        } else if dd == 0f64 && pp > 0f64 && rr == 0f64 {
+            // Two complex conjugate double roots
+            0
+        } else if pp < 0f64 && dd < 0f64 {
+            // A double root and two simple real roots
+            3
+        } else {
+            // A double root and a pair of complex conjugate roots
+            1
+        }
+    }
+}
+
+/// Checks if a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 has real roots without finding them.
+///
+/// # Examples
+///
+/// ```
+/// use roots::has_real_roots_quartic;
+///
+/// let has_roots = has_real_roots_quartic(1f64, 2f64, 3f64, 2f64, 2f64);
+/// // Returns false as '(x^2 + 2x + 2)(x^2 + 1) = 0' has no real roots
+/// ```
+pub fn has_real_roots_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> bool {
+    count_real_roots_quartic(a4, a3, a2, a1, a0) > 0
+}
+
+/// Returns P, R and D of https://en.wikipedia.org/wiki/Quartic_function#Nature_of_the_roots
+fn depressed_invariants<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> (F, F, F) {
+    let _3 = F::from(3i16);
+    let _4 = F::from(4i16);
+    let _8 = F::from(8i16);
+    let _16 = F::from(16i16);
+    let _64 = F::from(64i16);
+
+    let pp = _8 * a4 * a2 - _3 * a3 * a3;
+    let rr = a3 * a3 * a3 + _8 * a4 * a4 * a1 - _4 * a4 * a3 * a2;
+    let dd = _64 * a4 * a4 * a4 * a0 - _16 * a4 * a4 * a2 * a2 + _16 * a4 * a3 * a3 * a2
+        - _16 * a4 * a4 * a3 * a1
+        - _3 * a3 * a3 * a3 * a3;
+    (pp, rr, dd)
+}
+
+/// Calculates the discriminant, pp, rr, dd, and delta0 of the quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0.
+fn quartic_invariants<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> (F, F, F, F, F) {
+    let _3 = F::from(3i16);
+    let _12 = F::from(12i16);
+
+    let discriminant = discriminant_quartic(a4, a3, a2, a1, a0);
+    let (pp, rr, dd) = depressed_invariants(a4, a3, a2, a1, a0);
+    let delta0 = a2 * a2 - _3 * a3 * a1 + _12 * a4 * a0;
+    (discriminant, pp, rr, dd, delta0)
+}
+
+/// Checks if the quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 is palindromic (a0 = a4, a1 = a3)
+/// or anti-palindromic (a0 = a4, a1 = -a3) within the relative epsilon.
+/// Returns 1 or -1 correspondingly.
+fn reciprocal_symmetry<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Option<F> {
+    let mut max = a4.abs();
+    for a in [a3, a2, a1, a0].iter() {
+        if a.abs() > max {
+            max = a.abs();
+        }
+    }
+    let tolerance = F::epsilon() * max;
+    if (a0 - a4).abs() > tolerance {
+        None
+    } else if (a1 - a3).abs() <= tolerance {
+        Some(F::one())
+    } else if (a1 + a3).abs() <= tolerance {
+        Some(-F::one())
+    } else {
+        None
+    }
+}
+
+/// Solves the quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 when a3 and a1 are negligible
+/// compared to other coefficients. Roots of the bi-quadratic equation a4*x^4 + a2*x^2 + a0 = 0
+/// are corrected by Newton-Raphson steps; usually two steps reduce the initial error eps^(1/4) down to eps.
+/// Returns None if a3 or a1 are not small enough, or if the perturbation may change the number of real roots.
+fn find_roots_nearly_biquadratic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Option<Roots<F>> {
+    let _2 = F::from(2i16);
+    let _3 = F::from(3i16);
+    let _4 = F::from(4i16);
+    let mut scale = a4.abs();
+    for a in [a2, a0].iter() {
+        if a.abs() > scale {
+            scale = a.abs();
+        }
+    }
+    let tolerance = F::epsilon().sqrt().sqrt() * scale;
+    if a3.abs() > tolerance || a1.abs() > tolerance {
+        return None;
+    }
+
+    let mut roots = Roots::No([]);
+    for x in super::biquadratic::find_roots_biquadratic(a4, a2, a0).as_ref().iter() {
+        let mut x = *x;
+        let mut last_step = None;
+        for _ in 0..4 {
+            let value = (((a4 * x + a3) * x + a2) * x + a1) * x + a0;
+            let derivative = ((_4 * a4 * x + _3 * a3) * x + _2 * a2) * x + a1;
+            if derivative == F::zero() {
+                return None;
+            }
+            let step = value / derivative;
+            // Stop when rounding errors dominate the step
+            match last_step {
+                Some(last_step) if step.abs() >= last_step => break,
+                _ => last_step = Some(step.abs()),
+            }
+            x = x - step;
+        }
+        roots = roots.add_new_root(x);
+    }
+
+    // A double root of the bi-quadratic equation may split into two real or two complex roots.
+    // The sign of the discriminant tells the number of real roots: positive for 0 or 4, negative for 2.
+    let discriminant = discriminant_quartic(a4, a3, a2, a1, a0);
+    let expected = match roots {
+        Roots::Two(_) => discriminant < F::zero(),
+        Roots::No(_) | Roots::Four(_) => discriminant > F::zero(),
+        _ => false,
+    };
+    if expected && super::quartic_refined::max_relative_residual(a4, a3, a2, a1, a0, &roots) <= F::from(1000i16) {
+        Some(roots)
+    } else {
+        None
+    }
+}
+
+/// Solves a quartic equation which has a double real root.
+/// The double root is a root of the derivative, so it is the root of the cubic 4*a4*x^3 + 3*a3*x^2 + 2*a2*x + a1 = 0
+/// with the smallest residual. Dividing the quartic by the square of (x - x0) leaves a quadratic equation.
+fn find_roots_with_double_root<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
+    let _2 = F::from(2i16);
+    let _3 = F::from(3i16);
+    let _4 = F::from(4i16);
+    let mut double_root = None;
+    let mut min_residual = F::zero();
+    for x in super::cubic::find_roots_cubic(_4 * a4, _3 * a3, _2 * a2, a1).as_ref().iter() {
+        let residual = super::quartic_refined::max_relative_residual(a4, a3, a2, a1, a0, &Roots::One([*x]));
+        if double_root.is_none() || residual < min_residual {
+            double_root = Some(*x);
+            min_residual = residual;
+        }
+    }
+    // A double root is a simple root of the derivative; polish it by one Newton step
+    let polish = |x: F| {
+        // The derivative is close to zero and it is evaluated in double-double precision
+        let dd = DoubleDouble::from;
+        let derivative = (((DoubleDouble::from(4i16) * dd(a4) * dd(x) + DoubleDouble::from(3i16) * dd(a3)) * dd(x)
+            + DoubleDouble::from(2i16) * dd(a2))
+            * dd(x)
+            + dd(a1))
+        .value();
+        let second_derivative = (F::from(12i16) * a4 * x + F::from(6i16) * a3) * x + _2 * a2;
+        if second_derivative == F::zero() {
+            x
+        } else {
+            x - derivative / second_derivative
+        }
+    };
+    match double_root.map(polish) {
+        Some(x0) => {
+            // (x - x0)^2 * (a4*x^2 + b*x + c) = a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0
+            let b = a3 + _2 * x0 * a4;
+            let c = a2 + _2 * x0 * b - x0 * x0 * a4;
+            // Both b and c carry rounding errors; the quadratic may have a double root as well
+            let discriminant = b * b - _4 * a4 * c;
+            if discriminant.abs() <= F::from(16i16) * F::epsilon() * (b * b + (_4 * a4 * c).abs()) {
+                Roots::One([polish(-b / (_2 * a4))]).add_new_root(x0)
+            } else {
+                super::quadratic::find_roots_quadratic(a4, b, c).add_new_root(x0)
+            }
+        }
+        None => Roots::No([]),
+    }
+}
+
+/// Checks the roots found by the Ferrari's method. If residuals are too big,
+/// tries to solve the equation by factoring it with find_roots_quartic_refined.
+fn refine_if_needed<F: FloatType>(
+    a4: F,
+    a3: F,
+    a2: F,
+    a1: F,
+    a0: F,
+    roots: Roots<F>,
+    branch: QuarticBranch,
+) -> (Roots<F>, QuarticBranch) {
+    let residual = super::quartic_refined::max_relative_residual(a4, a3, a2, a1, a0, &roots);
+    if residual > F::from(1000i16) {
+        let refined = super::quartic_refined::find_roots_quartic_refined(a4, a3, a2, a1, a0);
+        if super::quartic_refined::max_relative_residual(a4, a3, a2, a1, a0, &refined) < residual {
+            return (refined, QuarticBranch::Refined);
+        }
+    }
+    (roots, branch)
+}
+
+/// Solves a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 in the given precision.
+fn solve_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> (Roots<F>, QuarticBranch) {
     // Handle non-standard cases
     if a4 == F::zero() {
         // a4 = 0; a3*x^3 + a2*x^2 + a1*x + a0 = 0; solve cubic equation
-        super::cubic::find_roots_cubic(a3, a2, a1, a0)
+        (super::cubic::find_roots_cubic(a3, a2, a1, a0), QuarticBranch::Cubic)
     } else if a0 == F::zero() {
         // a0 = 0; x^4 + a2*x^2 + a1*x = 0; reduce to cubic and arrange results
-        super::cubic::find_roots_cubic(a4, a3, a2, a1).add_new_root(F::zero())
+        (
+            super::cubic::find_roots_cubic(a4, a3, a2, a1).add_new_root(F::zero()),
+            QuarticBranch::ZeroRoot,
+        )
     } else if a1 == F::zero() && a3 == F::zero() {
         // a1 = 0, a3 =0; a4*x^4 + a2*x^2 + a0 = 0; solve bi-quadratic equation
-        super::biquadratic::find_roots_biquadratic(a4, a2, a0)
+        (
+            super::biquadratic::find_roots_biquadratic(a4, a2, a0),
+            QuarticBranch::Biquadratic,
+        )
+    } else if let Some(symmetry) = reciprocal_symmetry(a4, a3, a2, a1, a0) {
+        // a0 = a4, a1 = +/-a3; solve the quadratic equation in z = x +/- 1/x
+        let (a4, a3) = ((a4 + a0) / F::from(2i16), (a3 + symmetry * a1) / F::from(2i16));
+        if symmetry > F::zero() {
+            (
+                super::palindromic::find_roots_quartic_palindromic(a4, a3, a2),
+                QuarticBranch::Palindromic,
+            )
+        } else {
+            (
+                super::palindromic::find_roots_quartic_anti_palindromic(a4, a3, a2),
+                QuarticBranch::AntiPalindromic,
+            )
+        }
+    } else if let Some(roots) = find_roots_nearly_biquadratic(a4, a3, a2, a1, a0) {
+        // a1 and a3 are small; correct roots of a4*x^4 + a2*x^2 + a0 = 0
+        (roots, QuarticBranch::NearlyBiquadratic)
+    } else if a3 == F::zero() || is_shift_negligible(a4, a3, a2, a1, a0) {
+        // a3 = 0; a4*x^4 + a2*x^2 + a1*x + a0 = 0; the equation is already depressed, no need to shift roots
+        refine_if_needed(
+            a4,
+            a3,
+            a2,
+            a1,
+            a0,
+            super::quartic_depressed::find_roots_quartic_depressed(a2 / a4, a1 / a4, a0 / a4),
+            QuarticBranch::Depressed,
+        )
     } else {
         let _3 = F::from(3i16);
         let _4 = F::from(4i16);
-        let _6 = F::from(6i16);
         let _8 = F::from(8i16);
         let _9 = F::from(9i16);
         let _10 = F::from(10i16);
-        let _12 = F::from(12i16);
-        let _16 = F::from(16i16);
-        let _18 = F::from(18i16);
-        let _27 = F::from(27i16);
-        let _64 = F::from(64i16);
         let _72 = F::from(72i16);
-        let _80 = F::from(80i16);
-        let _128 = F::from(128i16);
-        let _144 = F::from(144i16);
-        let _192 = F::from(192i16);
-        let _256 = F::from(256i16);
-        // Discriminant
-        // https://en.wikipedia.org/wiki/Quartic_function#Nature_of_the_roots
-        // Partially simplifed to keep intermediate values smaller (to minimize rounding errors).
-        let discriminant = a4 * a0 * a4 * (_256 * a4 * a0 * a0 + a1 * (_144 * a2 * a1 - _192 * a3 * a0))
-            + a4 * a0 * a2 * a2 * (_16 * a2 * a2 - _80 * a3 * a1 - _128 * a4 * a0)
-            + (a3
-                * a3
-                * (a4 * a0 * (_144 * a2 * a0 - _6 * a1 * a1)
-                    + (a0 * (_18 * a3 * a2 * a1 - _27 * a3 * a3 * a0 - _4 * a2 * a2 * a2) + a1 * a1 * (a2 * a2 - _4 * a3 * a1))))
-            + a4 * a1 * a1 * (_18 * a3 * a2 * a1 - _27 * a4 * a1 * a1 - _4 * a2 * a2 * a2);
-        let pp = _8 * a4 * a2 - _3 * a3 * a3;
-        let rr = a3 * a3 * a3 + _8 * a4 * a4 * a1 - _4 * a4 * a3 * a2;
-        let delta0 = a2 * a2 - _3 * a3 * a1 + _12 * a4 * a0;
-        let dd = _64 * a4 * a4 * a4 * a0 - _16 * a4 * a4 * a2 * a2 + _16 * a4 * a3 * a3 * a2
-            - _16 * a4 * a4 * a3 * a1
-            - _3 * a3 * a3 * a3 * a3;
+        let (discriminant, pp, rr, dd, delta0) = quartic_invariants(a4, a3, a2, a1, a0);
 
         // Handle special cases
         let double_root = discriminant == F::zero();
@@ -136,7 +798,7 @@ pub fn find_roots_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Ro
             let no_roots = dd == F::zero() && pp > F::zero() && rr == F::zero();
             if quadruple_root {
                 // Wiki: all four roots are equal
-                Roots::One([-a3 / (_4 * a4)])
+                (Roots::One([-a3 / (_4 * a4)]), QuarticBranch::QuadrupleRoot)
             } else if triple_root {
                 // Wiki: At least three roots are equal to each other
                 // x0 is the unique root of the remainder of the Euclidean division of the quartic by its second derivative
@@ -155,20 +817,36 @@ pub fn find_roots_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Ro
                 let x0 = (-_72 * a4 * a4 * a0 + _10 * a4 * a2 * a2 - _3 * a3 * a3 * a2)
                     / (_9 * (_8 * a4 * a4 * a1 - _4 * a4 * a3 * a2 + a3 * a3 * a3));
                 let roots = Roots::One([x0]);
-                roots.add_new_root(-(a3 / a4 + _3 * x0))
+                (roots.add_new_root(-(a3 / a4 + _3 * x0)), QuarticBranch::TripleRoot)
             } else if no_roots {
                 // Wiki: two complex conjugate double roots
-                Roots::No([])
+                (Roots::No([]), QuarticBranch::ComplexDoubleRoots)
             } else {
-                find_roots_via_depressed_quartic(a4, a3, a2, a1, a0, pp, rr, dd)
+                refine_if_needed(
+                    a4,
+                    a3,
+                    a2,
+                    a1,
+                    a0,
+                    find_roots_with_double_root(a4, a3, a2, a1, a0),
+                    QuarticBranch::DoubleRoot,
+                )
             }
         } else {
             let no_roots = discriminant > F::zero() && (pp > F::zero() || dd > F::zero());
             if no_roots {
                 // Wiki: two pairs of non-real complex conjugate roots
-                Roots::No([])
+                (Roots::No([]), QuarticBranch::ComplexRoots)
             } else {
-                find_roots_via_depressed_quartic(a4, a3, a2, a1, a0, pp, rr, dd)
+                refine_if_needed(
+                    a4,
+                    a3,
+                    a2,
+                    a1,
+                    a0,
+                    find_roots_via_depressed_quartic(a4, a3, a2, a1, a0),
+                    QuarticBranch::ShiftedDepressed,
+                )
             }
         }
     }
@@ -187,9 +865,13 @@ mod test {
             Roots::Four([1f64, 2f64, 3f64, 4f64])
         );
 
-        match find_roots_quartic(1.1248467624839498f64, -4.8721513473605924f64,
-                                 7.9323705711747614f64, -5.7774307699949397f64,
-                                 1.5971379368787519f64) {
+        match find_roots_quartic(
+            1.1248467624839498f64,
+            -4.8721513473605924f64,
+            7.9323705711747614f64,
+            -5.7774307699949397f64,
+            1.5971379368787519f64,
+        ) {
             Roots::Two(x) => {
                 assert_float_array_eq!(2e-15f64, x, [1.225913506454221f64, 1.257275575390252f64]);
             }
@@ -217,6 +899,61 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_find_roots_quartic_depressed_input() {
+        // 2x^4 - 20x^2 + 10x + 2 is already depressed; solve it without shifting roots
+        let quartic = |x: f64| ((2f64 * x * x - 20f64) * x + 10f64) * x + 2f64;
+        let roots = find_roots_quartic(2f64, 0f64, -20f64, 10f64, 2f64);
+        assert_eq!(roots, find_roots_quartic_depressed(-10f64, 5f64, 1f64));
+        match roots {
+            Roots::Four(x) => {
+                assert!(x[0] < x[1] && x[1] < x[2] && x[2] < x[3]);
+                for root in x.iter() {
+                    assert_float_eq!(3e-14f64, quartic(*root), 0f64);
+                }
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+
+        // a3 is too small to change any root
+        assert_eq!(
+            find_roots_quartic(2f64, 1e-30f64, -20f64, 10f64, 2f64),
+            find_roots_quartic(2f64, 0f64, -20f64, 10f64, 2f64)
+        );
+    }
+
+    #[test]
+    fn test_find_roots_quartic_depressed_input_residuals() {
+        // Residuals of the direct solution compared to the shifted Ferrari's path used before,
+        // in rounding errors of the polynomial evaluation
+        let cases = [
+            [2f64, 0f64, -20f64, 10f64, 2f64],
+            [3f64, 0f64, -7f64, 1.1f64, 0.3f64],
+            [1f64, 0f64, -1e4f64, 1f64, 1f64],
+            [7f64, 0f64, -13f64, 0.1f64, 5f64],
+            [0.3f64, 0f64, -5f64, 2f64, -0.7f64],
+            [1.7f64, 0f64, -11.3f64, 3.3f64, 1.9f64],
+            [5f64, 0f64, -100f64, -7f64, 3f64],
+            [0.1f64, 0f64, -3f64, 0.7f64, 0.2f64],
+        ];
+        let mut improved = 0;
+        for c in cases.iter() {
+            let direct = find_roots_quartic(c[0], c[1], c[2], c[3], c[4]);
+            let shifted = super::find_roots_via_depressed_quartic(c[0], c[1], c[2], c[3], c[4]);
+            assert_eq!(direct.as_ref().len(), shifted.as_ref().len(), "{:?}", c);
+            let residual_direct = super::super::quartic_refined::max_relative_residual(c[0], c[1], c[2], c[3], c[4], &direct);
+            let residual_shifted = super::super::quartic_refined::max_relative_residual(c[0], c[1], c[2], c[3], c[4], &shifted);
+            assert!(residual_direct < 1f64, "{:?}", c);
+            assert!(residual_direct <= residual_shifted, "{:?}", c);
+            if residual_direct < residual_shifted {
+                improved += 1;
+            }
+        }
+        assert!(improved >= 4);
+    }
+
     #[test]
     fn test_find_roots_quartic_tim_luecke() {
         // Reported in December 2019
@@ -224,12 +961,12 @@ mod test {
             find_roots_quartic(-14.0625f64, -3.75f64, 29.75f64, 4.0f64, -16.0f64),
             Roots::Two([-1.1016116464173349f64, 0.9682783130840016f64])
         );
-        // 32-bit floating point is not accurate enough to solve this case ...
+        // 32-bit floating point is solved in 64-bit precision ...
         assert_eq!(
             find_roots_quartic(-14.0625f32, -3.75f32, 29.75f32, 4.0f32, -16.0f32),
-            Roots::No([])
+            Roots::Two([-1.1016116f32, 0.9682783f32])
         );
-        // ... even after normalizing
+        // ... but normalizing in f32 rounds the coefficients, and the double root splits into two complex ones
         assert_eq!(
             find_roots_quartic(
                 1f32,
@@ -240,10 +977,276 @@ mod test {
             ),
             Roots::No([])
         );
-        // assert_eq!(
-        //     find_roots_quartic(-14.0625f32, -3.75f32, 29.75f32, 4.0f32, -16.0f32),
-        //     Roots::Two([-1.1016117f32, 0.96827835f32])
-        // );
+    }
+
+    #[test]
+    fn test_find_roots_quartic_double_root_discriminant() {
+        // 9(x+33)^2(x^2+15x+63.8125) has the double root -33 and two complex roots.
+        // Rounding errors make the discriminant positive (no real roots) unless it is recalculated precisely.
+        assert_eq!(
+            find_roots_quartic(9f64, 729f64, 19285.3125f64, 184919.625f64, 625426.3125f64),
+            Roots::One([-33f64])
+        );
+        // -12(x-3.625)^2(x^2-12x+45)
+        assert_eq!(
+            find_roots_quartic(-12f64, 231f64, -1741.6875f64, 5807.25f64, -7095.9375f64),
+            Roots::One([3.625f64])
+        );
+        // 2(x-39)^2(x^2-6x+178)
+        assert_eq!(
+            find_roots_quartic(2f64, -168f64, 4334f64, -46020f64, 541476f64),
+            Roots::One([39f64])
+        );
+        // 330000000*(-14.0625x^4-3.75x^3+29.75x^2+4x-16) has two double roots for any scale of coefficients
+        assert_eq!(
+            find_roots_quartic(-4640625000f64, -1237500000f64, 9817500000f64, 1320000000f64, -5280000000f64),
+            Roots::Two([-1.1016116464173349f64, 0.9682783130840016f64])
+        );
+    }
+
+    #[test]
+    fn test_find_smallest_positive_root_quartic() {
+        assert_eq!(
+            find_smallest_positive_root_quartic(1f64, -5f64, 5f64, 5f64, -6f64, 1.5f64),
+            Some(2f64)
+        );
+        assert_eq!(
+            find_smallest_positive_root_quartic(1f64, -5f64, 5f64, 5f64, -6f64, 3f64),
+            None
+        );
+        assert_eq!(
+            find_smallest_positive_root_quartic(1f64, 10f64, 35f64, 50f64, 24f64, 0f64),
+            None
+        );
+        assert_eq!(
+            find_smallest_positive_root_quartic(0f64, 0f64, 0f64, 0f64, 0f64, -1f64),
+            Some(0f64)
+        );
+
+        // Compare with filtering all roots for random equations
+        let mut seed = 1u32;
+        let mut random = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            f64::from(seed >> 8) / f64::from(1u32 << 24) * 20f64 - 10f64
+        };
+        for _ in 0..10000 {
+            let (a4, a3, a2, a1, a0, t_min) = (random(), random(), random(), random(), random(), random() / 5f64);
+            let filtered = find_roots_quartic(a4, a3, a2, a1, a0)
+                .as_ref()
+                .iter()
+                .find(|x| **x > t_min)
+                .cloned();
+            match (find_smallest_positive_root_quartic(a4, a3, a2, a1, a0, t_min), filtered) {
+                // The isolated root is polished, so it may differ by rounding errors of the full solution
+                (Some(x), Some(y)) => assert!((x - y).abs() <= 1e-12f64 * y.abs().max(1f64)),
+                (x, y) => assert_eq!(x, y),
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_roots_quartic_nearly_biquadratic() {
+        // Tiny cubic and linear terms, like the ones reported
+        let a = [-0.5f64, 3.7e-9f64, 1f64, 1.1e-5f64, -0.375f64];
+        let (roots, diagnostics) = find_roots_quartic_diagnostics(a[0], a[1], a[2], a[3], a[4]);
+        assert_eq!(diagnostics.branch, QuarticBranch::NearlyBiquadratic);
+        match roots {
+            Roots::Four(x) => {
+                assert_float_array_eq!(
+                    1e-15f64,
+                    x,
+                    [
+                        -1.2247338655944386f64,
+                        -0.7071177832933818f64,
+                        0.7070957795933606f64,
+                        1.22475587669446f64
+                    ]
+                );
+                for check in verify_roots(&a, &x).iter() {
+                    assert!(check.residual < 1e-12f64);
+                }
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+
+        // Synthetic near bi-quadratic equations
+        let mut seed = 1u32;
+        let mut random = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            f64::from(seed >> 8) / f64::from(1u32 << 24)
+        };
+        for _ in 0..10000 {
+            let a4 = 0.5f64 + 1.5f64 * random();
+            let a2 = 4f64 * random() - 2f64;
+            let a0 = 4f64 * random() - 2f64;
+            let a3 = (random() - 0.5f64) * 10f64.powf(-4f64 - 8f64 * random());
+            let a1 = (random() - 0.5f64) * 10f64.powf(-4f64 - 8f64 * random());
+            let a = [a4, a3, a2, a1, a0];
+            let roots = find_roots_quartic(a4, a3, a2, a1, a0);
+            assert_eq!(
+                roots.as_ref().len(),
+                find_roots_quartic_refined(a4, a3, a2, a1, a0).as_ref().len()
+            );
+            for check in verify_roots(&a, roots.as_ref()).iter() {
+                assert!(check.residual < 1e-12f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_depress_quartic() {
+        assert_eq!(depress_quartic(1f64, -4f64, 0f64, 0f64, 0f64), (-6f64, -8f64, -3f64, 1f64));
+        assert_eq!(depress_quartic(2f64, 0f64, -10f64, 6f64, 8f64), (-5f64, 3f64, 4f64, 0f64));
+
+        // Shifted roots of the depressed quartic are roots of the original one
+        let (p, q, r, shift) = depress_quartic(1f64, -10f64, 35f64, -50f64, 24f64);
+        match find_roots_quartic_depressed(p, q, r) {
+            Roots::Four(y) => {
+                assert_float_array_eq!(
+                    1e-14,
+                    [y[0] + shift, y[1] + shift, y[2] + shift, y[3] + shift],
+                    [1f64, 2f64, 3f64, 4f64]
+                );
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_resolvent_cubic() {
+        assert_eq!(resolvent_cubic(1f64, 0f64, -5f64, 0f64, 4f64), (-12.5f64, 46f64, -52.5f64));
+
+        // Any root y with p + 2y > 0 factors the depressed quartic into two quadratics
+        let (a4, a3, a2, a1, a0) = (2f64, -3f64, -7f64, 5f64, 1f64);
+        let (p, q, r, _) = depress_quartic(a4, a3, a2, a1, a0);
+        let (b2, b1, b0) = resolvent_cubic(a4, a3, a2, a1, a0);
+        let mut factorizations = 0;
+        for u in find_roots_cubic_normalized(b2, b1, b0).as_ref().iter() {
+            if p + 2f64 * u > 0f64 {
+                let s = (p + 2f64 * u).sqrt();
+                let (t1, t2) = (p + u - q / (2f64 * s), p + u + q / (2f64 * s));
+                // (y^2 + s*y + t1)*(y^2 - s*y + t2) = y^4 + (t1 + t2 - s^2)*y^2 + s*(t2 - t1)*y + t1*t2
+                assert_float_eq!(1e-12, t1 + t2 - s * s, p);
+                assert_float_eq!(1e-12, s * (t2 - t1), q);
+                assert_float_eq!(1e-12, t1 * t2, r);
+                factorizations += 1;
+            }
+        }
+        assert!(factorizations > 0);
+    }
+
+    #[test]
+    fn test_discriminant_quartic() {
+        assert_eq!(discriminant_quartic(1f64, 0f64, -5f64, 0f64, 4f64), 5184f64);
+        assert_eq!(discriminant_quartic(1f64, -10f64, 35f64, -50f64, 24f64), 144f64);
+        // (x^2 + 2x + 2)(x^2 + 1)
+        assert_eq!(discriminant_quartic(1f64, 2f64, 3f64, 2f64, 2f64), 400f64);
+        // (x^2 - 3)(x^2 + x + 1)
+        assert_eq!(discriminant_quartic(1f32, 1f32, -2f32, -3f32, -3f32), -6084f32);
+        // (4x^2 - 9x - 1)^2
+        assert_eq!(discriminant_quartic(16f64, -72f64, 73f64, 18f64, 1f64), 0f64);
+
+        // Close roots are told apart in f64 arithmetic, but not in f32; the sign agrees with the roots found
+        let (a4, a3, a2, a1, a0) = (1f32, -4.952973f32, 8.769035f32, -6.4085703f32, 1.5387619f32);
+        assert!(discriminant_quartic(a4, a3, a2, a1, a0) > 0f32);
+        assert_eq!(find_roots_quartic(a4, a3, a2, a1, a0).as_ref().len(), 4);
+        let (a4, a3, a2, a1, a0) = (1f32, 6.522452f32, 15.755345f32, 16.661232f32, 6.484999f32);
+        assert!(discriminant_quartic(a4, a3, a2, a1, a0) < 0f32);
+        assert_eq!(find_roots_quartic(a4, a3, a2, a1, a0).as_ref().len(), 2);
+
+        // The discriminant scales by the sixth power of the coefficient scale
+        for scale in [2f64.powi(-20), 0.5f64, 1024f64].iter() {
+            let s = *scale;
+            let s6 = s * s * s * s * s * s;
+            assert_eq!(discriminant_quartic(s, s, -2f64 * s, -3f64 * s, -3f64 * s), -6084f64 * s6);
+            assert_eq!(discriminant_quartic(16f64 * s, -72f64 * s, 73f64 * s, 18f64 * s, s), 0f64);
+        }
+    }
+
+    #[test]
+    fn test_count_real_roots_quartic() {
+        assert_eq!(count_real_roots_quartic(1f64, 0f64, 0f64, 0f64, 0f64), 1);
+        assert_eq!(count_real_roots_quartic(1f64, 0f64, 0f64, 0f64, -1f64), 2);
+        // (x^2 + 2x + 2)^2
+        assert_eq!(count_real_roots_quartic(1f64, 4f64, 8f64, 8f64, 4f64), 0);
+        // (x+3)(3x-1)^3
+        assert_eq!(count_real_roots_quartic(27f64, 54f64, -72f64, 26f64, -3f64), 2);
+        assert_eq!(count_real_roots_quartic(-14.0625f32, -3.75f32, 29.75f32, 4.0f32, -16.0f32), 2);
+        assert!(!has_real_roots_quartic(1f64, 2f64, 3f64, 2f64, 2f64));
+        assert!(has_real_roots_quartic(1f64, -10f64, 35f64, -50f64, 24f64));
+
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            f64::from(seed >> 8) / f64::from(1u32 << 24) * 20f64 - 10f64
+        };
+        for _ in 0..3000 {
+            let a = [next(), next(), next(), next(), next()];
+            let count = find_roots_quartic(a[0], a[1], a[2], a[3], a[4]).as_ref().len();
+            assert_eq!(count_real_roots_quartic(a[0], a[1], a[2], a[3], a[4]), count);
+            assert_eq!(has_real_roots_quartic(a[0], a[1], a[2], a[3], a[4]), count > 0);
+            // Products of linear factors cover four real roots
+            let (x1, x2, x3) = (a[0], a[1], a[2]);
+            let (b3, b2, b1, b0) = (-(x1 + x2 + x3), x1 * x2 + x2 * x3 + x1 * x3, -x1 * x2 * x3, 0f64);
+            let c = [1f64, b3 - a[3], b2 - a[3] * b3, b1 - a[3] * b2, b0 - a[3] * b1];
+            let count = find_roots_quartic(c[0], c[1], c[2], c[3], c[4]).as_ref().len();
+            assert_eq!(count_real_roots_quartic(c[0], c[1], c[2], c[3], c[4]), count);
+            let f = [a[0].round(), a[1].round(), a[2].round() + 0.5f64];
+            let d = [
+                f[0] * f[0],
+                2f64 * f[0] * f[1],
+                f[1] * f[1] + 2f64 * f[0] * f[2],
+                2f64 * f[1] * f[2],
+                f[2] * f[2],
+            ];
+            // Squares have the double roots of the squared quadratic, which the solver may split into close roots
+            let count = super::super::quadratic::count_real_roots_quadratic(f[0], f[1], f[2]);
+            assert_eq!(count_real_roots_quartic(d[0], d[1], d[2], d[3], d[4]), count, "{:?}", d);
+        }
+    }
+
+    #[test]
+    fn test_find_roots_quartic_with_condition() {
+        let roots = find_roots_quartic_with_condition(1f64, -10f64, 35f64, -50f64, 24f64);
+        assert_eq!(roots, vec![(1f64, 20f64), (2f64, 90f64), (3f64, 140f64), (4f64, 70f64)]);
+
+        // Double roots found with errors of about sqrt(epsilon)
+        for (x, condition) in find_roots_quartic_with_condition(-14.0625f64, -3.75f64, 29.75f64, 4.0f64, -16.0f64) {
+            assert!(condition > 1e15f64, "root {} condition {}", x, condition);
+        }
+        for (x, condition) in find_roots_quartic_with_condition(-14.0625f32, -3.75f32, 29.75f32, 4.0f32, -16.0f32) {
+            assert!(condition > 1e6f32, "root {} condition {}", x, condition);
+        }
+
+        assert_eq!(find_roots_quartic_with_condition(1f32, 0f32, 0f32, 0f32, 1f32), vec![]);
+    }
+
+    #[test]
+    fn test_find_roots_quartic_diagnostics() {
+        let branch = |a4: f64, a3: f64, a2: f64, a1: f64, a0: f64| find_roots_quartic_diagnostics(a4, a3, a2, a1, a0).1.branch;
+        assert_eq!(branch(0f64, 1f64, 0f64, -1f64, 0f64), QuarticBranch::Cubic);
+        assert_eq!(branch(1f64, 1f64, 0f64, -1f64, 0f64), QuarticBranch::ZeroRoot);
+        assert_eq!(branch(1f64, 0f64, -5f64, 0f64, 4f64), QuarticBranch::Biquadratic);
+        assert_eq!(branch(2f64, 3f64, -1f64, 3f64, 2f64), QuarticBranch::Palindromic);
+        assert_eq!(branch(2f64, 3f64, -1f64, -3f64, 2f64), QuarticBranch::AntiPalindromic);
+        assert_eq!(branch(2f64, 0f64, -20f64, 10f64, 2f64), QuarticBranch::Depressed);
+        assert_eq!(branch(2401f64, 2744f64, 1176f64, 224f64, 16f64), QuarticBranch::QuadrupleRoot);
+        assert_eq!(branch(27f64, 54f64, -72f64, 26f64, -3f64), QuarticBranch::TripleRoot);
+        // (x^2 + 2x + 2)^2
+        assert_eq!(branch(1f64, 4f64, 8f64, 8f64, 4f64), QuarticBranch::ComplexDoubleRoots);
+        // (x^2 + 2x + 2)(x^2 + 1)
+        assert_eq!(branch(1f64, 2f64, 3f64, 2f64, 2f64), QuarticBranch::ComplexRoots);
+        assert_eq!(branch(1f64, -10f64, 35f64, -50f64, 24f64), QuarticBranch::ShiftedDepressed);
+
+        let (roots, diagnostics) = find_roots_quartic_diagnostics(3f32, 5f32, -5f32, -5f32, 2f32);
+        assert_eq!(roots, find_roots_quartic(3f32, 5f32, -5f32, -5f32, 2f32));
+        assert_eq!(diagnostics.pp, 8f32 * 3f32 * -5f32 - 3f32 * 5f32 * 5f32);
+        assert_eq!(diagnostics.delta0, 25f32 + 75f32 + 72f32);
+        assert!(diagnostics.discriminant > 0f32);
     }
 
     #[test]
@@ -0,0 +1,319 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use num_complex::Complex;
+use std::cmp::Ordering;
+
+/// Finds roots of x^2 + b*x + c = 0 including complex ones.
+fn complex_roots_of_quadratic<F: FloatType>(b: F, c: F) -> [Complex<F>; 2] {
+    let _2 = F::from(2i16);
+    let _4 = F::from(4i16);
+    let discriminant = b * b - _4 * c;
+    if discriminant < F::zero() {
+        let re = -b / _2;
+        let im = (-discriminant).sqrt() / _2;
+        [Complex::new(re, -im), Complex::new(re, im)]
+    } else {
+        // The root with the greatest absolute value does not lose precision; the other one is c/x1
+        let sqrt_discriminant = discriminant.sqrt();
+        let same_sign = if b < F::zero() {
+            -b + sqrt_discriminant
+        } else {
+            -b - sqrt_discriminant
+        };
+        if same_sign == F::zero() {
+            [Complex::new(F::zero(), F::zero()), Complex::new(F::zero(), F::zero())]
+        } else {
+            let x1 = same_sign / _2;
+            [Complex::new(x1, F::zero()), Complex::new(c / x1, F::zero())]
+        }
+    }
+}
+
+/// Square root of the complex number with the non-negative real part.
+pub fn complex_sqrt<F: FloatType>(z: Complex<F>) -> Complex<F> {
+    let _2 = F::from(2i16);
+    let modulus = (z.re * z.re + z.im * z.im).sqrt();
+    if modulus == F::zero() {
+        z
+    } else if z.re >= F::zero() {
+        let re = ((modulus + z.re) / _2).sqrt();
+        Complex::new(re, z.im / (_2 * re))
+    } else {
+        // Avoid cancellation in modulus - re
+        let im = ((modulus - z.re) / _2).sqrt();
+        Complex::new(z.im.abs() / (_2 * im), if z.im < F::zero() { -im } else { im })
+    }
+}
+
+/// Finds all roots of the depressed quartic y^4 + p*y^2 + r = 0 via the quadratic equation in y^2.
+fn complex_roots_of_biquadratic<F: FloatType>(p: F, r: F) -> [Complex<F>; 4] {
+    let [z1, z2] = complex_roots_of_quadratic(p, r);
+    let (y1, y2) = (complex_sqrt(z1), complex_sqrt(z2));
+    [y1, Complex::new(-y1.re, -y1.im), y2, Complex::new(-y2.re, -y2.im)]
+}
+
+/// Product of complex numbers; FloatType does not provide arithmetic of num-complex
+pub fn complex_mul<F: FloatType>(a: Complex<F>, b: Complex<F>) -> Complex<F> {
+    Complex::new(a.re * b.re - a.im * b.im, a.re * b.im + a.im * b.re)
+}
+
+/// Quotient of complex numbers
+pub fn complex_div<F: FloatType>(a: Complex<F>, b: Complex<F>) -> Complex<F> {
+    let norm_sqr = b.re * b.re + b.im * b.im;
+    Complex::new((a.re * b.re + a.im * b.im) / norm_sqr, (a.im * b.re - a.re * b.im) / norm_sqr)
+}
+
+/// Returns values of the polynomial with the given coefficients and its derivative at x
+fn evaluate_with_derivative<F: FloatType>(coefficients: &[F; 5], x: Complex<F>) -> (Complex<F>, Complex<F>) {
+    let mut value = Complex::new(F::zero(), F::zero());
+    let mut derivative = Complex::new(F::zero(), F::zero());
+    for a in coefficients.iter() {
+        let d = complex_mul(derivative, x);
+        derivative = Complex::new(d.re + value.re, d.im + value.im);
+        let v = complex_mul(value, x);
+        value = Complex::new(v.re + *a, v.im);
+    }
+    (value, derivative)
+}
+
+/// Refines the root by Newton-Raphson steps on the original polynomial while its value decreases.
+///
+/// The depressed quartic loses precision of small roots when the shift is big.
+fn polish_complex_root<F: FloatType>(coefficients: &[F; 5], x: Complex<F>) -> Complex<F> {
+    let mut x = x;
+    let (mut value, mut derivative) = evaluate_with_derivative(coefficients, x);
+    for _ in 0..4 {
+        if derivative.re == F::zero() && derivative.im == F::zero() {
+            break;
+        }
+        let step = complex_div(value, derivative);
+        let next = Complex::new(x.re - step.re, x.im - step.im);
+        let (next_value, next_derivative) = evaluate_with_derivative(coefficients, next);
+        if next_value.re.abs() + next_value.im.abs() >= value.re.abs() + value.im.abs() {
+            break;
+        }
+        x = next;
+        value = next_value;
+        derivative = next_derivative;
+    }
+    x
+}
+
+/// Finds roots of a3*x^3 + a2*x^2 + a1*x + a0 = 0 including complex ones, ordered and followed by NaN.
+///
+/// One real root of the cubic is deflated, and the remaining quadratic is solved.
+/// Equations of lower degrees are solved the same way, with more NaN.
+fn complex_roots_of_cubic<F: FloatType>(a3: F, a2: F, a1: F, a0: F) -> [Complex<F>; 4] {
+    let nan = Complex::new(F::zero() / F::zero(), F::zero() / F::zero());
+    let mut roots = [nan; 4];
+    let count = if a3 != F::zero() {
+        let r = match super::cubic::find_roots_cubic(a3, a2, a1, a0).as_ref().last() {
+            Some(r) => *r,
+            None => return roots,
+        };
+        // a3*x^3 + a2*x^2 + a1*x + a0 = (x - r)*(a3*x^2 + b1*x + b0)
+        let b1 = a2 + a3 * r;
+        let b0 = a1 + b1 * r;
+        let [x1, x2] = complex_roots_of_quadratic(b1 / a3, b0 / a3);
+        roots[0] = Complex::new(r, F::zero());
+        roots[1] = x1;
+        roots[2] = x2;
+        3
+    } else if a2 != F::zero() {
+        let [x1, x2] = complex_roots_of_quadratic(a1 / a2, a0 / a2);
+        roots[0] = x1;
+        roots[1] = x2;
+        2
+    } else if a1 != F::zero() {
+        roots[0] = Complex::new(-a0 / a1, F::zero());
+        1
+    } else {
+        0
+    };
+    let coefficients = [F::zero(), a3, a2, a1, a0];
+    for x in roots[..count].iter_mut() {
+        *x = polish_complex_root(&coefficients, *x);
+    }
+    roots[..count].sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap_or(Ordering::Equal));
+    roots
+}
+
+/// Solves a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 finding complex roots too.
+///
+/// Returns all four roots. Multiple roots are repeated, and complex roots come in conjugate pairs.
+/// Roots are ordered by the real part, then by the imaginary part.
+/// If a4 is zero, the roots of the equation of the lower degree are returned followed by NaN.
+/// Roots of the depressed quartic are refined by the Newton-Raphson method on the original equation,
+/// so real roots agree with find_roots_quartic within rounding errors.
+/// Requires the num-complex feature.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_quartic_complex;
+///
+/// let roots = find_roots_quartic_complex(1f64, 0f64, 0f64, 0f64, -1f64);
+/// // Returns [-1, -i, i, 1] as complex numbers
+/// ```
+pub fn find_roots_quartic_complex<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> [Complex<F>; 4] {
+    if a4 == F::zero() {
+        return complex_roots_of_cubic(a3, a2, a1, a0);
+    }
+    let _2 = F::from(2i16);
+    let (p, q, r, shift) = super::quartic::depress_quartic(a4, a3, a2, a1, a0);
+
+    // The greatest root of the resolvent cubic gives positive p + 2u unless q is zero or negligible
+    let (b2, b1, b0) = super::quartic_depressed::auxiliary_equation(p, q, r);
+    let resolvent_roots = super::cubic_normalized::find_roots_cubic_normalized(b2, b1, b0);
+    let p_plus_2u = match resolvent_roots.as_ref().last() {
+        Some(u) if q != F::zero() => super::quartic_depressed::polish_resolvent_root(p, q, r, p + _2 * *u),
+        _ => F::zero(),
+    };
+
+    let mut roots = if p_plus_2u > F::zero() {
+        let (s, q0a, q0b) = super::quartic_depressed::quadratic_factors(p, q, r, p_plus_2u);
+        let [y1, y2] = complex_roots_of_quadratic(s, q0a);
+        let [y3, y4] = complex_roots_of_quadratic(-s, q0b);
+        [y1, y2, y3, y4]
+    } else {
+        complex_roots_of_biquadratic(p, r)
+    };
+    let coefficients = [a4, a3, a2, a1, a0];
+    for y in roots.iter_mut() {
+        *y = polish_complex_root(&coefficients, Complex::new(y.re + shift, y.im));
+    }
+    roots.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap_or(Ordering::Equal));
+    roots
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+    use num_complex::Complex;
+
+    fn check_vieta(a: [f64; 5], roots: &[Complex<f64>; 4]) {
+        // Rounding errors are relative to magnitudes of roots
+        let sum = roots.iter().fold(Complex::new(0f64, 0f64), |s, x| s + x);
+        let sum_magnitude: f64 = roots.iter().map(|x| x.norm()).sum();
+        assert!((sum + a[1] / a[0]).norm() <= 1e-14f64 * sum_magnitude);
+        let product = roots.iter().fold(Complex::new(1f64, 0f64), |p, x| p * x);
+        let product_magnitude: f64 = roots.iter().map(|x| x.norm()).product();
+        assert!((product - a[4] / a[0]).norm() <= 1e-14f64 * product_magnitude);
+    }
+
+    fn check_real_roots(a: [f64; 5], roots: &[Complex<f64>; 4]) {
+        // Imaginary parts of real roots can be rounding errors
+        let real: Vec<f64> = roots
+            .iter()
+            .filter(|x| x.im.abs() <= 1e-12f64 * x.norm())
+            .map(|x| x.re)
+            .collect();
+        let expected = find_roots_quartic(a[0], a[1], a[2], a[3], a[4]);
+        assert_float_array_eq!(1e-12f64, real, expected.as_ref());
+    }
+
+    #[test]
+    fn test_find_roots_quartic_complex() {
+        let roots = find_roots_quartic_complex(1f64, 0f64, 0f64, 0f64, -1f64);
+        assert_eq!(
+            roots,
+            [
+                Complex::new(-1f64, 0f64),
+                Complex::new(0f64, -1f64),
+                Complex::new(0f64, 1f64),
+                Complex::new(1f64, 0f64)
+            ]
+        );
+
+        // (x^2 + 2x + 2)(x^2 + 1)
+        let roots = find_roots_quartic_complex(1f64, 2f64, 3f64, 2f64, 2f64);
+        let expected = [(-1f64, -1f64), (-1f64, 1f64), (0f64, -1f64), (0f64, 1f64)];
+        for (x, e) in roots.iter().zip(expected.iter()) {
+            assert_float_eq!(1e-15f64, x.re, e.0);
+            assert_float_eq!(1e-15f64, x.im, e.1);
+        }
+    }
+
+    #[test]
+    fn test_find_roots_quartic_complex_lower_degree() {
+        // x^3 + 1
+        let roots = find_roots_quartic_complex(0f64, 1f64, 0f64, 0f64, 1f64);
+        let expected = [(-1f64, 0f64), (0.5f64, -0.75f64.sqrt()), (0.5f64, 0.75f64.sqrt())];
+        for (x, e) in roots.iter().zip(expected.iter()) {
+            assert_float_eq!(1e-15f64, x.re, e.0);
+            assert_float_eq!(1e-15f64, x.im, e.1);
+        }
+        assert!(roots[3].re.is_nan() && roots[3].im.is_nan());
+
+        // x^2 - 1, 2x + 1 and 1
+        let roots = find_roots_quartic_complex(0f64, 0f64, 1f64, 0f64, -1f64);
+        assert_eq!(roots[..2], [Complex::new(-1f64, 0f64), Complex::new(1f64, 0f64)]);
+        assert!(roots[2..].iter().all(|x| x.re.is_nan()));
+        let roots = find_roots_quartic_complex(0f64, 0f64, 0f64, 2f64, 1f64);
+        assert_eq!(roots[0], Complex::new(-0.5f64, 0f64));
+        assert!(roots[1..].iter().all(|x| x.re.is_nan()));
+        assert!(find_roots_quartic_complex(0f64, 0f64, 0f64, 0f64, 1f64)
+            .iter()
+            .all(|x| x.re.is_nan()));
+    }
+
+    #[test]
+    fn test_find_roots_quartic_complex_vieta() {
+        let cases = [
+            [1f64, -10f64, 35f64, -50f64, 24f64],
+            [2f64, 0f64, -20f64, 10f64, 2f64],
+            [3f64, 5f64, -5f64, -5f64, 2f64],
+            [1f64, 1f64, 1f64, 1f64, 1f64],
+            [1f64, 4f64, 8f64, 8f64, 4f64],
+            [-2f64, 3f64, 0.5f64, 7f64, -1f64],
+            [1f64, 0f64, -5f64, 0f64, 4f64],
+            [1f64, 0f64, 5f64, 0f64, 4f64],
+        ];
+        for a in cases.iter() {
+            let roots = find_roots_quartic_complex(a[0], a[1], a[2], a[3], a[4]);
+            check_vieta(*a, &roots);
+            check_real_roots(*a, &roots);
+            // Complex roots come in conjugate pairs
+            for x in roots.iter().filter(|x| x.im != 0f64) {
+                assert!(roots.iter().any(|y| y.re == x.re && y.im == -x.im));
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_roots_quartic_complex_random() {
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            f64::from(seed >> 8) / f64::from(1u32 << 24) * 20f64 - 10f64
+        };
+        for _ in 0..1000 {
+            let a = [next(), next(), next(), next(), next()];
+            let roots = find_roots_quartic_complex(a[0], a[1], a[2], a[3], a[4]);
+            check_vieta(a, &roots);
+        }
+    }
+}
@@ -22,12 +22,93 @@
 // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use super::super::double_double::DoubleDouble;
+use super::super::numerical::eigen::companion_eigenvalues;
+use super::super::numerical::refine::refine_root;
 use super::super::FloatType;
 use super::super::Roots;
 
+/// Eigenvalues with relatively smaller imaginary parts are taken for real roots by the fallback solver
+const EIGEN_TOLERANCE: f64 = 1e-4;
+
+/// Returns coefficients (b2, b1, b0) of the auxiliary equation y^3 + b2*y^2 + b1*y + b0 = 0
+/// of the depressed quartic x^4 + a2*x^2 + a1*x + a0 = 0, i.e.
+/// y^3 + (5/2)*a2*y^2 + (2*a2^2-a0)*y + (a2^3/2 - a2*a0/2 - a1^2/8) = 0
+pub fn auxiliary_equation<F: FloatType>(a2: F, a1: F, a0: F) -> (F, F, F) {
+    let _2 = F::from(2i16);
+    let _5 = F::from(5i16);
+
+    let a2_pow_2 = a2 * a2;
+    let a1_div_2 = a1 / _2;
+    let b2 = a2 * _5 / _2;
+    let b1 = _2 * a2_pow_2 - a0;
+    let b0 = (a2_pow_2 * a2 - a2 * a0 - a1_div_2 * a1_div_2) / _2;
+    (b2, b1, b0)
+}
+
+/// Refines the root m = a2 + 2y of the auxiliary equation of the depressed quartic x^4 + a2*x^2 + a1*x + a0 = 0.
+///
+/// In terms of m, the auxiliary equation is m^3 + 2*a2*m^2 + (a2^2 - 4*a0)*m - a1^2 = 0.
+/// Its greatest root is always positive when a1 != 0, but it can be tiny. Then rounding errors of y
+/// make m inaccurate or even negative, so the real roots of the quartic get lost.
+/// A few Newton-Raphson steps starting from a non-negative m fix that.
+pub fn polish_resolvent_root<F: FloatType>(a2: F, a1: F, a0: F, m: F) -> F {
+    let _2 = F::from(2i16);
+    let _3 = F::from(3i16);
+    let _4 = F::from(4i16);
+
+    let b1 = a2 * a2 - _4 * a0;
+    let mut m = if m > F::zero() { m } else { F::zero() };
+    for _ in 0..3 {
+        // The value is close to zero near the root; double-double precision avoids cancellation
+        let (dd_a2, dd_m) = (DoubleDouble::from(a2), DoubleDouble::from(m));
+        let value = (((dd_m + DoubleDouble::from(2i16) * dd_a2) * dd_m + dd_a2 * dd_a2
+            - DoubleDouble::from(4i16) * DoubleDouble::from(a0))
+            * dd_m
+            - DoubleDouble::from(a1) * DoubleDouble::from(a1))
+        .value();
+        let derivative = (_3 * m + _4 * a2) * m + b1;
+        if derivative <= F::zero() {
+            break;
+        }
+        let next = m - value / derivative;
+        if next == m {
+            break;
+        }
+        m = next;
+    }
+    m
+}
+
+/// Returns coefficients (s, q0a, q0b) of quadratic factors x^4 + a2*x^2 + a1*x + a0 = (x^2 + s*x + q0a)*(x^2 - s*x + q0b)
+/// where s = sqrt(a2 + 2y) and y is a root of the auxiliary equation.
+pub fn quadratic_factors<F: FloatType>(a2: F, a1: F, a0: F, a2_plus_2y: F) -> (F, F, F) {
+    let _2 = F::from(2i16);
+    let sqrt_a2_plus_2y = a2_plus_2y.sqrt();
+    let a2_plus_y = (a2 + a2_plus_2y) / _2;
+    let a1_div_2_sqrt = a1 / (_2 * sqrt_a2_plus_2y);
+    // q0a*q0b = a0, so the one losing precision due to cancellation is calculated from the other
+    if (a2_plus_y < F::zero()) == (a1_div_2_sqrt < F::zero()) {
+        let q0b = a2_plus_y + a1_div_2_sqrt;
+        (sqrt_a2_plus_2y, a0 / q0b, q0b)
+    } else {
+        let q0a = a2_plus_y - a1_div_2_sqrt;
+        (sqrt_a2_plus_2y, q0a, a0 / q0a)
+    }
+}
+
+/// Solves both quadratic equations x^2 + s*x + q0a = 0 and x^2 - s*x + q0b = 0.
+fn roots_of_quadratic_factors<F: FloatType>((s, q0a, q0b): (F, F, F)) -> Roots<F> {
+    let mut roots = super::quadratic::find_roots_quadratic_normalized(s, q0a);
+    for x in super::quadratic::find_roots_quadratic_normalized(-s, q0b).as_ref().iter() {
+        roots = roots.add_new_root(*x);
+    }
+    roots
+}
+
 /// Solves a depressed quartic equation x^4 + a2*x^2 + a1*x + a0 = 0.
 ///
-/// Returned roots are ordered. Precision is about 1e-14 for f64.
+/// Returned roots are ordered. Precision is a few ulps for f64; it is lower for close roots.
 ///
 /// # Examples
 ///
@@ -50,37 +131,56 @@ pub fn find_roots_quartic_depressed<F: FloatType>(a2: F, a1: F, a0: F) -> Roots<
         super::cubic_normalized::find_roots_cubic_normalized(F::zero(), a2, a1).add_new_root(F::zero())
     } else {
         let _2 = F::from(2i16);
-        let _5 = F::from(5i16);
+        let (b2, b1, b0) = auxiliary_equation(a2, a1, a0);
 
-        // Solve the auxiliary equation y^3 + (5/2)*a2*y^2 + (2*a2^2-a0)*y + (a2^3/2 - a2*a0/2 - a1^2/8) = 0
-        let a2_pow_2 = a2 * a2;
-        let a1_div_2 = a1 / _2;
-        let b2 = a2 * _5 / _2;
-        let b1 = _2 * a2_pow_2 - a0;
-        let b0 = (a2_pow_2 * a2 - a2 * a0 - a1_div_2 * a1_div_2) / _2;
-
-        // At least one root always exists. The last root is the maximal one.
+        // At least one root always exists. Every root giving positive a2 + 2y factors the quartic;
+        // rounding errors differ, so choose the roots with the smallest residuals.
         let resolvent_roots = super::cubic_normalized::find_roots_cubic_normalized(b2, b1, b0);
-        let y = resolvent_roots.as_ref().iter().last().unwrap();
-
-        let _a2_plus_2y = a2 + _2 * *y;
-        if _a2_plus_2y > F::zero() {
-            let sqrt_a2_plus_2y = _a2_plus_2y.sqrt();
-            let q0a = a2 + *y - a1_div_2 / sqrt_a2_plus_2y;
-            let q0b = a2 + *y + a1_div_2 / sqrt_a2_plus_2y;
-
-            let mut roots = super::quadratic::find_roots_quadratic(F::one(), sqrt_a2_plus_2y, q0a);
-            for x in super::quadratic::find_roots_quadratic(F::one(), -sqrt_a2_plus_2y, q0b)
-                .as_ref()
-                .iter()
-            {
-                roots = roots.add_new_root(*x);
-            }
-            roots
+        let mut factored = false;
+        let mut best_roots = Roots::No([]);
+        let mut best_residual = F::zero();
+        for (i, y) in resolvent_roots.as_ref().iter().rev().enumerate() {
+            // The largest root is polished even if a2 + 2y is not positive because of rounding errors
+            if i == 0 || a2 + _2 * *y > F::zero() {
+                let _a2_plus_2y = polish_resolvent_root(a2, a1, a0, a2 + _2 * *y);
+                if _a2_plus_2y > F::zero() {
+                    let roots = roots_of_quadratic_factors(quadratic_factors(a2, a1, a0, _a2_plus_2y));
+                    let residual = super::quartic_refined::max_relative_residual(F::one(), F::zero(), a2, a1, a0, &roots);
+                    if best_roots.as_ref().is_empty() || residual < best_residual {
+                        best_roots = roots;
+                        best_residual = residual;
+                    }
+                    factored = true;
+                }
+            }
+        }
+        if factored {
+            best_roots
         } else {
-            Roots::No([])
+            // Rounding errors of huge or tiny coefficients have spoiled all roots of the auxiliary equation
+            find_roots_quartic_depressed_eigen(a2, a1, a0)
+        }
+    }
+}
+
+/// Finds real roots of the depressed quartic x^4 + a2*x^2 + a1*x + a0 = 0 as eigenvalues of its companion matrix,
+/// refined by refine_root. Only the refined roots with residuals within a few rounding errors are returned.
+fn find_roots_quartic_depressed_eigen<F: FloatType>(a2: F, a1: F, a0: F) -> Roots<F> {
+    let value = |x: F| ((x * x + a2) * x + a1) * x + a0;
+    let derivative = |x: F| (F::from(4i16) * x * x + F::from(2i16) * a2) * x + a1;
+
+    let mut roots = Roots::No([]);
+    for (re, im) in companion_eigenvalues(&[0f64, a2.to_f64(), a1.to_f64(), a0.to_f64()]) {
+        if im.abs() <= EIGEN_TOLERANCE * (re * re + im * im).sqrt() {
+            let x = F::from_f64(re);
+            let x = refine_root(x, &value, &derivative, &mut F::epsilon()).unwrap_or(x);
+            let residual = super::quartic_refined::max_relative_residual(F::one(), F::zero(), a2, a1, a0, &Roots::One([x]));
+            if residual < F::from(1000i16) {
+                roots = roots.add_new_root(x);
+            }
         }
     }
+    roots
 }
 
 #[cfg(test)]
@@ -120,4 +220,217 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_find_roots_quartic_depressed_eigen_fallback() {
+        // The auxiliary equation has no roots in f64 because of the huge range of coefficients
+        match find_roots_quartic_depressed(
+            -4.990457455832912e10f64,
+            -1.0862538962120474e-20f64,
+            -7.967096806306326e-20f64,
+        ) {
+            Roots::Two(x) => {
+                assert_float_array_eq!(1e-9, x, [-223393.31807001104f64, 223393.31807001104f64]);
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+        // a2 + 2y cannot be polished to a positive value
+        match find_roots_quartic_depressed(-3.753718513928941e14f64, -4.721117093435324e-15f64, -6.506358709921634e8f64) {
+            Roots::Two(x) => {
+                assert_float_array_eq!(1e-7, x, [-19374515.51375915f64, 19374515.51375915f64]);
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+        // Eigenvalues which are not roots are rejected
+        assert_eq!(
+            find_roots_quartic_depressed(1.0186770214385875e18f64, 1.8973641195006815e-5f64, 4.432295018613057e-11f64),
+            Roots::No([])
+        );
+    }
+
+    #[test]
+    fn test_find_roots_quartic_depressed_tiny_resolvent_root() {
+        // (x - a - d)(x + a - d)(x^2 + 2d*x + d^2 + v^2) with a tiny d used to return Roots::No
+        match find_roots_quartic_depressed(1.059395502339731f64, 7.728772579050963e-11f64, -3.9096804174652805e-1f64) {
+            Roots::Two(x) => {
+                assert_float_array_eq!(1e-15, x, [-0.5383144239655567f64, 0.5383144239184002f64]);
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+
+        match find_roots_quartic_depressed(-1.0567368430777574e1f64, 3.169904315802903e-11f64, -8.21164532101408f64) {
+            Roots::Two(x) => {
+                assert_float_array_eq!(1e-14, x, [-3.360717427149625f64, 3.360717427146988f64]);
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_roots_quartic_depressed_close_roots() {
+        // The largest root of the auxiliary equation used to give errors about 3e-9 here
+        match find_roots_quartic_depressed(-13.381974988280755f64, -3.1225162678808216f64, 36.78554599366345f64) {
+            Roots::Four(x) => {
+                assert_float_array_eq!(
+                    1e-11,
+                    x,
+                    [
+                        -2.5262585798011164f64,
+                        -2.5262582061252948f64,
+                        1.7401209671611746f64,
+                        3.3123958187652366f64
+                    ]
+                );
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+
+        match find_roots_quartic_depressed(-8.713667685650345f64, -0.48071136063042674f64, -0.006630491472216356f64) {
+            Roots::Four(x) => {
+                assert_float_array_eq!(
+                    1e-11,
+                    x,
+                    [
+                        -2.9240457900215564f64,
+                        -0.027588580862503641f64,
+                        -0.027588569780395629f64,
+                        2.9792229406644557f64
+                    ]
+                );
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_roots_quartic_depressed_reference() {
+        // Reference roots of random equations are calculated with 128-bit precision
+        let cases: [(f64, f64, f64, &[f64]); 16] = [
+            (
+                -7.127808349157014f64,
+                7.826902606790416f64,
+                -9.112984951912338f64,
+                &[-3.2289140491605003835f64, 2.3339316449168432979f64],
+            ),
+            (
+                -7.178392852704045f64,
+                -2.614340137299651f64,
+                3.40382529210925f64,
+                &[
+                    -2.3301307415269985499f64,
+                    -0.97923509430026579529f64,
+                    0.53834427188830194424f64,
+                    2.771021563938962401f64,
+                ],
+            ),
+            (
+                -9.637125461194065f64,
+                1.8155700712401952f64,
+                6.387768660517235f64,
+                &[
+                    -3.091276258749999429f64,
+                    -0.74461264159255246016f64,
+                    0.96747293545563170461f64,
+                    2.8684159648869201846f64,
+                ],
+            ),
+            (
+                -6.449958996338527f64,
+                6.754194606862917f64,
+                4.913546205448489f64,
+                &[-2.8650988596118820256f64, -0.49893369696019681566f64],
+            ),
+            (
+                -4.573396920361354f64,
+                2.0542273739476684f64,
+                -6.284445862831394f64,
+                &[-2.5245166568395851724f64, 2.2189980938219741993f64],
+            ),
+            (
+                1.5267622074323643f64,
+                -1.8854530965689449f64,
+                -2.315135390949723f64,
+                &[-0.70131549776472801666f64, 1.2337991188678679229f64],
+            ),
+            (
+                6.7233895021044425f64,
+                7.996788653898545f64,
+                -8.76261969896947f64,
+                &[-1.5217368757832141863f64, 0.68011382695575879515f64],
+            ),
+            (
+                4.303302683028242f64,
+                5.575722445836309f64,
+                -4.4321087516645274f64,
+                &[-1.3978206099400076522f64, 0.54746391002724370085f64],
+            ),
+            (
+                -2.828027095061925f64,
+                7.219402745014889f64,
+                7.963420473868343f64,
+                &[-2.1121103996789722824f64, -0.88211442634135879784f64],
+            ),
+            (
+                3.229755032804176f64,
+                -6.370046200029f64,
+                -8.20347916995808f64,
+                &[-0.84533926536633814482f64, 1.7530648821872333879f64],
+            ),
+            (
+                -4.219257975108848f64,
+                -6.033498098463996f64,
+                9.44923323126492f64,
+                &[1.0188249388640635091f64, 2.2429872419848467651f64],
+            ),
+            (
+                -4.313787817607107f64,
+                -1.7218827299362829f64,
+                -9.281163682999573f64,
+                &[-2.3051054965550793014f64, 2.5367089533395991733f64],
+            ),
+            (
+                -3.8192505244235946f64,
+                2.821116306776382f64,
+                4.3187281059984794f64,
+                &[-2.0407467557907538108f64, -0.8039389263561880628f64],
+            ),
+            (
+                0.14335729992114032f64,
+                8.881747761031402f64,
+                -6.5876065250112354f64,
+                &[-2.2557832279133841431f64, 0.70573307576479570985f64],
+            ),
+            (
+                -9.295193480974476f64,
+                1.330031272169176f64,
+                -4.641509785225f64,
+                &[-3.1888448476434747668f64, 3.058831895410394106f64],
+            ),
+            (
+                -0.7052640558011536f64,
+                -6.5995731806691404f64,
+                5.299564593901508f64,
+                &[0.79615899711499467011f64, 1.6606215218983963491f64],
+            ),
+        ];
+        for &(a2, a1, a0, expected) in cases.iter() {
+            let roots = find_roots_quartic_depressed(a2, a1, a0);
+            assert_eq!(roots.as_ref().len(), expected.len());
+            for (x, expected) in roots.as_ref().iter().zip(expected.iter()) {
+                assert_float_eq!(4f64 * f64::EPSILON * expected.abs().max(1f64), *x, *expected);
+            }
+        }
+    }
 }
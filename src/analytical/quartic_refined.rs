@@ -0,0 +1,248 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::super::Roots;
+
+/// Finds the initial factorization of the normalized quartic x^4 + a*x^3 + b*x^2 + c*x + d
+/// into (x^2 + alpha1*x + beta1)*(x^2 + alpha2*x + beta2) using the Ferrari's method.
+/// Returns [alpha1, beta1, alpha2, beta2].
+fn initial_factors<F: FloatType>(a: F, b: F, c: F, d: F) -> [F; 4] {
+    let _2 = F::from(2i16);
+    let _4 = F::from(4i16);
+
+    // Depressed quartic y^4 + p*y^2 + q*y + r where x = y - h
+    let (p, q, r, shift) = super::quartic::depress_quartic(F::one(), a, b, c, d);
+    let h = -shift;
+
+    // The greatest root u of the resolvent cubic gives m = p + 2*u and
+    // y^4 + p*y^2 + q*y + r = (y^2 + s*y + t1)*(y^2 - s*y + t2), where s = sqrt(m)
+    let (b2, b1, b0) = super::quartic::resolvent_cubic(F::one(), a, b, c, d);
+    let u = *super::cubic_normalized::find_roots_cubic_normalized(b2, b1, b0)
+        .as_ref()
+        .iter()
+        .last()
+        .unwrap();
+    let m = p + _2 * u;
+    let (s, t1, t2) = if m > F::zero() {
+        let s = m.sqrt();
+        let t = (p + m) / _2;
+        // t1 + t2 = p + m and t1*t2 = r, while t2 - t1 = q/s loses precision when m is tiny
+        let half_diff_pow_2 = t * t - r;
+        if half_diff_pow_2 > F::zero() {
+            let half_diff = half_diff_pow_2.sqrt();
+            if q < F::zero() {
+                (s, t + half_diff, t - half_diff)
+            } else {
+                (s, t - half_diff, t + half_diff)
+            }
+        } else {
+            (s, t - q / (_2 * s), t + q / (_2 * s))
+        }
+    } else if p * p >= _4 * r {
+        // q is negligible; the equation is bi-quadratic with real factors
+        let sqrt_d = (p * p - _4 * r).sqrt();
+        (F::zero(), (p - sqrt_d) / _2, (p + sqrt_d) / _2)
+    } else {
+        // q is negligible; y^4 + p*y^2 + r = (y^2 + t)^2 - (2*t - p)*y^2, where t = sqrt(r)
+        let t = r.sqrt();
+        ((_2 * t - p).sqrt(), t, t)
+    };
+
+    // Shift back: y^2 + s*y + t = x^2 + (2h + s)*x + (h^2 + s*h + t)
+    [_2 * h + s, h * h + s * h + t1, _2 * h - s, h * h - s * h + t2]
+}
+
+/// Residuals of equations which the coefficients of the quadratic factors must satisfy
+fn factor_residuals<F: FloatType>(a: F, b: F, c: F, d: F, z: &[F; 4]) -> [F; 4] {
+    [
+        z[0] + z[2] - a,
+        z[1] + z[3] + z[0] * z[2] - b,
+        z[0] * z[3] + z[2] * z[1] - c,
+        z[1] * z[3] - d,
+    ]
+}
+
+/// Solves the linear system m*x = v using the Gaussian elimination with partial pivoting.
+/// Returns None if the matrix is singular.
+fn solve_linear_system<F: FloatType>(mut m: [[F; 4]; 4], mut v: [F; 4]) -> Option<[F; 4]> {
+    for col in 0..4 {
+        let mut pivot = col;
+        for row in (col + 1)..4 {
+            if m[row][col].abs() > m[pivot][col].abs() {
+                pivot = row;
+            }
+        }
+        if m[pivot][col] == F::zero() {
+            return None;
+        }
+        m.swap(col, pivot);
+        v.swap(col, pivot);
+        for row in (col + 1)..4 {
+            let factor = m[row][col] / m[col][col];
+            let pivot_row = m[col];
+            for (item, pivot_item) in m[row].iter_mut().zip(pivot_row.iter()).skip(col) {
+                *item = *item - factor * *pivot_item;
+            }
+            v[row] = v[row] - factor * v[col];
+        }
+    }
+    let mut x = [F::zero(); 4];
+    for col in (0..4).rev() {
+        let mut sum = v[col];
+        for k in (col + 1)..4 {
+            sum = sum - m[col][k] * x[k];
+        }
+        x[col] = sum / m[col][col];
+    }
+    Some(x)
+}
+
+/// Returns the greatest residual |a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0| of given roots
+/// relative to its rounding error |a4*x^4| + |a3*x^3| + |a2*x^2| + |a1*x| + |a0|.
+pub fn max_relative_residual<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F, roots: &Roots<F>) -> F {
+    let mut result = F::zero();
+    for x in roots.as_ref().iter() {
+        let value = (((a4 * *x + a3) * *x + a2) * *x + a1) * *x + a0;
+        let x_abs = x.abs();
+        let magnitude = (((a4.abs() * x_abs + a3.abs()) * x_abs + a2.abs()) * x_abs + a1.abs()) * x_abs + a0.abs();
+        let residual = value.abs() / (magnitude * F::epsilon());
+        if residual > result {
+            result = residual;
+        }
+    }
+    result
+}
+
+/// Solves a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0
+/// by factoring it into two quadratic polynomials.
+///
+/// The initial factorization is found by the Ferrari's method.
+/// Then coefficients of factors are refined by the Newton-Raphson method (like the NBS method or Strobach's solver do),
+/// and the quadratic equations are solved. This is slower but more robust than find_roots_quartic
+/// for ill-conditioned coefficients, e.g. when a4 is small compared to other coefficients.
+///
+/// Returned roots are ordered.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_quartic_refined;
+///
+/// let four_roots = find_roots_quartic_refined(1f64, -10f64, 35f64, -50f64, 24f64);
+/// // Returns Roots::Four([1f64, 2f64, 3f64, 4f64])
+/// ```
+pub fn find_roots_quartic_refined<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
+    if a4 == F::zero() {
+        // a4 = 0; a3*x^3 + a2*x^2 + a1*x + a0 = 0; solve cubic equation
+        super::cubic::find_roots_cubic(a3, a2, a1, a0)
+    } else if a0 == F::zero() {
+        // a0 = 0; a4*x^4 + a3*x^3 + a2*x^2 + a1*x = 0; reduce to cubic and add zero root
+        super::cubic::find_roots_cubic(a4, a3, a2, a1).add_new_root(F::zero())
+    } else {
+        let roots = factor_and_solve(a3 / a4, a2 / a4, a1 / a4, a0 / a4);
+
+        // When the product of roots is big, the reversed equation a0*y^4 + a1*y^3 + a2*y^2 + a3*y + a4 = 0,
+        // where y = 1/x, may be easier to factor. Use the result with smaller residuals.
+        let mut reversed_roots = Roots::No([]);
+        for y in factor_and_solve(a1 / a0, a2 / a0, a3 / a0, a4 / a0).as_ref().iter() {
+            reversed_roots = reversed_roots.add_new_root(F::one() / *y);
+        }
+
+        if max_relative_residual(a4, a3, a2, a1, a0, &reversed_roots) < max_relative_residual(a4, a3, a2, a1, a0, &roots) {
+            reversed_roots
+        } else {
+            roots
+        }
+    }
+}
+
+/// Solves the normalized quartic x^4 + a*x^3 + b*x^2 + c*x + d = 0 by factoring it into two quadratic polynomials.
+fn factor_and_solve<F: FloatType>(a: F, b: F, c: F, d: F) -> Roots<F> {
+    let norm = |r: &[F; 4]| r.iter().fold(F::zero(), |sum, x| sum + x.abs());
+
+    let mut z = initial_factors(a, b, c, d);
+    let mut residuals = factor_residuals(a, b, c, d, &z);
+    for _ in 0..16 {
+        if norm(&residuals) == F::zero() {
+            break;
+        }
+        let jacobian = [
+            [F::one(), F::zero(), F::one(), F::zero()],
+            [z[2], F::one(), z[0], F::one()],
+            [z[3], z[2], z[1], z[0]],
+            [F::zero(), z[3], F::zero(), z[1]],
+        ];
+        let step = match solve_linear_system(jacobian, residuals) {
+            Some(step) => step,
+            None => break,
+        };
+        let next = [z[0] - step[0], z[1] - step[1], z[2] - step[2], z[3] - step[3]];
+        let next_residuals = factor_residuals(a, b, c, d, &next);
+        if norm(&next_residuals) < norm(&residuals) {
+            z = next;
+            residuals = next_residuals;
+        } else {
+            break;
+        }
+    }
+
+    let mut roots = super::quadratic::find_roots_quadratic_normalized(z[0], z[1]);
+    for x in super::quadratic::find_roots_quadratic_normalized(z[2], z[3]).as_ref().iter() {
+        roots = roots.add_new_root(*x);
+    }
+    roots
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_roots_quartic_refined() {
+        assert_eq!(
+            find_roots_quartic_refined(1f64, -10f64, 35f64, -50f64, 24f64),
+            Roots::Four([1f64, 2f64, 3f64, 4f64])
+        );
+        assert_eq!(
+            find_roots_quartic_refined(1f64, 0f64, 0f64, 0f64, -1f64),
+            Roots::Two([-1f64, 1f64])
+        );
+        assert_eq!(find_roots_quartic_refined(1f64, 2f64, 3f64, 2f64, 2f64), Roots::No([]));
+        // The tiny leading coefficient gives one huge root; the other roots are still accurate
+        match find_roots_quartic_refined(3.07e-8f64, -1f64, 2f64, 3f64, -1f64) {
+            Roots::Four(x) => {
+                assert_float_array_eq!(
+                    1e-15f64,
+                    x[..3],
+                    [-1.1986912331345633f64, 0.28646206497858787f64, 2.9122293830560066f64]
+                );
+                assert_float_eq!(1e-15f64, x[3] / 32573287.902279915f64, 1f64);
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::numerical::eigen::companion_eigenvalues;
+use super::super::numerical::refine::refine_root;
+use super::super::FloatType;
+use super::super::Roots;
+
+/// Eigenvalues with relatively smaller imaginary parts are taken for real roots;
+/// multiple roots split into eigenvalues as far as epsilon^(1/multiplicity) from each other
+const TOLERANCE: f64 = 1e-4;
+
+/// Solves a quintic equation a5*x^5 + a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0.
+///
+/// There is no general formula for roots of the quintic, so they are found numerically:
+/// eigenvalues of the companion matrix of the normalized polynomial are taken for real roots
+/// if their imaginary parts are relatively small, and then refined by refine_root on the original polynomial.
+/// Refined roots which are relatively closer than 1e-4 are taken for one multiple root.
+/// If a5 is 0, the quartic equation is solved by find_roots_quartic.
+/// Returned roots are arranged in the increasing order.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_quintic;
+///
+/// let roots = find_roots_quintic(1f64, 0f64, -5f64, 0f64, 4f64, 0f64);
+/// // Returns Roots::Five([-2f64, -1f64, 0f64, 1f64, 2f64]) as 'x^5 - 5x^3 + 4x = 0' has these roots
+/// ```
+pub fn find_roots_quintic<F: FloatType>(a5: F, a4: F, a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
+    if a5 == F::zero() {
+        return super::quartic::find_roots_quartic(a4, a3, a2, a1, a0);
+    }
+    if a0 == F::zero() {
+        // The root 0 and roots of the quartic
+        return super::quartic::find_roots_quartic(a5, a4, a3, a2, a1).add_new_root(F::zero());
+    }
+
+    let c = [a5, a4, a3, a2, a1, a0];
+    let normalized: Vec<f64> = c[1..].iter().map(|a| (*a / a5).to_f64()).collect();
+    let value = |x: F| c.iter().fold(F::zero(), |value, a| value * x + *a);
+    let derivative = |x: F| {
+        c.iter()
+            .fold((F::zero(), F::zero()), |(value, derivative), a| {
+                (value * x + *a, derivative * x + value)
+            })
+            .1
+    };
+
+    let mut candidates: Vec<F> = companion_eigenvalues(&normalized)
+        .into_iter()
+        .filter(|&(re, im)| im.abs() <= TOLERANCE * (re * re + im * im).sqrt())
+        .map(|(re, _)| {
+            let x = F::from_f64(re);
+            refine_root(x, &value, &derivative, &mut F::epsilon()).unwrap_or(x)
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut merged: Vec<F> = Vec::with_capacity(candidates.len());
+    for x in candidates {
+        if let Some(previous) = merged.last_mut() {
+            let distance = (x - *previous).abs().to_f64();
+            if distance <= TOLERANCE * x.abs().to_f64().max(previous.abs().to_f64()) {
+                // Approximations of a multiple root; keep the more precise one
+                if value(x).abs() < value(*previous).abs() {
+                    *previous = x;
+                }
+                continue;
+            }
+        }
+        merged.push(x);
+    }
+    merged.iter().fold(Roots::No([]), |roots, x| roots.add_new_root(*x))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_roots_quintic() {
+        // One real root: (x - 2)(x^2 + 1)(x^2 + 2x + 5)
+        let c = [1f64, 0f64, 2f64, -10f64, 1f64, -10f64];
+        assert_float_array_eq!(
+            1e-15f64,
+            find_roots_quintic(c[0], c[1], c[2], c[3], c[4], c[5]).as_ref(),
+            [2f64]
+        );
+
+        // Three real roots: 3(x + 1)(x - 0.5)(x - 4)(x^2 + 1)
+        let c = polynomial_from_roots(&[-1f64, 0.5f64, 4f64], 3f64);
+        let c = [c[0], c[1], c[0] + c[2], c[1] + c[3], c[2], c[3]];
+        assert_float_array_eq!(
+            1e-14f64,
+            find_roots_quintic(c[0], c[1], c[2], c[3], c[4], c[5]).as_ref(),
+            [-1f64, 0.5f64, 4f64]
+        );
+
+        // Five real roots
+        let roots = [-3f64, -1.5f64, 0.25f64, 2f64, 7f64];
+        let c = polynomial_from_roots(&roots, -2f64);
+        assert_float_array_eq!(
+            1e-14f64,
+            find_roots_quintic(c[0], c[1], c[2], c[3], c[4], c[5]).as_ref(),
+            roots
+        );
+
+        // Zero root and the quartic
+        assert_eq!(
+            find_roots_quintic(1f64, 0f64, -5f64, 0f64, 4f64, 0f64),
+            Roots::Five([-2f64, -1f64, 0f64, 1f64, 2f64])
+        );
+        assert_eq!(
+            find_roots_quintic(0f64, 1f64, 0f64, -5f64, 0f64, 4f64),
+            Roots::Four([-2f64, -1f64, 1f64, 2f64])
+        );
+    }
+
+    #[test]
+    fn test_find_roots_quintic_multiple() {
+        // (x - 1)^2 (x + 2)(x - 3)(x - 5)
+        let c = polynomial_from_roots(&[1f64, 1f64, -2f64, 3f64, 5f64], 1f64);
+        assert_float_array_eq!(
+            1e-7f64,
+            find_roots_quintic(c[0], c[1], c[2], c[3], c[4], c[5]).as_ref(),
+            [-2f64, 1f64, 3f64, 5f64]
+        );
+
+        // (x + 1)^3 (x - 2)^2
+        let c = polynomial_from_roots(&[-1f64, -1f64, -1f64, 2f64, 2f64], 1f64);
+        assert_float_array_eq!(
+            1e-5f64,
+            find_roots_quintic(c[0], c[1], c[2], c[3], c[4], c[5]).as_ref(),
+            [-1f64, 2f64]
+        );
+
+        // (x - 0.5)^5
+        let c = polynomial_from_roots(&[0.5f64; 5], 1f64);
+        assert_float_array_eq!(
+            1e-3f64,
+            find_roots_quintic(c[0], c[1], c[2], c[3], c[4], c[5]).as_ref(),
+            [0.5f64]
+        );
+    }
+}
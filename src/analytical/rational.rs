@@ -0,0 +1,179 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+/// Positive divisors of n, or None if n is too big to be factored by trial division
+fn divisors(n: i128) -> Option<Vec<i128>> {
+    let n = n.abs();
+    if n > 1 << 40 {
+        return None;
+    }
+    let mut small = Vec::new();
+    let mut big = Vec::new();
+    let mut d = 1;
+    while d * d <= n {
+        if n % d == 0 {
+            small.push(d);
+            if d * d != n {
+                big.push(n / d);
+            }
+        }
+        d += 1;
+    }
+    small.extend(big.iter().rev());
+    Some(small)
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Divides the polynomial c[0]*x^n + ... + c[n] by (q*x - p) if the remainder is zero and the quotient is integral
+fn divide(c: &[i128], p: i128, q: i128) -> Option<Vec<i128>> {
+    let mut quotient = Vec::with_capacity(c.len() - 1);
+    let mut carry = 0i128;
+    for a in c[..c.len() - 1].iter() {
+        let t = a.checked_add(carry.checked_mul(p)?)?;
+        if t % q != 0 {
+            return None;
+        }
+        carry = t / q;
+        quotient.push(carry);
+    }
+    if c[c.len() - 1].checked_add(carry.checked_mul(p)?)? == 0 {
+        Some(quotient)
+    } else {
+        None
+    }
+}
+
+/// Extracts rational roots of a polynomial equation c[0]*x^n + c[1]*x^(n-1) + ... + c[n] = 0 with integer coefficients.
+///
+/// Candidate roots p/q are found by the rational root theorem: p divides the constant term,
+/// and q divides the leading coefficient. Every root is checked and divided out exactly by synthetic division
+/// by (q*x - p) in integers, so the deflated polynomial has integer coefficients and exactly the remaining roots.
+/// Lower-degree remainders are usually better conditioned for numerical solvers.
+///
+/// Returns roots as reduced fractions (p, q) with q > 0 in the increasing order, repeated by their multiplicity,
+/// and coefficients of the deflated polynomial without leading zeros.
+/// Candidates are enumerated by trial division, so no roots except 0 are extracted
+/// when the leading or the constant coefficient exceeds 2^40 in magnitude.
+/// When all coefficients are zero, nothing is extracted.
+///
+/// # Examples
+///
+/// ```
+/// use roots::extract_rational_roots;
+///
+/// let (roots, deflated) = extract_rational_roots(&[6, -11, 6, -1]);
+/// // Returns vec![(1, 3), (1, 2), (1, 1)] and vec![1f64] as '6x^3 - 11x^2 + 6x - 1 = (3x - 1)(2x - 1)(x - 1)'
+///
+/// let (roots, deflated) = extract_rational_roots(&[1, -1, -2, 2]);
+/// // Returns vec![(1, 1)] and vec![1f64, 0f64, -2f64] as 'x^3 - x^2 - 2x + 2 = (x - 1)(x^2 - 2)'
+/// ```
+pub fn extract_rational_roots(coeffs: &[i64]) -> (Vec<(i64, i64)>, Vec<f64>) {
+    let mut c: Vec<i128> = match coeffs.iter().position(|c| *c != 0) {
+        Some(first) => coeffs[first..].iter().map(|c| i128::from(*c)).collect(),
+        None => return (Vec::new(), coeffs.iter().map(|c| *c as f64).collect()),
+    };
+
+    let mut roots: Vec<(i128, i128)> = Vec::new();
+    while c.len() > 1 && c[c.len() - 1] == 0 {
+        c.pop();
+        roots.push((0, 1));
+    }
+
+    if c.len() > 1 {
+        if let (Some(numerators), Some(denominators)) = (divisors(c[c.len() - 1]), divisors(c[0])) {
+            for q in denominators.iter() {
+                for p in numerators.iter().filter(|p| gcd(**p, *q) == 1) {
+                    for p in [*p, -*p].iter() {
+                        while c.len() > 1 {
+                            match divide(&c, *p, *q) {
+                                Some(quotient) => {
+                                    c = quotient;
+                                    roots.push((*p, *q));
+                                }
+                                None => break,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    roots.sort_by(|a, b| (a.0 * b.1).cmp(&(b.0 * a.1)));
+    (
+        roots.iter().map(|&(p, q)| (p as i64, q as i64)).collect(),
+        c.iter().map(|c| *c as f64).collect(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_extract_rational_roots() {
+        assert_eq!(
+            extract_rational_roots(&[6, -11, 6, -1]),
+            (vec![(1, 3), (1, 2), (1, 1)], vec![1f64])
+        );
+        assert_eq!(
+            extract_rational_roots(&[1, -1, -2, 2]),
+            (vec![(1, 1)], vec![1f64, 0f64, -2f64])
+        );
+        // x^2 - 2 and x^2 + 1 have no rational roots
+        assert_eq!(extract_rational_roots(&[1, 0, -2]), (vec![], vec![1f64, 0f64, -2f64]));
+        assert_eq!(extract_rational_roots(&[0, 1, 0, 1]), (vec![], vec![1f64, 0f64, 1f64]));
+        // (2x - 1)^2 * (x^2 + 1) * x^2 * (3x + 2)
+        assert_eq!(
+            extract_rational_roots(&[12, -4, 7, -2, -5, 2, 0, 0]),
+            (vec![(-2, 3), (0, 1), (0, 1), (1, 2), (1, 2)], vec![1f64, 0f64, 1f64])
+        );
+        // Negative leading coefficient: -4x^2 + 1
+        assert_eq!(extract_rational_roots(&[-4, 0, 1]), (vec![(-1, 2), (1, 2)], vec![-1f64]));
+    }
+
+    #[test]
+    fn test_extract_rational_roots_edge_cases() {
+        assert_eq!(extract_rational_roots(&[]), (vec![], vec![]));
+        assert_eq!(extract_rational_roots(&[0, 0]), (vec![], vec![0f64, 0f64]));
+        assert_eq!(extract_rational_roots(&[5]), (vec![], vec![5f64]));
+        assert_eq!(extract_rational_roots(&[0, 2, -3]), (vec![(3, 2)], vec![1f64]));
+        // The constant term is too big to be factored, but the zero root is still extracted
+        let big = 1i64 << 50;
+        assert_eq!(extract_rational_roots(&[1, -big, 0]), (vec![(0, 1)], vec![1f64, -big as f64]));
+        // Synthetic division by big candidates overflows and rejects them: 2^40 * (x^3 - 1)
+        let big = 1i64 << 40;
+        assert_eq!(
+            extract_rational_roots(&[big, 0, 0, -big]),
+            (vec![(1, 1)], vec![big as f64, big as f64, big as f64])
+        );
+    }
+}
@@ -25,7 +25,9 @@
 use super::super::FloatType;
 
 /// Sorted and unique list of roots of an equation.
+/// More variants may be added for equations of higher degrees, so matches must have a wildcard arm.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Roots<F: FloatType> {
     /// Equation has no roots
     No([F; 0]),
@@ -37,6 +39,8 @@ pub enum Roots<F: FloatType> {
     Three([F; 3]),
     /// Equation has four roots
     Four([F; 4]),
+    /// Equation has five roots
+    Five([F; 5]),
 }
 
 impl<F: FloatType> AsRef<[F]> for Roots<F> {
@@ -47,6 +51,7 @@ impl<F: FloatType> AsRef<[F]> for Roots<F> {
             &Roots::Two(ref x) => x,
             &Roots::Three(ref x) => x,
             &Roots::Four(ref x) => x,
+            &Roots::Five(ref x) => x,
         }
     }
 }
@@ -91,6 +96,11 @@ impl<F: FloatType> Roots<F> {
                         (3, 1) => Roots::Four([old_roots[0], new_root, old_roots[1], old_roots[2]]),
                         (3, 2) => Roots::Four([old_roots[0], old_roots[1], new_root, old_roots[2]]),
                         (3, 3) => Roots::Four([old_roots[0], old_roots[1], old_roots[2], new_root]),
+                        (4, 0) => Roots::Five([new_root, old_roots[0], old_roots[1], old_roots[2], old_roots[3]]),
+                        (4, 1) => Roots::Five([old_roots[0], new_root, old_roots[1], old_roots[2], old_roots[3]]),
+                        (4, 2) => Roots::Five([old_roots[0], old_roots[1], new_root, old_roots[2], old_roots[3]]),
+                        (4, 3) => Roots::Five([old_roots[0], old_roots[1], old_roots[2], new_root, old_roots[3]]),
+                        (4, 4) => Roots::Five([old_roots[0], old_roots[1], old_roots[2], old_roots[3], new_root]),
                         _ => panic!("Cannot add root"),
                     }
                 }
@@ -118,4 +128,7 @@ fn test_roots() {
 
     roots = roots.add_new_root(2f32);
     assert_eq!(roots, Roots::Four([0f32, 1f32, 2f32, 3f32]));
+
+    roots = roots.add_new_root(-1f32);
+    assert_eq!(roots, Roots::Five([-1f32, 0f32, 1f32, 2f32, 3f32]));
 }
@@ -0,0 +1,211 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::cubic::find_roots_cubic_or_deflated;
+
+/// Segment [x0, x1] of a piecewise cubic spline
+/// a3*t^3 + a2*t^2 + a1*t + a0, where t = x - x0 is the local coordinate
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SplineSegment<F>
+where
+    F: FloatType,
+{
+    /// Beginning of the segment
+    pub x0: F,
+    /// End of the segment
+    pub x1: F,
+    /// Coefficient of t^3
+    pub a3: F,
+    /// Coefficient of t^2
+    pub a2: F,
+    /// Coefficient of t
+    pub a1: F,
+    /// Constant term, the value at x0
+    pub a0: F,
+}
+
+impl<F> SplineSegment<F>
+where
+    F: FloatType,
+{
+    /// Value of the spline segment at the global x
+    pub fn evaluate(&self, x: F) -> F {
+        let t = x - self.x0;
+        ((self.a3 * t + self.a2) * t + self.a1) * t + self.a0
+    }
+}
+
+/// Find all zeros of a piecewise cubic spline.
+///
+/// The cubic equation of each segment is solved by find_roots_cubic in the local coordinate, keeping the exact
+/// zero root of segments beginning at a root, and its roots are mapped back to the global x. Roots outside
+/// the segment are discarded; roots beyond its ends by less than sqrt(epsilon) of the segment width are moved to the ends,
+/// because the rounding errors shift roots at the knots, and double roots are sensitive to them.
+/// Roots closer to each other than this tolerance are reported once (as their mean), so a root
+/// at a knot shared by two segments, or a tangential touch split into two close roots, is not duplicated.
+/// A segment identical to zero gives one root at its beginning. The roots are sorted in the ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_cubic_spline;
+/// use roots::SplineSegment;
+///
+/// // x^2 - 1 on [-2, 0] and [0, 2]
+/// let segments = [
+///     SplineSegment { x0: -2f64, x1: 0f64, a3: 0f64, a2: 1f64, a1: -4f64, a0: 3f64 },
+///     SplineSegment { x0: 0f64, x1: 2f64, a3: 0f64, a2: 1f64, a1: 0f64, a0: -1f64 },
+/// ];
+///
+/// let roots = find_roots_cubic_spline(&segments);
+/// // Returns [-1f64, 1f64]
+/// ```
+pub fn find_roots_cubic_spline<F>(segments: &[SplineSegment<F>]) -> Vec<F>
+where
+    F: FloatType,
+{
+    // Roots with the tolerance of their segments
+    let mut candidates: Vec<(F, F)> = Vec::new();
+    for segment in segments {
+        let width = segment.x1 - segment.x0;
+        let tolerance = F::epsilon().sqrt() * width.abs();
+        for &t in find_roots_cubic_or_deflated(segment.a3, segment.a2, segment.a1, segment.a0).as_ref() {
+            if t >= -tolerance && t <= width + tolerance {
+                let t = if t < F::zero() {
+                    F::zero()
+                } else if t > width {
+                    width
+                } else {
+                    t
+                };
+                candidates.push((segment.x0 + t, tolerance));
+            }
+        }
+    }
+    candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut roots = Vec::with_capacity(candidates.len());
+    // Sum, number and tolerance of the roots in the current cluster, and the last of them
+    let mut cluster: Option<(F, F, F, F)> = None;
+    for (x, tolerance) in candidates {
+        cluster = match cluster {
+            Some((sum, count, cluster_tolerance, last))
+                if x - last
+                    <= if tolerance > cluster_tolerance {
+                        tolerance
+                    } else {
+                        cluster_tolerance
+                    } =>
+            {
+                Some((sum + x, count + F::one(), cluster_tolerance, x))
+            }
+            Some((sum, count, _, _)) => {
+                roots.push(sum / count);
+                Some((x, F::one(), tolerance, x))
+            }
+            None => Some((x, F::one(), tolerance, x)),
+        };
+    }
+    if let Some((sum, count, _, _)) = cluster {
+        roots.push(sum / count);
+    }
+    roots
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    /// Segment [x0, x1] of the cubic p3*x^3 + p2*x^2 + p1*x + p0 in the local coordinate
+    fn segment(x0: f64, x1: f64, p3: f64, p2: f64, p1: f64, p0: f64) -> SplineSegment<f64> {
+        SplineSegment {
+            x0,
+            x1,
+            a3: p3,
+            a2: 3f64 * p3 * x0 + p2,
+            a1: (3f64 * p3 * x0 + 2f64 * p2) * x0 + p1,
+            a0: ((p3 * x0 + p2) * x0 + p1) * x0 + p0,
+        }
+    }
+
+    #[test]
+    fn test_find_roots_cubic_spline() {
+        // x^3 - x with the roots at the knots
+        let segments: Vec<_> = (-2..2)
+            .map(|i| segment(f64::from(i), f64::from(i + 1), 1f64, 0f64, -1f64, 0f64))
+            .collect();
+        assert_eq!(find_roots_cubic_spline(&segments), vec![-1f64, 0f64, 1f64]);
+        for s in &segments {
+            assert_eq!(s.evaluate(s.x0), s.x0 * s.x0 * s.x0 - s.x0);
+        }
+
+        // x^3 - x with the roots inside the segments
+        let knots = [-2.5f64, -0.5f64, 0.5f64, 2.5f64];
+        let segments: Vec<_> = knots
+            .windows(2)
+            .map(|k| segment(k[0], k[1], 1f64, 0f64, -1f64, 0f64))
+            .collect();
+        let roots = find_roots_cubic_spline(&segments);
+        assert_eq!(roots.len(), 3);
+        assert_float_eq!(1e-15f64, roots[0], -1f64);
+        assert_float_eq!(1e-15f64, roots[1], 0f64);
+        assert_float_eq!(1e-15f64, roots[2], 1f64);
+
+        // The root at the knot between the segments of different cubics
+        let segments = [
+            segment(-1f64, 0f64, 0f64, 0f64, 1f64, 0f64),
+            segment(0f64, 1f64, 1f64, 1f64, 1f64, 0f64),
+        ];
+        assert_eq!(find_roots_cubic_spline(&segments), vec![0f64]);
+
+        assert_eq!(find_roots_cubic_spline::<f64>(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_find_roots_cubic_spline_touch() {
+        // (x - 1)^2 (x - 3) touches zero at 1; the root 3 is out of the spline
+        let segments = [
+            segment(0f64, 2f64, 1f64, -5f64, 7f64, -3f64),
+            segment(-1f64, 0f64, 1f64, -5f64, 7f64, -3f64),
+        ];
+        let roots = find_roots_cubic_spline(&segments);
+        assert_eq!(roots.len(), 1);
+        assert_float_eq!(1e-7f64, roots[0], 1f64);
+
+        // (x - 1)^2 touches zero at the knot
+        let segments = [
+            segment(0f64, 1f64, 0f64, 1f64, -2f64, 1f64),
+            segment(1f64, 2f64, 0f64, 1f64, -2f64, 1f64),
+        ];
+        assert_eq!(find_roots_cubic_spline(&segments), vec![1f64]);
+
+        // A segment identical to zero
+        let segments = [
+            segment(0f64, 1f64, 0f64, 0f64, 1f64, 0f64),
+            segment(1f64, 2f64, 0f64, 0f64, 0f64, 0f64),
+        ];
+        assert_eq!(find_roots_cubic_spline(&segments), vec![0f64, 1f64]);
+    }
+}
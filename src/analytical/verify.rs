@@ -0,0 +1,351 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::double_double::DoubleDouble;
+use super::super::FloatType;
+use super::super::Roots;
+
+/// Result of checking a root of a polynomial equation by verify_roots
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RootCheck<F>
+where
+    F: FloatType,
+{
+    /// The checked root
+    pub root: F,
+    /// Absolute value of the polynomial at the root
+    pub residual: F,
+    /// The residual divided by the sum of absolute values of all terms of the polynomial at the root.
+    /// This is the relative change of coefficients which makes the root exact (backward error);
+    /// values within a few epsilons mean that the root is as precise as the floating point type allows.
+    pub relative_residual: F,
+    /// True if the polynomial changes its sign within 16 epsilons around the root.
+    /// Roots of even multiplicity never change the sign.
+    pub bracketed: bool,
+}
+
+/// Value of the polynomial c[0]*x^n + c[1]*x^(n-1) + ... + c[n] calculated by the Horner's scheme in double-double precision
+fn evaluate<F: FloatType>(coeffs: &[F], x: F) -> F {
+    let x = DoubleDouble::from(x);
+    let mut value = DoubleDouble::from(F::zero());
+    for c in coeffs.iter() {
+        value = value * x + DoubleDouble::from(*c);
+    }
+    value.value()
+}
+
+/// Derivative of the polynomial c[0]*x^n + c[1]*x^(n-1) + ... + c[n] calculated by the Horner's scheme in double-double precision
+fn evaluate_derivative<F: FloatType>(coeffs: &[F], x: F) -> F {
+    let x = DoubleDouble::from(x);
+    let mut value = DoubleDouble::from(F::zero());
+    let mut derivative = DoubleDouble::from(F::zero());
+    for c in coeffs.iter() {
+        derivative = derivative * x + value;
+        value = value * x + DoubleDouble::from(*c);
+    }
+    derivative.value()
+}
+
+/// Sum of absolute values of all terms of the polynomial c[0]*x^n + c[1]*x^(n-1) + ... + c[n]
+fn magnitude<F: FloatType>(coeffs: &[F], x: F) -> F {
+    let x = x.abs();
+    let mut magnitude = F::zero();
+    for c in coeffs.iter() {
+        magnitude = magnitude * x + c.abs();
+    }
+    magnitude
+}
+
+/// Checks if the polynomial c[0]*x^n + c[1]*x^(n-1) + ... + c[n] changes its sign around x
+fn is_bracketed<F: FloatType>(coeffs: &[F], x: F) -> bool {
+    if x == F::zero() {
+        // The multiplicity of the root 0 is the number of trailing zero coefficients
+        coeffs.iter().rev().take_while(|c| **c == F::zero()).count() % 2 == 1
+    } else {
+        let delta = F::from(16i16) * F::epsilon() * x.abs();
+        let (before, after) = (evaluate(coeffs, x - delta), evaluate(coeffs, x + delta));
+        (before <= F::zero() && after >= F::zero()) || (before >= F::zero() && after <= F::zero())
+    }
+}
+
+/// Checks roots of the polynomial equation c[0]*x^n + c[1]*x^(n-1) + ... + c[n] = 0.
+///
+/// For every given root, calculates the residual, the relative residual, and checks if the polynomial
+/// changes its sign around the root. The polynomial is evaluated by the compensated Horner's scheme
+/// in double-double precision, so rounding errors of the check are much smaller than those of the roots.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_quartic;
+/// use roots::verify_roots;
+///
+/// let coeffs = [1f64, -10f64, 35f64, -50f64, 24f64];
+/// let roots = find_roots_quartic(coeffs[0], coeffs[1], coeffs[2], coeffs[3], coeffs[4]);
+/// for check in verify_roots(&coeffs, roots.as_ref()) {
+///     assert!(check.bracketed);
+///     assert!(check.relative_residual < 1e-15f64);
+/// }
+/// ```
+pub fn verify_roots<F: FloatType>(coeffs: &[F], roots: &[F]) -> Vec<RootCheck<F>> {
+    roots
+        .iter()
+        .map(|x| {
+            let residual = evaluate(coeffs, *x).abs();
+            let magnitude = magnitude(coeffs, *x);
+            RootCheck {
+                root: *x,
+                residual,
+                relative_residual: if magnitude == F::zero() {
+                    F::zero()
+                } else {
+                    residual / magnitude
+                },
+                bracketed: is_bracketed(coeffs, *x),
+            }
+        })
+        .collect()
+}
+
+/// Estimates the condition number of a root of the polynomial c[0]*x^n + c[1]*x^(n-1) + ... + c[n].
+///
+/// The condition number is the standard relative one: sum of absolute values of all terms
+/// of the polynomial at the root divided by |root|*|derivative at the root|.
+/// It tells how many times a relative change of coefficients is magnified in the relative change of the root,
+/// so a root cannot be found more precisely than the condition number times epsilon.
+/// Simple well-separated roots have the condition number about 1;
+/// it grows without bounds as roots approach each other, and is infinite for multiple roots.
+/// The derivative is evaluated by the compensated Horner's scheme in double-double precision.
+/// For the root 0, the limit value 1 is returned if the root is simple.
+///
+/// # Examples
+///
+/// ```
+/// use roots::root_condition;
+///
+/// // (x-1)(x-2)
+/// let condition = root_condition(&[1f64, -3f64, 2f64], 2f64);
+/// // Returns 6f64 as the sum of terms is 4+6+2 and the derivative is 1
+/// ```
+pub fn root_condition<F: FloatType>(coeffs: &[F], root: F) -> F {
+    let derivative = evaluate_derivative(coeffs, root).abs();
+    if derivative == F::zero() {
+        F::one() / F::zero()
+    } else if root == F::zero() {
+        F::one()
+    } else {
+        magnitude(coeffs, root) / (root.abs() * derivative)
+    }
+}
+
+/// Calculates derivatives of a simple root of the polynomial c[0]*x^n + c[1]*x^(n-1) + ... + c[n] with respect to coefficients.
+///
+/// The i-th element is the derivative of the root by c[i], which is -root^(n-i) / p'(root).
+/// These derivatives propagate uncertainties of coefficients to roots to the first order.
+/// The derivative of the polynomial is evaluated by the compensated Horner's scheme in double-double precision.
+/// Multiple roots are not differentiable: if p'(root) is zero, all elements are infinite.
+///
+/// # Examples
+///
+/// ```
+/// use roots::root_sensitivities;
+///
+/// // (x-1)(x-2)
+/// let sensitivities = root_sensitivities(&[1f64, -3f64, 2f64], 2f64);
+/// // Returns vec![-4f64, -2f64, -1f64] as the derivative of the polynomial at 2 is 1
+/// ```
+pub fn root_sensitivities<F: FloatType>(coeffs: &[F], root: F) -> Vec<F> {
+    let derivative = evaluate_derivative(coeffs, root);
+    if derivative == F::zero() {
+        return vec![F::one() / F::zero(); coeffs.len()];
+    }
+    let mut sensitivities = Vec::with_capacity(coeffs.len());
+    let mut power = F::one();
+    for _ in coeffs.iter() {
+        sensitivities.push(-power / derivative);
+        power = power * root;
+    }
+    sensitivities.reverse();
+    sensitivities
+}
+
+/// Calculates derivatives of roots of the polynomial c[0]*x^n + c[1]*x^(n-1) + ... + c[n] with respect to coefficients.
+///
+/// Returns a row of root_sensitivities for every root, e.g. for roots returned by analytical solvers.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_cubic;
+/// use roots::sensitivity_matrix;
+///
+/// let roots = find_roots_cubic(1f64, -6f64, 11f64, -6f64);
+/// let matrix = sensitivity_matrix(&[1f64, -6f64, 11f64, -6f64], &roots);
+/// // Returns 3 rows of 4 derivatives; e.g. matrix[0] is approximately vec![-0.5f64, -0.5f64, -0.5f64, -0.5f64] for the root 1
+/// ```
+pub fn sensitivity_matrix<F: FloatType>(coeffs: &[F], roots: &Roots<F>) -> Vec<Vec<F>> {
+    roots.as_ref().iter().map(|x| root_sensitivities(coeffs, *x)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_verify_roots() {
+        // (x-1)(x-2)(x-3)(x-4)
+        let coeffs = [1f64, -10f64, 35f64, -50f64, 24f64];
+        let checks = verify_roots(&coeffs, &[1f64, 2f64, 3f64, 4f64, 1.001f64]);
+        for check in checks[..4].iter() {
+            assert_eq!(check.residual, 0f64);
+            assert_eq!(check.relative_residual, 0f64);
+            assert!(check.bracketed);
+        }
+        assert_float_eq!(1e-15f64, checks[4].residual, 0.001f64 * 0.999f64 * 1.999f64 * 2.999f64);
+        assert!(checks[4].relative_residual > 1e-5f64);
+        assert!(!checks[4].bracketed);
+
+        // (x-1)^2*x has the double root 1 and the simple root 0
+        let checks = verify_roots(&[1f32, -2f32, 1f32, 0f32], &[0f32, 1f32]);
+        assert_eq!(
+            checks[0],
+            RootCheck {
+                root: 0f32,
+                residual: 0f32,
+                relative_residual: 0f32,
+                bracketed: true
+            }
+        );
+        assert_eq!(
+            checks[1],
+            RootCheck {
+                root: 1f32,
+                residual: 0f32,
+                relative_residual: 0f32,
+                bracketed: false
+            }
+        );
+
+        // x^2*(x-1) has the double root 0
+        assert!(!verify_roots(&[1f64, -1f64, 0f64, 0f64], &[0f64])[0].bracketed);
+    }
+
+    #[test]
+    fn test_verify_roots_compensated() {
+        // (x-1)^3 at 1 + 2^-23 is 2^-69 exactly, while the plain Horner's scheme gives rounding errors only
+        let x = 1f64 + 2f64.powi(-23);
+        let check = verify_roots(&[1f64, -3f64, 3f64, -1f64], &[x])[0];
+        assert_eq!(check.residual, 2f64.powi(-69));
+        assert_eq!(((x - 3f64) * x + 3f64) * x - 1f64, 0f64);
+        // The triple root 1 is not within 16 epsilons
+        assert!(!check.bracketed);
+        assert!(verify_roots(&[1f64, -3f64, 3f64, -1f64], &[1f64])[0].bracketed);
+    }
+
+    #[test]
+    fn test_root_condition() {
+        // Well-separated roots 1, 10, 100, 1000
+        let coeffs = [1f64, -1111f64, 112110f64, -1111000f64, 1000000f64];
+        assert_float_eq!(1e-12f64, root_condition(&coeffs, 1f64), 2.498819807461783f64);
+        assert_float_eq!(1e-12f64, root_condition(&coeffs, 10f64), 3.0480109739368997f64);
+        assert_float_eq!(1e-12f64, root_condition(&coeffs, 100f64), 3.0480109739368997f64);
+        assert_float_eq!(1e-12f64, root_condition(&coeffs, 1000f64), 2.498819807461783f64);
+        // x^2-1 and x^2-x
+        assert_eq!(root_condition(&[1f32, 0f32, -1f32], -1f32), 1f32);
+        assert_eq!(root_condition(&[1f32, -1f32, 0f32], 0f32), 1f32);
+        assert_eq!(root_condition(&[1f32, -1f32, 0f32], 1f32), 2f32);
+    }
+
+    #[test]
+    fn test_root_condition_close_roots() {
+        // (x-1)(x-1-d)(x+1)(x+2): the condition number of the close roots grows as 1/d
+        let mut previous = 1f64;
+        for d in [1e-2f64, 1e-4f64, 1e-6f64, 1e-8f64].iter() {
+            let x = 1f64 + d;
+            let coeffs = [1f64, 2f64 - x, -2f64 * x - 1f64, x - 2f64, 2f64 * x];
+            let condition = root_condition(&coeffs, 1f64);
+            assert!(condition > 1f64 / d);
+            assert!(condition < 2f64 / d);
+            assert!(condition > previous);
+            previous = condition;
+        }
+        // Multiple roots have the infinite condition number
+        assert!(root_condition(&[1f64, -2f64, 1f64], 1f64).is_infinite());
+        assert!(root_condition(&[1f64, 0f64, 0f64], 0f64).is_infinite());
+    }
+
+    #[test]
+    fn test_root_sensitivities() {
+        assert_eq!(root_sensitivities(&[1f64, -3f64, 2f64], 2f64), vec![-4f64, -2f64, -1f64]);
+        assert_eq!(root_sensitivities(&[1f32, -3f32, 2f32], 1f32), vec![1f32, 1f32, 1f32]);
+        // The double root is not differentiable
+        assert!(root_sensitivities(&[1f64, -2f64, 1f64], 1f64).iter().all(|x| x.is_infinite()));
+        assert_eq!(
+            sensitivity_matrix(&[1f64, 0f64, 1f64], &Roots::No([])),
+            Vec::<Vec<f64>>::new()
+        );
+    }
+
+    #[test]
+    fn test_root_sensitivities_finite_differences() {
+        // Central differences of roots found by analytical solvers with perturbed coefficients
+        let cubic = [2f64, -3f64, -11f64, 6f64];
+        let roots = find_roots_cubic(cubic[0], cubic[1], cubic[2], cubic[3]);
+        let matrix = sensitivity_matrix(&cubic, &roots);
+        assert_eq!(matrix.len(), 3);
+        for i in 0..cubic.len() {
+            let h = 1e-6f64;
+            let (mut plus, mut minus) = (cubic, cubic);
+            plus[i] += h;
+            minus[i] -= h;
+            let (plus, minus) = (
+                find_roots_cubic(plus[0], plus[1], plus[2], plus[3]),
+                find_roots_cubic(minus[0], minus[1], minus[2], minus[3]),
+            );
+            for j in 0..3 {
+                let difference = (plus.as_ref()[j] - minus.as_ref()[j]) / (2f64 * h);
+                assert_float_eq!(1e-6f64, matrix[j][i], difference);
+            }
+        }
+
+        let quartic = [1f64, -2.5f64, -5.75f64, 9.25f64, 3f64];
+        let roots = find_roots_quartic(quartic[0], quartic[1], quartic[2], quartic[3], quartic[4]);
+        let matrix = sensitivity_matrix(&quartic, &roots);
+        assert_eq!(matrix.len(), 4);
+        for i in 0..quartic.len() {
+            let h = 1e-6f64;
+            let (mut plus, mut minus) = (quartic, quartic);
+            plus[i] += h;
+            minus[i] -= h;
+            let (plus, minus) = (
+                find_roots_quartic(plus[0], plus[1], plus[2], plus[3], plus[4]),
+                find_roots_quartic(minus[0], minus[1], minus[2], minus[3], minus[4]),
+            );
+            for j in 0..4 {
+                let difference = (plus.as_ref()[j] - minus.as_ref()[j]) / (2f64 * h);
+                assert_float_eq!(1e-6f64 * (1f64 + matrix[j][i].abs()), matrix[j][i], difference);
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::FloatType;
+use std::ops::Add;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+/// Unevaluated sum hi + lo of two floating-point numbers, where |lo| <= ulp(hi)/2.
+/// It has about twice as many significant bits as the underlying type
+/// and is used to recalculate ill-conditioned values precisely.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DoubleDouble<F>
+where
+    F: FloatType,
+{
+    /// Leading part
+    hi: F,
+    /// Rounding error of the leading part
+    lo: F,
+}
+
+impl<F> DoubleDouble<F>
+where
+    F: FloatType,
+{
+    /// Exact sum of two numbers (Knuth's two-sum)
+    fn two_sum(a: F, b: F) -> Self {
+        let hi = a + b;
+        let b_virtual = hi - a;
+        let lo = (a - (hi - b_virtual)) + (b - b_virtual);
+        DoubleDouble { hi, lo }
+    }
+
+    /// Exact sum of two numbers where |a| >= |b|
+    fn fast_two_sum(a: F, b: F) -> Self {
+        let hi = a + b;
+        let lo = b - (hi - a);
+        DoubleDouble { hi, lo }
+    }
+
+    /// Exact product of two numbers
+    fn two_prod(a: F, b: F) -> Self {
+        let hi = a * b;
+        let lo = a.mul_add(b, -hi);
+        DoubleDouble { hi, lo }
+    }
+
+    /// Value rounded to the underlying type
+    pub fn value(self) -> F {
+        self.hi + self.lo
+    }
+}
+
+impl<F> From<F> for DoubleDouble<F>
+where
+    F: FloatType,
+{
+    fn from(x: F) -> Self {
+        DoubleDouble { hi: x, lo: F::zero() }
+    }
+}
+
+impl<F> From<i16> for DoubleDouble<F>
+where
+    F: FloatType,
+{
+    fn from(x: i16) -> Self {
+        DoubleDouble {
+            hi: F::from(x),
+            lo: F::zero(),
+        }
+    }
+}
+
+impl<F> Neg for DoubleDouble<F>
+where
+    F: FloatType,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl<F> Add for DoubleDouble<F>
+where
+    F: FloatType,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        let s = Self::two_sum(self.hi, other.hi);
+        let t = Self::two_sum(self.lo, other.lo);
+        let s = Self::fast_two_sum(s.hi, s.lo + t.hi);
+        Self::fast_two_sum(s.hi, s.lo + t.lo)
+    }
+}
+
+impl<F> Sub for DoubleDouble<F>
+where
+    F: FloatType,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<F> Mul for DoubleDouble<F>
+where
+    F: FloatType,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let p = Self::two_prod(self.hi, other.hi);
+        Self::fast_two_sum(p.hi, p.lo + (self.hi * other.lo + self.lo * other.hi))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_double_double() {
+        // 1 + 2^-60 cannot be represented by f64
+        let tiny = 1f64 / 1152921504606846976f64;
+        let x = DoubleDouble::from(1f64) + DoubleDouble::from(tiny);
+        assert_eq!(x.value(), 1f64);
+        assert_eq!((x - DoubleDouble::from(1f64)).value(), tiny);
+
+        // (1 + 2^-30)^2 = 1 + 2^-29 + 2^-60
+        let y = DoubleDouble::from(1f64 + 1f64 / 1073741824f64);
+        assert_eq!((y * y - DoubleDouble::from(1f64 + 1f64 / 536870912f64)).value(), tiny);
+    }
+}
@@ -49,6 +49,16 @@ pub trait FloatType:
     fn zero() -> Self;
     fn one() -> Self;
     fn one_third() -> Self;
+    /// The difference between 1 and the next representable value
+    fn epsilon() -> Self {
+        let one = Self::one();
+        let two = Self::from(2i16);
+        let mut eps = one;
+        while one + eps / two != one {
+            eps = eps / two;
+        }
+        eps
+    }
     fn pi() -> Self;
     fn two_third_pi() -> Self;
     fn sqrt(self) -> Self;
@@ -66,6 +76,132 @@ pub trait FloatType:
     fn cos(self) -> Self;
     fn abs(self) -> Self;
     fn powf(self, n: Self) -> Self;
+    /// Fused multiply-add self*a + b with only one rounding error.
+    /// The default implementation rounds twice; types supporting FMA should override it
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+    /// Converts the value to f64 (used where calculations need extra precision).
+    /// The default implementation takes 53 significant bits one by one; override it if a cast exists
+    fn to_f64(self) -> f64 {
+        bits_to_f64(self)
+    }
+    /// Converts f64 back to the type, rounding if needed.
+    /// The default implementation builds the value from the bits of f64; override it if a cast exists
+    fn from_f64(value: f64) -> Self {
+        bits_from_f64(value)
+    }
+}
+
+/// Conversion to f64 by the arithmetic of the type, rounding the significand to 53 bits
+fn bits_to_f64<F: FloatType>(value: F) -> f64 {
+    let zero = F::zero();
+    let one = F::one();
+    let two = F::from(2i16);
+    if value.partial_cmp(&zero).is_none() {
+        return f64::NAN;
+    }
+    if value == zero {
+        return if one / value < zero { -0f64 } else { 0f64 };
+    }
+    if value + value == value {
+        return if value < zero { f64::NEG_INFINITY } else { f64::INFINITY };
+    }
+
+    // value = significand * 2^exponent with the significand in [1, 2)
+    let mut significand = value.abs();
+    let mut exponent = 0i32;
+    while significand >= two {
+        significand = significand / two;
+        exponent += 1;
+    }
+    while significand < one {
+        significand = significand * two;
+        exponent -= 1;
+    }
+
+    let mut bits = 0u64;
+    for _ in 0..53 {
+        bits <<= 1;
+        if significand >= one {
+            bits |= 1;
+            significand = significand - one;
+        }
+        significand = significand * two;
+    }
+    // Round half to even
+    if significand > one || (significand == one && bits & 1 == 1) {
+        bits += 1;
+    }
+
+    // Scale in two steps, so that the intermediate value does not leave the range of f64
+    let half = (exponent - 52) / 2;
+    let result = bits as f64 * 2f64.powi(half) * 2f64.powi(exponent - 52 - half);
+    if value < zero {
+        -result
+    } else {
+        result
+    }
+}
+
+/// Conversion from f64 by the arithmetic of the type, building the value from the significand and the exponent of f64
+fn bits_from_f64<F: FloatType>(value: f64) -> F {
+    if value.is_nan() {
+        return F::zero() / F::zero();
+    }
+    if value.is_infinite() {
+        let infinity = F::one() / F::zero();
+        return if value < 0f64 { -infinity } else { infinity };
+    }
+    if value == 0f64 {
+        return if value.is_sign_negative() { -F::zero() } else { F::zero() };
+    }
+
+    // |value| = significand * 2^exponent with the integer significand of at most 53 bits
+    let bits = value.abs().to_bits();
+    let biased = (bits >> 52) as i32;
+    let fraction = bits & ((1u64 << 52) - 1);
+    let (mut significand, mut exponent) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1u64 << 52), biased - 1075)
+    };
+
+    // Round half to even to the precision of the type, so that the parts below are added exactly
+    let mut precision = 1u32;
+    let mut eps = F::one();
+    while eps > F::epsilon() {
+        eps = eps / F::from(2i16);
+        precision += 1;
+    }
+    let length = 64 - significand.leading_zeros();
+    if length > precision {
+        let dropped = length - precision;
+        let remainder = significand & ((1u64 << dropped) - 1);
+        let half = 1u64 << (dropped - 1);
+        significand >>= dropped;
+        exponent += dropped as i32;
+        if remainder > half || (remainder == half && significand & 1 == 1) {
+            significand += 1;
+        }
+    }
+
+    // The significand is assembled from the parts fitting i16
+    let chunk = F::from(1i16 << 14);
+    let mut result = F::zero();
+    for shift in [42, 28, 14, 0].iter() {
+        result = result * chunk + F::from(((significand >> shift) & ((1u64 << 14) - 1)) as i16);
+    }
+
+    // Scale in two steps, so that the intermediate value does not leave the range of the type
+    let two = F::from(2i16);
+    let half = exponent / 2;
+    result = result * two.powf(F::from(half as i16)) * two.powf(F::from((exponent - half) as i16));
+    if value < 0f64 {
+        -result
+    } else {
+        result
+    }
 }
 
 impl FloatType for f32 {
@@ -82,6 +218,10 @@ impl FloatType for f32 {
         1f32
     }
     #[inline]
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+    #[inline]
     fn two_third_pi() -> Self {
         2f32 * f32::consts::FRAC_PI_3
     }
@@ -110,6 +250,18 @@ impl FloatType for f32 {
     fn powf(self, n: Self) -> Self {
         self.powf(n)
     }
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self.mul_add(a, b)
+    }
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
 }
 
 impl FloatType for f64 {
@@ -126,6 +278,10 @@ impl FloatType for f64 {
         1f64
     }
     #[inline]
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+    #[inline]
     fn two_third_pi() -> Self {
         2f64 * f64::consts::FRAC_PI_3
     }
@@ -154,6 +310,18 @@ impl FloatType for f64 {
     fn powf(self, n: Self) -> Self {
         self.powf(n)
     }
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self.mul_add(a, b)
+    }
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
 }
 
 #[test]
@@ -162,3 +330,30 @@ fn test_float_cbrt() {
     assert_eq!(8f64.cbrt(), 2f64);
     assert_eq!(0f32.cbrt(), 0f32);
 }
+
+#[test]
+fn test_float_defaults() {
+    assert_eq!(1f64.mul_add(2f64, 3f64), 5f64);
+    let values = [
+        1f64,
+        -2.5f64,
+        0.1f64,
+        -1e-30f64,
+        1e300f64,
+        5e-324f64,
+        -0f64,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    for &value in values.iter() {
+        assert_eq!(bits_to_f64(value), value);
+        assert_eq!(bits_to_f64(value).is_sign_negative(), value.is_sign_negative());
+        assert_eq!(bits_from_f64::<f64>(value), value);
+        assert_eq!(bits_from_f64::<f32>(value), value as f32);
+        assert_eq!(bits_from_f64::<f32>(value).is_sign_negative(), value.is_sign_negative());
+        assert_eq!(bits_to_f64(value as f32), value as f32 as f64);
+    }
+    assert!(bits_to_f64(f32::NAN).is_nan());
+    assert!(bits_from_f64::<f32>(f64::NAN).is_nan());
+}
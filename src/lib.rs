@@ -66,33 +66,205 @@ macro_rules! assert_float_array_eq(
     })
 );
 
+#[cfg(feature = "debug-exact")]
+extern crate num_bigint;
+#[cfg(feature = "num-complex")]
+extern crate num_complex;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
 mod analytical;
+mod double_double;
 mod float;
 mod numerical;
 
 pub use self::float::FloatType;
 
+pub use self::analytical::batch::find_roots_quartic_batch;
+pub use self::analytical::batch::BatchError;
 pub use self::analytical::biquadratic::find_roots_biquadratic;
+pub use self::analytical::cubic::count_real_roots_cubic;
+pub use self::analytical::cubic::discriminant_cubic;
 pub use self::analytical::cubic::find_roots_cubic;
+pub use self::analytical::cubic::has_real_roots_cubic;
 pub use self::analytical::cubic_depressed::find_roots_cubic_depressed;
 pub use self::analytical::cubic_normalized::find_roots_cubic_normalized;
+pub use self::analytical::even_polynomial::find_roots_even_polynomial;
 pub use self::analytical::linear::find_roots_linear;
+pub use self::analytical::palindromic::find_roots_quartic_anti_palindromic;
+pub use self::analytical::palindromic::find_roots_quartic_palindromic;
+pub use self::analytical::palindromic::find_roots_sextic_palindromic;
+pub use self::analytical::polynomial::find_roots_polynomial;
+pub use self::analytical::polynomial::polynomial_from_roots;
+pub use self::analytical::quadratic::count_real_roots_quadratic;
+pub use self::analytical::quadratic::discriminant_quadratic;
 pub use self::analytical::quadratic::find_roots_quadratic;
+pub use self::analytical::quadratic::has_real_roots_quadratic;
+pub use self::analytical::quartic::count_real_roots_quartic;
+pub use self::analytical::quartic::depress_quartic;
+pub use self::analytical::quartic::discriminant_quartic;
 pub use self::analytical::quartic::find_roots_quartic;
+pub use self::analytical::quartic::find_roots_quartic_diagnostics;
+pub use self::analytical::quartic::find_roots_quartic_with_condition;
+pub use self::analytical::quartic::find_smallest_positive_root_quartic;
+pub use self::analytical::quartic::has_real_roots_quartic;
+pub use self::analytical::quartic::resolvent_cubic;
+pub use self::analytical::quartic::QuarticBranch;
+pub use self::analytical::quartic::QuarticDiagnostics;
+#[cfg(feature = "num-complex")]
+pub use self::analytical::quartic_complex::find_roots_quartic_complex;
 pub use self::analytical::quartic_depressed::find_roots_quartic_depressed;
+pub use self::analytical::quartic_refined::find_roots_quartic_refined;
+pub use self::analytical::quintic::find_roots_quintic;
+pub use self::analytical::rational::extract_rational_roots;
 pub use self::analytical::roots::Roots;
+pub use self::analytical::spline::find_roots_cubic_spline;
+pub use self::analytical::spline::SplineSegment;
+pub use self::analytical::verify::root_condition;
+pub use self::analytical::verify::root_sensitivities;
+pub use self::analytical::verify::sensitivity_matrix;
+pub use self::analytical::verify::verify_roots;
+pub use self::analytical::verify::RootCheck;
 
+#[cfg(feature = "num-complex")]
+pub use self::numerical::aberth::find_roots_aberth;
+pub use self::numerical::adaptive_convergency::AdaptiveConvergency;
+pub use self::numerical::bairstow::find_quadratic_factors_bairstow;
+pub use self::numerical::bairstow::find_roots_bairstow;
+pub use self::numerical::bernstein::bernstein_to_power;
+pub use self::numerical::bernstein::find_roots_bernstein;
+pub use self::numerical::bernstein::power_to_bernstein;
+pub use self::numerical::bisection::bisection_iterations_needed;
+pub use self::numerical::bisection::find_root_bisection;
+pub use self::numerical::bisection::find_root_bisection_in;
+pub use self::numerical::bisection::find_root_bisection_with_stats;
+pub use self::numerical::bisection::find_root_log_bisection;
+pub use self::numerical::bisection::find_root_log_bisection_signed;
+pub use self::numerical::bisection::try_find_root_bisection;
+pub use self::numerical::bracket::expand_bracket;
+pub use self::numerical::bracket::find_brackets;
+pub use self::numerical::bracket::find_brackets_on_grid;
+pub use self::numerical::bracket::find_root_unbounded;
+pub use self::numerical::bracket::Direction;
 pub use self::numerical::brent::find_root_brent;
+pub use self::numerical::brent::find_root_brent_classified;
+pub use self::numerical::brent::find_root_brent_enclosure;
+pub use self::numerical::brent::find_root_brent_expand;
+pub use self::numerical::brent::find_root_brent_hyperbolic;
+pub use self::numerical::brent::find_root_brent_in;
+pub use self::numerical::brent::find_root_brent_noisy;
+pub use self::numerical::brent::find_root_brent_observed;
+pub use self::numerical::brent::find_root_brent_with_guess;
+pub use self::numerical::brent::find_root_brent_with_stats;
+pub use self::numerical::brent::try_find_root_brent;
+pub use self::numerical::cache::CachedFn;
+pub use self::numerical::cache::CACHE_SIZE;
+pub use self::numerical::chandrupatla::find_root_chandrupatla;
+pub use self::numerical::composite_convergency::AllOf;
+pub use self::numerical::composite_convergency::AnyOf;
+pub use self::numerical::composite_convergency::ConvergencyBuilder;
+pub use self::numerical::composite_convergency::ToleranceConvergency;
 pub use self::numerical::debug_convergency::DebugConvergency;
+pub use self::numerical::debug_convergency::PrintSink;
+pub use self::numerical::deflation::deflate;
+pub use self::numerical::deflation::deflate_quadratic;
+pub use self::numerical::deflation::deflate_stable;
+pub use self::numerical::dekker::find_root_dekker;
+pub use self::numerical::derivative::check_derivative;
+pub use self::numerical::derivative::DerivativeMismatch;
 pub use self::numerical::eigen::find_roots_eigen;
+pub use self::numerical::eigen::find_roots_eigen_polished;
+pub use self::numerical::extremum::find_extremum_brent;
+pub use self::numerical::extremum::Extremum;
+pub use self::numerical::extremum::ExtremumKind;
+pub use self::numerical::finder::Bisection;
+pub use self::numerical::finder::Brent;
+pub use self::numerical::finder::DerivativeRootFinder;
+pub use self::numerical::finder::NewtonRaphson;
+pub use self::numerical::finder::RegulaFalsi;
+pub use self::numerical::finder::RootFinder;
+pub use self::numerical::finder::Secant;
+pub use self::numerical::graeffe::graeffe_root_magnitudes;
+pub use self::numerical::halley::find_root_halley;
+pub use self::numerical::halley::find_root_halley_combined;
+pub use self::numerical::inverse::find_inverse;
+pub use self::numerical::inverse::find_inverse_newton;
 pub use self::numerical::inverse_quadratic::find_root_inverse_quadratic;
+pub use self::numerical::inverse_quadratic::find_root_inverse_quadratic_observed;
+pub use self::numerical::inverse_quadratic::IterationInfo;
 pub use self::numerical::inverse_quadratic::Parabola;
+pub use self::numerical::jenkins_traub::find_roots_jenkins_traub;
+#[cfg(feature = "num-complex")]
+pub use self::numerical::jenkins_traub::find_roots_jenkins_traub_complex;
+pub use self::numerical::laguerre::find_root_laguerre;
+#[cfg(feature = "num-complex")]
+pub use self::numerical::laguerre::find_root_laguerre_complex;
+pub use self::numerical::method::find_root;
+pub use self::numerical::method::Method;
+pub use self::numerical::method::Start;
+pub use self::numerical::newton_raphson::find_root_newton_bracketed;
+#[cfg(feature = "num-complex")]
+pub use self::numerical::newton_raphson::find_root_newton_complex;
+pub use self::numerical::newton_raphson::find_root_newton_numeric;
 pub use self::numerical::newton_raphson::find_root_newton_raphson;
+pub use self::numerical::newton_raphson::find_root_newton_raphson_bounded;
+pub use self::numerical::newton_raphson::find_root_newton_raphson_combined;
+pub use self::numerical::newton_raphson::find_root_newton_raphson_observed;
+pub use self::numerical::newton_raphson::find_root_newton_raphson_or_best;
+pub use self::numerical::newton_raphson::find_root_newton_raphson_with_stats;
+pub use self::numerical::newton_raphson::find_root_newton_raphson_with_strategy;
+pub use self::numerical::newton_raphson::find_root_newton_with_multiplicity;
+pub use self::numerical::newton_raphson::ZeroDerivativeStrategy;
+pub use self::numerical::ostrowski::find_root_ostrowski;
+pub use self::numerical::polynom::find_all_roots_newton_horner;
+pub use self::numerical::polynom::find_extremum_polynomial;
+pub use self::numerical::polynom::find_root_newton_horner;
+pub use self::numerical::polynom::find_roots_rational;
 pub use self::numerical::polynom::find_roots_sturm;
+pub use self::numerical::polynom::refine_polynomial_root;
+pub use self::numerical::recording_convergency::Event;
+pub use self::numerical::recording_convergency::RecordingConvergency;
+pub use self::numerical::refine::polish_roots_weierstrass;
+pub use self::numerical::refine::refine_root;
 pub use self::numerical::regula_falsi::find_root_regula_falsi;
+pub use self::numerical::regula_falsi::find_root_regula_falsi_enclosure;
+pub use self::numerical::regula_falsi::find_root_regula_falsi_in;
+pub use self::numerical::regula_falsi::find_root_regula_falsi_observed;
+pub use self::numerical::regula_falsi::find_root_regula_falsi_variant;
+pub use self::numerical::regula_falsi::find_root_regula_falsi_with_stats;
+pub use self::numerical::regula_falsi::try_find_root_regula_falsi;
+pub use self::numerical::regula_falsi::RegulaFalsiVariant;
+pub use self::numerical::relative_convergency::RelativeConvergency;
+pub use self::numerical::residual_relative_convergency::ResidualRelativeConvergency;
+pub use self::numerical::ridders::find_root_ridders;
+pub use self::numerical::routh::count_right_half_plane_roots;
+pub use self::numerical::routh::is_hurwitz_stable;
 pub use self::numerical::secant::find_root_secant;
+pub use self::numerical::secant::find_root_secant_auto;
+pub use self::numerical::secant::find_root_secant_bracketed;
+pub use self::numerical::secant::find_root_secant_guarded;
+pub use self::numerical::secant::find_root_secant_in;
+pub use self::numerical::secant::find_root_secant_observed;
+pub use self::numerical::secant::find_root_secant_or_best;
+pub use self::numerical::secant::find_root_secant_with_stats;
+pub use self::numerical::secant::try_find_root_secant;
 pub use self::numerical::simple_convergency::SimpleConvergency;
+pub use self::numerical::solver::Solver;
+pub use self::numerical::solver::SolverError;
+pub use self::numerical::steffensen::find_root_steffensen;
+pub use self::numerical::subdivision::find_all_roots;
+#[cfg(feature = "rayon")]
+pub use self::numerical::subdivision::find_roots_multistart;
+pub use self::numerical::suppression::find_n_roots;
+pub use self::numerical::suppression::suppress_roots;
+pub use self::numerical::tabulated::find_root_from_samples;
+pub use self::numerical::tabulated::find_root_from_samples_quadratic;
 pub use self::numerical::Convergency;
 pub use self::numerical::Interval;
+pub use self::numerical::InvalidInputReason;
+pub use self::numerical::IterationObserver;
+pub use self::numerical::RootKind;
 pub use self::numerical::Sample;
 pub use self::numerical::SearchError;
+pub use self::numerical::SolverStats;
+pub use self::numerical::TrySearchError;
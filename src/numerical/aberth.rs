@@ -0,0 +1,250 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use num_complex::Complex;
+
+use super::super::analytical::quartic_complex::{complex_div, complex_mul};
+use super::super::FloatType;
+use super::graeffe::graeffe_root_magnitudes;
+use super::Convergency;
+use super::SearchError;
+
+/// Values of the normalized polynomial and its derivative at complex x by the Horner's scheme
+fn evaluate<F: FloatType>(a: &[F], x: Complex<F>) -> (Complex<F>, Complex<F>) {
+    let (mut p, mut d) = (Complex::new(F::one(), F::zero()), Complex::new(F::zero(), F::zero()));
+    for c in a.iter() {
+        let t = complex_mul(d, x);
+        d = Complex::new(t.re + p.re, t.im + p.im);
+        let t = complex_mul(p, x);
+        p = Complex::new(t.re + *c, t.im);
+    }
+    (p, d)
+}
+
+/// Find all complex roots of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// using the Aberth-Ehrlich method.
+///
+/// All roots are refined simultaneously. Each Newton's correction w = p/p' of a root
+/// is replaced by w/(1 - w*S), where S is the sum of 1/(x - y) over all other approximations y,
+/// so that the approximations repel each other and do not converge to the same root.
+/// The initial approximations are spread by angle on circles of the root magnitudes estimated by
+/// graeffe_root_magnitudes, or on a slight spiral inside the Cauchy bound of the roots if the estimates fail.
+/// A root is not refined anymore once its correction is below the convergency threshold.
+/// Requires the num-complex feature.
+///
+/// Pro
+///
+/// + Finds all roots at once, including the complex ones
+/// + Cubic convergency to simple roots
+/// + O(n^2) operations per iteration, which is faster than find_roots_eigen for high degrees
+///
+/// Contra
+///
+/// - Polynomials only
+/// - Slow convergency to multiple roots
+///
+/// # Failures
+/// ## NoConvergency
+/// Algorithm cannot find all roots within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// extern crate num_complex;
+/// extern crate roots;
+///
+/// use roots::find_roots_aberth;
+///
+/// // x^3 - x^2 + x - 1 = (x - 1)(x - i)(x + i)
+/// let polynom = [-1f64, 1f64, -1f64];
+///
+/// let roots = find_roots_aberth(&polynom, &mut 1e-15f64);
+/// // Returns approximately Ok(vec![1, i, -i]) in some order;
+/// ```
+pub fn find_roots_aberth<F>(a: &[F], convergency: &mut (impl Convergency<F> + ?Sized)) -> Result<Vec<Complex<F>>, SearchError>
+where
+    F: FloatType,
+{
+    let n = a.len();
+    let modulus = |z: Complex<F>| (z.re * z.re + z.im * z.im).sqrt();
+
+    // Cauchy bound: all roots lie within the circle of radius equal to the positive root of
+    // x^n - |a[0]|*x^(n-1) - ... - |a[n-1]|; Newton's method from 1 + max(|a[i]|) approaches it from above
+    let mut bound = a.iter().fold(F::zero(), |max, c| if c.abs() > max { c.abs() } else { max }) + F::one();
+    for _ in 0..1000 {
+        let (mut p, mut d) = (F::one(), F::zero());
+        for c in a.iter() {
+            d = d * bound + p;
+            p = p * bound - c.abs();
+        }
+        let step = p / d;
+        bound = bound - step;
+        if step * F::from(1000i16) < bound {
+            break;
+        }
+    }
+    // Initial approximations on circles of the estimated root magnitudes, rotated off the real axis;
+    // a spiral from the bound to its half replaces estimates out of the bound
+    let angle_step = F::from_f64(2f64 * std::f64::consts::PI) / F::from(n as i16);
+    let offset = F::from_f64(0.4f64);
+    let magnitudes = graeffe_root_magnitudes(a, 3);
+    let mut roots: Vec<Complex<F>> = (0..n)
+        .map(|k| {
+            let m = magnitudes[k];
+            let k = F::from(k as i16);
+            let radius = if m > F::zero() && m < bound {
+                m
+            } else {
+                bound * (F::one() - k / F::from(2 * n as i16))
+            };
+            let angle = k * angle_step + offset;
+            Complex::new(radius * angle.cos(), radius * angle.sin())
+        })
+        .collect();
+    let mut converged = vec![false; n];
+
+    let mut iter = 0;
+    loop {
+        for k in 0..n {
+            if converged[k] {
+                continue;
+            }
+            let (p, d) = evaluate(a, roots[k]);
+            if convergency.is_root_found(modulus(p)) {
+                converged[k] = true;
+                continue;
+            }
+            let w = complex_div(p, d);
+            let mut s = Complex::new(F::zero(), F::zero());
+            for (j, root) in roots.iter().enumerate() {
+                if j != k {
+                    let r = complex_div(
+                        Complex::new(F::one(), F::zero()),
+                        Complex::new(roots[k].re - root.re, roots[k].im - root.im),
+                    );
+                    s = Complex::new(s.re + r.re, s.im + r.im);
+                }
+            }
+            let ws = complex_mul(w, s);
+            let correction = complex_div(w, Complex::new(F::one() - ws.re, -ws.im));
+            roots[k] = Complex::new(roots[k].re - correction.re, roots[k].im - correction.im);
+            converged[k] = convergency.is_converged(F::zero(), modulus(correction));
+        }
+
+        if converged.iter().all(|c| *c) {
+            return Ok(roots);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency_after(iter));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    /// Coefficients uniformly distributed in [-1, 1) by a linear congruential generator
+    fn random_polynom(n: usize, seed: u64) -> Vec<f64> {
+        let mut state = seed;
+        (0..n)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 11) as f64 / (1u64 << 52) as f64 - 1f64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_find_roots_aberth() {
+        // (x - 1)(x - i)(x + i)
+        let polynom = [-1f64, 1f64, -1f64];
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        let mut roots = find_roots_aberth(&polynom, &mut conv).unwrap();
+        assert_eq!(4, conv.get_iter_count());
+        roots.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+        assert_float_eq!(1e-15f64, roots[0].re, 0f64);
+        assert_float_eq!(1e-15f64, roots[0].im, -1f64);
+        assert_float_eq!(1e-15f64, roots[1].re, 1f64);
+        assert_float_eq!(1e-15f64, roots[1].im, 0f64);
+        assert_float_eq!(1e-15f64, roots[2].re, 0f64);
+        assert_float_eq!(1e-15f64, roots[2].im, 1f64);
+
+        // x^6 - 1
+        let polynom = [0f64, 0f64, 0f64, 0f64, 0f64, -1f64];
+        for root in find_roots_aberth(&polynom, &mut 1e-15f64).unwrap() {
+            let angle = root.im.atan2(root.re) * 3f64 / std::f64::consts::PI;
+            assert_float_eq!(1e-14f64, root.re * root.re + root.im * root.im, 1f64);
+            assert_float_eq!(1e-13f64, angle, angle.round());
+        }
+    }
+
+    #[test]
+    fn test_find_roots_aberth_spread() {
+        // (x - 1000)(x + 50)(x - 3)(x - 0.2)(x + 0.01)(x - 0.0001): estimates of the root magnitudes
+        // put the initial approximations close to the roots
+        let expected = [-50f64, -0.01f64, 0.0001f64, 0.2f64, 3f64, 1000f64];
+        let mut polynom = vec![1f64];
+        for root in expected.iter() {
+            polynom.push(0f64);
+            for i in (1..polynom.len()).rev() {
+                polynom[i] -= root * polynom[i - 1];
+            }
+        }
+        polynom.remove(0);
+        let mut conv = debug_convergency::DebugConvergency::new(1e-9f64, 30);
+
+        conv.reset();
+        let mut roots: Vec<f64> = find_roots_aberth(&polynom, &mut conv).unwrap().iter().map(|z| z.re).collect();
+        assert_eq!(4, conv.get_iter_count());
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (root, expected) in roots.iter().zip(expected.iter()) {
+            assert_float_eq!(1e-9f64, root / expected, 1f64);
+        }
+    }
+
+    #[test]
+    fn test_find_roots_aberth_vs_eigen() {
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+        for (n, iterations) in [(10, 4), (15, 8), (20, 6), (25, 9), (30, 9)].iter() {
+            let polynom = random_polynom(*n, *n as u64);
+
+            conv.reset();
+            let roots = find_roots_aberth(&polynom, &mut conv).unwrap();
+            assert_eq!(*iterations, conv.get_iter_count());
+            assert_eq!(*n, roots.len());
+
+            let mut real: Vec<f64> = roots.iter().filter(|z| z.im.abs() < 1e-10f64).map(|z| z.re).collect();
+            let mut eigen: Vec<f64> = eigen::find_roots_eigen(&polynom).collect();
+            real.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            eigen.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_float_array_eq!(1e-13f64, real, eigen);
+        }
+    }
+}
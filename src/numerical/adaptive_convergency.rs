@@ -0,0 +1,260 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::Convergency;
+use super::SearchError;
+use super::SolverStats;
+
+/// Convergency rules which allow to retry the search with more iterations.
+/// The absolute precision is the same for x and y axes, no relative precision.
+/// Used as a plain convergency, it allows `initial_max_iter` iterations.
+/// `retry_with_doubling` reruns the search with 2, 4, 8... times more iterations
+/// until it succeeds or `max_total_iter` iterations are spent; `retry_with_doubling_using` does the same
+/// with any other convergency deciding on the precision.
+#[derive(Debug, Clone, Copy)]
+pub struct AdaptiveConvergency<F: FloatType> {
+    /// Precision for both X and Y axes
+    pub eps: F,
+    /// Maximum number of iterations of the first attempt
+    pub initial_max_iter: usize,
+    /// Maximum number of iterations of all attempts together
+    pub max_total_iter: usize,
+}
+
+impl<F: FloatType> Convergency<F> for AdaptiveConvergency<F> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        y.abs() < self.eps.abs()
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        (x1 - x2).abs() < self.eps.abs()
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        iter >= self.initial_max_iter
+    }
+}
+
+/// Convergency with its own iteration limit, which remembers the last iteration checked against it
+struct Limited<'a, C: ?Sized> {
+    convergency: &'a mut C,
+    max_iter: usize,
+    iterations: usize,
+}
+
+impl<'a, F: FloatType, C: Convergency<F> + ?Sized> Convergency<F> for Limited<'a, C> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        self.convergency.is_root_found(y)
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        self.convergency.is_converged(x1, x2)
+    }
+    fn is_converged_at(&mut self, x1: F, x2: F, y: F) -> bool {
+        self.convergency.is_converged_at(x1, x2, y)
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        self.iterations = iter;
+        iter >= self.max_iter
+    }
+}
+
+impl<F: FloatType> AdaptiveConvergency<F> {
+    /// Run the search, and rerun it with the doubled iteration limit each time it fails with NoConvergency.
+    ///
+    /// The search gets the function and the convergency to pass to any find_root_* function.
+    /// The first attempt is limited by `initial_max_iter` iterations; the limit of the last attempt is cut down
+    /// so that no more than `max_total_iter` iterations are spent in total.
+    /// The result of the first successful attempt, or the error of the last one, comes with `SolverStats`
+    /// counting the iterations and the evaluations of the function over all attempts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use roots::find_root_newton_raphson;
+    /// use roots::AdaptiveConvergency;
+    ///
+    /// let f = |x: f64| x.powi(10) - 1f64;
+    /// let d = |x: f64| 10f64 * x.powi(9);
+    /// let mut convergency = AdaptiveConvergency { eps: 1e-12f64, initial_max_iter: 10, max_total_iter: 100 };
+    ///
+    /// let (root, stats) = convergency.retry_with_doubling(&f, |f, convergency| {
+    ///     find_root_newton_raphson(10f64, f, d, convergency)
+    /// });
+    /// // Returns approximately Ok(1) after the attempts with 10, 20 and 40 iterations
+    /// ```
+    pub fn retry_with_doubling<T, Func, Search>(&mut self, f: Func, search: Search) -> (Result<T, SearchError>, SolverStats<F>)
+    where
+        Func: FnMut(F) -> F,
+        Search: FnMut(&mut dyn FnMut(F) -> F, &mut dyn Convergency<F>) -> Result<T, SearchError>,
+    {
+        let mut eps = self.eps;
+        self.retry_with_doubling_using(&mut eps, f, search)
+    }
+
+    /// Run the search with the given convergency, and rerun it with the doubled iteration limit
+    /// each time it fails with NoConvergency.
+    ///
+    /// Same as `retry_with_doubling`, but the precision is decided by `convergency` instead of `eps`.
+    /// The iteration limits are the ones of `self`; the iteration limit of `convergency` is not used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use roots::find_root_newton_raphson;
+    /// use roots::AdaptiveConvergency;
+    /// use roots::RelativeConvergency;
+    ///
+    /// let f = |x: f64| x.powi(10) - 1f64;
+    /// let d = |x: f64| 10f64 * x.powi(9);
+    /// let mut convergency = AdaptiveConvergency { eps: 1e-12f64, initial_max_iter: 10, max_total_iter: 100 };
+    /// let mut relative = RelativeConvergency { rel_eps: 1e-12f64, abs_floor: 1e-12f64, max_iter: 1 };
+    ///
+    /// let (root, stats) = convergency.retry_with_doubling_using(&mut relative, &f, |f, convergency| {
+    ///     find_root_newton_raphson(10f64, f, d, convergency)
+    /// });
+    /// // Returns approximately Ok(1) after the attempts with 10, 20 and 40 iterations,
+    /// // as max_iter of the relative convergency is not used
+    /// ```
+    pub fn retry_with_doubling_using<T, C, Func, Search>(
+        &self,
+        convergency: &mut C,
+        mut f: Func,
+        mut search: Search,
+    ) -> (Result<T, SearchError>, SolverStats<F>)
+    where
+        C: Convergency<F> + ?Sized,
+        Func: FnMut(F) -> F,
+        Search: FnMut(&mut dyn FnMut(F) -> F, &mut dyn Convergency<F>) -> Result<T, SearchError>,
+    {
+        let mut stats = SolverStats {
+            iterations: 0,
+            fn_evaluations: 0,
+            derivative_evaluations: 0,
+            final_bracket_width: None,
+        };
+        let mut max_iter = if self.initial_max_iter < self.max_total_iter {
+            self.initial_max_iter
+        } else {
+            self.max_total_iter
+        };
+        loop {
+            let mut limited = Limited {
+                convergency: &mut *convergency,
+                max_iter,
+                iterations: 0,
+            };
+            let result = {
+                let mut counted = |x: F| {
+                    stats.fn_evaluations += 1;
+                    f(x)
+                };
+                search(&mut counted, &mut limited)
+            };
+            stats.iterations += limited.iterations;
+
+            let left = self.max_total_iter - stats.iterations.min(self.max_total_iter);
+            match result {
+                Err(SearchError::NoConvergency { .. }) if left > 0 && max_iter > 0 => {
+                    max_iter = if 2 * max_iter < left { 2 * max_iter } else { left };
+                }
+                result => return (result, stats),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_retry_with_doubling() {
+        // The Newton-Raphson method crawls towards the root of x^10 - 1 from 10, reducing x by about 10% per step
+        let f = |x: f64| x.powi(10) - 1f64;
+        let d = |x: f64| 10f64 * x.powi(9);
+        let search = |f: &mut dyn FnMut(f64) -> f64, convergency: &mut dyn Convergency<f64>| {
+            newton_raphson::find_root_newton_raphson(10f64, f, d, convergency)
+        };
+
+        let mut convergency = AdaptiveConvergency {
+            eps: 1e-12f64,
+            initial_max_iter: 10,
+            max_total_iter: 100,
+        };
+        assert!(matches!(
+            newton_raphson::find_root_newton_raphson(10f64, &f, &d, &mut convergency),
+            Err(SearchError::NoConvergency { .. })
+        ));
+        convergency.initial_max_iter = 40;
+        assert_float_eq!(
+            1e-12f64,
+            newton_raphson::find_root_newton_raphson(10f64, &f, &d, &mut convergency)
+                .ok()
+                .unwrap(),
+            1f64
+        );
+
+        // Attempts with 10 and 20 iterations fail, the one with 40 iterations succeeds
+        convergency.initial_max_iter = 10;
+        let (root, stats) = convergency.retry_with_doubling(&f, search);
+        assert_float_eq!(1e-12f64, root.ok().unwrap(), 1f64);
+        let (_, last) = newton_raphson::find_root_newton_raphson_with_stats(10f64, &f, &d, &mut 1e-12f64);
+        assert_eq!(stats.iterations, 10 + 20 + last.iterations);
+        assert!(stats.fn_evaluations > stats.iterations);
+
+        // The last attempt is cut down to the total limit
+        convergency.max_total_iter = 25;
+        let (root, stats) = convergency.retry_with_doubling(&f, search);
+        assert!(matches!(root, Err(SearchError::NoConvergency { .. })));
+        assert_eq!(stats.iterations, 25);
+
+        // Any convergency decides on the precision, the iteration limits are the ones of the adaptive convergency
+        convergency.max_total_iter = 100;
+        let mut recording = recording_convergency::RecordingConvergency::new(simple_convergency::SimpleConvergency {
+            eps: 1e-12f64,
+            max_iter: 1,
+        });
+        let (root, stats) = convergency.retry_with_doubling_using(&mut recording, &f, search);
+        assert_eq!((root, stats), convergency.retry_with_doubling(&f, search));
+        assert!(recording.history().len() > stats.iterations);
+    }
+
+    #[test]
+    fn test_retry_with_doubling_other_errors() {
+        let f = |x: f64| x * x - 1f64;
+        let mut convergency = AdaptiveConvergency {
+            eps: 1e-15f64,
+            initial_max_iter: 1,
+            max_total_iter: 100,
+        };
+        let mut attempts = 0;
+        let (root, stats) = convergency.retry_with_doubling(&f, |f, convergency| {
+            attempts += 1;
+            brent::find_root_brent(2f64, 10f64, f, convergency)
+        });
+        assert!(matches!(root, Err(SearchError::NoBracketing { .. })));
+        assert_eq!(attempts, 1);
+        assert_eq!(stats.fn_evaluations, 2);
+    }
+}
@@ -0,0 +1,280 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::find_roots_linear;
+use super::super::find_roots_quadratic;
+use super::super::FloatType;
+use super::Convergency;
+use super::SearchError;
+
+/// Quadratic factors (u, v) of x^2 + u*x + v and the root of the remaining linear factor
+type Factors<F> = (Vec<(F, F)>, Option<F>);
+
+/// Search for a quadratic factor x^2 - r*x - s of the polynomial p (highest power first)
+/// starting from (r, s). Returns (r, s) and the quotient (highest power first).
+fn bairstow_iterate<F>(p: &[F], r: F, s: F, convergency: &mut (impl Convergency<F> + ?Sized)) -> Result<(F, F, Vec<F>), SearchError>
+where
+    F: FloatType,
+{
+    let n = p.len() - 1;
+    let (mut r, mut s) = (r, s);
+    let mut b = vec![F::zero(); n + 1];
+    let mut c = vec![F::zero(); n];
+
+    let mut iter = 0;
+    loop {
+        // Synthetic division by x^2 - r*x - s, then once more for the partial derivatives
+        b[0] = p[0];
+        b[1] = p[1] + r * b[0];
+        for k in 2..=n {
+            b[k] = p[k] + r * b[k - 1] + s * b[k - 2];
+        }
+        c[0] = b[0];
+        c[1] = b[1] + r * c[0];
+        for k in 2..n {
+            c[k] = b[k] + r * c[k - 1] + s * c[k - 2];
+        }
+
+        if convergency.is_root_found(b[n - 1]) && convergency.is_root_found(b[n]) {
+            break;
+        }
+
+        let det = c[n - 2] * c[n - 2] - c[n - 3] * c[n - 1];
+        if det == F::zero() {
+            return Err(SearchError::ZeroDerivative {
+                iterations: iter,
+                last: None,
+                bracket: None,
+            });
+        }
+        let dr = (c[n - 3] * b[n] - c[n - 2] * b[n - 1]) / det;
+        let ds = (c[n - 1] * b[n - 1] - c[n - 2] * b[n]) / det;
+
+        let (r1, s1) = (r + dr, s + ds);
+        let converged = convergency.is_converged(r, r1) && convergency.is_converged(s, s1);
+        r = r1;
+        s = s1;
+        if converged {
+            break;
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency_after(iter));
+        }
+    }
+
+    // Quotient of the division by the final factor
+    b[0] = p[0];
+    b[1] = p[1] + r * b[0];
+    for k in 2..n - 1 {
+        b[k] = p[k] + r * b[k - 1] + s * b[k - 2];
+    }
+    b.truncate(n - 1);
+    Ok((r, s, b))
+}
+
+/// Find quadratic factors of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// using the Bairstow's method.
+///
+/// Each factor x^2 + u*x + v is refined by the Newton's method on (u, v), evaluating the remainder
+/// of the synthetic division, then the polynomial is deflated by it.
+/// Returns the (u, v) pairs of the quadratic factors and the root of the remaining linear factor
+/// if the degree of the polynomial is odd.
+///
+/// Pro
+///
+/// + Complex pairs of roots are found in real arithmetic
+/// + Quadratic convergency
+/// + No need for starting points
+///
+/// Contra
+///
+/// - Polynomials only
+/// - Deflation accumulates rounding errors in the later factors
+/// - Slow convergency to multiple factors
+///
+/// # Failures
+/// ## ZeroDerivative
+/// The Jacobian of the remainder is singular for all tried starting points. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a factor within the given number of iterations for all tried starting points.
+/// # Examples
+///
+/// ```
+/// use roots::find_quadratic_factors_bairstow;
+///
+/// // x^3 - x^2 + x - 1 = (x^2 + 1)(x - 1)
+/// let polynom = [-1f64, 1f64, -1f64];
+///
+/// let factors = find_quadratic_factors_bairstow(&polynom, &mut 1e-15f64);
+/// // Returns approximately Ok((vec![(0, 1)], Some(1)));
+/// ```
+pub fn find_quadratic_factors_bairstow<F>(
+    a: &[F],
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<Factors<F>, SearchError>
+where
+    F: FloatType,
+{
+    let mut factors = Vec::with_capacity(a.len() / 2);
+    let mut p = Vec::with_capacity(a.len() + 1);
+    p.push(F::one());
+    p.extend_from_slice(a);
+
+    while p.len() > 3 {
+        let n = p.len() - 1;
+        // Start on the circle with the geometric mean of the root magnitudes
+        let last = p[n].abs();
+        let radius = if last > F::zero() {
+            last.powf(F::one() / F::from(n as i16))
+        } else {
+            F::one()
+        };
+        let mut result = Err(SearchError::no_convergency_after(0));
+        for attempt in 0..10i16 {
+            let angle = F::from(2 * attempt + 1) / F::from(2i16);
+            let r = F::from(2i16) * radius * angle.cos();
+            let s = -radius * radius;
+            result = bairstow_iterate(&p, r, s, convergency);
+            if result.is_ok() {
+                break;
+            }
+        }
+        let (r, s, quotient) = result?;
+        factors.push((-r, -s));
+        p = quotient;
+    }
+
+    let linear = match p.len() {
+        3 => {
+            factors.push((p[1], p[2]));
+            None
+        }
+        2 => Some(-p[1]),
+        _ => None,
+    };
+    Ok((factors, linear))
+}
+
+/// Find real roots of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// solving the quadratic factors found by find_quadratic_factors_bairstow.
+///
+/// # Failures
+/// See find_quadratic_factors_bairstow.
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_bairstow;
+///
+/// // x^4 - 1 = (x^2 + 1)(x - 1)(x + 1)
+/// let polynom = [0f64, 0f64, 0f64, -1f64];
+///
+/// let roots = find_roots_bairstow(&polynom, &mut 1e-15f64);
+/// // Returns approximately Ok(vec![-1, 1]);
+/// ```
+pub fn find_roots_bairstow<F>(a: &[F], convergency: &mut (impl Convergency<F> + ?Sized)) -> Result<Vec<F>, SearchError>
+where
+    F: FloatType,
+{
+    let (factors, linear) = find_quadratic_factors_bairstow(a, convergency)?;
+    let mut roots = Vec::with_capacity(a.len());
+    for (u, v) in factors {
+        roots.extend_from_slice(find_roots_quadratic(F::one(), u, v).as_ref());
+    }
+    if let Some(root) = linear {
+        roots.extend_from_slice(find_roots_linear(F::one(), -root).as_ref());
+    }
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Normalized polynomial (without the leading 1) of the product of quadratic factors and linear factor x - root
+    fn product(factors: &[(f64, f64)], linear: Option<f64>) -> Vec<f64> {
+        let mut p = vec![1f64];
+        let mut multiply = |f: &[f64]| {
+            let mut q = vec![0f64; p.len() + f.len() - 1];
+            for (i, x) in p.iter().enumerate() {
+                for (j, y) in f.iter().enumerate() {
+                    q[i + j] += x * y;
+                }
+            }
+            p = q;
+        };
+        for &(u, v) in factors {
+            multiply(&[1f64, u, v]);
+        }
+        if let Some(root) = linear {
+            multiply(&[1f64, -root]);
+        }
+        p.remove(0);
+        p
+    }
+
+    #[test]
+    fn test_find_quadratic_factors_bairstow() {
+        // (x^2 + 1)(x^2 - 1) and (x^2 + x + 1)(x - 2)
+        let (factors, linear) = find_quadratic_factors_bairstow(&[0f64, 0f64, 0f64, -1f64], &mut 1e-15f64).unwrap();
+        assert_eq!(2, factors.len());
+        assert_eq!(None, linear);
+        assert_float_array_eq!(1e-14f64, product(&factors, linear), [0f64, 0f64, 0f64, -1f64]);
+
+        let (factors, linear) = find_quadratic_factors_bairstow(&[-1f64, -1f64, -2f64], &mut 1e-15f64).unwrap();
+        assert_eq!(1, factors.len());
+        assert_float_eq!(1e-14f64, linear.unwrap(), 2f64);
+        assert_float_array_eq!(1e-14f64, product(&factors, linear), [-1f64, -1f64, -2f64]);
+    }
+
+    #[test]
+    fn test_find_quadratic_factors_bairstow_degree_8() {
+        // (x + 2)(x - 0.5)(x - 1)(x - 3)(x^2 + 1)(x^2 - 2x + 5)
+        let polynom = product(&[(1.5f64, -1f64), (-4f64, 3f64), (0f64, 1f64), (-2f64, 5f64)], None);
+        let (factors, linear) = find_quadratic_factors_bairstow(&polynom, &mut 1e-15f64).unwrap();
+        assert_eq!(4, factors.len());
+        assert_eq!(None, linear);
+        assert_float_array_eq!(1e-13f64, product(&factors, linear), polynom);
+
+        let roots = find_roots_bairstow(&polynom, &mut 1e-15f64).unwrap();
+        assert_float_array_eq!(1e-13f64, roots, [-2f64, 0.5f64, 1f64, 3f64]);
+    }
+
+    #[test]
+    fn test_find_quadratic_factors_bairstow_degree_7() {
+        // (x^2 + x + 1)(x^2 - x + 3.25)(x^2 - 2)(x + 4)
+        let polynom = product(&[(1f64, 1f64), (-1f64, 3.25f64), (0f64, -2f64)], Some(-4f64));
+        let (factors, linear) = find_quadratic_factors_bairstow(&polynom, &mut 1e-15f64).unwrap();
+        assert_eq!(3, factors.len());
+        assert!(linear.is_some());
+        assert_float_array_eq!(1e-13f64, product(&factors, linear), polynom);
+
+        let roots = find_roots_bairstow(&polynom, &mut 1e-15f64).unwrap();
+        assert_float_array_eq!(1e-13f64, roots, [-4f64, -2f64.sqrt(), 2f64.sqrt()]);
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::find_root_brent;
+use super::super::FloatType;
+use super::Convergency;
+
+/// Convert coefficients of a polynomial c[0]*x^n + c[1]*x^(n-1) + ... + c[n] into its Bernstein basis on [0, 1].
+///
+/// The result b satisfies b[0]*B0(x) + b[1]*B1(x) + ... + b[n]*Bn(x) = c[0]*x^n + ... + c[n],
+/// where Bk(x) = C(n,k) * x^k * (1-x)^(n-k) are the Bernstein basis polynomials of degree n.
+/// b[0] and b[n] are the values of the polynomial at 0 and 1 respectively.
+///
+/// # Examples
+///
+/// ```
+/// use roots::power_to_bernstein;
+///
+/// let b = power_to_bernstein(&[1f64, -1f64, 0.1875f64]);
+/// // Returns vec![0.1875f64, -0.3125f64, 0.1875f64] as x^2 - x + 3/16 = 3/16*(1-x)^2 - 5/16*2x(1-x) + 3/16*x^2
+/// ```
+pub fn power_to_bernstein<F: FloatType>(c: &[F]) -> Vec<F> {
+    let n = match c.len() {
+        0 => return Vec::new(),
+        len => len - 1,
+    };
+    // b[k] = sum(C(k,i) / C(n,i) * a[i]) for i <= k, where a[i] is the coefficient of x^i
+    (0..=n)
+        .map(|k| (0..=k).fold(F::zero(), |acc, i| acc + binomial::<F>(k, i) / binomial::<F>(n, i) * c[n - i]))
+        .collect()
+}
+
+/// Convert Bernstein coefficients of a polynomial on [0, 1] into c[0]*x^n + c[1]*x^(n-1) + ... + c[n].
+///
+/// This is the inverse of power_to_bernstein. Note that the conversion is badly conditioned for high degrees:
+/// the power coefficients may be many orders of magnitude larger than the Bernstein ones.
+///
+/// # Examples
+///
+/// ```
+/// use roots::bernstein_to_power;
+///
+/// let c = bernstein_to_power(&[0.1875f64, -0.3125f64, 0.1875f64]);
+/// // Returns vec![1f64, -1f64, 0.1875f64]
+/// ```
+pub fn bernstein_to_power<F: FloatType>(b: &[F]) -> Vec<F> {
+    let n = match b.len() {
+        0 => return Vec::new(),
+        len => len - 1,
+    };
+    // a[i] = C(n,i) * sum((-1)^(i-k) * C(i,k) * b[k]) for k <= i, where a[i] is the coefficient of x^i
+    (0..=n)
+        .rev()
+        .map(|i| {
+            binomial::<F>(n, i)
+                * (0..=i).fold(F::zero(), |acc, k| {
+                    let term = binomial::<F>(i, k) * b[k];
+                    if (i - k) % 2 == 0 {
+                        acc + term
+                    } else {
+                        acc - term
+                    }
+                })
+        })
+        .collect()
+}
+
+/// Find all roots in [0, 1] of a polynomial given by its Bernstein coefficients b[0]*B0(x) + ... + b[n]*Bn(x).
+///
+/// The polynomial lies within the convex hull of its control points (k/n, b[k]), so the parts of the interval
+/// where the hull does not cross zero are clipped away, and the rest is subdivided by de Casteljau algorithm
+/// until the control polygon changes its sign exactly once. Such a sub-interval holds a single root,
+/// which is polished by find_root_brent. Working in the Bernstein basis avoids the conversion
+/// to the power basis, which is ill-conditioned for polynomials of high degree with many roots in [0, 1].
+///
+/// Roots closer to each other than about sqrt(epsilon), including the multiple ones, are returned once.
+/// When all coefficients are zero, every x is a root, and the single root 0 is returned like find_roots_linear does.
+/// Returned roots are arranged in the increasing order.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_bernstein;
+///
+/// let roots = find_roots_bernstein(&[0.1875f64, -0.3125f64, 0.1875f64]);
+/// // Returns approximately vec![0.25f64, 0.75f64] as the polynomial is x^2 - x + 3/16
+/// ```
+pub fn find_roots_bernstein<F: FloatType>(coeffs: &[F]) -> Vec<F> {
+    // Roots do not depend on the scale, and the normalized values are easier to compare with epsilon
+    let scale = coeffs
+        .iter()
+        .fold(F::zero(), |max, c| if c.abs() > max { c.abs() } else { max });
+    if coeffs.is_empty() || scale == F::zero() {
+        return if coeffs.is_empty() { Vec::new() } else { vec![F::zero()] };
+    }
+    let normalized: Vec<F> = coeffs.iter().map(|&c| c / scale).collect();
+
+    let mut roots = Vec::new();
+    isolate(normalized, F::zero(), F::one(), &mut roots);
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots.dedup();
+    roots
+}
+
+/// Find roots of the polynomial with Bernstein coefficients b on [lo, hi]
+fn isolate<F: FloatType>(mut b: Vec<F>, lo: F, hi: F, roots: &mut Vec<F>) {
+    // Exact zeros at the ends are roots, divide them out to keep counting the sign changes inside
+    while b.len() > 1 && b[0] == F::zero() {
+        roots.push(lo);
+        let n = F::from((b.len() - 1) as i16);
+        b = (1..b.len()).map(|j| b[j] * n / F::from(j as i16)).collect();
+    }
+    while b.len() > 1 && b[b.len() - 1] == F::zero() {
+        roots.push(hi);
+        let n = b.len() - 1;
+        b = (0..n).map(|j| b[j] * F::from(n as i16) / F::from((n - j) as i16)).collect();
+    }
+
+    match sign_changes(&b) {
+        // The polynomial has no roots inside, as the number of roots does not exceed the number of sign changes
+        0 => {}
+        // Exactly one root inside, and the ends have the different signs
+        1 => {
+            let mut convergency = Separated;
+            let t =
+                find_root_brent(F::zero(), F::one(), |t| evaluate(&b, t), &mut convergency).unwrap_or_else(|_| F::from_f64(0.5f64));
+            roots.push(lo + t * (hi - lo));
+        }
+        _ => {
+            let (t_min, t_max) = match clip(&b) {
+                Some(clipped) => clipped,
+                None => return,
+            };
+            if hi - lo < F::epsilon().sqrt() {
+                // A multiple root or a cluster of roots too close to be separated
+                roots.push(lo + (t_min + t_max) / F::from(2i16) * (hi - lo));
+            } else if t_max - t_min < F::from_f64(0.5f64) {
+                let (left, _) = split(&b, t_max);
+                let inner = if t_max > F::zero() {
+                    split(&left, t_min / t_max).1
+                } else {
+                    left
+                };
+                let width = hi - lo;
+                isolate(inner, lo + t_min * width, lo + t_max * width, roots);
+            } else {
+                let half = F::from_f64(0.5f64);
+                let (left, right) = split(&b, half);
+                let mid = lo + half * (hi - lo);
+                isolate(left, lo, mid, roots);
+                isolate(right, mid, hi, roots);
+            }
+        }
+    }
+}
+
+/// Convergency which accepts only exact zeros, as the values between close roots may be much smaller than epsilon
+struct Separated;
+
+impl<F: FloatType> Convergency<F> for Separated {
+    fn is_root_found(&mut self, y: F) -> bool {
+        y == F::zero()
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        (x1 - x2).abs() <= F::epsilon()
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        iter >= 100
+    }
+}
+
+/// Number of sign changes in the sequence, zeros are skipped
+fn sign_changes<F: FloatType>(b: &[F]) -> usize {
+    let mut changes = 0;
+    let mut last = F::zero();
+    for &c in b.iter().filter(|&&c| c != F::zero()) {
+        if c * last < F::zero() {
+            changes += 1;
+        }
+        last = c;
+    }
+    changes
+}
+
+/// Range [t_min, t_max] where the convex hull of the control points crosses zero
+fn clip<F: FloatType>(b: &[F]) -> Option<(F, F)> {
+    let n = F::from((b.len() - 1) as i16);
+    let mut range: Option<(F, F)> = None;
+    let mut include = |t: F| {
+        range = Some(match range {
+            Some((t_min, t_max)) => (if t < t_min { t } else { t_min }, if t > t_max { t } else { t_max }),
+            None => (t, t),
+        })
+    };
+    for i in 0..b.len() {
+        let ti = F::from(i as i16) / n;
+        if b[i] == F::zero() {
+            include(ti);
+        }
+        for j in i + 1..b.len() {
+            if b[i] * b[j] < F::zero() {
+                let tj = F::from(j as i16) / n;
+                include(ti - b[i] * (tj - ti) / (b[j] - b[i]));
+            }
+        }
+    }
+    range
+}
+
+/// Split the polynomial at t into the Bernstein coefficients on [0, t] and on [t, 1] by de Casteljau algorithm
+fn split<F: FloatType>(b: &[F], t: F) -> (Vec<F>, Vec<F>) {
+    let mut work = b.to_vec();
+    let mut left = Vec::with_capacity(b.len());
+    let mut right = Vec::with_capacity(b.len());
+    for level in 0..b.len() {
+        left.push(work[0]);
+        right.push(work[b.len() - 1 - level]);
+        for k in 0..b.len() - 1 - level {
+            work[k] = work[k] + t * (work[k + 1] - work[k]);
+        }
+    }
+    right.reverse();
+    (left, right)
+}
+
+/// Value of the polynomial with Bernstein coefficients b at t by de Casteljau algorithm
+fn evaluate<F: FloatType>(b: &[F], t: F) -> F {
+    let mut work = b.to_vec();
+    for level in 1..b.len() {
+        for k in 0..b.len() - level {
+            work[k] = work[k] + t * (work[k + 1] - work[k]);
+        }
+    }
+    work[0]
+}
+
+/// Binomial coefficient C(n,k) as a float
+fn binomial<F: FloatType>(n: usize, k: usize) -> F {
+    (0..k).fold(F::one(), |acc, i| acc * F::from((n - i) as i16) / F::from((i + 1) as i16))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Bernstein coefficients of (x - r[0])*(x - r[1])*... built without the power basis
+    fn bernstein_from_roots(roots: &[f64]) -> Vec<f64> {
+        roots.iter().fold(vec![1f64], |b, &r| {
+            // Multiply by x - r = -r*(1-x) + (1-r)*x, raising the degree by one
+            let n = b.len() as f64;
+            (0..=b.len())
+                .map(|k| {
+                    let down = if k < b.len() { -r * b[k] * (n - k as f64) / n } else { 0f64 };
+                    let up = if k > 0 { (1f64 - r) * b[k - 1] * k as f64 / n } else { 0f64 };
+                    down + up
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(power_to_bernstein::<f64>(&[]), vec![]);
+        assert_eq!(bernstein_to_power::<f64>(&[]), vec![]);
+        assert_eq!(
+            power_to_bernstein(&[1f64, -1f64, 0.1875f64]),
+            vec![0.1875f64, -0.3125f64, 0.1875f64]
+        );
+        assert_eq!(
+            bernstein_to_power(&[0.1875f64, -0.3125f64, 0.1875f64]),
+            vec![1f64, -1f64, 0.1875f64]
+        );
+
+        let c = [2f64, -3f64, 0.5f64, 7f64, -1f64];
+        for (expected, actual) in c.iter().zip(bernstein_to_power(&power_to_bernstein(&c)).iter()) {
+            assert_float_eq!(1e-13f64, *actual, *expected);
+        }
+        // The first and the last Bernstein coefficients are the values at 0 and 1
+        let b = power_to_bernstein(&c);
+        assert_float_eq!(1e-15f64, b[0], -1f64);
+        assert_float_eq!(1e-15f64, b[4], 5.5f64);
+        assert_eq!(
+            bernstein_from_roots(&[0.25f64, 0.75f64]),
+            vec![0.1875f64, -0.3125f64, 0.1875f64]
+        );
+    }
+
+    #[test]
+    fn test_find_roots_bernstein() {
+        assert_eq!(find_roots_bernstein::<f64>(&[]), vec![]);
+        assert_eq!(find_roots_bernstein(&[0f64, 0f64]), vec![0f64]);
+        assert_eq!(find_roots_bernstein(&[1f64]), vec![]);
+        assert_eq!(find_roots_bernstein(&[1f64, 2f64, 1f64]), vec![]);
+        // The control polygon changes its sign, but the polynomial does not
+        assert_eq!(find_roots_bernstein(&[1f64, -0.1f64, 1f64]), vec![]);
+        // Roots at the ends
+        assert_eq!(find_roots_bernstein(&[0f64, -0.5f64, 0f64]), vec![0f64, 1f64]);
+        assert_eq!(find_roots_bernstein(&[-1f64, 0.5f64, 0f64]), vec![0.5f64, 1f64]);
+
+        let roots = find_roots_bernstein(&[0.1875f64, -0.3125f64, 0.1875f64]);
+        assert_eq!(roots.len(), 2);
+        assert_float_eq!(1e-15f64, roots[0], 0.25f64);
+        assert_float_eq!(1e-15f64, roots[1], 0.75f64);
+
+        // Roots outside of [0, 1] are not returned
+        let roots = find_roots_bernstein(&bernstein_from_roots(&[-0.5f64, 0.3f64, 1.5f64]));
+        assert_eq!(roots.len(), 1);
+        assert_float_eq!(1e-15f64, roots[0], 0.3f64);
+
+        // A double root is returned once
+        let roots = find_roots_bernstein(&bernstein_from_roots(&[0.3f64, 0.3f64, 0.8f64]));
+        assert_eq!(roots.len(), 2);
+        assert_float_eq!(1e-7f64, roots[0], 0.3f64);
+        assert_float_eq!(1e-15f64, roots[1], 0.8f64);
+    }
+
+    #[test]
+    fn test_find_roots_bernstein_clustered() {
+        let expected = [0.47f64, 0.49f64, 0.5f64, 0.505f64, 0.52f64];
+        let roots = find_roots_bernstein(&bernstein_from_roots(&expected));
+        assert_eq!(roots.len(), expected.len());
+        for (root, x) in roots.iter().zip(expected.iter()) {
+            assert_float_eq!(1e-10f64, *root, *x);
+        }
+    }
+
+    #[test]
+    fn test_find_roots_bernstein_ill_conditioned() {
+        // Wilkinson-like polynomial with 20 equidistant roots in [0, 1];
+        // find_roots_polynomial misses most of them after the conversion to the power basis
+        let expected: Vec<f64> = (1..=20).map(|k| f64::from(k) / 21f64).collect();
+        let b = bernstein_from_roots(&expected);
+        let roots = find_roots_bernstein(&b);
+        assert_eq!(roots.len(), expected.len());
+        for (root, x) in roots.iter().zip(expected.iter()) {
+            assert_float_eq!(1e-8f64, *root, *x);
+        }
+    }
+}
@@ -0,0 +1,702 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::check_bracket;
+use super::check_value;
+use super::Convergency;
+use super::InvalidInputReason;
+use super::SearchError;
+use super::SolverStats;
+use super::TrySearchError;
+use std::ops::RangeInclusive;
+
+/// Find a root of the function f(x) = 0 using the bisection method.
+///
+/// Pro
+///
+/// + Simple
+/// + Robust
+/// + Predictable: the interval is halved at every iteration
+/// + No need for derivative function
+///
+/// Contra
+///
+/// - Slow
+/// - Needs initial bracketing
+///
+/// The search stops when the interval cannot be halved any more. Use bisection_iterations_needed
+/// to set the iteration limit of the convergency.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::bisection_iterations_needed;
+/// use roots::find_root_bisection;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let max_iter = bisection_iterations_needed(10f64, 0f64, 1e-15f64);
+/// // Returns 54
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter };
+///
+/// let root1 = find_root_bisection(10f64, 0f64, &f, &mut convergency);
+/// // Returns approximately Ok(1);
+///
+/// let root2 = find_root_bisection(-10f64, 0f64, &f, &mut 1e-15f64);
+/// // Returns Err(SearchError::NoConvergency { .. }) as the default convergency allows 30 iterations only
+/// ```
+pub fn find_root_bisection<F, Func>(
+    a: F,
+    b: F,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    check_bracket(a, b)?;
+    let (mut x1, mut x2) = if a > b { (b, a) } else { (a, b) };
+    let mut y1 = f(x1);
+    check_value(y1)?;
+    if convergency.is_root_found(y1) {
+        return Ok(x1);
+    }
+    let mut y2 = f(x2);
+    check_value(y2)?;
+    if convergency.is_root_found(y2) {
+        return Ok(x2);
+    }
+    if y1 * y2 > F::zero() {
+        return Err(SearchError::no_bracketing(x1, x2));
+    }
+
+    let mut iter = 0;
+    loop {
+        let x = x1 + (x2 - x1) / _2;
+        if convergency.is_converged(x1, x2) || x == x1 || x == x2 {
+            return Ok(x);
+        }
+        if convergency.is_iteration_limit_reached(iter) {
+            let (x, y) = if y1.abs() < y2.abs() { (x1, y1) } else { (x2, y2) };
+            return Err(SearchError::no_convergency(iter, x, y).with_bracket(x1, x2));
+        }
+        let y = f(x);
+        if convergency.is_root_found(y) {
+            return Ok(x);
+        }
+
+        if y * y1 > F::zero() {
+            x1 = x;
+            y1 = y;
+        } else {
+            x2 = x;
+            y2 = y;
+        }
+        iter += 1;
+    }
+}
+
+/// Find a positive root of the function f(x) = 0 using the bisection in the logarithmic scale.
+///
+/// The same as `find_root_bisection`, but the interval is split at the geometric mean of its ends,
+/// so every iteration halves the ratio of the ends instead of their difference. A root anywhere
+/// between 1e-9 and 1e9 is located in the number of iterations needed by the linear bisection
+/// to find its digits only, without spending about 30 iterations on its exponent.
+/// Both ends must be positive; use `find_root_log_bisection_signed` for negative intervals.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends are not positive or not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_log_bisection;
+///
+/// let f = |x: f64| { 1f64 - 1e-7f64 / x };
+///
+/// let root = find_root_log_bisection(1e-12f64, 1e3f64, &f, &mut 1e-12f64);
+/// // Returns approximately Ok(1e-7);
+/// ```
+pub fn find_root_log_bisection<F, Func>(
+    a: F,
+    b: F,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    check_bracket(a, b)?;
+    let (mut x1, mut x2) = if a > b { (b, a) } else { (a, b) };
+    if x1 <= F::zero() {
+        return Err(SearchError::invalid_input(InvalidInputReason::Unsuitable));
+    }
+    let mut y1 = f(x1);
+    check_value(y1)?;
+    if convergency.is_root_found(y1) {
+        return Ok(x1);
+    }
+    let mut y2 = f(x2);
+    check_value(y2)?;
+    if convergency.is_root_found(y2) {
+        return Ok(x2);
+    }
+    if y1 * y2 > F::zero() {
+        return Err(SearchError::no_bracketing(x1, x2));
+    }
+
+    let mut iter = 0;
+    loop {
+        // The geometric mean; the product of the ends could overflow
+        let x = x1.sqrt() * x2.sqrt();
+        if convergency.is_converged(x1, x2) || x <= x1 || x >= x2 {
+            return Ok(x);
+        }
+        if convergency.is_iteration_limit_reached(iter) {
+            let (x, y) = if y1.abs() < y2.abs() { (x1, y1) } else { (x2, y2) };
+            return Err(SearchError::no_convergency(iter, x, y).with_bracket(x1, x2));
+        }
+        let y = f(x);
+        if convergency.is_root_found(y) {
+            return Ok(x);
+        }
+
+        if y * y1 > F::zero() {
+            x1 = x;
+            y1 = y;
+        } else {
+            x2 = x;
+            y2 = y;
+        }
+        iter += 1;
+    }
+}
+
+/// Find a root of the function f(x) = 0 using the bisection in the logarithmic scale on either half-axis.
+///
+/// The same as `find_root_log_bisection`, but the ends may also be both negative,
+/// in which case the root of f(-x) is searched in [-b, -a] and negated.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends have different signs, one of them is zero, or they are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_log_bisection_signed;
+///
+/// let f = |x: f64| { 1f64 + 1e-7f64 / x };
+///
+/// let root = find_root_log_bisection_signed(-1e3f64, -1e-12f64, &f, &mut 1e-12f64);
+/// // Returns approximately Ok(-1e-7);
+/// ```
+pub fn find_root_log_bisection_signed<F, Func>(
+    a: F,
+    b: F,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    if a < F::zero() && b < F::zero() {
+        find_root_log_bisection(-a, -b, |x| f(-x), convergency).map(|x| -x)
+    } else {
+        find_root_log_bisection(a, b, f, convergency)
+    }
+}
+
+/// Find a root of the function f(x) = 0 using the bisection method and count the work done.
+///
+/// The same as `find_root_bisection`, but the result comes with `SolverStats`: the last iteration,
+/// the numbers of evaluations of the function, and the width of the last
+/// bracketing interval if the root is found.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_bisection_with_stats;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let (root, stats) = find_root_bisection_with_stats(10f64, 0f64, &f, &mut SimpleConvergency { eps: 1e-15f64, max_iter: 60 });
+/// // Returns approximately Ok(1) and SolverStats { iterations: 52, fn_evaluations: 55, .. }
+/// ```
+pub fn find_root_bisection_with_stats<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> (Result<F, SearchError>, SolverStats<F>)
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (result, mut stats) = super::search_with_stats(f, convergency, |f, convergency| find_root_bisection(a, b, f, convergency));
+    // The bracket is halved at every iteration
+    let _2 = F::from(2i16);
+    let mut width = (b - a).abs();
+    for _ in 0..stats.iterations {
+        width = width / _2;
+    }
+    stats.final_bracket_width = result.ok().map(|_| width);
+    (result, stats)
+}
+
+/// Find a root of the function f(x) = 0 which can fail using the bisection method.
+///
+/// The same as `find_root_bisection`, but f returns Result. The first error of f stops the search
+/// and is returned as `TrySearchError::Function`; f is not called after it.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## Function
+/// The function has failed.
+/// ## Search
+/// The search has failed as `find_root_bisection` does.
+/// # Examples
+///
+/// ```
+/// use roots::try_find_root_bisection;
+///
+/// // ln is defined for positive x only
+/// let f = |x: f64| if x > 0f64 { Ok(x.ln() - 1f64) } else { Err("not positive") };
+///
+/// let root = try_find_root_bisection(1f64, 10f64, &f, &mut 1e-6f64);
+/// // Returns approximately Ok(2.718281828459045);
+///
+/// let error = try_find_root_bisection(0f64, 10f64, &f, &mut 1e-6f64);
+/// // Returns Err(TrySearchError::Function("not positive"));
+/// ```
+pub fn try_find_root_bisection<F, Func, E>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, TrySearchError<E>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> Result<F, E>,
+{
+    super::try_search(f, convergency, |f, convergency| find_root_bisection(a, b, f, convergency))
+}
+
+/// Find a root of the function f(x) = 0 within the given range using the bisection method.
+///
+/// The same as `find_root_bisection` with the ends of the range.
+///
+/// # Failures
+/// ## InvalidInput
+/// The range is empty or its ends are not finite.
+/// ## NoBracketing
+/// The ends of the range do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::bisection_iterations_needed;
+/// use roots::find_root_bisection_in;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let max_iter = bisection_iterations_needed(0f64, 10f64, 1e-15f64);
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter };
+///
+/// let root = find_root_bisection_in(0f64..=10f64, &f, &mut convergency);
+/// // Returns approximately Ok(1);
+/// ```
+pub fn find_root_bisection_in<F, Func>(
+    range: RangeInclusive<F>,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (start, end) = super::range_ends(&range)?;
+    find_root_bisection(start, end, f, convergency)
+}
+
+/// Predicts the number of iterations of find_root_bisection which make the interval from a to b shorter than eps.
+///
+/// The result is the least iteration limit of the convergency which lets find_root_bisection converge,
+/// unless the root is found earlier.
+///
+/// # Examples
+///
+/// ```
+/// use roots::bisection_iterations_needed;
+///
+/// let iterations = bisection_iterations_needed(0f64, 1f64, 0.001f64);
+/// // Returns 10 as 2^-10 < 0.001
+/// ```
+pub fn bisection_iterations_needed<F: FloatType>(a: F, b: F, eps: F) -> usize {
+    let _2 = F::from(2i16);
+    let mut width = (b - a).abs();
+    let mut iterations = 0;
+    while width >= eps.abs() && width > F::zero() {
+        if width / _2 == width {
+            // The infinite interval cannot be halved
+            return usize::MAX;
+        }
+        width = width / _2;
+        iterations += 1;
+    }
+    iterations
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::brent::find_root_brent;
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_find_root_bisection() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 60);
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_bisection(10f64, 0f64, &f, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(52, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_bisection(-10f64, 0f64, &f, &mut conv).ok().unwrap(),
+            -1f64
+        );
+        assert_eq!(52, conv.get_iter_count());
+
+        conv.reset();
+        assert!(matches!(
+            find_root_bisection(10f64, 20f64, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(0, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_bisection_simple() {
+        let f = |x| 1f64 * x * x - 2f64;
+
+        assert_float_eq!(
+            1e-8f64,
+            find_root_bisection(10f64, 0f64, &f, &mut 1e-8f64).ok().unwrap(),
+            2f64.sqrt()
+        );
+
+        assert!(matches!(
+            find_root_bisection(-10f64, 0f64, &f, &mut 1e-15f64),
+            Err(SearchError::NoConvergency { .. })
+        ));
+
+        assert!(matches!(
+            find_root_bisection(10f64, 20f64, &f, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_bisection_iterations_needed() {
+        let f = |x| 1f64 * x * x - 2f64;
+        for eps in [1e-3f64, 1e-8f64, 1e-15f64].iter() {
+            let needed = bisection_iterations_needed(0f64, 10f64, *eps);
+            let mut conv = debug_convergency::DebugConvergency::new(*eps, needed);
+            assert_float_eq!(
+                *eps,
+                find_root_bisection(0f64, 10f64, &f, &mut conv).ok().unwrap(),
+                2f64.sqrt()
+            );
+            assert_eq!(needed - 1, conv.get_iter_count());
+            let mut conv = simple_convergency::SimpleConvergency {
+                eps: *eps,
+                max_iter: needed - 1,
+            };
+            assert!(matches!(
+                find_root_bisection(0f64, 10f64, &f, &mut conv),
+                Err(SearchError::NoConvergency { .. })
+            ));
+        }
+
+        assert_eq!(bisection_iterations_needed(0f32, 1f32, 0.001f32), 10);
+        assert_eq!(bisection_iterations_needed(1f32, 0f32, 0.5f32), 2);
+        assert_eq!(bisection_iterations_needed(0f64, 0f64, 1e-15f64), 0);
+        assert_eq!(bisection_iterations_needed(0f64, 1f64, 0f64), 1075);
+        assert_eq!(bisection_iterations_needed(0f64, f64::INFINITY, 1e-15f64), usize::MAX);
+    }
+
+    #[test]
+    fn test_find_root_log_bisection() {
+        let f = |x: f64| 1f64 - 1e-7f64 / x;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 100);
+
+        conv.reset();
+        let log_root = find_root_log_bisection(1e-12f64, 1e3f64, &f, &mut conv).unwrap();
+        assert_float_eq!(1e-15f64, log_root, 1e-7f64);
+        let log_iterations = conv.get_iter_count();
+        assert_eq!(31, log_iterations);
+
+        // The linear bisection and the Brent method spend iterations on the exponent
+        conv.reset();
+        let root = find_root_bisection(1e-12f64, 1e3f64, &f, &mut conv).unwrap();
+        assert_float_eq!(1e-15f64, root, 1e-7f64);
+        assert_eq!(59, conv.get_iter_count());
+
+        conv.reset();
+        let root = find_root_brent(1e-12f64, 1e3f64, &f, &mut conv).unwrap();
+        assert_float_eq!(1e-15f64, root, 1e-7f64);
+        assert_eq!(42, conv.get_iter_count());
+
+        // The ends may be given in any order
+        conv.reset();
+        assert_eq!(find_root_log_bisection(1e3f64, 1e-12f64, &f, &mut conv), Ok(log_root));
+        assert_eq!(log_iterations, conv.get_iter_count());
+
+        assert!(matches!(
+            find_root_log_bisection(1e-6f64, 1e3f64, &f, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(
+            find_root_log_bisection(0f64, 1e3f64, &f, &mut 1e-15f64),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            })
+        );
+        assert_eq!(
+            find_root_log_bisection(-1f64, 1e3f64, &f, &mut 1e-15f64),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            })
+        );
+        assert_eq!(
+            find_root_log_bisection(1e-12f64, f64::INFINITY, &f, &mut 1e-15f64),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            find_root_log_bisection(f64::NAN, 1e3f64, &f, &mut 1e-15f64),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_root_log_bisection_signed() {
+        let f = |x: f64| 1f64 - 1e-7f64 / x;
+        let g = |x: f64| 1f64 + 1e-7f64 / x;
+
+        let root = find_root_log_bisection(1e-12f64, 1e3f64, &f, &mut 1e-12f64).unwrap();
+        assert_eq!(find_root_log_bisection_signed(1e-12f64, 1e3f64, &f, &mut 1e-12f64), Ok(root));
+        assert_eq!(
+            find_root_log_bisection_signed(-1e3f64, -1e-12f64, &g, &mut 1e-12f64),
+            Ok(-root)
+        );
+        assert_eq!(
+            find_root_log_bisection_signed(-1e-12f64, -1e3f64, &g, &mut 1e-12f64),
+            Ok(-root)
+        );
+
+        assert_eq!(
+            find_root_log_bisection_signed(-1e3f64, 1e3f64, &f, &mut 1e-12f64),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            })
+        );
+        assert_eq!(
+            find_root_log_bisection_signed(-1e3f64, 0f64, &g, &mut 1e-12f64),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_root_bisection_with_stats() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 60);
+
+        let (root, stats) = find_root_bisection_with_stats(10f64, 0f64, &f, &mut conv);
+        assert_eq!(root, find_root_bisection(10f64, 0f64, &f, &mut conv));
+        assert_eq!(stats.iterations, conv.get_iter_count());
+        assert_eq!(stats.iterations, 52);
+        assert_eq!(stats.fn_evaluations, 55);
+        assert_eq!(stats.derivative_evaluations, 0);
+        assert_eq!(stats.final_bracket_width, Some(10f64 / 2f64.powi(52)));
+
+        let (root, stats) = find_root_bisection_with_stats(
+            10f64,
+            0f64,
+            &f,
+            &mut simple_convergency::SimpleConvergency {
+                eps: 1e-15f64,
+                max_iter: 3,
+            },
+        );
+        assert!(matches!(root, Err(SearchError::NoConvergency { .. })));
+        assert_eq!(stats.iterations, 3);
+        assert_eq!(stats.final_bracket_width, None);
+    }
+
+    #[test]
+    fn test_try_find_root_bisection() {
+        let f = |x: f64| if x < 0f64 { Err("negative") } else { Ok(x * x - 2f64) };
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+
+        // The root lies in the valid half of the domain
+        assert_float_eq!(
+            1e-15f64,
+            try_find_root_bisection(0f64, 4f64, &f, &mut conv).ok().unwrap(),
+            2f64.sqrt()
+        );
+        // The function fails at a bracket endpoint
+        assert_eq!(
+            try_find_root_bisection(-4f64, 4f64, &f, &mut conv),
+            Err(TrySearchError::Function("negative"))
+        );
+        assert!(matches!(
+            try_find_root_bisection(2f64, 4f64, &f, &mut conv),
+            Err(TrySearchError::Search(SearchError::NoBracketing { .. }))
+        ));
+
+        // No evaluations after the first error
+        let calls = std::cell::RefCell::new(Vec::new());
+        let g = |x: f64| {
+            calls.borrow_mut().push(x);
+            if x > 3.5f64 && x < 4.5f64 {
+                Err(x)
+            } else {
+                Ok(x - 5f64)
+            }
+        };
+        assert_eq!(
+            try_find_root_bisection(0f64, 8f64, &g, &mut conv),
+            Err(TrySearchError::Function(4f64))
+        );
+        assert_eq!(*calls.borrow(), vec![0f64, 8f64, 4f64]);
+    }
+
+    #[test]
+    fn test_find_root_bisection_in() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: bisection_iterations_needed(-10f64, 0f64, 1e-15f64),
+        };
+
+        assert_eq!(
+            find_root_bisection_in(0f64..=10f64, &f, &mut conv),
+            find_root_bisection(0f64, 10f64, &f, &mut conv)
+        );
+        assert_float_eq!(
+            1e-15f64,
+            find_root_bisection_in(-10f64..=0f64, &f, &mut conv).ok().unwrap(),
+            -1f64
+        );
+        assert!(matches!(
+            find_root_bisection_in(2f64..=3f64, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+
+        // Empty and infinite ranges
+        assert_eq!(
+            find_root_bisection_in(10f64..=0f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::EmptyRange
+            })
+        );
+        assert_eq!(
+            find_root_bisection_in(0f64..=f64::INFINITY, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            find_root_bisection_in(f64::NEG_INFINITY..=0f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            find_root_bisection_in(f64::NAN..=10f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_root_bisection_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let bracket = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteBracket));
+        assert_eq!(find_root_bisection(f64::NAN, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_bisection(0.5f64, f64::NAN, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_bisection(f64::NEG_INFINITY, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_bisection(0.5f64, f64::INFINITY, &f, &mut 1e-15f64), bracket);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_bisection(0f64, 10f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_bisection(10f64, -1f64, &f, &mut 1e-15f64), value);
+        assert_eq!(
+            find_root_log_bisection(1f64, 10f64, |x: f64| (x - 1f64).ln(), &mut 1e-15f64),
+            value
+        );
+    }
+}
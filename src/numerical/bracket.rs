@@ -0,0 +1,477 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::brent::find_root_brent;
+use super::check_bracket;
+use super::check_start;
+use super::check_value;
+use super::is_finite;
+use super::Convergency;
+use super::InvalidInputReason;
+use super::Sample;
+use super::SearchError;
+
+/// Direction of the search from a starting point
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Direction {
+    /// Towards +infinity
+    TowardsPositive,
+    /// Towards -infinity
+    TowardsNegative,
+}
+
+/// True if the values have different signs or one of them is zero
+fn brackets<F: FloatType>(ya: F, yb: F) -> bool {
+    ya == F::zero() || yb == F::zero() || (ya < F::zero()) != (yb < F::zero())
+}
+
+/// Expand the interval [a, b] outwards until it brackets a root of the function f(x) = 0.
+///
+/// The ends are moved away from each other in turn, starting from the one with the smaller |f|,
+/// each time by 1.6 widths of the current interval, as zbrac of Numerical Recipes does.
+/// If the function is infinite or NaN at the new end, the step on that side is halved and tried again.
+/// Every evaluation of the function counts as an expansion.
+///
+/// # Failures
+/// ## NoBracketing
+/// The root is not bracketed after `max_expansions` expansions.
+/// ## InvalidInput
+/// The ends coincide, or the ends or the function values at them are not finite.
+/// # Examples
+///
+/// ```
+/// use roots::expand_bracket;
+///
+/// let f = |x| { 1f64*x*x - 4f64 };
+///
+/// let bracket = expand_bracket(0f64, 1f64, &f, 10);
+/// // Returns approximately Ok((0, 2.6));
+/// ```
+pub fn expand_bracket<F, Func>(a: F, b: F, mut f: Func, max_expansions: usize) -> Result<(F, F), SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    check_bracket(a, b)?;
+    let (mut a, mut b) = if a < b { (a, b) } else { (b, a) };
+    if a == b {
+        return Err(SearchError::invalid_input(InvalidInputReason::EmptyRange));
+    }
+    let (mut ya, mut yb) = (f(a), f(b));
+    check_value(ya)?;
+    check_value(yb)?;
+
+    let factor = F::from(16i16) / F::from(10i16);
+    let _2 = F::from(2i16);
+    // Steps are measured in widths of the interval; they are halved if the function is not finite there
+    let (mut step_a, mut step_b) = (factor, factor);
+    let mut expand_a = ya.abs() < yb.abs();
+    // The failure after the expansions, with the end of the smaller |f| as the last point
+    let no_bracketing = |expansions: usize, a: F, ya: F, b: F, yb: F| {
+        let (x, y) = if ya.abs() < yb.abs() { (a, ya) } else { (b, yb) };
+        SearchError::NoBracketing {
+            iterations: expansions,
+            last: Some((x.to_f64(), y.to_f64())),
+            bracket: Some((a.to_f64(), b.to_f64())),
+        }
+    };
+    for expansion in 0..max_expansions {
+        if brackets(ya, yb) {
+            return Ok((a, b));
+        }
+        let width = b - a;
+        if expand_a {
+            let x = a - step_a * width;
+            let y = f(x);
+            if is_finite(y) {
+                a = x;
+                ya = y;
+                expand_a = false;
+            } else {
+                step_a = step_a / _2;
+            }
+        } else {
+            let x = b + step_b * width;
+            let y = f(x);
+            if is_finite(y) {
+                b = x;
+                yb = y;
+                expand_a = true;
+            } else {
+                step_b = step_b / _2;
+            }
+        }
+        if !is_finite(a) || !is_finite(b) {
+            return Err(no_bracketing(expansion + 1, a, ya, b, yb));
+        }
+    }
+
+    if brackets(ya, yb) {
+        Ok((a, b))
+    } else {
+        Err(no_bracketing(max_expansions, a, ya, b, yb))
+    }
+}
+
+/// Find sub-intervals of [a, b] where the function f(x) changes its sign.
+///
+/// The function is evaluated at n+1 equally spaced points from a to b (one point is
+/// added if n is 0), and every pair of neighbouring points with the different signs of f(x)
+/// is returned in the ascending order. A point where f(x) is exactly zero is returned
+/// as its own degenerate bracket (x, x). Each bracket can be refined by any bracketing method.
+/// Roots closer to each other than the step of the grid, or of even multiplicity, can be missed.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_brackets;
+///
+/// let f = |x| { 1f64*x*x - 4f64 };
+///
+/// let brackets = find_brackets(&f, -3f64, 3f64, 3);
+/// // Returns [(-3, -1), (1, 3)];
+/// ```
+pub fn find_brackets<F, Func>(f: Func, a: F, b: F, n: usize) -> Vec<(F, F)>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (a, b) = if a > b { (b, a) } else { (a, b) };
+    let n = if n > 0 { n } else { 1 };
+    let step = (b - a) / F::from_f64(n as f64);
+    let grid: Vec<F> = (0..=n)
+        .map(|i| if i == n { b } else { a + step * F::from_f64(i as f64) })
+        .collect();
+    find_brackets_on_grid(f, &grid)
+}
+
+/// Find sub-intervals where the function f(x) changes its sign on the given grid of points.
+///
+/// Works as `find_brackets`, but the function is evaluated at the points of `grid`,
+/// which allows finer steps where roots are expected to be close. The brackets are returned
+/// in the order of the grid, so the grid is expected to be sorted.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_brackets_on_grid;
+///
+/// let f = |x| { 1f64*x*x - 4f64 };
+///
+/// let brackets = find_brackets_on_grid(&f, &[-3f64, -2f64, 0f64, 2.5f64]);
+/// // Returns [(-2, -2), (0, 2.5)];
+/// ```
+pub fn find_brackets_on_grid<F, Func>(mut f: Func, grid: &[F]) -> Vec<(F, F)>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let mut brackets = Vec::new();
+    let mut previous: Option<Sample<F>> = None;
+    for &x in grid {
+        let sample = Sample { x, y: f(x) };
+        if sample.y == F::zero() {
+            brackets.push((x, x));
+        } else if let Some(ref previous) = previous {
+            if previous.y != F::zero() && previous.is_bracketed_with(&sample) {
+                brackets.push((previous.x, x));
+            }
+        }
+        previous = Some(sample);
+    }
+    brackets
+}
+
+/// Find a root of the function f(x) = 0 on the half-line starting at `start` in the given direction.
+///
+/// The function is probed at points going away from `start` with the step doubled each time, beginning
+/// with max(1, |start|), until its sign changes. The last two probes bracket the root, which is then found
+/// by the Brent method. If the function is infinite or NaN at a probe, the step is halved and tried again.
+/// The same convergency limits the number of probes and the iterations of the Brent method.
+///
+/// # Failures
+/// ## NoBracketing
+/// The sign does not change within the given number of iterations, or the probe point overflows.
+/// ## InvalidInput
+/// The start or the function value at it is not finite.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_unbounded;
+/// use roots::Direction;
+///
+/// let f = |x| { 1f64*x - 1e6f64 };
+///
+/// let root = find_root_unbounded(0f64, Direction::TowardsPositive, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1000000);
+/// ```
+pub fn find_root_unbounded<F, Func>(
+    start: F,
+    direction: Direction,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    check_start(start)?;
+    let (mut x, mut y) = (start, f(start));
+    check_value(y)?;
+    if convergency.is_root_found(y) {
+        return Ok(x);
+    }
+
+    let _2 = F::from(2i16);
+    let mut step = if start.abs() > F::one() { start.abs() } else { F::one() };
+    if direction == Direction::TowardsNegative {
+        step = -step;
+    }
+    // The failure after the steps, with the last point where f is known
+    let no_bracketing = |iter: usize, x: F, y: F| SearchError::NoBracketing {
+        iterations: iter,
+        last: Some((x.to_f64(), y.to_f64())),
+        bracket: None,
+    };
+    let mut iter = 0;
+    loop {
+        let next_x = x + step;
+        if !is_finite(next_x) {
+            return Err(no_bracketing(iter, x, y));
+        }
+        let next_y = f(next_x);
+        if !is_finite(next_y) {
+            step = step / _2;
+        } else if brackets(y, next_y) {
+            return find_root_brent(x, next_x, f, convergency);
+        } else {
+            x = next_x;
+            y = next_y;
+            step = step * _2;
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(no_bracketing(iter, x, y));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_expand_bracket() {
+        let f = |x| 1f64 * x * x - 4f64;
+        // The root is just outside on either side
+        assert_eq!(expand_bracket(0f64, 1.25f64, &f, 1), Ok((0f64, 3.25f64)));
+        assert_eq!(expand_bracket(-1.25f64, 0f64, &f, 1), Ok((-3.25f64, 0f64)));
+        // The ends may be given in any order
+        assert_eq!(expand_bracket(1.25f64, 0f64, &f, 1), Ok((0f64, 3.25f64)));
+        // Already bracketed
+        assert_eq!(expand_bracket(0f64, 3f64, &f, 0), Ok((0f64, 3f64)));
+
+        // Expanding alternates between the ends
+        let g = |x| 1f64 * x - 10f64;
+        assert!(matches!(
+            expand_bracket(0f64, 1f64, &g, 2),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        let (a, b) = expand_bracket(0f64, 1f64, &g, 3).unwrap();
+        assert!(a < 0f64 && b > 10f64);
+
+        // No root at all
+        let h = |x| 1f64 * x * x + 1f64;
+        assert!(matches!(
+            expand_bracket(-1f64, 1f64, &h, 100),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_unbounded() {
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 60);
+
+        // Roots 1e6 away from the start in either direction
+        let f = |x| 1f64 * x - 1e6f64;
+        assert_float_eq!(
+            1e-15f64,
+            find_root_unbounded(0f64, Direction::TowardsPositive, &f, &mut conv)
+                .ok()
+                .unwrap(),
+            1e6f64
+        );
+        let f = |x| 1f64 * x + 1e6f64;
+        assert_float_eq!(
+            1e-15f64,
+            find_root_unbounded(0f64, Direction::TowardsNegative, &f, &mut conv)
+                .ok()
+                .unwrap(),
+            -1e6f64
+        );
+        let f = |x: f64| (x - 5f64).atan() * (x - 1e6f64 - 5f64);
+        assert_float_eq!(
+            1e-9f64,
+            find_root_unbounded(10f64, Direction::TowardsPositive, &f, &mut conv)
+                .ok()
+                .unwrap(),
+            1e6f64 + 5f64
+        );
+        // The root is on the other side
+        assert!(matches!(
+            find_root_unbounded(0f64, Direction::TowardsNegative, &|x| 1f64 * x - 1e6f64, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        // The root at the start
+        assert_eq!(
+            find_root_unbounded(1f64, Direction::TowardsNegative, &|x| 1f64 * x - 1f64, &mut conv),
+            Ok(1f64)
+        );
+    }
+
+    #[test]
+    fn test_find_root_unbounded_overflow() {
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 2000,
+        };
+        // The probe point overflows before the iteration limit
+        let f = |x: f64| x * x + 1f64;
+        assert!(matches!(
+            find_root_unbounded(0f64, Direction::TowardsPositive, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        // The function is not defined beyond -2, the step is shrunk after the probe at -3
+        let f = |x: f64| if x < -2f64 { f64::NAN } else { x + 1.9f64 };
+        assert_float_eq!(
+            1e-15f64,
+            find_root_unbounded(0f64, Direction::TowardsNegative, &f, &mut conv)
+                .ok()
+                .unwrap(),
+            -1.9f64
+        );
+        assert_eq!(
+            find_root_unbounded(f64::NAN, Direction::TowardsNegative, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteStart
+            })
+        );
+    }
+
+    #[test]
+    fn test_expand_bracket_not_finite() {
+        // The function is infinite at 3 and NaN beyond it; the root 2.5 is found by shrinking the step
+        let f = |x: f64| {
+            if x < 3f64 {
+                1f64 / (3f64 - x) - 2f64
+            } else if x == 3f64 {
+                f64::INFINITY
+            } else {
+                f64::NAN
+            }
+        };
+        let (a, b) = expand_bracket(0f64, 2f64, &f, 20).unwrap();
+        assert!(a <= 2.5f64 && b >= 2.5f64 && b < 3f64);
+
+        assert_eq!(
+            expand_bracket(0f64, 0f64, &f, 20),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::EmptyRange
+            })
+        );
+        assert_eq!(
+            expand_bracket(0f64, f64::INFINITY, &f, 20),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            expand_bracket(0f64, 3f64, &f, 20),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteValue
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_brackets() {
+        let f = |x: f64| x.sin();
+
+        // Roots k*pi for k from 1 to 9, one per bracket
+        let brackets = find_brackets(&f, 0.5f64, 30f64, 30);
+        assert_eq!(brackets.len(), 9);
+        for (k, &(a, b)) in brackets.iter().enumerate() {
+            let root = (k + 1) as f64 * std::f64::consts::PI;
+            assert!(a < root && root < b);
+            assert_float_eq!(1e-15f64, b - a, 29.5f64 / 30f64);
+        }
+        // The ends may be given in any order
+        assert_eq!(find_brackets(&f, 30f64, 0.5f64, 30), brackets);
+
+        // A grid too coarse for the roots
+        assert_eq!(find_brackets(&f, 0.5f64, 30f64, 1), vec![(0.5f64, 30f64)]);
+        assert_eq!(find_brackets(&f, 0.5f64, 30f64, 0), vec![(0.5f64, 30f64)]);
+    }
+
+    #[test]
+    fn test_find_brackets_exact_zeros() {
+        let f = |x| (x + 1f64) * x * (x - 1f64);
+
+        // Zeros at the grid points are degenerate brackets
+        assert_eq!(
+            find_brackets(&f, -2f64, 2f64, 4),
+            vec![(-1f64, -1f64), (0f64, 0f64), (1f64, 1f64)]
+        );
+        // The grid misses all roots
+        let brackets = find_brackets(&f, -1.2f64, 1.2f64, 3);
+        assert_eq!(brackets.len(), 3);
+        for (&(a, b), &root) in brackets.iter().zip(&[-1f64, 0f64, 1f64]) {
+            assert!(a < root && root < b);
+        }
+        // Roots of even multiplicity do not change the sign
+        let g = |x| (x - 1f64) * (x - 1f64);
+        assert_eq!(find_brackets(&g, -2f64, 2f64, 4), vec![(1f64, 1f64)]);
+        assert_eq!(find_brackets(&g, -2f64, 2f64, 3), vec![]);
+    }
+
+    #[test]
+    fn test_find_brackets_on_grid() {
+        let f = |x| 1f64 * x * x - 4f64;
+
+        assert_eq!(
+            find_brackets_on_grid(&f, &[-3f64, -2f64, 0f64, 2.5f64]),
+            vec![(-2f64, -2f64), (0f64, 2.5f64)]
+        );
+        assert_eq!(find_brackets_on_grid(&f, &[1f64]), vec![]);
+        assert_eq!(find_brackets_on_grid(&f, &[]), vec![]);
+        // Points where the function is NaN do not bracket anything
+        let g = |x: f64| if x < 0f64 { f64::NAN } else { x - 1f64 };
+        assert_eq!(find_brackets_on_grid(&g, &[-2f64, -1f64, 0f64, 2f64]), vec![(0f64, 2f64)]);
+    }
+}
@@ -9,8 +9,18 @@
 // except according to those terms.
 
 use super::super::FloatType;
+use super::bracket::expand_bracket;
+use super::check_bracket;
+use super::check_start;
+use super::check_value;
 use super::Convergency;
+use super::IterationObserver;
+use super::NoObserver;
+use super::RootKind;
 use super::SearchError;
+use super::SolverStats;
+use super::TrySearchError;
+use std::ops::RangeInclusive;
 
 /// Arrange two points so that the greatest value is first
 fn arrange<F: FloatType>(a: F, ya: F, b: F, yb: F) -> (F, F, F, F) {
@@ -35,6 +45,8 @@ fn arrange<F: FloatType>(a: F, ya: F, b: F, yb: F) -> (F, F, F, F) {
 /// - Needs initial bracketing
 ///
 /// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
 /// ## NoBracketing
 /// Initial values do not bracket the root.
 /// ## NoConvergency
@@ -54,14 +66,507 @@ fn arrange<F: FloatType>(a: F, ya: F, b: F, yb: F) -> (F, F, F, F) {
 /// let root2 = find_root_brent(-10f64, 0f64, &f, &mut 1e-15f64);
 /// // Returns approximately Ok(-1);
 /// ```
-pub fn find_root_brent<F, Func>(a: F, b: F, mut f: Func, convergency: &mut dyn Convergency<F>) -> Result<F, SearchError>
+pub fn find_root_brent<F, Func>(a: F, b: F, f: Func, convergency: &mut (impl Convergency<F> + ?Sized)) -> Result<F, SearchError>
 where
     F: FloatType,
     Func: FnMut(F) -> F,
 {
-    let (mut a, mut ya, mut b, mut yb) = arrange(a, f(a), b, f(b));
+    find_root_brent_with(a, b, None, f, convergency, interpolate_quadratic, &mut NoObserver).map(|(root, _, _, _, _)| root)
+}
+
+/// Find a root of the function f(x) = 0 using the Brent method and count the work done.
+///
+/// The same as `find_root_brent`, but the result comes with `SolverStats`: the last iteration,
+/// the numbers of evaluations of the function, and the width of the last
+/// bracketing interval if the root is found.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_with_stats;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let (root, stats) = find_root_brent_with_stats(10f64, 0f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1) and SolverStats { iterations: 10, fn_evaluations: 12, .. }
+/// ```
+pub fn find_root_brent_with_stats<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> (Result<F, SearchError>, SolverStats<F>)
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (result, mut stats) = super::search_with_stats(f, convergency, |f, convergency| {
+        find_root_brent_enclosure(a, b, f, convergency)
+    });
+    stats.final_bracket_width = result.ok().map(|(_, lo, hi)| hi - lo);
+    (result.map(|(root, _, _)| root), stats)
+}
+
+/// Find a root of the function f(x) = 0 using the Brent method and report every iteration to the observer.
+///
+/// The same as `find_root_brent`, but `observer.on_iteration` is called once per iteration with
+/// the end of the bracketing interval where the absolute value of the function is the least.
+/// These values never grow, so the trajectory shows how fast the method converges.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_observed;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let mut trajectory = Vec::new();
+///
+/// let root = find_root_brent_observed(10f64, 0f64, &f, &mut 1e-15f64, &mut |_: usize, x: f64, y: f64| {
+///     trajectory.push((x, y))
+/// });
+/// // Returns approximately Ok(1), and trajectory has 10 points
+/// ```
+pub fn find_root_brent_observed<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    observer: &mut (impl IterationObserver<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    find_root_brent_with(a, b, None, f, convergency, interpolate_quadratic, observer).map(|(root, _, _, _, _)| root)
+}
+
+/// Find a root of the function f(x) = 0 which can fail using the Brent method.
+///
+/// The same as `find_root_brent`, but f returns Result. The first error of f stops the search
+/// and is returned as `TrySearchError::Function`; f is not called after it.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## Function
+/// The function has failed.
+/// ## Search
+/// The search has failed as `find_root_brent` does.
+/// # Examples
+///
+/// ```
+/// use roots::try_find_root_brent;
+///
+/// // ln is defined for positive x only
+/// let f = |x: f64| if x > 0f64 { Ok(x.ln() - 1f64) } else { Err("not positive") };
+///
+/// let root = try_find_root_brent(1f64, 10f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(2.718281828459045);
+///
+/// let error = try_find_root_brent(0f64, 10f64, &f, &mut 1e-15f64);
+/// // Returns Err(TrySearchError::Function("not positive"));
+/// ```
+pub fn try_find_root_brent<F, Func, E>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, TrySearchError<E>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> Result<F, E>,
+{
+    super::try_search(f, convergency, |f, convergency| find_root_brent(a, b, f, convergency))
+}
+
+/// Find a root of the function f(x) = 0 within the given range using the Brent method.
+///
+/// The same as `find_root_brent` with the ends of the range.
+///
+/// # Failures
+/// ## InvalidInput
+/// The range is empty or its ends are not finite.
+/// ## NoBracketing
+/// The ends of the range do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_in;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let root = find_root_brent_in(0f64..=10f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1);
+/// ```
+pub fn find_root_brent_in<F, Func>(
+    range: RangeInclusive<F>,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (start, end) = super::range_ends(&range)?;
+    find_root_brent(start, end, f, convergency)
+}
+
+/// Find a root of the function f(x) = 0 using the Brent method and the interval enclosing it.
+///
+/// The same as `find_root_brent`, but the result is the tuple (root, lo, hi), where [lo, hi] is the last
+/// bracketing interval: f(lo) and f(hi) have opposite signs, or one of them satisfies `is_root_found`.
+/// Unless the root has been found that way, the interval is narrower than the precision of `convergency`.
+/// The root is the same as returned by `find_root_brent`; it can be the previous approximation,
+/// which is within the precision of `convergency` from the interval but not necessarily inside it.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_enclosure;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let result = find_root_brent_enclosure(10f64, 0f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok((1, 0.9999999889562693, 1.0000000000000002)),
+/// // as the value at 1.0000000000000002 is found to be zero
+/// ```
+pub fn find_root_brent_enclosure<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<(F, F, F), SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    find_root_brent_with(a, b, None, f, convergency, interpolate_quadratic, &mut NoObserver).map(|(root, a, _, b, _)| {
+        if a < b {
+            (root, a, b)
+        } else {
+            (root, b, a)
+        }
+    })
+}
+
+/// Find a root of the function f(x) = 0 using the Brent method and tell it from a discontinuity.
+///
+/// The function changes its sign at poles and jumps as well, and the Brent method converges to them
+/// as if they were roots. The result is `RootKind::Root` if the least value of the function at the ends
+/// of the last bracketing interval satisfies `is_root_found` of `convergency`,
+/// and `RootKind::Discontinuity` otherwise. No additional evaluations of the function are needed.
+/// Steep functions whose values are not small at the ends of the converged interval are reported
+/// as discontinuities as well; relax the precision of `is_root_found` for them.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_brent_classified;
+///
+/// let f = |x: f64| { x.tan() };
+/// // Converging to a pole takes more iterations than to a root
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:100 };
+///
+/// let root = find_root_brent_classified(-1f64, 1f64, &f, &mut convergency);
+/// // Returns approximately Ok(RootKind::Root(0));
+///
+/// let pole = find_root_brent_classified(1f64, 2f64, &f, &mut convergency);
+/// // Returns approximately Ok(RootKind::Discontinuity(1.5707963267948966));
+/// ```
+pub fn find_root_brent_classified<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<RootKind<F>, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (root, _, _, _, y) = find_root_brent_with(a, b, None, f, convergency, interpolate_quadratic, &mut NoObserver)?;
+    if convergency.is_root_found(y) {
+        Ok(RootKind::Root(root))
+    } else {
+        Ok(RootKind::Discontinuity(root))
+    }
+}
+
+/// Find a root of the function f(x) = 0 using the Brent method, starting from the guess of the root.
+///
+/// The same as `find_root_brent`, but the bracket is first split at the guess, and the iterations start
+/// from the part where the function changes its sign. If the guess is close to the root, e.g. the root
+/// found for slightly different parameters, it takes fewer iterations. The guess outside of the bracket is ignored.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends, the guess or the function values at the ends are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_with_guess;
+///
+/// let f = |x| { 1f64*x*x - 1.001f64 };
+///
+/// // The root has moved slightly from 1
+/// let root = find_root_brent_with_guess(10f64, 0f64, 1f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1.000499875062461);
+/// ```
+pub fn find_root_brent_with_guess<F, Func>(
+    a: F,
+    b: F,
+    guess: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    find_root_brent_with(a, b, Some(guess), f, convergency, interpolate_quadratic, &mut NoObserver).map(|(root, _, _, _, _)| root)
+}
+
+/// Find a root of the function f(x) = 0 using the Brent method, expanding the bracket first if needed.
+///
+/// The same as `find_root_brent`, but if the initial values do not bracket the root, the interval
+/// is widened by `expand_bracket` up to `max_expansions` times.
+///
+/// # Failures
+/// ## NoBracketing
+/// The root is not bracketed after `max_expansions` expansions.
+/// ## InvalidInput
+/// The ends coincide, or the ends or the function values at them are not finite.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_expand;
+///
+/// let f = |x| { 1f64*x*x - 4f64 };
+///
+/// let root = find_root_brent_expand(0f64, 1f64, &f, 10, &mut 1e-15f64);
+/// // Returns approximately Ok(2);
+/// ```
+pub fn find_root_brent_expand<F, Func>(
+    a: F,
+    b: F,
+    mut f: Func,
+    max_expansions: usize,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (a, b) = expand_bracket(a, b, &mut f, max_expansions)?;
+    find_root_brent(a, b, f, convergency)
+}
+
+/// Find a root of the function f(x) = 0 using the Brent method with hyperbolic interpolation (Bus and Dekker).
+///
+/// The inverse quadratic interpolation is replaced by the root of the hyperbola y = (x - s)/(px + q)
+/// drawn through the last three points. This step is exact for functions like 1/(x - x0) - k and behaves
+/// better near poles or where the function is strongly curved.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_hyperbolic;
+///
+/// let f = |x| { 1f64/(x - 2f64) - 1f64 };
+///
+/// let root = find_root_brent_hyperbolic(2.1f64, 10f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(3);
+/// ```
+pub fn find_root_brent_hyperbolic<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    find_root_brent_with(a, b, None, f, convergency, interpolate_hyperbolic, &mut NoObserver).map(|(root, _, _, _, _)| root)
+}
+
+/// Find a root of the noisy function f(x) = 0 using the Brent method.
+///
+/// Values with |f(x)| not greater than `noise_level` are considered to be zero: their sign is not used
+/// to bracket the root, and the iterations stop as soon as such a value is found. The root is then within
+/// the noise band around that point, whose width is estimated by the slope of the function over the bracket.
+/// The result is the midpoint of the band and its width, or the midpoint of the final bracket and its width
+/// if `convergency` stops the search first.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_noisy;
+///
+/// let f = |x: f64| { x*x - 1f64 + 1e-6f64 * (1e6f64 * x).sin() };
+///
+/// let root = find_root_brent_noisy(10f64, 0f64, &f, 1e-6f64, &mut 1e-15f64);
+/// // Returns approximately Ok((1, 1e-6));
+/// ```
+pub fn find_root_brent_noisy<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    noise_level: F,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<(F, F), SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let mut convergency = NoiseFloor {
+        convergency,
+        noise_level,
+    };
+    let (root, a, ya, b, yb) = find_root_brent_with(a, b, None, f, &mut convergency, interpolate_quadratic, &mut NoObserver)?;
+    let _2 = F::from(2i16);
+    if ya.abs() <= noise_level || yb.abs() <= noise_level {
+        // The root is within noise_level/slope of the point where the noise hides the sign of the function
+        let width = noise_level * (a - b).abs() / (ya - yb).abs();
+        Ok((root, _2 * width))
+    } else {
+        Ok(((a + b) / _2, (a - b).abs()))
+    }
+}
+
+/// Convergency which takes the values within the noise level for zero
+struct NoiseFloor<'a, F, C: ?Sized> {
+    convergency: &'a mut C,
+    noise_level: F,
+}
+
+impl<'a, F: FloatType, C: Convergency<F> + ?Sized> Convergency<F> for NoiseFloor<'a, F, C> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        y.abs() <= self.noise_level || self.convergency.is_root_found(y)
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        self.convergency.is_converged(x1, x2)
+    }
+    fn is_converged_at(&mut self, x1: F, x2: F, y: F) -> bool {
+        self.convergency.is_converged_at(x1, x2, y)
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        self.convergency.is_iteration_limit_reached(iter)
+    }
+}
+
+/// Inverse quadratic interpolation through three points, the secant method if it is not possible
+pub fn interpolate_quadratic<F: FloatType>(a: F, ya: F, b: F, yb: F, c: F, yc: F) -> F {
+    if (ya != yc) && (yb != yc) {
+        a * yb * yc / ((ya - yb) * (ya - yc)) + b * ya * yc / ((yb - ya) * (yb - yc)) + c * ya * yb / ((yc - ya) * (yc - yb))
+    } else {
+        b - yb * (b - a) / (yb - ya)
+    }
+}
+
+/// Hyperbolic interpolation through three points, the secant method if it is not possible
+fn interpolate_hyperbolic<F: FloatType>(a: F, ya: F, b: F, yb: F, c: F, yc: F) -> F {
+    // In coordinates relative to b, y*(p*x + q) = x - s gives s = -yb*q
+    let (u, v) = (a - b, c - b);
+    let d = ya * u * (yc - yb) - yc * v * (ya - yb);
+    if d != F::zero() && v != F::zero() {
+        b - yb * u * v * (ya - yc) / d
+    } else {
+        b - yb * (b - a) / (yb - ya)
+    }
+}
+
+fn find_root_brent_with<F, Func>(
+    a: F,
+    b: F,
+    guess: Option<F>,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    interpolate: fn(F, F, F, F, F, F) -> F,
+    observer: &mut (impl IterationObserver<F> + ?Sized),
+) -> Result<(F, F, F, F, F), SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    check_bracket(a, b)?;
+    if let Some(g) = guess {
+        check_start(g)?;
+    }
+    let (ya, yb) = (f(a), f(b));
+    check_value(ya)?;
+    check_value(yb)?;
+    let (mut a, mut ya, mut b, mut yb) = arrange(a, ya, b, yb);
     if ya * yb > F::zero() {
-        return Err(SearchError::NoBracketing);
+        return Err(SearchError::no_bracketing(a, b));
+    }
+
+    // Start from the part of the bracket on the side of the guess where the sign changes
+    match guess {
+        Some(g) if (g - a) * (g - b) < F::zero() => {
+            let yg = f(g);
+            check_value(yg)?;
+            let (_a, _ya, _b, _yb) = if ya * yg < F::zero() {
+                arrange(a, ya, g, yg)
+            } else {
+                arrange(g, yg, b, yb)
+            };
+            a = _a;
+            ya = _ya;
+            b = _b;
+            yb = _yb;
+        }
+        _ => {}
     }
 
     let (mut c, mut yc, mut d) = (a, ya, a);
@@ -74,19 +579,15 @@ where
     let mut iter = 0;
     loop {
         if convergency.is_root_found(ya) {
-            return Ok(a);
+            return Ok((a, a, ya, b, yb));
         }
         if convergency.is_root_found(yb) {
-            return Ok(b);
+            return Ok((b, a, ya, b, yb));
         }
-        if convergency.is_converged(a, b) {
-            return Ok(c);
+        if convergency.is_converged_at(a, b, yc) {
+            return Ok((c, a, ya, b, yb));
         }
-        let mut s = if (ya != yc) && (yb != yc) {
-            a * yb * yc / ((ya - yb) * (ya - yc)) + b * ya * yc / ((yb - ya) * (yb - yc)) + c * ya * yb / ((yc - ya) * (yc - yb))
-        } else {
-            b - yb * (b - a) / (yb - ya)
-        };
+        let mut s = interpolate(a, ya, b, yb, c, yc);
 
         let cond1 = (s - b) * (s - (_3 * a + b) / _4) > F::zero();
         let cond2 = flag && (s - b).abs() >= (b - c).abs() / _2;
@@ -107,7 +608,7 @@ where
         yc = yb;
         if ya * ys < F::zero() {
             // Root bracketed between a ans s
-            match arrange(a, f(a), s, ys) {
+            match arrange(a, ya, s, ys) {
                 (_a, _ya, _b, _yb) => {
                     a = _a;
                     ya = _ya;
@@ -117,7 +618,7 @@ where
             }
         } else {
             // Root bracketed between s ans b
-            match arrange(s, ys, b, f(b)) {
+            match arrange(s, ys, b, yb) {
                 (_a, _ya, _b, _yb) => {
                     a = _a;
                     ya = _ya;
@@ -126,10 +627,11 @@ where
                 }
             }
         }
+        observer.on_iteration(iter, b, yb);
 
         iter = iter + 1;
         if convergency.is_iteration_limit_reached(iter) {
-            return Err(SearchError::NoConvergency);
+            return Err(SearchError::no_convergency(iter, b, yb).with_bracket(a, b));
         }
     }
 }
@@ -139,6 +641,52 @@ mod test {
     use super::super::*;
     use super::*;
 
+    #[test]
+    fn test_find_root_brent_classified() {
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+        let tan = |x: f64| x.tan();
+
+        match find_root_brent_classified(-1f64, 1f64, &tan, &mut conv) {
+            Ok(RootKind::Root(x)) => assert_float_eq!(1e-15f64, x, 0f64),
+            other => panic!("{:?}", other),
+        }
+        match find_root_brent_classified(1f64, 2f64, &tan, &mut conv) {
+            Ok(RootKind::Discontinuity(x)) => assert_float_eq!(1e-14f64, x, std::f64::consts::FRAC_PI_2),
+            other => panic!("{:?}", other),
+        }
+        match find_root_brent_classified(4f64, 5f64, &tan, &mut conv) {
+            Ok(RootKind::Discontinuity(x)) => assert_float_eq!(1e-14f64, x, 3f64 * std::f64::consts::FRAC_PI_2),
+            other => panic!("{:?}", other),
+        }
+
+        let hyperbola = |x: f64| 1f64 / (x - 2f64);
+        match find_root_brent_classified(1f64, 3.5f64, &hyperbola, &mut conv) {
+            Ok(RootKind::Discontinuity(x)) => assert_float_eq!(1e-14f64, x, 2f64),
+            other => panic!("{:?}", other),
+        }
+        // The same hyperbola shifted down has a root beyond the pole
+        let shifted = |x: f64| 1f64 / (x - 2f64) - 1f64;
+        match find_root_brent_classified(2.001f64, 100f64, &shifted, &mut conv) {
+            Ok(RootKind::Root(x)) => assert_float_eq!(1e-14f64, x, 3f64),
+            other => panic!("{:?}", other),
+        }
+
+        let step = |x: f64| if x < 0.3f64 { -1f64 } else { 1f64 };
+        match find_root_brent_classified(-1f64, 1f64, &step, &mut conv) {
+            Ok(RootKind::Discontinuity(x)) => assert_float_eq!(1e-14f64, x, 0.3f64),
+            other => panic!("{:?}", other),
+        }
+
+        let parabola = |x: f64| x * x - 1f64;
+        assert_eq!(
+            find_root_brent_classified(10f64, 0f64, &parabola, &mut conv),
+            Ok(RootKind::Root(find_root_brent(10f64, 0f64, &parabola, &mut conv).unwrap()))
+        );
+    }
+
     #[test]
     fn test_find_root_brent() {
         let f = |x| 1f64 * x * x - 1f64;
@@ -153,7 +701,10 @@ mod test {
         assert_eq!(10, conv.get_iter_count());
 
         conv.reset();
-        assert_eq!(find_root_brent(10f64, 20f64, &f, &mut conv), Err(SearchError::NoBracketing));
+        assert!(matches!(
+            find_root_brent(10f64, 20f64, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
         assert_eq!(0, conv.get_iter_count());
     }
 
@@ -169,9 +720,437 @@ mod test {
             -1f64
         );
 
-        assert_eq!(
+        assert!(matches!(
             find_root_brent(10f64, 20f64, &f, &mut 1e-15f64),
-            Err(SearchError::NoBracketing)
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_brent_hyperbolic() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_brent_hyperbolic(10f64, 0f64, &f, &mut conv).ok().unwrap(),
+            1f64
+        );
+        assert_eq!(10, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_brent_hyperbolic(-10f64, 0f64, &f, &mut conv).ok().unwrap(),
+            -1f64
+        );
+        assert_eq!(10, conv.get_iter_count());
+
+        conv.reset();
+        assert!(matches!(
+            find_root_brent_hyperbolic(10f64, 20f64, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(0, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_brent_hyperbolic_pole() {
+        // Inverse quadratic interpolation steps poorly next to the pole at 2
+        let f = |x| 1f64 / (x - 2f64) - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_brent(2.001f64, 100f64, &f, &mut conv).ok().unwrap(), 3f64);
+        assert_eq!(14, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_brent_hyperbolic(2.001f64, 100f64, &f, &mut conv).ok().unwrap(),
+            3f64
+        );
+        assert_eq!(8, conv.get_iter_count());
+
+        let f = |x| 1f64 / (x * x) - 4f64;
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_brent(0.01f64, 10f64, &f, &mut conv).ok().unwrap(), 0.5f64);
+        assert_eq!(13, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_brent_hyperbolic(0.01f64, 10f64, &f, &mut conv).ok().unwrap(),
+            0.5f64
+        );
+        assert_eq!(12, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_brent_hyperbolic_polynomials() {
+        type Case = (fn(f64) -> f64, f64, f64, f64);
+        let polynomials: [Case; 3] = [
+            (|x| x * x * x * x - 1f64, 0f64, 10f64, 1f64),
+            (|x| x * x * x - 2f64 * x - 5f64, 2f64, 3f64, 2.0945514815423265f64),
+            (|x| (x - 1f64) * (x + 2f64) * (x - 3f64), 0f64, 2f64, 1f64),
+        ];
+        for (f, a, b, root) in polynomials.iter() {
+            let brent = find_root_brent(*a, *b, f, &mut 1e-15f64).ok().unwrap();
+            let hyperbolic = find_root_brent_hyperbolic(*a, *b, f, &mut 1e-15f64).ok().unwrap();
+            assert_float_eq!(1e-15f64, brent, *root);
+            assert_float_eq!(1e-15f64, hyperbolic, *root);
+        }
+    }
+
+    #[test]
+    fn test_find_root_brent_evaluations() {
+        // Roots are the same as returned when the values at the ends of the interval were re-evaluated
+        type Case = (fn(f64) -> f64, f64, f64, f64, f64);
+        let cases: [Case; 5] = [
+            (|x| x * x - 1f64, 10f64, 0f64, 1.0000000000000002f64, 1f64),
+            (|x| x * x - 1f64, -10f64, 0f64, -1.0000000000000002f64, -1f64),
+            (|x| 1f64 / (x - 2f64) - 1f64, 2.001f64, 100f64, 2.9999999999999996f64, 3f64),
+            (|x| 1f64 / (x * x) - 4f64, 0.01f64, 10f64, 0.5f64, 0.5f64),
+            (
+                |x| x * x * x - 2f64 * x - 5f64,
+                2f64,
+                3f64,
+                2.0945514815423265f64,
+                2.0945514815423265f64,
+            ),
+        ];
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+        for (f, a, b, brent, hyperbolic) in cases.iter() {
+            let mut evaluations = 0;
+            conv.reset();
+            let root = find_root_brent(
+                *a,
+                *b,
+                |x| {
+                    evaluations += 1;
+                    f(x)
+                },
+                &mut conv,
+            );
+            assert_eq!(root, Ok(*brent));
+            // One evaluation per iteration after the ends of the interval
+            assert_eq!(evaluations, conv.get_iter_count() + 2);
+
+            let mut evaluations = 0;
+            conv.reset();
+            let root = find_root_brent_hyperbolic(
+                *a,
+                *b,
+                |x| {
+                    evaluations += 1;
+                    f(x)
+                },
+                &mut conv,
+            );
+            assert_eq!(root, Ok(*hyperbolic));
+            assert_eq!(evaluations, conv.get_iter_count() + 2);
+        }
+    }
+
+    #[test]
+    fn test_find_root_brent_enclosure() {
+        type Case = (fn(f64) -> f64, f64, f64);
+        let cases: [Case; 5] = [
+            (|x| x * x - 1f64, 10f64, 0f64),
+            (|x| x * x - 1f64, -10f64, 0f64),
+            (|x| 1f64 / (x - 2f64) - 1f64, 2.001f64, 100f64),
+            (|x| x * x * x - 2f64 * x - 5f64, 2f64, 3f64),
+            // The step is never found as a root; the interval must shrink
+            (|x| if x < 1f64 / 3f64 { -1f64 } else { 1f64 }, -5f64, 7f64),
+        ];
+        // Bisection of the step takes more than 30 iterations
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+        for (f, a, b) in cases.iter() {
+            let (root, lo, hi) = find_root_brent_enclosure(*a, *b, f, &mut conv).ok().unwrap();
+            assert_eq!(root, find_root_brent(*a, *b, f, &mut conv).ok().unwrap());
+            assert!(lo <= hi);
+            assert!(f(lo).signum() * f(hi).signum() <= 0f64 || f(lo).abs() < 1e-15f64 || f(hi).abs() < 1e-15f64);
+            assert!(hi - lo < 1e-15f64 || f(lo).abs() < 1e-15f64 || f(hi).abs() < 1e-15f64);
+        }
+
+        let (root, lo, hi) = find_root_brent_enclosure(-5f64, 7f64, cases[4].0, &mut conv).ok().unwrap();
+        assert!(hi - lo < 1e-15f64);
+        assert!(lo <= 1f64 / 3f64 && 1f64 / 3f64 <= hi);
+        // The estimate is the previous point, which may be just outside of the interval
+        assert_float_eq!(1e-14f64, root, 1f64 / 3f64);
+
+        assert!(matches!(
+            find_root_brent_enclosure(10f64, 20f64, &|x| x * x - 1f64, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_brent_with_guess() {
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+        let mut root = 2.0945514815423265f64;
+        for frame in 1..6 {
+            // The root moves by 1e-3 between frames
+            let shift = 1e-3f64 * frame as f64;
+            let f = |x: f64| (x - shift) * (x - shift) * (x - shift) - 2f64 * (x - shift) - 5f64;
+
+            conv.reset();
+            let cold = find_root_brent(0f64, 10f64, &f, &mut conv).ok().unwrap();
+            assert_eq!(11, conv.get_iter_count());
+
+            conv.reset();
+            let warm = find_root_brent_with_guess(0f64, 10f64, root, &f, &mut conv).ok().unwrap();
+            assert_eq!(4, conv.get_iter_count());
+
+            assert_float_eq!(1e-15f64, warm, cold);
+            assert_float_eq!(1e-15f64, warm, 2.0945514815423265f64 + shift);
+            root = warm;
+        }
+
+        // Guesses outside of the bracket are ignored
+        let f = |x| 1f64 * x * x - 1f64;
+        for guess in [-1f64, 0f64, 10f64, 20f64].iter() {
+            conv.reset();
+            assert_eq!(
+                find_root_brent_with_guess(10f64, 0f64, *guess, &f, &mut conv),
+                find_root_brent(10f64, 0f64, &f, &mut 1e-15f64)
+            );
+            assert_eq!(10, conv.get_iter_count());
+        }
+        assert!(matches!(
+            find_root_brent_with_guess(10f64, 20f64, 15f64, &f, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+
+        // The value at the guess is checked like the values at the ends
+        let g = |x: f64| if x == 0.5f64 { f64::NAN } else { x * x - 1f64 };
+        assert_eq!(
+            find_root_brent_with_guess(0f64, 10f64, 0.5f64, &g, &mut 1e-15f64),
+            Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue))
+        );
+    }
+
+    /// Deterministic pseudo-noise uniformly distributed in [-amplitude, amplitude]
+    fn noise(x: f64, amplitude: f64) -> f64 {
+        let bits = x.to_bits().wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let bits = (bits ^ (bits >> 29)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        amplitude * (2f64 * ((bits >> 11) as f64) / ((1u64 << 53) as f64) - 1f64)
+    }
+
+    #[test]
+    fn test_find_root_brent_noisy() {
+        let f = |x| 1f64 * x * x - 1f64 + noise(x, 1e-6f64);
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert!(matches!(
+            find_root_brent(10f64, 0f64, &f, &mut conv),
+            Err(SearchError::NoConvergency { .. })
+        ));
+
+        conv.reset();
+        let (root, width) = find_root_brent_noisy(10f64, 0f64, &f, 1e-6f64, &mut conv).ok().unwrap();
+        assert_eq!(10, conv.get_iter_count());
+        assert!((root - 1f64).abs() <= width);
+        assert!(width < 1e-5f64);
+
+        conv.reset();
+        let (root, width) = find_root_brent_noisy(-10f64, 0f64, &f, 1e-6f64, &mut conv).ok().unwrap();
+        assert_eq!(9, conv.get_iter_count());
+        assert!((root + 1f64).abs() <= width);
+        assert!(width < 1e-5f64);
+
+        // Without the noise, the steps are the ones of the Brent method
+        let g = |x| 1f64 * x * x - 1f64;
+        conv.reset();
+        let (root, width) = find_root_brent_noisy(10f64, 0f64, &g, 0f64, &mut conv).ok().unwrap();
+        assert_eq!(10, conv.get_iter_count());
+        let (_, lo, hi) = find_root_brent_enclosure(10f64, 0f64, &g, &mut 1e-15f64).ok().unwrap();
+        assert_eq!((root, width), ((lo + hi) / 2f64, hi - lo));
+
+        conv.reset();
+        assert!(matches!(
+            find_root_brent_noisy(10f64, 20f64, &f, 1e-6f64, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(0, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_brent_with_stats() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        let (root, stats) = find_root_brent_with_stats(10f64, 0f64, &f, &mut conv);
+        assert_eq!(root, find_root_brent(10f64, 0f64, &f, &mut conv));
+        assert_eq!(stats.iterations, conv.get_iter_count());
+        assert_eq!(stats.iterations, 10);
+        assert_eq!(stats.fn_evaluations, 12);
+        assert_eq!(stats.derivative_evaluations, 0);
+        let (_, lo, hi) = find_root_brent_enclosure(10f64, 0f64, &f, &mut conv).unwrap();
+        assert_eq!(stats.final_bracket_width, Some(hi - lo));
+
+        let (root, stats) = find_root_brent_with_stats(
+            10f64,
+            0f64,
+            &f,
+            &mut simple_convergency::SimpleConvergency {
+                eps: 1e-15f64,
+                max_iter: 3,
+            },
+        );
+        assert!(matches!(root, Err(SearchError::NoConvergency { .. })));
+        assert_eq!(stats.iterations, 3);
+        assert_eq!(stats.final_bracket_width, None);
+    }
+
+    #[test]
+    fn test_find_root_brent_observed() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+        let mut trajectory = Vec::new();
+
+        let root = find_root_brent_observed(10f64, 0f64, &f, &mut conv, &mut |iter: usize, x: f64, y: f64| {
+            trajectory.push((iter, x, y))
+        });
+        assert_eq!(root, find_root_brent(10f64, 0f64, &f, &mut 1e-15f64));
+        assert_eq!(trajectory.len(), conv.get_iter_count());
+        assert_eq!(trajectory.last().unwrap().1, root.unwrap());
+        for (i, window) in trajectory.windows(2).enumerate() {
+            assert_eq!(window[0].0, i);
+            assert!(window[1].2.abs() <= window[0].2.abs());
+        }
+    }
+
+    #[test]
+    fn test_try_find_root_brent() {
+        let f = |x: f64| if x < 0f64 { Err("negative") } else { Ok(x * x - 2f64) };
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+
+        // The root lies in the valid half of the domain
+        assert_float_eq!(
+            1e-15f64,
+            try_find_root_brent(0f64, 4f64, &f, &mut conv).ok().unwrap(),
+            2f64.sqrt()
+        );
+        // The function fails at a bracket endpoint
+        assert_eq!(
+            try_find_root_brent(-4f64, 4f64, &f, &mut conv),
+            Err(TrySearchError::Function("negative"))
+        );
+        assert!(matches!(
+            try_find_root_brent(2f64, 4f64, &f, &mut conv),
+            Err(TrySearchError::Search(SearchError::NoBracketing { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_find_root_brent_expand() {
+        let f = |x| 1f64 * x * x - 4f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        // The root is just outside on either side
+        assert_float_eq!(
+            1e-15f64,
+            find_root_brent_expand(0f64, 1.9f64, &f, 10, &mut conv).ok().unwrap(),
+            2f64
+        );
+        assert_float_eq!(
+            1e-15f64,
+            find_root_brent_expand(-1.9f64, 0f64, &f, 10, &mut conv).ok().unwrap(),
+            -2f64
+        );
+        assert_eq!(
+            find_root_brent_expand(0f64, 1.25f64, &f, 10, &mut conv),
+            find_root_brent(0f64, 3.25f64, &f, &mut conv)
+        );
+        // Bracketed already
+        assert_eq!(
+            find_root_brent_expand(0f64, 10f64, &f, 0, &mut conv),
+            find_root_brent(0f64, 10f64, &f, &mut conv)
+        );
+        assert!(matches!(
+            find_root_brent_expand(0f64, 1f64, &f, 0, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_brent_in() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = 1e-15f64;
+
+        assert_eq!(
+            find_root_brent_in(0f64..=10f64, &f, &mut conv),
+            find_root_brent(0f64, 10f64, &f, &mut conv)
+        );
+        assert_float_eq!(
+            1e-15f64,
+            find_root_brent_in(-10f64..=0f64, &f, &mut conv).ok().unwrap(),
+            -1f64
+        );
+        assert!(matches!(
+            find_root_brent_in(2f64..=3f64, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+
+        // Empty and infinite ranges
+        assert_eq!(
+            find_root_brent_in(10f64..=0f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::EmptyRange
+            })
+        );
+        assert_eq!(
+            find_root_brent_in(0f64..=f64::INFINITY, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            find_root_brent_in(f64::NEG_INFINITY..=0f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            find_root_brent_in(f64::NAN..=10f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_root_brent_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let bracket = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteBracket));
+        assert_eq!(find_root_brent(f64::NAN, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_brent(0.5f64, f64::NAN, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_brent(f64::NEG_INFINITY, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_brent(0.5f64, f64::INFINITY, &f, &mut 1e-15f64), bracket);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_brent(0f64, 10f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_brent(10f64, -1f64, &f, &mut 1e-15f64), value);
+        assert_eq!(
+            find_root_brent_noisy(f64::NAN, 10f64, &f, 1e-9f64, &mut 1e-15f64).map(|(x, _)| x),
+            bracket
+        );
+        assert_eq!(
+            find_root_brent_noisy(0f64, 10f64, &f, 1e-9f64, &mut 1e-15f64).map(|(x, _)| x),
+            value
+        );
+        assert_eq!(
+            find_root_brent_with_guess(0.5f64, 10f64, f64::NAN, &f, &mut 1e-15f64),
+            Err(SearchError::invalid_input(InvalidInputReason::NotFiniteStart))
         );
     }
 }
@@ -0,0 +1,190 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+
+/// Number of the values remembered by CachedFn
+pub const CACHE_SIZE: usize = 8;
+
+/// Function wrapper which remembers the last values of the function.
+///
+/// The last `CACHE_SIZE` pairs (x, f(x)) are kept in a ring buffer; when it is full, the oldest pair
+/// is replaced by the new one. The function is called only if x is not found in the buffer.
+/// Values of x are compared exactly, including the sign of zero, and NaN is never found.
+/// Stable Rust does not allow implementing FnMut for a structure, so pass `|x| cached.call(x)` to solvers.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_expand;
+/// use roots::CachedFn;
+///
+/// let mut cached = CachedFn::new(|x: f64| x * x - 4f64);
+///
+/// // The values at the ends of the expanded bracket are not calculated again by the Brent method
+/// let root = find_root_brent_expand(0f64, 1f64, |x| cached.call(x), 10, &mut 1e-15f64);
+/// // Returns approximately Ok(2);
+/// let hits = cached.hits();
+/// // Returns 2
+/// ```
+pub struct CachedFn<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    f: Func,
+    entries: [Option<(F, F)>; CACHE_SIZE],
+    /// Position of the next entry to be replaced
+    next: usize,
+    hits: usize,
+    misses: usize,
+}
+
+impl<F, Func> CachedFn<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    /// Wrap the function with the empty cache
+    pub fn new(f: Func) -> Self {
+        CachedFn {
+            f,
+            entries: [None; CACHE_SIZE],
+            next: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Value of the function at x, taken from the cache if possible
+    pub fn call(&mut self, x: F) -> F {
+        for &(cached_x, cached_y) in self.entries.iter().flatten() {
+            if cached_x == x && cached_x.to_f64().is_sign_negative() == x.to_f64().is_sign_negative() {
+                self.hits += 1;
+                return cached_y;
+            }
+        }
+        self.misses += 1;
+        let y = (self.f)(x);
+        self.entries[self.next] = Some((x, y));
+        self.next = (self.next + 1) % CACHE_SIZE;
+        y
+    }
+
+    /// Number of values taken from the cache
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of calls of the function
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Forget all remembered values; the counters are kept
+    pub fn clear(&mut self) {
+        self.entries = [None; CACHE_SIZE];
+        self.next = 0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_cached_fn() {
+        let calls = Cell::new(0);
+        let mut cached = CachedFn::new(|x: f64| {
+            calls.set(calls.get() + 1);
+            x * x
+        });
+
+        assert_eq!(cached.call(2f64), 4f64);
+        assert_eq!(cached.call(2f64), 4f64);
+        assert_eq!(cached.call(0f64), 0f64);
+        assert_eq!(cached.call(0f64), 0f64);
+        assert!(cached.call(f64::NAN).is_nan());
+        assert!(cached.call(f64::NAN).is_nan());
+        assert_eq!((cached.hits(), cached.misses(), calls.get()), (2, 4, 4));
+
+        // The oldest value is replaced when the buffer is full
+        for i in 0..CACHE_SIZE {
+            cached.call(10f64 + i as f64);
+        }
+        assert_eq!((cached.hits(), cached.misses()), (2, 4 + CACHE_SIZE));
+        cached.call(10f64 + (CACHE_SIZE - 1) as f64);
+        assert_eq!(cached.hits(), 3);
+        cached.call(2f64);
+        assert_eq!(cached.misses(), 5 + CACHE_SIZE);
+        cached.call(10f64);
+        assert_eq!(cached.misses(), 6 + CACHE_SIZE);
+
+        cached.clear();
+        cached.call(2f64);
+        assert_eq!(
+            (cached.hits(), cached.misses(), calls.get()),
+            (3, 7 + CACHE_SIZE, 7 + CACHE_SIZE)
+        );
+    }
+
+    #[test]
+    fn test_cached_fn_signed_zero() {
+        let mut cached = CachedFn::new(|x: f64| 1f64 / x);
+
+        assert_eq!(cached.call(0f64), f64::INFINITY);
+        assert_eq!(cached.call(-0f64), f64::NEG_INFINITY);
+        assert_eq!(cached.call(-0f64), f64::NEG_INFINITY);
+        assert_eq!(cached.call(0f64), f64::INFINITY);
+        assert_eq!((cached.hits(), cached.misses()), (2, 2));
+    }
+
+    #[test]
+    fn test_cached_fn_brent() {
+        let f = |x: f64| x * x - 4f64;
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 30,
+        };
+        let calls = Cell::new(0);
+        let counted = |x: f64| {
+            calls.set(calls.get() + 1);
+            f(x)
+        };
+
+        // The Brent method after the expansion of the bracket
+        let root = brent::find_root_brent_expand(0f64, 1f64, &counted, 10, &mut conv);
+        let uncached_calls = calls.get();
+        calls.set(0);
+        let mut cached = CachedFn::new(&counted);
+        assert_eq!(
+            brent::find_root_brent_expand(0f64, 1f64, |x| cached.call(x), 10, &mut conv),
+            root
+        );
+        assert_eq!(cached.hits(), 2);
+        assert_eq!(calls.get(), uncached_calls - 2);
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::check_bracket;
+use super::check_value;
+use super::Convergency;
+use super::SearchError;
+
+/// Find a root of the function f(x) = 0 using the Chandrupatla's method.
+///
+/// Pro
+///
+/// + Fast: inverse quadratic interpolation when the function is smooth enough
+/// + Robust: bisection otherwise, and the root stays bracketed
+/// + Simpler than Brent
+/// + No need for derivative function
+///
+/// Contra
+///
+/// - Needs initial bracketing
+///
+/// See T.R. Chandrupatla, A new hybrid quadratic/bisection algorithm for finding the zero of a nonlinear function
+/// without using derivatives, Advances in Engineering Software 28 (1997), 145-149.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_chandrupatla;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:30 };
+///
+/// let root1 = find_root_chandrupatla(10f64, 0f64, &f, &mut convergency);
+/// // Returns approximately Ok(1);
+///
+/// let root2 = find_root_chandrupatla(-10f64, 0f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(-1);
+/// ```
+pub fn find_root_chandrupatla<F, Func>(
+    a: F,
+    b: F,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    check_bracket(a, b)?;
+    let (mut a, mut b) = (b, a);
+    let mut fa = f(a);
+    check_value(fa)?;
+    if convergency.is_root_found(fa) {
+        return Ok(a);
+    }
+    let mut fb = f(b);
+    check_value(fb)?;
+    if convergency.is_root_found(fb) {
+        return Ok(b);
+    }
+    if fa * fb > F::zero() {
+        return Err(SearchError::no_bracketing(a, b));
+    }
+
+    let (mut c, mut fc);
+    let mut t = F::one() / _2;
+    let mut iter = 0;
+    loop {
+        // The new point replaces a, while the root stays between a and b; c is the discarded point
+        let xt = a + t * (b - a);
+        let ft = f(xt);
+        if (ft < F::zero()) == (fa < F::zero()) {
+            c = a;
+            fc = fa;
+        } else {
+            c = b;
+            fc = fb;
+            b = a;
+            fb = fa;
+        }
+        a = xt;
+        fa = ft;
+
+        let (xm, fm) = if fa.abs() < fb.abs() { (a, fa) } else { (b, fb) };
+        if convergency.is_root_found(fm) || convergency.is_converged(a, b) {
+            return Ok(xm);
+        }
+        // Steps shorter than rounding errors of xm are useless
+        let tlim = _2 * F::epsilon() * xm.abs() / (b - c).abs();
+        if tlim > F::one() / _2 {
+            return Ok(xm);
+        }
+
+        // Inverse quadratic interpolation if the function is monotonic and smooth enough, bisection otherwise
+        let xi = (a - b) / (c - b);
+        let phi = (fa - fb) / (fc - fb);
+        t = if phi * phi < xi && (F::one() - phi) * (F::one() - phi) < F::one() - xi {
+            fa / (fb - fa) * fc / (fb - fc) + (c - a) / (b - a) * fa / (fc - fa) * fb / (fc - fb)
+        } else {
+            F::one() / _2
+        };
+        if t < tlim {
+            t = tlim;
+        } else if t > F::one() - tlim {
+            t = F::one() - tlim;
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency(iter, xm, fm).with_bracket(a, b));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_find_root_chandrupatla() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_chandrupatla(10f64, 0f64, &f, &mut conv).ok().unwrap(),
+            1f64
+        );
+        assert_eq!(8, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_chandrupatla(-10f64, 0f64, &f, &mut conv).ok().unwrap(),
+            -1f64
+        );
+        assert_eq!(8, conv.get_iter_count());
+
+        conv.reset();
+        assert!(matches!(
+            find_root_chandrupatla(10f64, 20f64, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(0, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_chandrupatla_vs_brent() {
+        let functions: [fn(f64) -> f64; 3] = [
+            |x| x * x - 1f64,
+            |x| x * x * x * x - 1f64,
+            // The inflection point 0.9 is close to the root
+            |x| (x - 0.9f64) * (x - 0.9f64) * (x - 0.9f64) - 0.001f64,
+        ];
+        for f in functions.iter() {
+            let mut evaluations = 0;
+            let found = find_root_chandrupatla(
+                0f64,
+                10f64,
+                |x| {
+                    evaluations += 1;
+                    f(x)
+                },
+                &mut 1e-15f64,
+            );
+            let mut brent_evaluations = 0;
+            let brent = brent::find_root_brent(
+                0f64,
+                10f64,
+                |x| {
+                    brent_evaluations += 1;
+                    f(x)
+                },
+                &mut 1e-15f64,
+            );
+            assert_float_eq!(1e-15f64, found.ok().unwrap(), 1f64);
+            assert_float_eq!(1e-12f64, brent.ok().unwrap(), 1f64);
+            assert!(evaluations < brent_evaluations);
+        }
+    }
+
+    #[test]
+    fn test_find_root_chandrupatla_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let bracket = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteBracket));
+        assert_eq!(find_root_chandrupatla(f64::NAN, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_chandrupatla(0.5f64, f64::NAN, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_chandrupatla(f64::NEG_INFINITY, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_chandrupatla(0.5f64, f64::INFINITY, &f, &mut 1e-15f64), bracket);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_chandrupatla(0f64, 10f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_chandrupatla(10f64, -1f64, &f, &mut 1e-15f64), value);
+    }
+}
@@ -0,0 +1,370 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::Convergency;
+
+/// Convergency rules satisfied when both children are satisfied.
+///
+/// Each of `is_root_found`, `is_converged` and `is_iteration_limit_reached` returns true
+/// only if it returns true for both children. Both children are always asked, the first one first,
+/// so children keeping a state (counters, magnitudes) see every check.
+/// The search stops by the iteration limit only when both limits are reached, i.e. at the greater one.
+/// More children are combined by nesting: `AllOf(a, AllOf(b, c))`.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent;
+/// use roots::AllOf;
+/// use roots::RelativeConvergency;
+/// use roots::SimpleConvergency;
+///
+/// let f = |x| { 1f64*x*x - 2f64 };
+/// // x within 1e-10 relative and within 1e-12 absolute, |f| below 1e-12
+/// let mut convergency = AllOf(
+///     RelativeConvergency { rel_eps: 1e-10f64, abs_floor: 1e-12f64, max_iter: 100 },
+///     SimpleConvergency { eps: 1e-12f64, max_iter: 100 },
+/// );
+///
+/// let root = find_root_brent(0f64, 10f64, &f, &mut convergency);
+/// // Returns approximately Ok(1.4142135623730951);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AllOf<A, B>(pub A, pub B);
+
+impl<F: FloatType, A: Convergency<F>, B: Convergency<F>> Convergency<F> for AllOf<A, B> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        let first = self.0.is_root_found(y);
+        let second = self.1.is_root_found(y);
+        first && second
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        let first = self.0.is_converged(x1, x2);
+        let second = self.1.is_converged(x1, x2);
+        first && second
+    }
+    fn is_converged_at(&mut self, x1: F, x2: F, y: F) -> bool {
+        let first = self.0.is_converged_at(x1, x2, y);
+        let second = self.1.is_converged_at(x1, x2, y);
+        first && second
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        let first = self.0.is_iteration_limit_reached(iter);
+        let second = self.1.is_iteration_limit_reached(iter);
+        first && second
+    }
+}
+
+/// Convergency rules satisfied when either child is satisfied.
+///
+/// Each of `is_root_found`, `is_converged` and `is_iteration_limit_reached` returns true
+/// if it returns true for at least one child. Both children are always asked, the first one first,
+/// so children keeping a state (counters, magnitudes) see every check.
+/// The search stops by the iteration limit when either limit is reached, i.e. at the smaller one.
+/// More children are combined by nesting: `AnyOf(a, AnyOf(b, c))`.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent;
+/// use roots::AnyOf;
+/// use roots::RelativeConvergency;
+/// use roots::SimpleConvergency;
+///
+/// let f = |x| { (x - 1e8f64)*(x + 1f64) + 0.5f64 };
+/// // x within 1e-15 relative or within 1e-15 absolute, at most 200 iterations
+/// let mut convergency = AnyOf(
+///     RelativeConvergency { rel_eps: 1e-15f64, abs_floor: 0f64, max_iter: 200 },
+///     SimpleConvergency { eps: 1e-15f64, max_iter: 200 },
+/// );
+///
+/// let root = find_root_brent(1e7f64, 1e9f64, &f, &mut convergency);
+/// // Returns approximately Ok(1e8);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AnyOf<A, B>(pub A, pub B);
+
+impl<F: FloatType, A: Convergency<F>, B: Convergency<F>> Convergency<F> for AnyOf<A, B> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        let first = self.0.is_root_found(y);
+        let second = self.1.is_root_found(y);
+        first || second
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        let first = self.0.is_converged(x1, x2);
+        let second = self.1.is_converged(x1, x2);
+        first || second
+    }
+    fn is_converged_at(&mut self, x1: F, x2: F, y: F) -> bool {
+        let first = self.0.is_converged_at(x1, x2, y);
+        let second = self.1.is_converged_at(x1, x2, y);
+        first || second
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        let first = self.0.is_iteration_limit_reached(iter);
+        let second = self.1.is_iteration_limit_reached(iter);
+        first || second
+    }
+}
+
+/// Convergency rules built by `ConvergencyBuilder`.
+///
+/// Two values of X are converged when |x1 - x2| <= x_abs + x_rel * max(|x1|, |x2|),
+/// a root is found when |y| <= residual, and the iteration limit is reached at max_iter.
+/// The tolerances which have not been set are zero, so identical values of X are always converged
+/// and the exact zero of the function is always a root.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ToleranceConvergency<F: FloatType> {
+    x_abs: F,
+    x_rel: F,
+    residual: F,
+    max_iter: usize,
+}
+
+impl<F: FloatType> Convergency<F> for ToleranceConvergency<F> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        y.abs() <= self.residual
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        let magnitude = if x1.abs() > x2.abs() { x1.abs() } else { x2.abs() };
+        (x1 - x2).abs() <= self.x_abs + self.x_rel * magnitude
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        iter >= self.max_iter
+    }
+}
+
+/// Builder of the convergency rules from the tolerances of X and Y.
+///
+/// The tolerances are set one by one; the ones not set are zero. The maximal number
+/// of iterations is 100 unless set. See `ToleranceConvergency` for the rules.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent;
+/// use roots::ConvergencyBuilder;
+///
+/// let f = |x| { 1f64*x*x - 2f64 };
+/// let mut convergency = ConvergencyBuilder::new().x_abs(1e-12).x_rel(1e-10).residual(1e-9).max_iter(100).build();
+///
+/// let root = find_root_brent(0f64, 10f64, &f, &mut convergency);
+/// // Returns approximately Ok(1.4142135623730951);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ConvergencyBuilder<F: FloatType> {
+    convergency: ToleranceConvergency<F>,
+}
+
+impl<F: FloatType> ConvergencyBuilder<F> {
+    /// Builder with all tolerances zero and 100 iterations
+    pub fn new() -> Self {
+        ConvergencyBuilder {
+            convergency: ToleranceConvergency {
+                x_abs: F::zero(),
+                x_rel: F::zero(),
+                residual: F::zero(),
+                max_iter: 100,
+            },
+        }
+    }
+
+    /// Set the absolute precision of X
+    pub fn x_abs(self, x_abs: F) -> Self {
+        ConvergencyBuilder {
+            convergency: ToleranceConvergency {
+                x_abs: x_abs.abs(),
+                ..self.convergency
+            },
+        }
+    }
+
+    /// Set the precision of X relative to the magnitude of X
+    pub fn x_rel(self, x_rel: F) -> Self {
+        ConvergencyBuilder {
+            convergency: ToleranceConvergency {
+                x_rel: x_rel.abs(),
+                ..self.convergency
+            },
+        }
+    }
+
+    /// Set the precision of Y
+    pub fn residual(self, residual: F) -> Self {
+        ConvergencyBuilder {
+            convergency: ToleranceConvergency {
+                residual: residual.abs(),
+                ..self.convergency
+            },
+        }
+    }
+
+    /// Set the maximal number of iterations
+    pub fn max_iter(self, max_iter: usize) -> Self {
+        ConvergencyBuilder {
+            convergency: ToleranceConvergency {
+                max_iter,
+                ..self.convergency
+            },
+        }
+    }
+
+    /// Convergency rules with the tolerances set
+    pub fn build(self) -> ToleranceConvergency<F> {
+        self.convergency
+    }
+}
+
+impl<F: FloatType> Default for ConvergencyBuilder<F> {
+    fn default() -> Self {
+        ConvergencyBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Call {
+        Root(f64),
+        Converged(f64, f64),
+        Limit(usize),
+    }
+
+    /// Convergency giving the same answer to every check and recording the checks
+    struct Mock {
+        answer: bool,
+        calls: Vec<Call>,
+    }
+
+    impl Mock {
+        fn new(answer: bool) -> Mock {
+            Mock {
+                answer,
+                calls: Vec::new(),
+            }
+        }
+    }
+
+    impl Convergency<f64> for Mock {
+        fn is_root_found(&mut self, y: f64) -> bool {
+            self.calls.push(Call::Root(y));
+            self.answer
+        }
+        fn is_converged(&mut self, x1: f64, x2: f64) -> bool {
+            self.calls.push(Call::Converged(x1, x2));
+            self.answer
+        }
+        fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+            self.calls.push(Call::Limit(iter));
+            self.answer
+        }
+    }
+
+    fn check(convergency: &mut dyn Convergency<f64>) -> [bool; 3] {
+        [
+            convergency.is_root_found(1f64),
+            convergency.is_converged(2f64, 3f64),
+            convergency.is_iteration_limit_reached(4),
+        ]
+    }
+
+    #[test]
+    fn test_all_of() {
+        let calls = vec![Call::Root(1f64), Call::Converged(2f64, 3f64), Call::Limit(4)];
+        for &(first, second) in [(false, false), (false, true), (true, false), (true, true)].iter() {
+            let mut convergency = AllOf(Mock::new(first), Mock::new(second));
+            assert_eq!(check(&mut convergency), [first && second; 3]);
+            // Both children see every check, even when the first one decides
+            assert_eq!(convergency.0.calls, calls);
+            assert_eq!(convergency.1.calls, calls);
+        }
+
+        let mut convergency = AllOf(Mock::new(true), AllOf(Mock::new(true), Mock::new(false)));
+        assert_eq!(check(&mut convergency), [false; 3]);
+        assert_eq!((convergency.1).1.calls, calls);
+    }
+
+    #[test]
+    fn test_any_of() {
+        let calls = vec![Call::Root(1f64), Call::Converged(2f64, 3f64), Call::Limit(4)];
+        for &(first, second) in [(false, false), (false, true), (true, false), (true, true)].iter() {
+            let mut convergency = AnyOf(Mock::new(first), Mock::new(second));
+            assert_eq!(check(&mut convergency), [first || second; 3]);
+            assert_eq!(convergency.0.calls, calls);
+            assert_eq!(convergency.1.calls, calls);
+        }
+
+        let mut convergency = AnyOf(Mock::new(false), AnyOf(Mock::new(false), Mock::new(true)));
+        assert_eq!(check(&mut convergency), [true; 3]);
+        assert_eq!((convergency.1).1.calls, calls);
+    }
+
+    #[test]
+    fn test_all_of_any_of_iteration_limits() {
+        let simple = |max_iter| simple_convergency::SimpleConvergency { eps: 1e-15f64, max_iter };
+        let mut all = AllOf(simple(10), simple(20));
+        let mut any = AnyOf(simple(10), simple(20));
+        assert!(!all.is_iteration_limit_reached(10));
+        assert!(all.is_iteration_limit_reached(20));
+        assert!(any.is_iteration_limit_reached(10));
+    }
+
+    #[test]
+    fn test_convergency_builder() {
+        let mut convergency = ConvergencyBuilder::new()
+            .x_abs(1e-12)
+            .x_rel(1e-10)
+            .residual(1e-9)
+            .max_iter(50)
+            .build();
+        assert!(convergency.is_converged(1e3f64, 1e3f64 + 1e-7f64 + 1e-12f64 / 2f64));
+        assert!(!convergency.is_converged(1e3f64, 1e3f64 + 2e-7f64));
+        assert!(convergency.is_converged(0f64, 1e-12f64));
+        assert!(!convergency.is_converged(0f64, 2e-12f64));
+        assert!(convergency.is_root_found(-1e-9f64));
+        assert!(!convergency.is_root_found(2e-9f64));
+        assert!(!convergency.is_iteration_limit_reached(49));
+        assert!(convergency.is_iteration_limit_reached(50));
+
+        // Nothing set: only identical values and the exact zero, 100 iterations
+        let mut convergency = ConvergencyBuilder::<f64>::default().build();
+        assert!(convergency.is_converged(1f64, 1f64));
+        assert!(!convergency.is_converged(1f64, 1f64 + f64::EPSILON));
+        assert!(convergency.is_root_found(0f64));
+        assert!(!convergency.is_root_found(f64::MIN_POSITIVE));
+        assert!(convergency.is_iteration_limit_reached(100));
+
+        let f = |x: f64| x * x - 2f64;
+        let mut convergency = ConvergencyBuilder::new().x_rel(1e-14).residual(1e-14).build();
+        assert_float_eq!(
+            1e-14f64,
+            brent::find_root_brent(0f64, 10f64, &f, &mut convergency).ok().unwrap(),
+            2f64.sqrt()
+        );
+    }
+}
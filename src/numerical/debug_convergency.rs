@@ -26,51 +26,170 @@ use super::super::FloatType;
 use super::Convergency;
 use std::fmt::Display;
 use std::fmt::LowerExp;
+use std::io;
+use std::io::Write;
+
+/// Sink printing to the standard output with `print!`, so that the output is captured by the tests
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PrintSink;
+
+impl Write for PrintSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        print!("{}", String::from_utf8_lossy(buf));
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()
+    }
+}
 
 /// Convergency provider for debugging.
-/// It will print out the error at each iteration.
-pub struct DebugConvergency<F: FloatType> {
+/// It will write out the error at each iteration, one line per check.
+///
+/// `new` prints to the standard output; `with_writer` writes to any `std::io::Write`.
+/// Errors of the writer do not stop the search; `has_write_failed` tells if any write has failed.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent;
+/// use roots::DebugConvergency;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let mut convergency = DebugConvergency::with_writer(1e-15f64, 30, Vec::new());
+///
+/// let root = find_root_brent(10f64, 0f64, &f, &mut convergency);
+/// // Returns approximately Ok(1);
+///
+/// let log = String::from_utf8(convergency.into_writer()).unwrap();
+/// // Starts with "#0 check root 9.900000000000000e1"
+/// ```
+pub struct DebugConvergency<F: FloatType, W: Write = PrintSink> {
     /// Precision for both X and Y axes
     eps: F,
     /// Maximum number of iterations
     max_iter: usize,
     /// Last iteration
     iter: usize,
+    /// Sink of the output
+    writer: W,
+    /// Whether any write has failed
+    write_failed: bool,
+}
+
+impl<F: FloatType> DebugConvergency<F, PrintSink> {
+    pub fn new(eps: F, max_iter: usize) -> DebugConvergency<F, PrintSink> {
+        DebugConvergency::with_writer(eps, max_iter, PrintSink)
+    }
 }
 
-impl<F: FloatType> DebugConvergency<F> {
-    pub fn new(eps: F, max_iter: usize) -> DebugConvergency<F> {
+impl<F: FloatType, W: Write> DebugConvergency<F, W> {
+    pub fn with_writer(eps: F, max_iter: usize, writer: W) -> DebugConvergency<F, W> {
         DebugConvergency {
-            eps: eps,
-            max_iter: max_iter,
+            eps,
+            max_iter,
             iter: 0,
+            writer,
+            write_failed: false,
         }
     }
 
-    pub fn reset(self: &mut DebugConvergency<F>) {
+    pub fn reset(self: &mut DebugConvergency<F, W>) {
         self.iter = 0;
     }
 
-    pub fn get_iter_count(self: &DebugConvergency<F>) -> usize {
+    pub fn get_iter_count(self: &DebugConvergency<F, W>) -> usize {
         self.iter
     }
+
+    /// Whether any write has failed since the creation
+    pub fn has_write_failed(self: &DebugConvergency<F, W>) -> bool {
+        self.write_failed
+    }
+
+    pub fn writer(self: &DebugConvergency<F, W>) -> &W {
+        &self.writer
+    }
+
+    pub fn into_writer(self: DebugConvergency<F, W>) -> W {
+        self.writer
+    }
 }
 
-impl<F: FloatType + Display + LowerExp> Convergency<F> for DebugConvergency<F> {
-    /// Prints the value being checked
+impl<F: FloatType + Display + LowerExp, W: Write> Convergency<F> for DebugConvergency<F, W> {
+    /// Writes the value being checked
     fn is_root_found(&mut self, y: F) -> bool {
-        println!("#{} check root {:.15e}", self.iter, y);
+        if writeln!(self.writer, "#{} check root {:.15e}", self.iter, y).is_err() {
+            self.write_failed = true;
+        }
         y.abs() < self.eps.abs()
     }
-    /// Prints values being checked
+    /// Writes values being checked
     fn is_converged(&mut self, x1: F, x2: F) -> bool {
-        println!("#{} check convergency {:.15e}-{:.15e}", self.iter, x1, x2);
+        if writeln!(self.writer, "#{} check convergency {:.15e}-{:.15e}", self.iter, x1, x2).is_err() {
+            self.write_failed = true;
+        }
         (x1 - x2).abs() < self.eps.abs()
     }
     /// Updates internal iteration counter
     fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
-        println!("#{} check iteration limit {}", self.iter, iter);
+        if writeln!(self.writer, "#{} check iteration limit {}", self.iter, iter).is_err() {
+            self.write_failed = true;
+        }
         self.iter = iter;
         iter >= self.max_iter
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_debug_convergency_writer() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = DebugConvergency::with_writer(1e-15f64, 30, Vec::new());
+
+        assert_float_eq!(
+            1e-15f64,
+            brent::find_root_brent(10f64, 0f64, &f, &mut conv).ok().unwrap(),
+            1f64
+        );
+        assert_eq!(10, conv.get_iter_count());
+        assert!(!conv.has_write_failed());
+
+        let log = String::from_utf8(conv.into_writer()).unwrap();
+        let lines: Vec<&str> = log.lines().collect();
+        // 5 checks per iteration, and 2 checks of the values at the ends of the last bracket
+        assert_eq!(lines.len(), 10 * 5 + 2);
+        assert_eq!(lines.iter().filter(|line| line.contains("check iteration limit")).count(), 10);
+        assert_eq!(lines[0], "#0 check root 9.900000000000000e1");
+        assert_eq!(lines[lines.len() - 1].split(' ').next(), Some("#10"));
+    }
+
+    struct Failing;
+
+    impl Write for Failing {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::Other, "failing"))
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_debug_convergency_failing_writer() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = DebugConvergency::with_writer(1e-15f64, 30, Failing);
+
+        assert_float_eq!(
+            1e-15f64,
+            brent::find_root_brent(10f64, 0f64, &f, &mut conv).ok().unwrap(),
+            1f64
+        );
+        assert_eq!(10, conv.get_iter_count());
+        assert!(conv.has_write_failed());
+    }
+}
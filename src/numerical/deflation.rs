@@ -0,0 +1,193 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+
+/// Divides the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1]
+/// by (x - root) and returns the normalized quotient; the remainder is dropped.
+///
+/// The synthetic division runs from the highest power (forward deflation).
+/// It is accurate when the root is of the smallest magnitude; see deflate_stable otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use roots::deflate;
+///
+/// // (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+/// let quotient = deflate(&[-6f64, 11f64, -6f64], 1f64);
+/// // Returns vec![-5f64, 6f64] as (x - 2)(x - 3) = x^2 - 5x + 6
+/// ```
+pub fn deflate<F: FloatType>(a: &[F], root: F) -> Vec<F> {
+    let mut q = Vec::with_capacity(a.len());
+    let mut value = F::one();
+    for c in a[..a.len().saturating_sub(1)].iter() {
+        value = value * root + *c;
+        q.push(value);
+    }
+    q
+}
+
+/// Divides the normalized polynomial by (x - root) from the lowest power (backward deflation);
+/// the remainder at the highest power is dropped. Accurate when the root is of the largest magnitude.
+fn deflate_backward<F: FloatType>(a: &[F], root: F) -> Vec<F> {
+    let n = a.len();
+    let mut q = vec![F::zero(); n.saturating_sub(1)];
+    let mut next = F::zero();
+    for k in (1..n).rev() {
+        // a[k] = q[k - 1] - root * q[k], hence q[k - 1] = (q[k] - a[k]) / root with q[n - 1] = 0
+        next = (next - a[k]) / root;
+        q[k - 1] = next;
+    }
+    q
+}
+
+/// Divides the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1]
+/// by (x - root) and returns the normalized quotient.
+///
+/// Deflation is forward if the magnitude of the root does not exceed the geometric mean of the magnitudes
+/// of all roots, i.e. |a[n-1]|^(1/n), and backward otherwise. This keeps the errors of the coefficients
+/// of the quotient small, as the division runs from the end where the root is dominated by the other roots.
+///
+/// # Examples
+///
+/// ```
+/// use roots::deflate_stable;
+///
+/// // (x - 0.001)(x - 1)(x - 1000)
+/// let quotient = deflate_stable(&[-1001.001f64, 1001.001f64, -1f64], 1000f64);
+/// // Returns approximately vec![-1.001f64, 0.001f64] as (x - 0.001)(x - 1) = x^2 - 1.001x + 0.001
+/// ```
+pub fn deflate_stable<F: FloatType>(a: &[F], root: F) -> Vec<F> {
+    let n = a.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mean = a[n - 1].abs().powf(F::one() / F::from(n as i16));
+    if root.abs() <= mean {
+        deflate(a, root)
+    } else {
+        deflate_backward(a, root)
+    }
+}
+
+/// Divides the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1]
+/// by the quadratic factor x^2 - sum*x + product of a pair of complex conjugate roots
+/// and returns the normalized quotient; the remainder is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use roots::deflate_quadratic;
+///
+/// // (x^2 + 1)(x - 2) = x^3 - 2x^2 + x - 2
+/// let quotient = deflate_quadratic(&[-2f64, 1f64, -2f64], 0f64, 1f64);
+/// // Returns vec![-2f64] as the roots i and -i have the sum 0 and the product 1
+/// ```
+pub fn deflate_quadratic<F: FloatType>(a: &[F], sum: F, product: F) -> Vec<F> {
+    let mut q = Vec::with_capacity(a.len());
+    let (mut previous, mut value) = (F::zero(), F::one());
+    for c in a[..a.len().saturating_sub(2)].iter() {
+        let next = *c + sum * value - product * previous;
+        previous = value;
+        value = next;
+        q.push(value);
+    }
+    q
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Normalized coefficients of the polynomial with the given roots
+    fn from_roots(roots: &[f64]) -> Vec<f64> {
+        let mut c = vec![1f64];
+        for r in roots {
+            c.push(0f64);
+            for i in (1..c.len()).rev() {
+                c[i] -= r * c[i - 1];
+            }
+        }
+        c.remove(0);
+        c
+    }
+
+    /// Largest relative error of the coefficients
+    fn relative_error(actual: &[f64], expected: &[f64]) -> f64 {
+        actual
+            .iter()
+            .zip(expected.iter())
+            .map(|(a, e)| ((a - e) / e).abs())
+            .fold(0f64, |m, x| if x > m { x } else { m })
+    }
+
+    #[test]
+    fn test_deflate() {
+        assert_float_array_eq!(0f64, deflate(&[-6f64, 11f64, -6f64], 1f64), [-5f64, 6f64]);
+        assert_float_array_eq!(0f64, deflate(&[-6f64, 11f64, -6f64], 3f64), [-3f64, 2f64]);
+        assert!(deflate(&[-1f64], 1f64).is_empty());
+        assert_float_array_eq!(0f64, deflate(&[0f64, 0f64], 0f64), [0f64]);
+        assert!(deflate::<f64>(&[], 1f64).is_empty());
+    }
+
+    #[test]
+    fn test_deflate_stable() {
+        assert_float_array_eq!(1e-15f64, deflate_stable(&[-6f64, 11f64, -6f64], 1f64), [-5f64, 6f64]);
+        assert_float_array_eq!(1e-15f64, deflate_stable(&[-6f64, 11f64, -6f64], 3f64), [-3f64, 2f64]);
+        // Zero constant term: the root 0 is divided out forward
+        assert_float_array_eq!(0f64, deflate_stable(&[-3f64, 2f64, 0f64], 0f64), [-3f64, 2f64]);
+        assert!(deflate_stable::<f64>(&[], 1f64).is_empty());
+    }
+
+    #[test]
+    fn test_deflate_stable_spread_roots() {
+        let roots = [1e-3f64, 1f64, 1e3f64, 1e6f64, 1e9f64];
+        let polynom = from_roots(&roots);
+        let expected = from_roots(&roots[..4]);
+
+        let forward = relative_error(&deflate(&polynom, 1e9f64), &expected);
+        let backward = relative_error(&deflate_stable(&polynom, 1e9f64), &expected);
+        assert!(backward < 1e-15f64);
+        // Forward deflation by the largest root loses all digits of the lowest coefficients
+        assert!(forward > 1f64);
+
+        // The smallest root is divided out forward
+        let expected = from_roots(&roots[1..]);
+        assert!(relative_error(&deflate_stable(&polynom, 1e-3f64), &expected) < 1e-14f64);
+    }
+
+    #[test]
+    fn test_deflate_quadratic() {
+        // (x^2 + 1)(x - 2)
+        assert_float_array_eq!(0f64, deflate_quadratic(&[-2f64, 1f64, -2f64], 0f64, 1f64), [-2f64]);
+        // (x^2 - 2x + 5)(x^2 - 4x + 3); roots 1 ± 2i, 1, 3
+        let polynom = [-6f64, 16f64, -26f64, 15f64];
+        assert_float_array_eq!(0f64, deflate_quadratic(&polynom, 2f64, 5f64), [-4f64, 3f64]);
+        assert!(deflate_quadratic(&[-2f64, 5f64], 2f64, 5f64).is_empty());
+    }
+}
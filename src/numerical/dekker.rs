@@ -0,0 +1,225 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::check_bracket;
+use super::check_value;
+use super::Convergency;
+use super::SearchError;
+
+/// Find a root of the function f(x) = 0 using the Dekker's method.
+///
+/// Pro
+///
+/// + Fast for well-behaved functions
+/// + The root stays bracketed
+/// + No need for derivative function
+///
+/// Contra
+///
+/// - Needs initial bracketing
+/// - Secant steps can creep slowly towards a false target; the Brent's method does not suffer from this
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_dekker;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:30 };
+///
+/// let root1 = find_root_dekker(10f64, 0f64, &f, &mut convergency);
+/// // Returns approximately Ok(1);
+///
+/// let root2 = find_root_dekker(-10f64, 0f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(-1);
+/// ```
+pub fn find_root_dekker<F, Func>(
+    a: F,
+    b: F,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    check_bracket(a, b)?;
+    let (mut a, mut fa) = (a, f(a));
+    check_value(fa)?;
+    if convergency.is_root_found(fa) {
+        return Ok(a);
+    }
+    let (mut b, mut fb) = (b, f(b));
+    check_value(fb)?;
+    if convergency.is_root_found(fb) {
+        return Ok(b);
+    }
+    if fa * fb > F::zero() {
+        return Err(SearchError::no_bracketing(a, b));
+    }
+    // b is the best approximation, a is the contrapoint
+    if fa.abs() < fb.abs() {
+        ::std::mem::swap(&mut a, &mut b);
+        ::std::mem::swap(&mut fa, &mut fb);
+    }
+    // c is the previous approximation
+    let (mut c, mut fc) = (a, fa);
+
+    let mut iter = 0;
+    loop {
+        if convergency.is_converged(a, b) {
+            return Ok(b);
+        }
+
+        let m = (a + b) / _2;
+        let mut s = if fb != fc { b - fb * (b - c) / (fb - fc) } else { m };
+        // The secant step must stay between b and the midpoint, otherwise bisect
+        if (s - b) * (s - m) > F::zero() {
+            s = m;
+        }
+        // Steps shorter than rounding errors of b make no progress
+        let tol = _2 * F::epsilon() * b.abs();
+        if (s - b).abs() < tol {
+            s = if m > b { b + tol } else { b - tol };
+        }
+
+        c = b;
+        fc = fb;
+        b = s;
+        fb = f(b);
+        if convergency.is_root_found(fb) {
+            return Ok(b);
+        }
+        if (fa < F::zero()) == (fb < F::zero()) {
+            a = c;
+            fa = fc;
+        }
+        if fa.abs() < fb.abs() {
+            ::std::mem::swap(&mut a, &mut b);
+            ::std::mem::swap(&mut fa, &mut fb);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency(iter, b, fb).with_bracket(a, b));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_find_root_dekker() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_dekker(10f64, 0f64, &f, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(10, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_dekker(-10f64, 0f64, &f, &mut conv).ok().unwrap(), -1f64);
+        assert_eq!(10, conv.get_iter_count());
+
+        conv.reset();
+        assert!(matches!(
+            find_root_dekker(10f64, 20f64, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(0, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_dekker_simple() {
+        let f = |x| 1f64 * x * x - 1f64;
+
+        assert_float_eq!(1e-15f64, find_root_dekker(10f64, 0f64, &f, &mut 1e-15f64).ok().unwrap(), 1f64);
+        assert_float_eq!(
+            1e-15f64,
+            find_root_dekker(-10f64, 0f64, &f, &mut 1e-15f64).ok().unwrap(),
+            -1f64
+        );
+        assert!(matches!(
+            find_root_dekker(10f64, 20f64, &f, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_dekker_stall() {
+        // Secant steps creep towards the almost double root at 1 while the root -3 stays far away
+        let f = |x| (x + 3f64) * ((x - 1f64) * (x - 1f64) + 1e-14f64);
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert!(matches!(
+            find_root_dekker(-4f64, 4f64 / 3f64, &f, &mut conv),
+            Err(SearchError::NoConvergency { .. })
+        ));
+        assert_eq!(30, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            brent::find_root_brent(-4f64, 4f64 / 3f64, &f, &mut conv).ok().unwrap(),
+            -3f64
+        );
+        assert_eq!(11, conv.get_iter_count());
+
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 100);
+        assert_float_eq!(
+            1e-15f64,
+            find_root_dekker(-4f64, 4f64 / 3f64, &f, &mut conv).ok().unwrap(),
+            -3f64
+        );
+        assert_eq!(41, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_dekker_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let bracket = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteBracket));
+        assert_eq!(find_root_dekker(f64::NAN, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_dekker(0.5f64, f64::NAN, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_dekker(f64::NEG_INFINITY, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_dekker(0.5f64, f64::INFINITY, &f, &mut 1e-15f64), bracket);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_dekker(0f64, 10f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_dekker(10f64, -1f64, &f, &mut 1e-15f64), value);
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use std::cmp::Ordering;
+
+/// Sample point where the derivative function disagrees with the finite difference, found by check_derivative
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DerivativeMismatch<F>
+where
+    F: FloatType,
+{
+    /// The sample point
+    pub x: F,
+    /// Value of the checked derivative function at x
+    pub derivative: F,
+    /// Central finite difference of the function at x
+    pub estimate: F,
+    /// |derivative - estimate| / max(1, |estimate|)
+    pub relative_error: F,
+}
+
+/// Compares the derivative function d with the central finite difference of the function f at the sample points.
+///
+/// The step of the finite difference is cbrt(epsilon)*max(1, |x|), so it agrees with the true derivative
+/// to about 1e-10 for well-scaled f64 functions. The error is relative to the derivative, or absolute
+/// when the derivative is less than 1. Returns the points where the error exceeds rel_tol, the worst first;
+/// the result is empty if the derivative is consistent with the function.
+///
+/// Use it when the Newton-Raphson method or its relatives do not converge: a wrong derivative is the usual cause.
+///
+/// # Examples
+///
+/// ```
+/// use roots::check_derivative;
+///
+/// let f = |x: f64| { x.sin() * x };
+/// let correct = |x: f64| { x.cos() * x + x.sin() };
+/// let wrong = |x: f64| { x.cos() * x };
+/// let samples = [-2f64, -1f64, 0.5f64, 3f64];
+///
+/// let mismatches = check_derivative(&f, &correct, &samples, 1e-6f64);
+/// // Returns an empty vector
+///
+/// let mismatches = check_derivative(&f, &wrong, &samples, 1e-6f64);
+/// // Returns mismatches at all points, the worst at x = -2
+/// ```
+pub fn check_derivative<F, Func, Deriv>(mut f: Func, mut d: Deriv, x_samples: &[F], rel_tol: F) -> Vec<DerivativeMismatch<F>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    let mut mismatches: Vec<DerivativeMismatch<F>> = Vec::new();
+    for &x in x_samples.iter() {
+        let scale = if x.abs() > F::one() { x.abs() } else { F::one() };
+        let h = F::epsilon().cbrt() * scale;
+        let estimate = (f(x + h) - f(x - h)) / (_2 * h);
+        let derivative = d(x);
+        let relative_error = (derivative - estimate).abs() / if estimate.abs() > F::one() { estimate.abs() } else { F::one() };
+        match relative_error.partial_cmp(&rel_tol) {
+            Some(Ordering::Less) | Some(Ordering::Equal) => {}
+            // NaN in any of the values is a mismatch as well
+            _ => mismatches.push(DerivativeMismatch {
+                x,
+                derivative,
+                estimate,
+                relative_error,
+            }),
+        }
+    }
+    mismatches.sort_by(|a, b| b.relative_error.partial_cmp(&a.relative_error).unwrap_or(Ordering::Equal));
+    mismatches
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check_derivative() {
+        let samples = [-10f64, -3f64, -1f64, -0.5f64, 0f64, 0.5f64, 1f64, 3f64, 10f64];
+
+        // Functions of the benchmarks
+        let x2_min_1 = |x: f64| x * x - 1f64;
+        let x4_min_1 = |x: f64| x * x * x * x - 1f64;
+        assert!(check_derivative(&x2_min_1, |x: f64| 2f64 * x, &samples, 1e-9f64).is_empty());
+        assert!(check_derivative(&x4_min_1, |x: f64| 4f64 * x * x * x, &samples, 1e-9f64).is_empty());
+        let ln_sin_min_1 = |x: f64| (x.sin() + 2f64).ln() - 1f64;
+        let ln_sin_min_1_derivative = |x: f64| x.cos() / (x.sin() + 2f64);
+        assert!(check_derivative(&ln_sin_min_1, &ln_sin_min_1_derivative, &samples, 1e-9f64).is_empty());
+
+        // The forgotten factor of 4 is flagged everywhere but at 0
+        let mismatches = check_derivative(&x4_min_1, |x: f64| x * x * x, &samples, 1e-9f64);
+        assert_eq!(mismatches.len(), 8);
+        assert_float_eq!(1e-9f64, mismatches[0].relative_error, 0.75f64);
+        assert_float_eq!(1e-9f64, mismatches[7].relative_error, 0.375f64);
+        assert_eq!(mismatches[7].x.abs(), 0.5f64);
+        let worst = mismatches.iter().find(|m| m.x == 10f64).unwrap();
+        assert_float_eq!(1e-6f64, worst.estimate, 4000f64);
+        assert_eq!(worst.derivative, 1000f64);
+        for pair in mismatches.windows(2) {
+            assert!(pair[0].relative_error >= pair[1].relative_error);
+        }
+
+        // The wrong sign is flagged where the derivative is not small
+        let mismatches = check_derivative(&ln_sin_min_1, |x: f64| -ln_sin_min_1_derivative(x), &samples, 1e-3f64);
+        assert_eq!(mismatches.len(), samples.len());
+
+        // NaN is a mismatch
+        let mismatches = check_derivative(
+            &x2_min_1,
+            |x: f64| if x == 1f64 { f64::NAN } else { 2f64 * x },
+            &samples,
+            1e-9f64,
+        );
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].x, 1f64);
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-derivative", debug_assertions))]
+    #[should_panic(expected = "find_root_newton_raphson: derivative")]
+    fn test_find_root_newton_raphson_wrong_derivative() {
+        let f = |x: f64| x * x * x * x - 1f64;
+        let d = |x: f64| x * x * x;
+        let _ = super::super::newton_raphson::find_root_newton_raphson(10f64, &f, &d, &mut 1e-15f64);
+    }
+}
@@ -22,6 +22,8 @@ use std::fmt;
 use std::ops::Index;
 use std::ops::IndexMut;
 
+use super::super::polish_roots_weierstrass;
+use super::Convergency;
 use super::FloatType;
 
 pub struct Matrix {
@@ -666,6 +668,13 @@ fn calc_eigen(m: &mut Matrix) -> Vec<(f64, f64)> {
 /// // Returns [0f64, 0.9999999999999999f64, -0.9999999999999999f64] while 'x^3 - x = 0' has roots -1, 0, and 1
 /// ```
 pub fn find_roots_eigen(c: &[f64]) -> impl Iterator<Item = f64> {
+    let ei = companion_eigenvalues(c);
+    ei.into_iter().filter(|c| c.1 * c.1 == 0.).map(|c| c.0)
+}
+
+/// Eigen numbers (re, im) of the companion matrix of the normalized polynomial
+/// x^n + c[0]*x^(n-1) + c[1]*x^(n-2) + … + c[n-1], i.e. its real and complex roots.
+pub fn companion_eigenvalues(c: &[f64]) -> Vec<(f64, f64)> {
     let n = c.len();
     let mut m = Matrix::new(n);
     for i in 0..(n - 1) {
@@ -674,8 +683,26 @@ pub fn find_roots_eigen(c: &[f64]) -> impl Iterator<Item = f64> {
     for i in 0..(n) {
         m[[i, n - 1]] = -c[n - i - 1];
     }
-    let ei = calc_eigen(&mut m);
-    ei.into_iter().filter(|c| c.1 * c.1 == 0.).map(|c| c.0)
+    calc_eigen(&mut m)
+}
+
+/// Find all roots of the normalized polynomial x^n + c[0]*x^(n-1) + c[1]*x^(n-2) + … + c[n-1] = 0 by find_roots_eigen
+/// and polish them simultaneously by polish_roots_weierstrass within max_sweeps.
+///
+/// Note that found roots are not sorted.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_eigen_polished;
+///
+/// let roots = find_roots_eigen_polished(&[0f64, -1f64, 0f64], 10, &mut 1e-15f64);
+/// // Returns [0f64, 1f64, -1f64] while 'x^3 - x = 0' has roots -1, 0, and 1
+/// ```
+pub fn find_roots_eigen_polished(c: &[f64], max_sweeps: usize, convergency: &mut (impl Convergency<f64> + ?Sized)) -> Vec<f64> {
+    let mut roots: Vec<f64> = find_roots_eigen(c).collect();
+    polish_roots_weierstrass(c, &mut roots, max_sweeps, convergency);
+    roots
 }
 
 #[cfg(test)]
@@ -749,4 +776,33 @@ mod test {
             find_roots_eigen(&[-111.35528725660045, 4666.666666666667, -87228.30835100368, 613541.6666666666]).collect();
         assert_eq!(roots.len(), 0);
     }
+
+    #[test]
+    fn test_find_roots_eigen_polished() {
+        let value = |c: &[f64], x: f64| c.iter().fold(1f64, |value, a| value * x + a);
+
+        // Tim Lueke's polynomial has two double roots; polishing brings the approximations of each one together
+        let vec = vec![
+            -3.75f64 / -14.0625f64,
+            29.75f64 / -14.0625f64,
+            4.0f64 / -14.0625f64,
+            -16.0f64 / -14.0625f64,
+        ];
+        let mut roots = find_roots_eigen_polished(&vec, 20, &mut 1e-17f64);
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for root in roots.iter() {
+            assert!(value(&vec, *root).abs() <= 1e-15f64);
+        }
+        assert_float_eq!(1e-8f64, roots[0], -1.1016116464173349f64);
+        assert_float_eq!(1e-8f64, roots[1], -1.1016116464173349f64);
+        assert_float_eq!(1e-8f64, roots[2], 0.9682783130840016f64);
+        assert_float_eq!(1e-8f64, roots[3], 0.9682783130840016f64);
+
+        // Sebedard13's polynomial: the eigenvalue 0.49999999999999833 is polished
+        let vec = vec![-2.5, 5.0, -5.0, 2.5, -0.5];
+        let roots = find_roots_eigen_polished(&vec, 20, &mut 1e-17f64);
+        assert_eq!(1, roots.len());
+        assert!(value(&vec, roots[0]).abs() <= 1e-15f64);
+        assert_float_eq!(2e-16f64, roots[0], 0.5f64);
+    }
 }
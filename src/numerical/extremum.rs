@@ -0,0 +1,171 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::brent::find_root_brent;
+use super::cache::CachedFn;
+use super::Convergency;
+use super::SearchError;
+
+/// Kind of a local extremum of a function
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ExtremumKind {
+    /// The derivative changes its sign from negative to positive
+    Minimum,
+    /// The derivative changes its sign from positive to negative
+    Maximum,
+}
+
+/// Local extremum of a function, returned by the find_extremum_* functions
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Extremum<F>
+where
+    F: FloatType,
+{
+    /// Location of the extremum
+    pub x: F,
+    /// Whether it is a minimum or a maximum
+    pub kind: ExtremumKind,
+}
+
+/// Find a local extremum of a function as the root of its derivative df(x) = 0 using the Brent method.
+///
+/// The kind of the extremum follows from the direction in which the derivative changes its sign
+/// between a and b, so the second derivative is not needed. If the derivative is zero at one end,
+/// the kind follows from the sign at the other end.
+///
+/// # Failures
+/// ## NoBracketing
+/// The derivative does not change its sign between a and b, or it is zero at both ends.
+/// ## NoConvergency
+/// Algorithm cannot find a root of the derivative within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_extremum_brent;
+/// use roots::ExtremumKind;
+///
+/// // f(x) = x^3 - 3x has the maximum at -1 and the minimum at 1
+/// let df = |x| { 3f64*x*x - 3f64 };
+///
+/// let extremum = find_extremum_brent(0f64, 2f64, &df, &mut 1e-15f64);
+/// // Returns approximately Ok(Extremum { x: 1, kind: ExtremumKind::Minimum });
+/// ```
+pub fn find_extremum_brent<F, Func>(
+    a: F,
+    b: F,
+    df: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<Extremum<F>, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (a, b) = if a > b { (b, a) } else { (a, b) };
+    // The Brent method evaluates the derivative at the ends again
+    let mut cached = CachedFn::new(df);
+    let (ya, yb) = (cached.call(a), cached.call(b));
+    let kind = if (ya < F::zero() && yb >= F::zero()) || (ya == F::zero() && yb > F::zero()) {
+        ExtremumKind::Minimum
+    } else if (ya > F::zero() && yb <= F::zero()) || (ya == F::zero() && yb < F::zero()) {
+        ExtremumKind::Maximum
+    } else {
+        return Err(SearchError::no_bracketing(a, b));
+    };
+    let x = find_root_brent(a, b, |x| cached.call(x), convergency)?;
+    Ok(Extremum { x, kind })
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_find_extremum_brent() {
+        // f(x) = x^3 - 3x
+        let df = |x| 3f64 * x * x - 3f64;
+
+        let extremum = find_extremum_brent(0f64, 2f64, &df, &mut 1e-15f64).unwrap();
+        assert_float_eq!(1e-15f64, extremum.x, 1f64);
+        assert_eq!(extremum.kind, ExtremumKind::Minimum);
+        let extremum = find_extremum_brent(0f64, -2f64, &df, &mut 1e-15f64).unwrap();
+        assert_float_eq!(1e-15f64, extremum.x, -1f64);
+        assert_eq!(extremum.kind, ExtremumKind::Maximum);
+
+        // The derivative is zero at one end
+        assert_eq!(
+            find_extremum_brent(1f64, 2f64, &df, &mut 1e-15f64),
+            Ok(Extremum {
+                x: 1f64,
+                kind: ExtremumKind::Minimum
+            })
+        );
+        assert_eq!(
+            find_extremum_brent(-1f64, 0f64, &df, &mut 1e-15f64),
+            Ok(Extremum {
+                x: -1f64,
+                kind: ExtremumKind::Maximum
+            })
+        );
+
+        assert!(matches!(
+            find_extremum_brent(2f64, 3f64, &df, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert!(matches!(
+            find_extremum_brent(-1f64, 1f64, &df, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(not(feature = "debug-derivative"))]
+    fn test_find_extremum_brent_evaluations() {
+        let mut calls = 0;
+        let mut brent_calls = 0;
+        find_extremum_brent(
+            0f64,
+            2f64,
+            |x| {
+                calls += 1;
+                3f64 * x * x - 3f64
+            },
+            &mut 1e-15f64,
+        )
+        .unwrap();
+        find_root_brent(
+            0f64,
+            2f64,
+            |x| {
+                brent_calls += 1;
+                3f64 * x * x - 3f64
+            },
+            &mut 1e-15f64,
+        )
+        .unwrap();
+        // The values at the ends are not calculated twice
+        assert_eq!(calls, brent_calls);
+    }
+}
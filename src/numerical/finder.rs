@@ -0,0 +1,189 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::method::find_root;
+use super::method::Method;
+use super::method::Start;
+use super::Convergency;
+use super::SearchError;
+
+/// Root finding algorithm that can be stored and passed around as a value
+///
+/// # Examples
+///
+/// ```
+/// use roots::Brent;
+/// use roots::RootFinder;
+/// use roots::Secant;
+///
+/// let finders: Vec<Box<dyn RootFinder<f64>>> = vec![Box::new(Brent), Box::new(Secant)];
+/// for finder in finders.iter() {
+///     let root = finder.find(&mut |x| x * x - 1f64, (0f64, 10f64), &mut 1e-15f64);
+///     // Returns approximately Ok(1);
+/// }
+/// ```
+pub trait RootFinder<F: FloatType> {
+    /// Find a root of the function f(x) = 0, starting from the bracket
+    fn find(&self, f: &mut dyn FnMut(F) -> F, bracket: (F, F), convergency: &mut dyn Convergency<F>) -> Result<F, SearchError>;
+}
+
+/// Root finding algorithm that needs the derivative of the function
+pub trait DerivativeRootFinder<F: FloatType> {
+    /// Find a root of the function f(x) = 0 with the derivative d(x), starting from the bracket
+    fn find_with_derivative(
+        &self,
+        f: &mut dyn FnMut(F) -> F,
+        d: &mut dyn FnMut(F) -> F,
+        bracket: (F, F),
+        convergency: &mut dyn Convergency<F>,
+    ) -> Result<F, SearchError>;
+}
+
+/// The bisection method, see find_root_bisection
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Bisection;
+
+/// The Brent method, see find_root_brent
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Brent;
+
+/// The regula falsi method, see find_root_regula_falsi
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct RegulaFalsi;
+
+/// The secant method starting from both ends of the bracket, see find_root_secant
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Secant;
+
+/// The Newton-Raphson method safeguarded by the bracket, see find_root_newton_bracketed
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct NewtonRaphson;
+
+impl<F: FloatType> RootFinder<F> for Bisection {
+    fn find(&self, f: &mut dyn FnMut(F) -> F, bracket: (F, F), convergency: &mut dyn Convergency<F>) -> Result<F, SearchError> {
+        super::bisection::find_root_bisection(bracket.0, bracket.1, f, convergency)
+    }
+}
+
+impl<F: FloatType> RootFinder<F> for Brent {
+    fn find(&self, f: &mut dyn FnMut(F) -> F, bracket: (F, F), convergency: &mut dyn Convergency<F>) -> Result<F, SearchError> {
+        super::brent::find_root_brent(bracket.0, bracket.1, f, convergency)
+    }
+}
+
+impl<F: FloatType> RootFinder<F> for RegulaFalsi {
+    fn find(&self, f: &mut dyn FnMut(F) -> F, bracket: (F, F), convergency: &mut dyn Convergency<F>) -> Result<F, SearchError> {
+        super::regula_falsi::find_root_regula_falsi(bracket.0, bracket.1, f, convergency)
+    }
+}
+
+impl<F: FloatType> RootFinder<F> for Secant {
+    fn find(&self, f: &mut dyn FnMut(F) -> F, bracket: (F, F), convergency: &mut dyn Convergency<F>) -> Result<F, SearchError> {
+        super::secant::find_root_secant(bracket.0, bracket.1, f, convergency)
+    }
+}
+
+impl<F: FloatType> DerivativeRootFinder<F> for NewtonRaphson {
+    fn find_with_derivative(
+        &self,
+        f: &mut dyn FnMut(F) -> F,
+        d: &mut dyn FnMut(F) -> F,
+        bracket: (F, F),
+        convergency: &mut dyn Convergency<F>,
+    ) -> Result<F, SearchError> {
+        super::newton_raphson::find_root_newton_bracketed(bracket.0, bracket.1, f, d, convergency)
+    }
+}
+
+/// Methods that need one starting point, like the Steffensen's method, fail with InvalidInput
+impl<F: FloatType> RootFinder<F> for Method {
+    fn find(&self, f: &mut dyn FnMut(F) -> F, bracket: (F, F), convergency: &mut dyn Convergency<F>) -> Result<F, SearchError> {
+        find_root(*self, Start::Bracket(bracket.0, bracket.1), f, convergency)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_root_finder() {
+        let mut f = |x| 1f64 * x * x - 1f64;
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+
+        let finders: Vec<Box<dyn RootFinder<f64>>> = vec![
+            Box::new(Bisection),
+            Box::new(Brent),
+            Box::new(RegulaFalsi),
+            Box::new(Secant),
+            Box::new(Method::Ridders),
+        ];
+        for finder in finders.iter() {
+            assert_float_eq!(1e-15f64, finder.find(&mut f, (0f64, 10f64), &mut conv).ok().unwrap(), 1f64);
+        }
+        assert_eq!(
+            Brent.find(&mut f, (0f64, 10f64), &mut conv),
+            brent::find_root_brent(0f64, 10f64, &f, &mut conv)
+        );
+        assert!(matches!(
+            Brent.find(&mut f, (2f64, 10f64), &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(
+            Method::Steffensen.find(&mut f, (0f64, 10f64), &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            })
+        );
+    }
+
+    #[test]
+    fn test_derivative_root_finder() {
+        let mut f = |x| 1f64 * x * x - 1f64;
+        let mut d = |x| 2f64 * x;
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+
+        let finder: Box<dyn DerivativeRootFinder<f64>> = Box::new(NewtonRaphson);
+        assert_eq!(
+            finder.find_with_derivative(&mut f, &mut d, (0f64, 10f64), &mut conv),
+            newton_raphson::find_root_newton_bracketed(0f64, 10f64, &f, &d, &mut conv)
+        );
+        assert_float_eq!(
+            1e-15f64,
+            finder
+                .find_with_derivative(&mut f, &mut d, (-10f64, 0f64), &mut conv)
+                .ok()
+                .unwrap(),
+            -1f64
+        );
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+
+/// Estimate magnitudes of roots of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// by the Dandelin-Graeffe root-squaring method.
+///
+/// Each iteration transforms the polynomial into the one with squared roots, so the magnitudes
+/// of the roots separate quickly, and the ratios of consecutive coefficients approach
+/// the magnitudes of the roots raised to the power 2^iterations.
+/// The polynomial is rescaled before each squaring to keep its coefficients from overflowing.
+/// Returns n estimates sorted in descending order; roots at the origin give zeros.
+/// Zero coefficients give equal estimates for the roots around them.
+///
+/// Estimates are cheap but rough: they are good to seed brackets and starting points of iterative methods,
+/// e.g. find_roots_aberth starts from them.
+/// Roots of equal or close magnitudes (complex pairs, multiple roots and clusters) do not separate,
+/// so the estimates of their individual magnitudes are meaningless, although their geometric mean is correct.
+///
+/// # Examples
+///
+/// ```
+/// use roots::graeffe_root_magnitudes;
+///
+/// // (x - 10)(x + 2)(x - 0.1)
+/// let polynom = [-8.1f64, -19.2f64, 2f64];
+///
+/// let magnitudes = graeffe_root_magnitudes(&polynom, 4);
+/// // Returns approximately vec![10, 2, 0.1];
+/// ```
+pub fn graeffe_root_magnitudes<F: FloatType>(a: &[F], iterations: usize) -> Vec<F> {
+    let mut p: Vec<f64> = Vec::with_capacity(a.len() + 1);
+    p.push(1f64);
+    p.extend(a.iter().map(|c| c.to_f64()));
+
+    // Remove the roots at the origin
+    let mut zeros = 0;
+    while p.len() > 1 && p[p.len() - 1] == 0f64 {
+        p.pop();
+        zeros += 1;
+    }
+    let n = p.len() - 1;
+
+    // Roots of p are the original roots raised to the power 2^squarings and divided by exp(log_scale)
+    let mut log_scale = 0f64;
+    let mut squarings = 0;
+    while squarings < iterations && n > 0 {
+        // Rescale the roots to have the geometric mean of their magnitudes equal to 1
+        let log_mean = (p[n].abs().ln() - p[0].abs().ln()) / n as f64;
+        for (k, c) in p.iter_mut().enumerate() {
+            *c *= (-(k as f64) * log_mean).exp();
+        }
+        log_scale += log_mean;
+
+        // p(x)p(-x) is the polynomial of x^2 with squared roots
+        let mut q = vec![0f64; n + 1];
+        for (k, q) in q.iter_mut().enumerate() {
+            let mut sum = p[k] * p[k];
+            let mut sign = -2f64;
+            for j in 1..=k.min(n - k) {
+                sum += sign * p[k - j] * p[k + j];
+                sign = -sign;
+            }
+            *q = if k % 2 == 0 { sum } else { -sum };
+        }
+        p = q;
+        log_scale *= 2f64;
+        squarings += 1;
+    }
+
+    // Ratios of consecutive nonzero coefficients give magnitudes of as many roots as the distance between them
+    let power = (2f64).powi(squarings as i32);
+    let mut magnitudes: Vec<F> = Vec::with_capacity(n + zeros);
+    let mut last = 0;
+    for i in 1..=n {
+        if p[i] != 0f64 {
+            let distance = (i - last) as f64;
+            let log_magnitude = ((p[i] / p[last]).abs().ln() / distance + log_scale) / power;
+            magnitudes.resize(i, F::from_f64(log_magnitude.exp()));
+            last = i;
+        }
+    }
+    magnitudes.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    magnitudes.resize(n + zeros, F::zero());
+    magnitudes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_graeffe_root_magnitudes() {
+        // (x - 8)(x + 3)(x - 1)(x + 0.25)
+        let polynom = [-5.75f64, -20.5f64, 19.25f64, 6f64];
+        let magnitudes = graeffe_root_magnitudes(&polynom, 3);
+        assert_float_array_eq!(0.01f64, magnitudes, [8f64, 3f64, 1f64, 0.25f64]);
+
+        let magnitudes = graeffe_root_magnitudes(&polynom, 4);
+        assert_float_array_eq!(1e-4f64, magnitudes, [8f64, 3f64, 1f64, 0.25f64]);
+    }
+
+    #[test]
+    fn test_graeffe_root_magnitudes_close() {
+        // (x - 2)(x + 1.5)(x - 1)(x^2 + 0.25): squarings separate 2 and 1.5 slowly,
+        // while magnitudes of the complex pair are wrong individually but right together
+        let polynom = [-1.5f64, -2.25f64, 2.625f64, -0.625f64, 0.75f64];
+        let magnitudes = graeffe_root_magnitudes(&polynom, 4);
+        assert_eq!(5, magnitudes.len());
+        assert_float_eq!(0.02f64, magnitudes[0] / 2f64, 1f64);
+        assert_float_eq!(0.02f64, magnitudes[1] / 1.5f64, 1f64);
+        assert_float_eq!(1e-3f64, magnitudes[2], 1f64);
+        assert_float_eq!(1e-3f64, magnitudes[3] * magnitudes[4], 0.25f64);
+    }
+
+    #[test]
+    fn test_graeffe_root_magnitudes_zero_roots() {
+        // x^2 (x - 4)(x - 0.5)
+        let magnitudes = graeffe_root_magnitudes(&[-4.5f64, 2f64, 0f64, 0f64], 4);
+        assert_float_array_eq!(1e-6f64, magnitudes, [4f64, 0.5f64, 0f64, 0f64]);
+        assert_eq!(0, graeffe_root_magnitudes::<f64>(&[], 4).len());
+        // x^6 - 1: roots of equal magnitudes do not separate
+        let magnitudes = graeffe_root_magnitudes(&[0f64, 0f64, 0f64, 0f64, 0f64, -1f64], 4);
+        assert_float_array_eq!(0.02f64, magnitudes, [1f64; 6]);
+    }
+}
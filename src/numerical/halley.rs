@@ -0,0 +1,243 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::check_start;
+use super::check_value;
+use super::Convergency;
+use super::SearchError;
+
+/// Find a root of the function f(x) = 0 using the Halley's method.
+///
+/// Pro
+///
+/// + Simple
+/// + Cubic convergency for well-behaved functions
+/// + No need for initial bracketing
+///
+/// Contra
+///
+/// - Needs the first and the second derivative functions
+/// - Impossible to predict which root will be found when many roots exist
+/// - Unstable convergency for non-trivial functions
+/// - Cannot continue when the denominator 2f'^2 - ff'' is zero
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The denominator 2f'^2 - ff'' vanishes. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_halley;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let d1 = |x| { 2f64*x };
+/// let d2 = |_| { 2f64 };
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:30 };
+///
+/// let root1 = find_root_halley(10f64, &f, &d1, &d2, &mut convergency);
+/// // Returns approximately Ok(1);
+///
+/// let root2 = find_root_halley(-10f64, &f, &d1, &d2, &mut 1e-15f64);
+/// // Returns approximately Ok(-1);
+/// ```
+pub fn find_root_halley<F, Func, Deriv1, Deriv2>(
+    start: F,
+    mut f: Func,
+    mut d1: Deriv1,
+    mut d2: Deriv2,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv1: FnMut(F) -> F,
+    Deriv2: FnMut(F) -> F,
+{
+    find_root_halley_combined(start, |x| (f(x), d1(x), d2(x)), convergency)
+}
+
+/// Find a root of the function f(x) = 0 using the Halley's method.
+///
+/// The same as `find_root_halley`, but the function and its derivatives are calculated by one closure
+/// returning the tuple (f(x), f'(x), f''(x)). Use it when they share most of the calculations.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The denominator 2f'^2 - ff'' vanishes. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::find_root_halley_combined;
+///
+/// let fd = |x: f64| { let e = x.exp(); (e - 2f64, e, e) };
+///
+/// let root = find_root_halley_combined(10f64, &fd, &mut 1e-15f64);
+/// // Returns approximately Ok(0.6931471805599453);
+/// ```
+pub fn find_root_halley_combined<F, FuncDerivs>(
+    start: F,
+    mut fd: FuncDerivs,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    FuncDerivs: FnMut(F) -> (F, F, F),
+{
+    let _2 = F::from(2i16);
+    check_start(start)?;
+    let mut x = start;
+
+    let mut iter = 0;
+    loop {
+        let (f, d1, d2) = fd(x);
+        if iter == 0 {
+            check_value(f)?;
+        }
+        if convergency.is_root_found(f) {
+            return Ok(x);
+        }
+        // Derivative is 0; try to correct the bad starting point
+        if iter == 0 && convergency.is_root_found(d1) {
+            x = x + F::one();
+            iter += 1;
+            continue;
+        }
+        let denominator = _2 * d1 * d1 - f * d2;
+        if convergency.is_root_found(denominator) {
+            return Err(SearchError::zero_derivative(iter, x, f));
+        }
+
+        let x1 = x - _2 * f * d1 / denominator;
+        if convergency.is_converged(x, x1) {
+            return Ok(x1);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency(iter, x, f));
+        }
+        x = x1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+    use std::cell::RefCell;
+
+    #[test]
+    fn test_find_root_halley() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let d1 = |x| 2f64 * x;
+        let d2 = |_| 2f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_halley(10f64, &f, &d1, &d2, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(5, conv.get_iter_count());
+        conv.reset();
+        newton_raphson::find_root_newton_raphson(10f64, &f, &d1, &mut conv)
+            .ok()
+            .unwrap();
+        assert_eq!(8, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_halley(-10f64, &f, &d1, &d2, &mut conv).ok().unwrap(),
+            -1f64
+        );
+        assert_eq!(5, conv.get_iter_count());
+        conv.reset();
+        newton_raphson::find_root_newton_raphson(-10f64, &f, &d1, &mut conv)
+            .ok()
+            .unwrap();
+        assert_eq!(8, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_halley_zero_derivative() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let d1 = |x| 2f64 * x;
+        let d2 = |_| 2f64;
+
+        // The starting point is moved away from the stationary point
+        assert_float_eq!(
+            1e-15f64,
+            find_root_halley(0f64, &f, &d1, &d2, &mut 1e-15f64).ok().unwrap(),
+            1f64
+        );
+
+        // 2f'^2 - ff'' = 2exp(2x) - (exp(x) + 1)exp(x) = 0 at x = 0
+        let f = |x: f64| x.exp() + 1f64;
+        let d = |x: f64| x.exp();
+        assert!(matches!(
+            find_root_halley(0f64, &f, &d, &d, &mut 1e-15f64),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_halley_combined() {
+        let iterates = RefCell::new(Vec::new());
+        let f = |x: f64| {
+            iterates.borrow_mut().push(x);
+            x * x * x * x - 1f64
+        };
+        let d1 = |x: f64| 4f64 * x * x * x;
+        let d2 = |x: f64| 12f64 * x * x;
+        let fd = |x: f64| (f(x), d1(x), d2(x));
+
+        for &start in [0.5f64, 10f64, -10f64, 0f64].iter() {
+            let root = find_root_halley(start, &f, &d1, &d2, &mut 1e-15f64);
+            let separate = iterates.replace(Vec::new());
+            assert_eq!(find_root_halley_combined(start, &fd, &mut 1e-15f64), root);
+            assert_eq!(iterates.replace(Vec::new()), separate);
+        }
+    }
+
+    #[test]
+    fn test_find_root_halley_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let d1 = |x: f64| 1f64 / x;
+        let d2 = |x: f64| -1f64 / (x * x);
+        let start = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteStart));
+        assert_eq!(find_root_halley(f64::NAN, &f, &d1, &d2, &mut 1e-15f64), start);
+        assert_eq!(find_root_halley(f64::INFINITY, &f, &d1, &d2, &mut 1e-15f64), start);
+        assert_eq!(find_root_halley(f64::NEG_INFINITY, &f, &d1, &d2, &mut 1e-15f64), start);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_halley(0f64, &f, &d1, &d2, &mut 1e-15f64), value);
+        assert_eq!(find_root_halley(-1f64, &f, &d1, &d2, &mut 1e-15f64), value);
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::brent::find_root_brent;
+use super::newton_raphson::find_root_newton_raphson;
+use super::Convergency;
+use super::SearchError;
+
+/// Find x such that f(x) = y_target using the Brent method.
+///
+/// The root of f(x) - y_target is searched in the bracket [a, b], so the values of the function
+/// at the ends must lie on the opposite sides of y_target. The precision of `is_root_found` is applied
+/// to f(x) - y_target, i.e. it is absolute in y.
+///
+/// # Failures
+/// ## NoBracketing
+/// y_target is not between the values of the function at the ends of the bracket.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_inverse;
+///
+/// // Cumulative distribution function of the logistic distribution
+/// let cdf = |x: f64| 1f64 / (1f64 + (-x).exp());
+///
+/// let quantile = find_inverse(&cdf, 0.9f64, -100f64, 100f64, &mut 1e-15f64);
+/// // Returns approximately Ok(2.1972245773362196), i.e. ln(9)
+///
+/// let impossible = find_inverse(&cdf, 2f64, -100f64, 100f64, &mut 1e-15f64);
+/// // Returns Err(SearchError::NoBracketing { .. })
+/// ```
+pub fn find_inverse<F, Func>(
+    mut f: Func,
+    y_target: F,
+    a: F,
+    b: F,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    find_root_brent(a, b, |x| f(x) - y_target, convergency)
+}
+
+/// Find x such that f(x) = y_target using the Newton-Raphson method.
+///
+/// The root of f(x) - y_target is searched from the starting point; d is the derivative of f.
+///
+/// # Failures
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_inverse_newton;
+///
+/// let f = |x: f64| x * x * x;
+/// let d = |x: f64| 3f64 * x * x;
+///
+/// let root = find_inverse_newton(&f, &d, 8f64, 1f64, &mut 1e-15f64);
+/// // Returns approximately Ok(2)
+/// ```
+pub fn find_inverse_newton<F, Func, Deriv>(
+    mut f: Func,
+    d: Deriv,
+    y_target: F,
+    start: F,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    find_root_newton_raphson(start, |x| f(x) - y_target, d, convergency)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_inverse() {
+        let cdf = |x: f64| 1f64 / (1f64 + (-x).exp());
+
+        assert_float_eq!(
+            1e-15f64,
+            find_inverse(&cdf, 0.9f64, -100f64, 100f64, &mut 1e-15f64).unwrap(),
+            9f64.ln()
+        );
+        assert_float_eq!(
+            1e-15f64,
+            find_inverse(&cdf, 0.5f64, 100f64, -100f64, &mut 1e-15f64).unwrap(),
+            0f64
+        );
+        assert_eq!(
+            find_inverse(&cdf, 0.9f64, -100f64, 100f64, &mut 1e-15f64),
+            find_root_brent(-100f64, 100f64, |x| cdf(x) - 0.9f64, &mut 1e-15f64)
+        );
+
+        // The target is out of the range of the function
+        assert!(matches!(
+            find_inverse(&cdf, 2f64, -100f64, 100f64, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert!(matches!(
+            find_inverse(&cdf, -0.1f64, -100f64, 100f64, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        // The target is in the range, but not in the bracket
+        assert!(matches!(
+            find_inverse(&cdf, 0.9f64, -100f64, 0f64, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_inverse_newton() {
+        let f = |x: f64| x * x * x;
+        let d = |x: f64| 3f64 * x * x;
+
+        assert_float_eq!(
+            1e-15f64,
+            find_inverse_newton(&f, &d, 8f64, 1f64, &mut 1e-15f64).unwrap(),
+            2f64
+        );
+        assert_float_eq!(
+            1e-15f64,
+            find_inverse_newton(&f, &d, -27f64, -1f64, &mut 1e-15f64).unwrap(),
+            -3f64
+        );
+
+        // No solution; the iterations are attracted to the minimum of the function
+        let g = |x: f64| x * x;
+        let dg = |x: f64| 2f64 * x;
+        assert!(matches!(
+            find_inverse_newton(&g, &dg, -1f64, 1f64, &mut 1e-15f64),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+    }
+}
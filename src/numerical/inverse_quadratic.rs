@@ -24,13 +24,17 @@
 
 use super::super::find_roots_quadratic;
 use super::super::FloatType;
+use super::super::Roots;
+use super::brent::interpolate_quadratic;
+use super::check_bracket;
+use super::check_value;
 use super::Convergency;
 use super::Interval;
 use super::Sample;
 use super::SearchError;
 
 /// Definition of the quadratic equation a*x^2 + b*x + c
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Parabola<F>
 where
     F: FloatType,
@@ -44,22 +48,118 @@ impl<F> Parabola<F>
 where
     F: FloatType,
 {
-    /// Restore coefficients of the quadratic equation by 3 points
-    fn from_three_points(p1: &Sample<F>, p2: &Sample<F>, p3: &Sample<F>) -> Self {
-        let denom = (p1.x - p2.x) * (p1.x - p3.x) * (p2.x - p3.x);
-        let a = (p3.x * (p2.y - p1.y) + p2.x * (p1.y - p3.y) + p1.x * (p3.y - p2.y)) / denom;
-        let b = (p1.x * p1.x * (p2.y - p3.y) + p3.x * p3.x * (p1.y - p2.y) + p2.x * p2.x * (p3.y - p1.y)) / denom;
-        let c = (p2.x * p2.x * (p3.x * p1.y - p1.x * p3.y)
-            + p2.x * (p1.x * p1.x * p3.y - p3.x * p3.x * p1.y)
-            + p1.x * p3.x * (p3.x - p1.x) * p2.y)
-            / denom;
-
-        Parabola { a: a, b: b, c: c }
+    /// The parabola a*x^2 + b*x + c; a may be 0
+    pub fn new(a: F, b: F, c: F) -> Self {
+        Parabola { a, b, c }
     }
+
+    /// Restore coefficients of the quadratic equation by 3 points (x, y); None if two x values coincide.
+    ///
+    /// The parabola is built by the Newton's divided differences in the form y2 + s*(x - x2) + a*(x - x2)^2
+    /// and then expanded, so closely clustered points keep the precision.
+    /// If the points are collinear, a is 0, and the parabola is the line through them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use roots::Parabola;
+    ///
+    /// let parabola = Parabola::from_three_points((-1f64, 0f64), (0f64, -1f64), (2f64, 3f64));
+    /// // Returns Some(Parabola::new(1f64, 0f64, -1f64))
+    ///
+    /// let degenerate = Parabola::from_three_points((-1f64, 0f64), (0f64, -1f64), (0f64, 3f64));
+    /// // Returns None
+    /// ```
+    pub fn from_three_points(p1: (F, F), p2: (F, F), p3: (F, F)) -> Option<Self> {
+        let ((x1, y1), (x2, y2), (x3, y3)) = (p1, p2, p3);
+        if x1 == x2 || x2 == x3 || x1 == x3 {
+            return None;
+        }
+        let d12 = (y2 - y1) / (x2 - x1);
+        let d23 = (y3 - y2) / (x3 - x2);
+        let a = (d23 - d12) / (x3 - x1);
+        // Slope at x2
+        let s = d12 + a * (x2 - x1);
+
+        Some(Parabola {
+            a,
+            b: s - F::from(2i16) * a * x2,
+            c: y2 - (s - a * x2) * x2,
+        })
+    }
+
+    /// Coefficient of x^2
+    pub fn a(&self) -> F {
+        self.a
+    }
+
+    /// Coefficient of x
+    pub fn b(&self) -> F {
+        self.b
+    }
+
+    /// Constant term
+    pub fn c(&self) -> F {
+        self.c
+    }
+
+    /// Value a*x^2 + b*x + c at x
+    pub fn evaluate(&self, x: F) -> F {
+        (self.a * x + self.b) * x + self.c
+    }
+
+    /// The extremum (x, y) of the parabola, or None if a is 0 and it is a line
+    pub fn vertex(&self) -> Option<(F, F)> {
+        if self.a == F::zero() {
+            None
+        } else {
+            let x = -self.b / (F::from(2i16) * self.a);
+            Some((x, self.evaluate(x)))
+        }
+    }
+
+    /// Roots of a*x^2 + b*x + c = 0 found by find_roots_quadratic
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use roots::Parabola;
+    /// use roots::Roots;
+    ///
+    /// let roots = Parabola::new(1f64, 0f64, -1f64).roots();
+    /// // Returns Roots::Two([-1f64, 1f64])
+    /// ```
+    pub fn roots(&self) -> Roots<F> {
+        find_roots_quadratic(self.a, self.b, self.c)
+    }
+}
+
+/// State of find_root_inverse_quadratic_observed after evaluating the function at the new approximation
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct IterationInfo<F>
+where
+    F: FloatType,
+{
+    /// Number of the iteration, starting from 0
+    pub iter: usize,
+    /// Lower end of the bracketing interval the approximation has been taken from
+    pub begin: F,
+    /// Upper end of the bracketing interval
+    pub end: F,
+    /// The new approximation
+    pub x: F,
+    /// Value of the function at the new approximation
+    pub y: F,
+    /// True if the approximation is interpolated quadratically, false if the secant method is used
+    pub interpolated: bool,
 }
 
 /// Find a root of the function f(x) = 0 using inverse quadratic approximation.
 ///
+/// x is interpolated as a quadratic function of y through the ends of the bracketing interval
+/// and the last point removed from it. If the approximation leaves the interval, the secant method is used.
+/// The first approximation is the root of the parabola y(x) through the same points, so quadratic functions are solved at once.
+///
 /// Pro
 ///
 /// + Faster than linear approximation
@@ -67,10 +167,11 @@ where
 ///
 /// Contra
 ///
-/// - sqrt is calculated on every step
 /// - only works for polynomial-like functions
 ///
 /// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
 /// ## NoBracketing
 /// Initial values do not bracket the root.
 /// ## NoConvergency
@@ -90,22 +191,68 @@ where
 /// let root2 = find_root_inverse_quadratic(-10f64, 0f64, &f, &mut 1e-15f64);
 /// // Returns approximately Ok(-1);
 /// ```
-pub fn find_root_inverse_quadratic<F, Func>(a: F, b: F, mut f: Func, convergency: &mut dyn Convergency<F>) -> Result<F, SearchError>
+pub fn find_root_inverse_quadratic<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
 where
     F: FloatType,
     Func: FnMut(F) -> F,
 {
+    find_root_inverse_quadratic_observed(a, b, f, convergency, |_| {})
+}
+
+/// Find a root of the function f(x) = 0 using inverse quadratic approximation, reporting every iteration.
+///
+/// The same as `find_root_inverse_quadratic`, but on_iteration is called once per iteration with the interval,
+/// the new approximation and the value of the function there. Use it to trace the search.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_inverse_quadratic_observed;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let mut trace = Vec::new();
+///
+/// let root = find_root_inverse_quadratic_observed(10f64, 0f64, &f, &mut 1e-15f64, |info| trace.push(info.x));
+/// // Returns approximately Ok(1); trace contains the approximations
+/// ```
+pub fn find_root_inverse_quadratic_observed<F, Func, Observer>(
+    a: F,
+    b: F,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    mut on_iteration: Observer,
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Observer: FnMut(&IterationInfo<F>),
+{
+    check_bracket(a, b)?;
     let (x1, x2) = if a > b { (b, a) } else { (a, b) };
     let sample1 = Sample { x: x1, y: f(x1) };
+    check_value(sample1.y)?;
     if convergency.is_root_found(sample1.y) {
         return Ok(sample1.x);
     }
     let sample2 = Sample { x: x2, y: f(x2) };
+    check_value(sample2.y)?;
     if convergency.is_root_found(sample2.y) {
         return Ok(sample2.x);
     }
     if !sample1.is_bracketed_with(&sample2) {
-        return Err(SearchError::NoBracketing);
+        return Err(SearchError::no_bracketing(x1, x2));
     }
 
     // Initially, find x3 using the regula falsi method
@@ -113,51 +260,98 @@ where
         begin: sample1,
         end: sample2,
     };
+    // Best ends of the previous intervals, and whether the last step fell back from the interpolation
+    let mut best_before = best_end(&interval).x;
+    let mut best_before_last = best_before;
+    let mut bisected = true;
     let mut x3 = interval.middle();
     if interval.is_converged(convergency) {
         return Ok(x3);
     }
-    let mut sample3 = Sample { x: x3, y: f(x3) };
+    let sample3 = Sample { x: x3, y: f(x3) };
     if convergency.is_root_found(sample3.y) {
         return Ok(sample3.x);
     }
+    let mut previous = x3;
+    // The third point of the interpolation
+    let mut removed = narrow(&mut interval, sample3);
+    if interval.is_converged(convergency) {
+        return Ok(interval.middle());
+    }
 
     // Iterate quadratically
     let mut iter = 0;
     loop {
-        let parabola = Parabola::from_three_points(&interval.begin, &interval.end, &sample3);
-
-        // Find the new approximation quadratically
-        x3 = if let Some(root) = find_roots_quadratic(parabola.a, parabola.b, parabola.c)
-            .as_ref()
-            .iter()
-            .find(|x| interval.contains_x(x))
-        {
-            *root
+        // Accept the approximation between the best end and 3/4 of the interval from it (as in the Brent method)
+        let (best, other) = if interval.begin.y.abs() < interval.end.y.abs() {
+            (interval.begin.x, interval.end.x)
         } else {
-            // no roots inside interval, fallback to linear approximation
-            interval.middle()
+            (interval.end.x, interval.begin.x)
         };
+        let limit = (F::from(3i16) * other + best) / F::from(4i16);
+        // At first, try the root of the parabola through the three points: it is exact if the function is quadratic
+        let parabola_root = if iter == 0 {
+            // The removed point is outside the interval; ordered points keep the precision
+            let (begin, end) = ((interval.begin.x, interval.begin.y), (interval.end.x, interval.end.y));
+            let parabola = if removed.x < interval.begin.x {
+                Parabola::from_three_points((removed.x, removed.y), begin, end)
+            } else {
+                Parabola::from_three_points(begin, end, (removed.x, removed.y))
+            };
+            parabola.and_then(|parabola| {
+                parabola
+                    .roots()
+                    .as_ref()
+                    .iter()
+                    .find(|x| (**x - best) * (**x - limit) < F::zero())
+                    .cloned()
+            })
+        } else {
+            None
+        };
+        // Find the new approximation quadratically
+        x3 = parabola_root.unwrap_or_else(|| {
+            interpolate_quadratic(
+                interval.begin.x,
+                interval.begin.y,
+                interval.end.x,
+                interval.end.y,
+                removed.x,
+                removed.y,
+            )
+        });
+        // Consecutive interpolations must halve the step before last (as in the Brent method), or they may creep towards the root
+        let accepted = (x3 - best) * (x3 - limit) < F::zero()
+            && (bisected || (x3 - best).abs() < (best_before - best_before_last).abs() / F::from(2i16));
+        if !accepted {
+            // fallback to linear approximation
+            x3 = interval.middle();
+        }
+        bisected = !accepted;
+        best_before_last = best_before;
+        best_before = best;
 
         // calculate the approximated value
-        sample3 = Sample { x: x3, y: f(x3) };
+        let sample3 = Sample { x: x3, y: f(x3) };
+        on_iteration(&IterationInfo {
+            iter,
+            begin: interval.begin.x,
+            end: interval.end.x,
+            x: sample3.x,
+            y: sample3.y,
+            interpolated: accepted,
+        });
 
         if convergency.is_root_found(sample3.y) {
             return Ok(sample3.x);
         }
-
-        // Narrow down the search interval while keeping the root bracketed
-        if sample3.is_bracketed_with(&interval.begin) {
-            interval.end = Sample {
-                x: sample3.x,
-                y: sample3.y,
-            };
-        } else {
-            interval.begin = Sample {
-                x: sample3.x,
-                y: sample3.y,
-            };
+        // Successive approximations are close enough
+        if convergency.is_converged(previous, x3) {
+            return Ok(x3);
         }
+        previous = x3;
+
+        removed = narrow(&mut interval, sample3);
 
         if interval.is_converged(convergency) {
             return Ok(interval.middle());
@@ -165,16 +359,116 @@ where
 
         iter = iter + 1;
         if convergency.is_iteration_limit_reached(iter) {
-            return Err(SearchError::NoConvergency);
+            let best = best_end(&interval);
+            return Err(SearchError::no_convergency(iter, best.x, best.y).with_bracket(interval.begin.x, interval.end.x));
         }
     }
 }
 
+/// The end of the interval with the least absolute value of the function
+fn best_end<F: FloatType>(interval: &Interval<F>) -> &Sample<F> {
+    if interval.begin.y.abs() < interval.end.y.abs() {
+        &interval.begin
+    } else {
+        &interval.end
+    }
+}
+
+/// Narrow down the search interval by the sample inside it while keeping the root bracketed;
+/// returns the removed end
+fn narrow<F: FloatType>(interval: &mut Interval<F>, sample: Sample<F>) -> Sample<F> {
+    if sample.is_bracketed_with(&interval.begin) {
+        std::mem::replace(&mut interval.end, sample)
+    } else {
+        std::mem::replace(&mut interval.begin, sample)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::super::*;
     use super::*;
 
+    #[test]
+    fn test_parabola() {
+        let parabola = Parabola::new(2f64, -4f64, -6f64);
+        assert_eq!((parabola.a(), parabola.b(), parabola.c()), (2f64, -4f64, -6f64));
+        assert_eq!(parabola.evaluate(0f64), -6f64);
+        assert_eq!(parabola.evaluate(3f64), 0f64);
+        assert_eq!(parabola.vertex(), Some((1f64, -8f64)));
+        assert_eq!(parabola.roots(), Roots::Two([-1f64, 3f64]));
+        assert_eq!(Parabola::new(1f64, 0f64, 1f64).roots(), Roots::No([]));
+
+        // A line has no vertex, and its root is still found
+        let line = Parabola::new(0f64, 2f64, -1f64);
+        assert_eq!(line.vertex(), None);
+        assert_eq!(line.roots(), Roots::One([0.5f64]));
+    }
+
+    #[test]
+    fn test_from_three_points() {
+        let expected = Some(Parabola::new(1f64, 0f64, -1f64));
+        assert_eq!(
+            Parabola::from_three_points((-10f64, 99f64), (-2f64, 3f64), (0f64, -1f64)),
+            expected
+        );
+        assert_eq!(
+            Parabola::from_three_points((10f64, 99f64), (2f64, 3f64), (0f64, -1f64)),
+            expected
+        );
+        assert_eq!(
+            Parabola::from_three_points((-3f64, 8f64), (2f64, 3f64), (0f64, -1f64)),
+            expected
+        );
+
+        // Collinear points give a line
+        let line = Parabola::from_three_points((0f64, 1f64), (1f64, 3f64), (4f64, 9f64)).unwrap();
+        assert_eq!(line, Parabola::new(0f64, 2f64, 1f64));
+        assert_eq!(line.vertex(), None);
+        assert_eq!(line.roots(), Roots::One([-0.5f64]));
+        // Points on a horizontal line give a constant
+        let constant = Parabola::from_three_points((0f64, 5f64), (1f64, 5f64), (3f64, 5f64)).unwrap();
+        assert_eq!(constant, Parabola::new(0f64, 0f64, 5f64));
+        assert_eq!(constant.roots(), Roots::No([]));
+
+        // Coinciding x values
+        assert_eq!(Parabola::from_three_points((0f64, 5f64), (1f64, 5f64), (0f64, 3f64)), None);
+        assert_eq!(Parabola::from_three_points((1f64, 5f64), (1f64, 5f64), (0f64, 3f64)), None);
+    }
+
+    #[test]
+    fn test_from_three_points_clustered() {
+        // (x - 1)^2 + 3(x - 1) = x^2 + x - 2 at points spaced by about 1e-12 around 1;
+        // the values are exact as t = x - 1 is a multiple of a power of 2
+        let h = 2f64.powi(-40);
+        let point = |k: f64| {
+            let t = k * h;
+            (1f64 + t, t * t + 3f64 * t)
+        };
+        let parabola = Parabola::from_three_points(point(-1f64), point(0.5f64), point(3f64)).unwrap();
+        assert_float_eq!(1e-15f64, parabola.a(), 1f64);
+        assert_float_eq!(1e-15f64, parabola.b(), 1f64);
+        assert_float_eq!(1e-15f64, parabola.c(), -2f64);
+        // The order of points does not matter
+        let reordered = Parabola::from_three_points(point(3f64), point(-1f64), point(0.5f64)).unwrap();
+        assert_float_eq!(1e-15f64, reordered.a(), 1f64);
+        assert_float_eq!(1e-15f64, reordered.b(), 1f64);
+        assert_float_eq!(1e-15f64, reordered.c(), -2f64);
+
+        // (x - 1)^2 has the vertex in the middle of the cluster
+        let point = |k: f64| {
+            let t = k * h;
+            (1f64 + t, t * t)
+        };
+        let parabola = Parabola::from_three_points(point(-2f64), point(1f64), point(5f64)).unwrap();
+        assert_float_eq!(1e-15f64, parabola.a(), 1f64);
+        assert_float_eq!(1e-15f64, parabola.b(), -2f64);
+        assert_float_eq!(1e-15f64, parabola.c(), 1f64);
+        let (x, y) = parabola.vertex().unwrap();
+        assert_float_eq!(1e-15f64, x, 1f64);
+        assert_float_eq!(1e-15f64, y, 0f64);
+    }
+
     #[test]
     fn test_find_root_inverse_quadratic() {
         let f = |x| 1f64 * x * x - 1f64;
@@ -197,52 +491,116 @@ mod test {
         assert_eq!(0, conv.get_iter_count());
 
         conv.reset();
-        assert_eq!(
+        assert!(matches!(
             find_root_inverse_quadratic(10f64, 20f64, &f, &mut conv),
-            Err(SearchError::NoBracketing)
-        );
+            Err(SearchError::NoBracketing { .. })
+        ));
         let result = find_root_inverse_quadratic(10f64, 20f64, &f, &mut conv);
-        assert_eq!(result.unwrap_err().to_string(), "Bracketing Error");
+        assert_eq!(result.unwrap_err().to_string(), "Bracketing Error, bracket [10, 20]");
         assert_eq!(0, conv.get_iter_count());
     }
 
     #[test]
-    fn test_from_three_points() {
-        assert_eq!(
-            Parabola {
-                a: 1f64,
-                b: 0f64,
-                c: -1f64
-            },
-            Parabola::from_three_points(
-                &Sample { x: -10f64, y: 99f64 },
-                &Sample { x: -2f64, y: 3f64 },
-                &Sample { x: 0f64, y: -1f64 }
+    fn test_find_root_inverse_quadratic_evaluations() {
+        let functions: [(fn(f64) -> f64, f64, f64, f64, usize); 6] = [
+            (|x| x * x - 1f64, 0f64, 10f64, 1f64, 4),
+            (|x| x * x * x * x - 1f64, 0f64, 10f64, 1f64, 13),
+            (|x| x * x * x * x - 1f64, 0.5f64, 3f64, 1f64, 11),
+            (|x| (x.sin() + 2f64).ln() - 1f64, 0f64, 2f64, 0.8013296440898812f64, 8),
+            (|x| x.exp() - 2f64, -5f64, 10f64, 2f64.ln(), 24),
+            (|x| x * x * x - 2f64 * x + 2f64, -3f64, 1.5f64, -1.7692923542386314f64, 20),
+        ];
+        for &(f, a, b, root, evaluations) in functions.iter() {
+            let mut count = 0;
+            let mut conv = simple_convergency::SimpleConvergency {
+                eps: 1e-15f64,
+                max_iter: 100,
+            };
+            let found = find_root_inverse_quadratic(
+                a,
+                b,
+                |x| {
+                    count += 1;
+                    f(x)
+                },
+                &mut conv,
             )
-        );
-        assert_eq!(
-            Parabola {
-                a: 1f64,
-                b: 0f64,
-                c: -1f64
+            .ok()
+            .unwrap();
+            assert_float_eq!(1e-15f64, found, root);
+            assert_eq!(evaluations, count);
+            // The order of the ends does not matter
+            assert_eq!(find_root_inverse_quadratic(b, a, f, &mut conv), Ok(found));
+        }
+    }
+
+    #[test]
+    fn test_find_root_inverse_quadratic_creeping() {
+        // Interpolation through the flat part creeps towards the root; the steps must keep shrinking
+        let f = |x: f64| if x < 0.7f64 { -1e-3f64 } else { x - 0.7f64 };
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 200,
+        };
+        let mut iterations = 0;
+        let root = find_root_inverse_quadratic_observed(0f64, 1f64, &f, &mut conv, |info| iterations = info.iter + 1);
+        assert_float_eq!(1e-15f64, root.ok().unwrap(), 0.7f64);
+        assert_eq!(86, iterations);
+    }
+
+    #[test]
+    fn test_find_root_inverse_quadratic_observed() {
+        let f = |x: f64| x.exp() - 2f64;
+        let mut evaluations = 0;
+        let mut events: Vec<IterationInfo<f64>> = Vec::new();
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+        let root = find_root_inverse_quadratic_observed(
+            -5f64,
+            10f64,
+            |x| {
+                evaluations += 1;
+                f(x)
             },
-            Parabola::from_three_points(
-                &Sample { x: 10f64, y: 99f64 },
-                &Sample { x: 2f64, y: 3f64 },
-                &Sample { x: 0f64, y: -1f64 }
-            )
+            &mut conv,
+            |info| events.push(*info),
         );
+        assert_eq!(root, find_root_inverse_quadratic(-5f64, 10f64, &f, &mut 1e-15f64));
+
+        // One event per iteration; the first three evaluations are made before the iterations
+        assert_eq!(events.len(), evaluations - 3);
+        assert_eq!(events.len(), conv.get_iter_count() + 1);
+        for (iter, info) in events.iter().enumerate() {
+            assert_eq!(info.iter, iter);
+            assert!(info.begin < info.x && info.x < info.end);
+            assert_eq!(info.y, f(info.x));
+        }
+        assert_eq!(events.last().unwrap().x, root.unwrap());
+        assert!(events.iter().any(|info| info.interpolated));
+        assert!(events.iter().any(|info| !info.interpolated));
+
+        // No iterations are made without bracketing
+        let mut count = 0;
+        assert!(matches!(
+            find_root_inverse_quadratic_observed(10f64, 20f64, &f, &mut 1e-15f64, |_| count += 1),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_find_root_inverse_quadratic_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let bracket = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteBracket));
+        assert_eq!(find_root_inverse_quadratic(f64::NAN, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_inverse_quadratic(0.5f64, f64::NAN, &f, &mut 1e-15f64), bracket);
         assert_eq!(
-            Parabola {
-                a: 1f64,
-                b: 0f64,
-                c: -1f64
-            },
-            Parabola::from_three_points(
-                &Sample { x: -3f64, y: 8f64 },
-                &Sample { x: 2f64, y: 3f64 },
-                &Sample { x: 0f64, y: -1f64 }
-            )
+            find_root_inverse_quadratic(f64::NEG_INFINITY, 10f64, &f, &mut 1e-15f64),
+            bracket
         );
+        assert_eq!(find_root_inverse_quadratic(0.5f64, f64::INFINITY, &f, &mut 1e-15f64), bracket);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_inverse_quadratic(0f64, 10f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_inverse_quadratic(10f64, -1f64, &f, &mut 1e-15f64), value);
     }
 }
@@ -0,0 +1,831 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+use super::super::FloatType;
+
+/// How the scalars of the next K polynomial are calculated
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Scalars {
+    /// The remainder of K is scaled by its constant term
+    ByConstant,
+    /// The remainder of K is scaled by its linear term
+    ByLinear,
+    /// The quadratic factor almost divides K
+    Factor,
+}
+
+/// Divides p by x^2 + u*x + v into q; returns (a, b) of the remainder b*(x + u) + a
+fn divide_quadratic<F: FloatType>(u: F, v: F, p: &[F], q: &mut [F]) -> (F, F) {
+    let mut b = p[0];
+    q[0] = b;
+    let mut a = p[1] - u * b;
+    q[1] = a;
+    for i in 2..p.len() {
+        let c = p[i] - u * a - v * b;
+        q[i] = c;
+        b = a;
+        a = c;
+    }
+    (a, b)
+}
+
+/// Roots of x^2 + u*x + v as (re, im) of the smaller and the larger one
+fn solve_quadratic<F: FloatType>(u: F, v: F) -> ((F, F), (F, F)) {
+    let _2 = F::from(2i16);
+    let half = -u / _2;
+    let discriminant = half * half - v;
+    if discriminant < F::zero() {
+        let im = (-discriminant).sqrt();
+        ((half, im), (half, -im))
+    } else {
+        let sqrt = discriminant.sqrt();
+        let large = if half < F::zero() { half - sqrt } else { half + sqrt };
+        let small = if large == F::zero() { F::zero() } else { v / large };
+        ((small, F::zero()), (large, F::zero()))
+    }
+}
+
+/// State of the three-stage Jenkins-Traub algorithm for real polynomials
+struct JenkinsTraub<F> {
+    /// Polynomial being solved, highest power first
+    p: Vec<F>,
+    /// Quotient of p by the current factor
+    qp: Vec<F>,
+    /// K polynomial, highest power first
+    k: Vec<F>,
+    /// Quotient of k by the current quadratic factor
+    qk: Vec<F>,
+    /// Current quadratic factor x^2 + u*x + v
+    u: F,
+    v: F,
+    /// Remainders of p and k by the quadratic factor
+    a: F,
+    b: F,
+    c: F,
+    d: F,
+    /// Scalars of the K polynomial recurrence
+    a1: F,
+    a3: F,
+    a7: F,
+    e: F,
+    f: F,
+    g: F,
+    h: F,
+    /// Found roots as (re, im)
+    roots: Vec<(F, F)>,
+}
+
+impl<F: FloatType> JenkinsTraub<F> {
+    fn new(p: Vec<F>) -> JenkinsTraub<F> {
+        let n = p.len();
+        JenkinsTraub {
+            p,
+            qp: vec![F::zero(); n],
+            k: vec![F::zero(); n - 1],
+            qk: vec![F::zero(); n - 1],
+            u: F::zero(),
+            v: F::zero(),
+            a: F::zero(),
+            b: F::zero(),
+            c: F::zero(),
+            d: F::zero(),
+            a1: F::zero(),
+            a3: F::zero(),
+            a7: F::zero(),
+            e: F::zero(),
+            f: F::zero(),
+            g: F::zero(),
+            h: F::zero(),
+            roots: Vec::new(),
+        }
+    }
+
+    /// Degree of the polynomial being solved
+    fn degree(&self) -> usize {
+        self.p.len() - 1
+    }
+
+    /// Divides p by the current quadratic factor
+    fn divide_p(&mut self) {
+        let (a, b) = divide_quadratic(self.u, self.v, &self.p, &mut self.qp);
+        self.a = a;
+        self.b = b;
+    }
+
+    /// Lower bound of the moduli of the roots: the positive root of
+    /// |p[0]|*x^n + ... + |p[n-1]|*x - |p[n]|
+    fn lower_bound(&self) -> F {
+        let n = self.degree();
+        let mut pt: Vec<F> = self.p.iter().map(|c| c.abs()).collect();
+        pt[n] = -pt[n];
+        let evaluate = |x: F| pt.iter().fold(F::zero(), |value, c| value * x + *c);
+
+        let mut x = (-pt[n] / pt[0]).powf(F::one() / F::from(n as i16));
+        if pt[n - 1] != F::zero() {
+            let xm = -pt[n] / pt[n - 1];
+            if xm < x {
+                x = xm;
+            }
+        }
+        // Chop the interval (0, x) until the polynomial is not positive
+        loop {
+            let xm = x / F::from(10i16);
+            if evaluate(xm) <= F::zero() {
+                break;
+            }
+            x = xm;
+        }
+        // Newton's iterations until the bound is within 0.5%
+        let mut dx = x;
+        while (dx / x).abs() > F::from_f64(0.005f64) {
+            let mut ff = pt[0];
+            let mut df = ff;
+            for c in pt[1..n].iter() {
+                ff = ff * x + *c;
+                df = df * x + ff;
+            }
+            ff = ff * x + pt[n];
+            dx = ff / df;
+            x = x - dx;
+        }
+        x
+    }
+
+    /// Calculates the scalars of the next K polynomial from the remainders of p and k
+    fn calculate_scalars(&mut self) -> Scalars {
+        let n = self.degree();
+        let eta = F::epsilon();
+        let (c, d) = divide_quadratic(self.u, self.v, &self.k, &mut self.qk);
+        self.c = c;
+        self.d = d;
+        let _100 = F::from(100i16);
+        if c.abs() <= _100 * eta * self.k[n - 1].abs() && d.abs() <= _100 * eta * self.k[n - 2].abs() {
+            return Scalars::Factor;
+        }
+        let (a, b, u, v) = (self.a, self.b, self.u, self.v);
+        if d.abs() >= c.abs() {
+            self.e = a / d;
+            self.f = c / d;
+            self.g = u * b;
+            self.h = v * b;
+            self.a3 = (a + self.g) * self.e + self.h * (b / d);
+            self.a1 = b * self.f - a;
+            self.a7 = (self.f + u) * a + self.h;
+            Scalars::ByLinear
+        } else {
+            self.e = a / c;
+            self.f = d / c;
+            self.g = u * self.e;
+            self.h = v * b;
+            self.a3 = a * self.e + (self.h / c + self.g) * b;
+            self.a1 = b - a * (d / c);
+            self.a7 = a + self.g * d + self.h * self.f;
+            Scalars::ByConstant
+        }
+    }
+
+    /// Calculates the next K polynomial
+    fn next_k(&mut self, scalars: Scalars) {
+        let n = self.degree();
+        if scalars == Scalars::Factor {
+            // Use unscaled form of the recurrence
+            self.k[0] = F::zero();
+            self.k[1] = F::zero();
+            for i in 2..n {
+                self.k[i] = self.qk[i - 2];
+            }
+            return;
+        }
+        let temp = if scalars == Scalars::ByConstant { self.b } else { self.a };
+        if self.a1.abs() <= temp.abs() * F::epsilon() * F::from(10i16) {
+            // The leading coefficient vanishes
+            self.k[0] = F::zero();
+            self.k[1] = -self.a7 * self.qp[0];
+            for i in 2..n {
+                self.k[i] = self.a3 * self.qk[i - 2] - self.a7 * self.qp[i - 1];
+            }
+        } else {
+            self.a7 = self.a7 / self.a1;
+            self.a3 = self.a3 / self.a1;
+            self.k[0] = self.qp[0];
+            self.k[1] = self.qp[1] - self.a7 * self.qp[0];
+            for i in 2..n {
+                self.k[i] = self.a3 * self.qk[i - 2] - self.a7 * self.qp[i - 1] + self.qp[i];
+            }
+        }
+    }
+
+    /// New estimate (u, v) of the quadratic factor
+    fn next_factor(&self, scalars: Scalars) -> (F, F) {
+        let n = self.degree();
+        if scalars == Scalars::Factor {
+            return (F::zero(), F::zero());
+        }
+        let (u, v) = (self.u, self.v);
+        let (a4, a5) = if scalars == Scalars::ByLinear {
+            ((self.a + self.g) * self.f + self.h, (self.f + u) * self.c + v * self.d)
+        } else {
+            (self.a + u * self.b + self.h * self.f, self.c + (u + v * self.f) * self.d)
+        };
+        let b1 = -self.k[n - 1] / self.p[n];
+        let b2 = -(self.k[n - 2] + b1 * self.p[n - 1]) / self.p[n];
+        let c1 = v * b2 * self.a1;
+        let c2 = b1 * self.a7;
+        let c3 = b1 * b1 * self.a3;
+        let c4 = c1 - c2 - c3;
+        let temp = a5 + b1 * a4 - c4;
+        if temp == F::zero() {
+            return (F::zero(), F::zero());
+        }
+        (
+            u - (u * (c3 + c2) + v * (b1 * self.a1 + b2 * self.a7)) / temp,
+            v * (F::one() + c4 / temp),
+        )
+    }
+
+    /// Stage 1: K polynomials without shifts accentuate the smallest roots
+    fn no_shift(&mut self) {
+        let n = self.degree();
+        for i in 0..n {
+            self.k[i] = F::from((n - i) as i16) * self.p[i] / F::from(n as i16);
+        }
+        let (aa, bb) = (self.p[n], self.p[n - 1]);
+        let mut zero = self.k[n - 1] == F::zero();
+        for _ in 0..5 {
+            let cc = self.k[n - 1];
+            if zero {
+                // Use unscaled form of the recurrence
+                for j in (1..n).rev() {
+                    self.k[j] = self.k[j - 1];
+                }
+                self.k[0] = F::zero();
+                zero = self.k[n - 1] == F::zero();
+            } else {
+                // Use scaled form of the recurrence if value of K at 0 is nonzero
+                let t = -aa / cc;
+                for j in (1..n).rev() {
+                    self.k[j] = t * self.k[j - 1] + self.p[j];
+                }
+                self.k[0] = self.p[0];
+                zero = self.k[n - 1].abs() <= bb.abs() * F::epsilon() * F::from(10i16);
+            }
+        }
+    }
+
+    /// Stage 2: K polynomials with the fixed shift (u, v) separate the roots.
+    /// Switches to stage 3 when the estimates of a real root or of the quadratic factor settle.
+    /// Returns the number of found roots.
+    fn fixed_shift(&mut self, steps: usize, shift: F) -> usize {
+        let n = self.degree();
+        let quarter = F::from_f64(0.25f64);
+        let (mut beta_v, mut beta_s) = (quarter, quarter);
+        let (mut old_s, mut old_v) = (shift, self.v);
+        let (mut old_tv, mut old_ts) = (F::one(), F::one());
+
+        self.divide_p();
+        let mut scalars = self.calculate_scalars();
+        for j in 0..steps {
+            // Calculate the next K polynomial and estimates of v and of a real root s
+            self.next_k(scalars);
+            scalars = self.calculate_scalars();
+            let (ui, vi) = self.next_factor(scalars);
+            let vv = vi;
+            let ss = if self.k[n - 1] != F::zero() {
+                -self.p[n] / self.k[n - 1]
+            } else {
+                F::zero()
+            };
+            let (mut tv, mut ts) = (F::one(), F::one());
+            if j != 0 && scalars != Scalars::Factor {
+                // Compute relative measures of convergency of the estimates
+                if vv != F::zero() {
+                    tv = ((vv - old_v) / vv).abs();
+                }
+                if ss != F::zero() {
+                    ts = ((ss - old_s) / ss).abs();
+                }
+                let tvv = if tv < old_tv { tv * old_tv } else { F::one() };
+                let tss = if ts < old_ts { ts * old_ts } else { F::one() };
+                let v_pass = tvv < beta_v;
+                let s_pass = tss < beta_s;
+                if s_pass || v_pass {
+                    // One of the sequences converges; save the state and try stage 3
+                    let (saved_u, saved_v) = (self.u, self.v);
+                    let saved_k = self.k.clone();
+                    let mut s = ss;
+                    let (mut v_tried, mut s_tried) = (false, false);
+                    let mut quadratic = !(s_pass && (!v_pass || tss < tvv));
+                    let (mut qu, mut qv) = (ui, vi);
+                    loop {
+                        if quadratic {
+                            let found = self.quadratic_iteration(qu, qv);
+                            if found > 0 {
+                                return found;
+                            }
+                            // Linear iteration has not been tried and the s sequence is converging
+                            v_tried = true;
+                            beta_v = beta_v * quarter;
+                            if !s_tried && s_pass {
+                                self.k.copy_from_slice(&saved_k);
+                                quadratic = false;
+                                continue;
+                            }
+                        } else {
+                            let (found, cluster) = self.real_iteration(&mut s);
+                            if found > 0 {
+                                return found;
+                            }
+                            s_tried = true;
+                            beta_s = beta_s * quarter;
+                            if cluster {
+                                // Linear iteration signals an almost double real root; try quadratic iteration
+                                qu = -(s + s);
+                                qv = s * s;
+                                quadratic = true;
+                                continue;
+                            }
+                        }
+                        // Restore the variables
+                        self.u = saved_u;
+                        self.v = saved_v;
+                        self.k.copy_from_slice(&saved_k);
+                        // Try quadratic iteration if it has not been tried and the v sequence is converging
+                        if v_pass && !v_tried {
+                            quadratic = true;
+                            qu = ui;
+                            qv = vi;
+                            continue;
+                        }
+                        break;
+                    }
+                    // Recompute the quotient and the scalars to continue the second stage
+                    self.divide_p();
+                    scalars = self.calculate_scalars();
+                }
+            }
+            old_v = vv;
+            old_s = ss;
+            old_tv = tv;
+            old_ts = ts;
+        }
+        0
+    }
+
+    /// Stage 3: variable shift iteration for a quadratic factor starting from (u, v).
+    /// Returns 2 if the factor is found.
+    fn quadratic_iteration(&mut self, u: F, v: F) -> usize {
+        let n = self.degree();
+        let eta = F::epsilon();
+        let (are, mre) = (eta, eta);
+        let _2 = F::from(2i16);
+        let _5 = F::from(5i16);
+        self.u = u;
+        self.v = v;
+        let mut tried = false;
+        let mut relative_step = F::zero();
+        let mut old_mp = F::zero();
+        let mut j = 0;
+        loop {
+            let ((szr, szi), (lzr, lzi)) = solve_quadratic(self.u, self.v);
+            // Return if the roots of the quadratic are real and not close to multiple
+            // or nearly equal and of opposite sign
+            if (szr.abs() - lzr.abs()).abs() > F::from_f64(0.01f64) * lzr.abs() {
+                return 0;
+            }
+            // Evaluate the polynomial by quadratic synthetic division
+            self.divide_p();
+            let mp = (self.a - szr * self.b).abs() + (szi * self.b).abs();
+            // Compute a rigorous bound on the rounding error in evaluating p
+            let zm = self.v.abs().sqrt();
+            let t = -szr * self.b;
+            let mut ee = _2 * self.qp[0].abs();
+            for q in self.qp[1..n].iter() {
+                ee = ee * zm + q.abs();
+            }
+            ee = ee * zm + (self.a + t).abs();
+            ee = (_5 * mre + F::from(4i16) * are) * ee - (_5 * mre + _2 * are) * ((self.a + t).abs() + self.b.abs() * zm)
+                + _2 * are * t.abs();
+            // Iteration has converged sufficiently if the polynomial value is less than 20 times this bound
+            if mp <= F::from(20i16) * ee {
+                self.roots.push((szr, szi));
+                self.roots.push((lzr, lzi));
+                return 2;
+            }
+            j += 1;
+            // Stop iteration after 20 steps
+            if j > 20 {
+                return 0;
+            }
+            if j >= 2 && relative_step <= F::from_f64(0.01f64) && mp >= old_mp && !tried {
+                // A cluster appears to be stalling the convergency; five fixed shift steps are taken with a perturbed u, v
+                if relative_step < eta {
+                    relative_step = eta;
+                }
+                relative_step = relative_step.sqrt();
+                self.u = self.u - self.u * relative_step;
+                self.v = self.v + self.v * relative_step;
+                self.divide_p();
+                for _ in 0..5 {
+                    let scalars = self.calculate_scalars();
+                    self.next_k(scalars);
+                }
+                tried = true;
+                j = 0;
+            }
+            old_mp = mp;
+            // Calculate the next K polynomial and new u and v
+            let scalars = self.calculate_scalars();
+            self.next_k(scalars);
+            let scalars = self.calculate_scalars();
+            let (ui, vi) = self.next_factor(scalars);
+            // If vi is zero, the iteration is not converging
+            if vi == F::zero() {
+                return 0;
+            }
+            relative_step = ((vi - self.v) / vi).abs();
+            self.u = ui;
+            self.v = vi;
+        }
+    }
+
+    /// Stage 3: variable shift iteration for a real root starting from s.
+    /// Returns 1 if the root is found, and whether the iteration stalls at a cluster of roots;
+    /// in the latter case s is the starting point for a quadratic iteration.
+    fn real_iteration(&mut self, s: &mut F) -> (usize, bool) {
+        let n = self.degree();
+        let eta = F::epsilon();
+        let (are, mre) = (eta, eta);
+        let mut x = *s;
+        let mut t = F::zero();
+        let mut old_mp = F::zero();
+        let mut j = 0;
+        loop {
+            // Evaluate p at x
+            let mut pv = self.p[0];
+            self.qp[0] = pv;
+            for i in 1..=n {
+                pv = pv * x + self.p[i];
+                self.qp[i] = pv;
+            }
+            let mp = pv.abs();
+            // Compute a rigorous bound on the error in evaluating p
+            let ms = x.abs();
+            let mut ee = mre / (are + mre) * self.qp[0].abs();
+            for q in self.qp[1..=n].iter() {
+                ee = ee * ms + q.abs();
+            }
+            // Iteration has converged sufficiently if the polynomial value is less than 20 times this bound
+            if mp <= F::from(20i16) * ((are + mre) * ee - mre * mp) {
+                self.roots.push((x, F::zero()));
+                return (1, false);
+            }
+            j += 1;
+            // Stop iteration after 10 steps
+            if j > 10 {
+                return (0, false);
+            }
+            if j >= 2 && t.abs() <= F::from_f64(0.001f64) * (x - t).abs() && mp > old_mp {
+                // A cluster of zeros near the real axis has been encountered; return with the flag
+                // to initiate a quadratic iteration
+                *s = x;
+                return (0, true);
+            }
+            // Return if the polynomial value has increased significantly
+            old_mp = mp;
+            // Compute t, the next polynomial, and the new iterate
+            let mut kv = self.k[0];
+            self.qk[0] = kv;
+            for i in 1..n {
+                kv = kv * x + self.k[i];
+                self.qk[i] = kv;
+            }
+            let small = self.k[n - 1].abs() * F::from(10i16) * eta;
+            if kv.abs() <= small {
+                // Use unscaled form
+                self.k[0] = F::zero();
+                for i in 1..n {
+                    self.k[i] = self.qk[i - 1];
+                }
+            } else {
+                // Use the scaled form of the recurrence if the value of K at x is nonzero
+                let tt = -pv / kv;
+                self.k[0] = self.qp[0];
+                for i in 1..n {
+                    self.k[i] = tt * self.qk[i - 1] + self.qp[i];
+                }
+            }
+            // Use unscaled form
+            let kv = self.k.iter().fold(F::zero(), |value, c| value * x + *c);
+            t = if kv.abs() > small { -pv / kv } else { F::zero() };
+            x = x + t;
+        }
+    }
+
+    /// Finds one real root or a quadratic factor of p and deflates p by it.
+    /// Returns false if the iterations do not converge.
+    fn find_factor(&mut self) -> bool {
+        let n = self.degree();
+        let bound = self.lower_bound();
+        self.no_shift();
+        let saved_k = self.k.clone();
+
+        // Shifts are on the circle of the lower bound, rotated by 94 degrees each time
+        let (cos, sin) = (F::from_f64(-0.069_756_474f64), F::from_f64(0.997_564_05f64));
+        let (mut xx, mut yy) = (
+            F::from_f64(std::f64::consts::FRAC_1_SQRT_2),
+            -F::from_f64(std::f64::consts::FRAC_1_SQRT_2),
+        );
+        for attempt in 1..=20 {
+            let x = cos * xx - sin * yy;
+            yy = sin * xx + cos * yy;
+            xx = x;
+            let shift = bound * xx;
+            self.u = -F::from(2i16) * shift;
+            self.v = bound * bound;
+
+            let found = self.fixed_shift(20 * attempt, shift);
+            if found > 0 {
+                // Deflate the polynomial by the found factor
+                self.p = self.qp[..=n - found].to_vec();
+                self.qp.truncate(n + 1 - found);
+                self.k.truncate(n - found);
+                self.qk.truncate(n - found);
+                return true;
+            }
+            // Restore K to try another shift
+            self.k.copy_from_slice(&saved_k);
+        }
+        false
+    }
+}
+
+/// Finds roots of the normalized polynomial as (re, im)
+fn solve<F: FloatType>(a: &[F]) -> Vec<(F, F)> {
+    let mut p = Vec::with_capacity(a.len() + 1);
+    p.push(F::one());
+    p.extend_from_slice(a);
+
+    // Remove the roots at the origin
+    let mut zeros = 0;
+    while p.len() > 1 && p[p.len() - 1] == F::zero() {
+        p.pop();
+        zeros += 1;
+    }
+
+    let mut solver = JenkinsTraub::new(p);
+    solver.roots.resize(zeros, (F::zero(), F::zero()));
+    loop {
+        match solver.degree() {
+            0 => break,
+            1 => {
+                solver.roots.push((-solver.p[1] / solver.p[0], F::zero()));
+                break;
+            }
+            2 => {
+                let (small, large) = solve_quadratic(solver.p[1] / solver.p[0], solver.p[2] / solver.p[0]);
+                solver.roots.push(small);
+                solver.roots.push(large);
+                break;
+            }
+            _ => {
+                if !solver.find_factor() {
+                    break;
+                }
+            }
+        }
+    }
+    solver.roots
+}
+
+/// Find real roots of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// using the three-stage Jenkins-Traub algorithm for real polynomials (RPOLY).
+///
+/// Stage 1 accentuates the smallest roots by several K polynomials without shifts.
+/// Stage 2 separates a root or a pair of complex roots by K polynomials with a fixed quadratic shift.
+/// Stage 3 finds it by variable shifts, either a real one or a quadratic factor.
+/// Then the polynomial is deflated by the found real root or quadratic factor.
+/// Iterations stop when the value of the polynomial is within the bound of its rounding error,
+/// so no convergency criteria are needed.
+///
+/// Pro
+///
+/// + Robust black-box solver for polynomials of high degree
+/// + Complex pairs of roots are found in real arithmetic
+/// + Fast convergency to multiple and clustered roots
+///
+/// Contra
+///
+/// - Polynomials only
+/// - Complex implementation
+///
+/// If the iterations fail to converge, roots found so far are returned.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_jenkins_traub;
+///
+/// // x^3 - x^2 + x - 1 = (x - 1)(x^2 + 1)
+/// let polynom = [-1f64, 1f64, -1f64];
+///
+/// let roots = find_roots_jenkins_traub(&polynom);
+/// // Returns approximately vec![1];
+/// ```
+pub fn find_roots_jenkins_traub<F: FloatType>(a: &[F]) -> Vec<F> {
+    let mut roots: Vec<F> = solve(a)
+        .into_iter()
+        .filter(|&(_, im)| im == F::zero())
+        .map(|(re, _)| re)
+        .collect();
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots
+}
+
+/// Find complex roots of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// using the three-stage Jenkins-Traub algorithm for real polynomials (RPOLY).
+///
+/// See find_roots_jenkins_traub. Complex pairs of roots are reported consecutively.
+/// Requires the num-complex feature.
+///
+/// # Examples
+///
+/// ```
+/// extern crate num_complex;
+/// extern crate roots;
+///
+/// use roots::find_roots_jenkins_traub_complex;
+///
+/// // x^3 - x^2 + x - 1 = (x - 1)(x - i)(x + i)
+/// let polynom = [-1f64, 1f64, -1f64];
+///
+/// let roots = find_roots_jenkins_traub_complex(&polynom);
+/// // Returns approximately vec![i, -i, 1] in some order;
+/// ```
+#[cfg(feature = "num-complex")]
+pub fn find_roots_jenkins_traub_complex<F: FloatType>(a: &[F]) -> Vec<Complex<F>> {
+    solve(a).into_iter().map(|(re, im)| Complex::new(re, im)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Normalized polynomial (without the leading 1) with the given roots
+    fn polynom_from_roots(roots: &[f64]) -> Vec<f64> {
+        let mut p = vec![1f64];
+        for root in roots {
+            p.push(0f64);
+            for i in (1..p.len()).rev() {
+                p[i] = p[i] - root * p[i - 1];
+            }
+        }
+        p.remove(0);
+        p
+    }
+
+    /// Coefficients uniformly distributed in [-1, 1) by a linear congruential generator
+    fn random_polynom(n: usize, seed: u64) -> Vec<f64> {
+        let mut state = seed;
+        (0..n)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 11) as f64 / (1u64 << 52) as f64 - 1f64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_find_roots_jenkins_traub() {
+        assert_float_array_eq!(1e-15f64, find_roots_jenkins_traub(&[-1f64, 1f64, -1f64]), [1f64]);
+        assert_float_array_eq!(1e-15f64, find_roots_jenkins_traub(&[0f64, -1f64, 0f64]), [-1f64, 0f64, 1f64]);
+        // (x + 3)(x + 1)(x - 2)(x - 4)(x - 5)
+        let polynom = polynom_from_roots(&[-3f64, -1f64, 2f64, 4f64, 5f64]);
+        assert_float_array_eq!(1e-13f64, find_roots_jenkins_traub(&polynom), [-3f64, -1f64, 2f64, 4f64, 5f64]);
+        // x^4 + 1 has no real roots
+        assert_eq!(0, find_roots_jenkins_traub(&[0f64, 0f64, 0f64, 1f64]).len());
+    }
+
+    #[test]
+    fn test_find_roots_jenkins_traub_wilkinson() {
+        let roots: Vec<f64> = (1..=10).map(f64::from).collect();
+        let polynom = polynom_from_roots(&roots);
+        assert_float_array_eq!(1e-9f64, find_roots_jenkins_traub(&polynom), roots);
+    }
+
+    #[test]
+    fn test_find_roots_jenkins_traub_multiple() {
+        // (x - 1)^3 (x + 2)^2
+        let polynom = polynom_from_roots(&[1f64, 1f64, 1f64, -2f64, -2f64]);
+        let roots = find_roots_jenkins_traub(&polynom);
+        assert_eq!(5, roots.len());
+        assert_float_array_eq!(1e-4f64, roots, [-2f64, -2f64, 1f64, 1f64, 1f64]);
+    }
+
+    #[test]
+    fn test_find_roots_jenkins_traub_reported() {
+        // Polynomials reported for find_roots_eigen
+        let polynom = [
+            0.0126298310280606f64 / -0.000000000000000040410628481035f64,
+            -0.100896606408756f64 / -0.000000000000000040410628481035f64,
+            0.0689539597036461f64 / -0.000000000000000040410628481035f64,
+        ];
+        let roots = find_roots_jenkins_traub(&polynom);
+        assert_eq!(3, roots.len());
+        assert_float_eq!(1e-12f64, roots[0], 0.7547108770537f64);
+        assert_float_eq!(1e-10f64, roots[1], 7.23404258961f64);
+        assert_float_eq!(1e-14f64, roots[2] / 312537357195213f64, 1f64);
+
+        // Both roots are double, so they are found within sqrt(epsilon)
+        let polynom = [
+            -3.75f64 / -14.0625f64,
+            29.75f64 / -14.0625f64,
+            4.0f64 / -14.0625f64,
+            -16.0f64 / -14.0625f64,
+        ];
+        assert_float_array_eq!(
+            1e-7f64,
+            find_roots_jenkins_traub(&polynom),
+            [
+                -1.1016116464173349f64,
+                -1.1016116464173349f64,
+                0.9682783130840016f64,
+                0.9682783130840016f64
+            ]
+        );
+
+        assert_float_array_eq!(1e-14f64, find_roots_jenkins_traub(&[-2.5, 5.0, -5.0, 2.5, -0.5]), [0.5f64]);
+
+        // Nearly double root, which find_roots_eigen misses but find_roots_quartic finds
+        let polynom = [-111.35528725660045, 4666.666666666667, -87228.30835100368, 613541.6666666666];
+        assert_float_array_eq!(1e-5f64, find_roots_jenkins_traub(&polynom), [27.8388216f64, 27.8388216f64]);
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_find_roots_jenkins_traub_complex() {
+        // (x - 1)(x^2 + 1)(x^2 - 2x + 5)
+        let mut roots = find_roots_jenkins_traub_complex(&[-3f64, 8f64, -8f64, 7f64, -5f64]);
+        roots.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+        let expected = [(0f64, -1f64), (0f64, 1f64), (1f64, -2f64), (1f64, 0f64), (1f64, 2f64)];
+        assert_eq!(expected.len(), roots.len());
+        for (root, &(re, im)) in roots.iter().zip(expected.iter()) {
+            assert_float_eq!(1e-14f64, root.re, re);
+            assert_float_eq!(1e-14f64, root.im, im);
+        }
+    }
+
+    #[test]
+    fn test_find_roots_jenkins_traub_random() {
+        for seed in 1..20 {
+            let polynom = random_polynom(12, seed);
+            let roots = solve(&polynom);
+            assert_eq!(12, roots.len());
+            for &(re, im) in roots.iter() {
+                // Residual of the root relative to the magnitude of the terms of the polynomial
+                let (mut value_re, mut value_im, mut magnitude) = (1f64, 0f64, 1f64);
+                let modulus = (re * re + im * im).sqrt();
+                for c in polynom.iter() {
+                    let t = value_re * re - value_im * im + c;
+                    value_im = value_re * im + value_im * re;
+                    value_re = t;
+                    magnitude = magnitude * modulus + c.abs();
+                }
+                let residual = (value_re * value_re + value_im * value_im).sqrt() / magnitude;
+                assert!(
+                    residual < 1e-11f64,
+                    "seed {} root ({}, {}) residual {}",
+                    seed,
+                    re,
+                    im,
+                    residual
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+#[cfg(feature = "num-complex")]
+use super::super::analytical::quartic_complex::{complex_div, complex_mul, complex_sqrt};
+use super::super::FloatType;
+use super::check_start;
+use super::check_value;
+use super::Convergency;
+use super::SearchError;
+
+/// Values of the normalized polynomial, its first and second derivatives at x by the Horner's scheme
+fn evaluate<F: FloatType>(a: &[F], x: F) -> (F, F, F) {
+    let (mut p, mut d1, mut d2) = (F::one(), F::zero(), F::zero());
+    for c in a.iter() {
+        d2 = d2 * x + d1;
+        d1 = d1 * x + p;
+        p = p * x + *c;
+    }
+    (p, d1, F::from(2i16) * d2)
+}
+
+/// Find a root of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// using the Laguerre's method.
+///
+/// Each step is n/(G ± sqrt((n-1)(nH - G^2))) with G = p'/p and H = G^2 - p''/p,
+/// where the sign gives the greatest denominator. If the square root is imaginary (complex roots are near),
+/// only its real part is used; find_root_laguerre_complex can escape such points.
+///
+/// Pro
+///
+/// + Cubic convergency to simple roots
+/// + Converges from almost any starting point
+/// + No need for derivative function
+///
+/// Contra
+///
+/// - Polynomials only
+/// - Can stagnate when the nearest roots are complex
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The denominator of the step vanishes. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_laguerre;
+///
+/// // x^3 - 6x^2 + 11x - 6 = (x - 1)(x - 2)(x - 3)
+/// let polynom = [-6f64, 11f64, -6f64];
+///
+/// let root = find_root_laguerre(&polynom, 0f64, &mut 1e-15f64);
+/// // Returns approximately Ok(1);
+/// ```
+pub fn find_root_laguerre<F>(a: &[F], start: F, convergency: &mut (impl Convergency<F> + ?Sized)) -> Result<F, SearchError>
+where
+    F: FloatType,
+{
+    let n = F::from(a.len() as i16);
+    check_start(start)?;
+    let mut x = start;
+
+    let mut iter = 0;
+    loop {
+        let (p, d1, d2) = evaluate(a, x);
+        if iter == 0 {
+            check_value(p)?;
+        }
+        if convergency.is_root_found(p) {
+            return Ok(x);
+        }
+        let g = d1 / p;
+        let h = g * g - d2 / p;
+        let discriminant = (n - F::one()) * (n * h - g * g);
+        let sqrt = if discriminant > F::zero() {
+            discriminant.sqrt()
+        } else {
+            F::zero()
+        };
+        let denominator = if g < F::zero() { g - sqrt } else { g + sqrt };
+        // Denominator is 0; try to correct the bad starting point
+        if convergency.is_root_found(denominator) {
+            if iter == 0 {
+                x = x + F::one();
+                iter += 1;
+                continue;
+            } else {
+                return Err(SearchError::zero_derivative(iter, x, p));
+            }
+        }
+
+        let x1 = x - n / denominator;
+        if convergency.is_converged(x, x1) {
+            return Ok(x1);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency(iter, x, p));
+        }
+        x = x1;
+    }
+}
+
+/// Values of the normalized polynomial, its first and second derivatives at complex x by the Horner's scheme
+#[cfg(feature = "num-complex")]
+fn evaluate_complex<F: FloatType>(a: &[F], x: Complex<F>) -> (Complex<F>, Complex<F>, Complex<F>) {
+    let (mut p, mut d1, mut d2) = (
+        Complex::new(F::one(), F::zero()),
+        Complex::new(F::zero(), F::zero()),
+        Complex::new(F::zero(), F::zero()),
+    );
+    for c in a.iter() {
+        let t = complex_mul(d2, x);
+        d2 = Complex::new(t.re + d1.re, t.im + d1.im);
+        let t = complex_mul(d1, x);
+        d1 = Complex::new(t.re + p.re, t.im + p.im);
+        let t = complex_mul(p, x);
+        p = Complex::new(t.re + *c, t.im);
+    }
+    let _2 = F::from(2i16);
+    (p, d1, Complex::new(_2 * d2.re, _2 * d2.im))
+}
+
+/// Find a complex root of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// using the Laguerre's method with complex iterates.
+///
+/// Unlike find_root_laguerre, it finds complex roots and does not stagnate near them.
+/// Requires the num-complex feature.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The denominator of the step vanishes. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// extern crate num_complex;
+/// extern crate roots;
+///
+/// use num_complex::Complex;
+/// use roots::find_root_laguerre_complex;
+///
+/// // x^2 + 1
+/// let polynom = [0f64, 1f64];
+///
+/// let root = find_root_laguerre_complex(&polynom, Complex::new(0.5f64, 0f64), &mut 1e-15f64);
+/// // Returns approximately Ok(i) or Ok(-i);
+/// ```
+#[cfg(feature = "num-complex")]
+pub fn find_root_laguerre_complex<F>(
+    a: &[F],
+    start: Complex<F>,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<Complex<F>, SearchError>
+where
+    F: FloatType,
+{
+    let n = F::from(a.len() as i16);
+    let modulus = |z: Complex<F>| (z.re * z.re + z.im * z.im).sqrt();
+    check_start(start.re)?;
+    check_start(start.im)?;
+    let mut x = start;
+
+    let mut iter = 0;
+    loop {
+        let (p, d1, d2) = evaluate_complex(a, x);
+        if iter == 0 {
+            check_value(p.re)?;
+            check_value(p.im)?;
+        }
+        if convergency.is_root_found(modulus(p)) {
+            return Ok(x);
+        }
+        let g = complex_div(d1, p);
+        let g2 = complex_mul(g, g);
+        let d2p = complex_div(d2, p);
+        let h = Complex::new(g2.re - d2p.re, g2.im - d2p.im);
+        let discriminant = Complex::new((n - F::one()) * (n * h.re - g2.re), (n - F::one()) * (n * h.im - g2.im));
+        let sqrt = complex_sqrt(discriminant);
+        let plus = Complex::new(g.re + sqrt.re, g.im + sqrt.im);
+        let minus = Complex::new(g.re - sqrt.re, g.im - sqrt.im);
+        let denominator = if modulus(plus) < modulus(minus) { minus } else { plus };
+        // Denominator is 0; try to correct the bad starting point
+        if convergency.is_root_found(modulus(denominator)) {
+            if iter == 0 {
+                x = Complex::new(x.re + F::one(), x.im);
+                iter += 1;
+                continue;
+            } else {
+                // The complex point does not fit into the error
+                return Err(SearchError::ZeroDerivative {
+                    iterations: iter,
+                    last: None,
+                    bracket: None,
+                });
+            }
+        }
+
+        let step = complex_div(Complex::new(n, F::zero()), denominator);
+        let x1 = Complex::new(x.re - step.re, x.im - step.im);
+        if convergency.is_converged(F::zero(), modulus(step)) {
+            return Ok(x1);
+        }
+
+        x = x1;
+        iter += 1;
+
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency_after(iter));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_find_root_laguerre() {
+        // (x - 1)(x - 2)(x - 3)
+        let polynom = [-6f64, 11f64, -6f64];
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_laguerre(&polynom, 0f64, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(3, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_laguerre(&polynom, 10f64, &mut conv).ok().unwrap(), 3f64);
+        assert_eq!(4, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_roots_laguerre_deflation() {
+        // (x + 4)(x + 2)(x + 0.5)(x - 1)(x - 3)(x - 7)
+        let polynom = [-4.5f64, -29.5f64, 63.5f64, 160.5f64, -107f64, -84f64];
+        let mut roots = Vec::new();
+        let mut deflated = polynom.to_vec();
+        while !deflated.is_empty() {
+            let root = find_root_laguerre(&deflated, 0f64, &mut 1e-15f64).ok().unwrap();
+            // Deflated polynomial accumulates rounding errors; polish the root on the original one
+            let root = find_root_laguerre(&polynom, root, &mut 1e-15f64).ok().unwrap();
+            deflated = deflation::deflate(&deflated, root);
+            roots.push(root);
+        }
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_float_array_eq!(1e-13f64, roots, [-4f64, -2f64, -0.5f64, 1f64, 3f64, 7f64]);
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_find_root_laguerre_complex() {
+        // x^2 + 1 has no real roots; real iterates jump around
+        let polynom = [0f64, 1f64];
+        assert!(find_root_laguerre(&polynom, 0.5f64, &mut 1e-15f64).is_err());
+
+        let root = find_root_laguerre_complex(&polynom, Complex::new(0.5f64, 0f64), &mut 1e-15f64)
+            .ok()
+            .unwrap();
+        assert_float_eq!(1e-15f64, root.re, 0f64);
+        assert_float_eq!(1e-15f64, root.im.abs(), 1f64);
+
+        // x^6 - 1
+        let polynom = [0f64, 0f64, 0f64, 0f64, 0f64, -1f64];
+        for &start in [0.3f64, -0.7f64, 2f64].iter() {
+            let root = find_root_laguerre_complex(&polynom, Complex::new(start, 0.1f64), &mut 1e-15f64)
+                .ok()
+                .unwrap();
+            assert_float_eq!(1e-15f64, root.re * root.re + root.im * root.im, 1f64);
+        }
+    }
+
+    #[test]
+    fn test_find_root_laguerre_invalid_input() {
+        let polynom = [-6f64, 11f64, -6f64];
+        let start = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteStart));
+        assert_eq!(find_root_laguerre(&polynom, f64::NAN, &mut 1e-15f64), start);
+        assert_eq!(find_root_laguerre(&polynom, f64::INFINITY, &mut 1e-15f64), start);
+        assert_eq!(
+            find_root_laguerre(&[-6f64, 11f64, f64::NAN], 0f64, &mut 1e-15f64),
+            Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue))
+        );
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::Convergency;
+use super::InvalidInputReason;
+use super::SearchError;
+
+/// Algorithms available through find_root
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Method {
+    /// find_root_bisection, needs a bracket
+    Bisection,
+    /// find_root_brent, needs a bracket
+    Brent,
+    /// find_root_chandrupatla, needs a bracket
+    Chandrupatla,
+    /// find_root_dekker, needs a bracket
+    Dekker,
+    /// find_root_inverse_quadratic, needs a bracket
+    InverseQuadratic,
+    /// find_root_regula_falsi, needs a bracket
+    RegulaFalsi,
+    /// find_root_ridders, needs a bracket
+    Ridders,
+    /// find_root_secant with two starting points, or find_root_secant_auto with one
+    Secant,
+    /// find_root_steffensen, needs one starting point
+    Steffensen,
+}
+
+impl Method {
+    /// All methods, e.g. to compare them on the same function
+    pub const ALL: [Method; 9] = [
+        Method::Bisection,
+        Method::Brent,
+        Method::Chandrupatla,
+        Method::Dekker,
+        Method::InverseQuadratic,
+        Method::RegulaFalsi,
+        Method::Ridders,
+        Method::Secant,
+        Method::Steffensen,
+    ];
+
+    /// True if the method needs an initial bracket
+    pub fn needs_bracket(&self) -> bool {
+        !matches!(self, Method::Secant | Method::Steffensen)
+    }
+}
+
+/// Where find_root starts the search
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Start<F>
+where
+    F: FloatType,
+{
+    /// Values that bracket the root, or two starting points for the secant method
+    Bracket(F, F),
+    /// One starting point
+    Point(F),
+}
+
+/// Find a root of the function f(x) = 0 using the given method.
+///
+/// Makes it possible to switch between algorithms without changing the call.
+/// Bracketing methods need `Start::Bracket`, the Steffensen's method needs `Start::Point`,
+/// and the secant method accepts both.
+///
+/// # Failures
+/// ## InvalidInput
+/// The method cannot start from the given input, e.g. a bracketing method from a single point.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## ZeroDerivative
+/// The slope of the function is zero. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root;
+/// use roots::Method;
+/// use roots::Start;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let root1 = find_root(Method::Brent, Start::Bracket(0f64, 10f64), &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1);
+///
+/// let root2 = find_root(Method::Steffensen, Start::Point(10f64), &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1);
+///
+/// let misuse = find_root(Method::Brent, Start::Point(10f64), &f, &mut 1e-15f64);
+/// // Returns Err(SearchError::InvalidInput { .. });
+/// ```
+pub fn find_root<F, Func>(
+    method: Method,
+    start: Start<F>,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    match (method, start) {
+        (Method::Bisection, Start::Bracket(a, b)) => super::bisection::find_root_bisection(a, b, f, convergency),
+        (Method::Brent, Start::Bracket(a, b)) => super::brent::find_root_brent(a, b, f, convergency),
+        (Method::Chandrupatla, Start::Bracket(a, b)) => super::chandrupatla::find_root_chandrupatla(a, b, f, convergency),
+        (Method::Dekker, Start::Bracket(a, b)) => super::dekker::find_root_dekker(a, b, f, convergency),
+        (Method::InverseQuadratic, Start::Bracket(a, b)) => {
+            super::inverse_quadratic::find_root_inverse_quadratic(a, b, f, convergency)
+        }
+        (Method::RegulaFalsi, Start::Bracket(a, b)) => super::regula_falsi::find_root_regula_falsi(a, b, f, convergency),
+        (Method::Ridders, Start::Bracket(a, b)) => super::ridders::find_root_ridders(a, b, f, convergency),
+        (Method::Secant, Start::Bracket(a, b)) => super::secant::find_root_secant(a, b, f, convergency),
+        (Method::Secant, Start::Point(x)) => super::secant::find_root_secant_auto(x, f, convergency),
+        (Method::Steffensen, Start::Point(x)) => super::steffensen::find_root_steffensen(x, f, convergency),
+        _ => Err(SearchError::invalid_input(InvalidInputReason::Unsuitable)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_find_root() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+
+        for method in Method::ALL.iter() {
+            let start = if method.needs_bracket() {
+                Start::Bracket(0f64, 10f64)
+            } else {
+                Start::Point(2f64)
+            };
+            assert_float_eq!(1e-15f64, find_root(*method, start, &f, &mut conv).ok().unwrap(), 1f64);
+        }
+        assert_float_eq!(
+            1e-15f64,
+            find_root(Method::Secant, Start::Bracket(2f64, 3f64), &f, &mut conv)
+                .ok()
+                .unwrap(),
+            1f64
+        );
+    }
+
+    #[test]
+    fn test_find_root_misuse() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+
+        for method in Method::ALL.iter() {
+            let result = find_root(*method, Start::Point(2f64), &f, &mut conv);
+            assert_eq!(result.is_ok(), !method.needs_bracket());
+            if method.needs_bracket() {
+                assert_eq!(
+                    result,
+                    Err(SearchError::InvalidInput {
+                        reason: InvalidInputReason::Unsuitable
+                    })
+                );
+            }
+        }
+        assert_eq!(
+            find_root(Method::Steffensen, Start::Bracket(0f64, 10f64), &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            })
+        );
+        assert!(matches!(
+            find_root(Method::Brent, Start::Bracket(2f64, 10f64), &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+}
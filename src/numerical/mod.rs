@@ -23,8 +23,10 @@
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use super::FloatType;
+use std::cell::Cell;
 use std::error::Error;
 use std::fmt;
+use std::ops::RangeInclusive;
 
 /// Pair of the independent variable x and the function value y=F(x)
 #[derive(Debug, PartialEq)]
@@ -66,13 +68,9 @@ where
     fn is_bracketed(&self) -> bool {
         self.begin.is_bracketed_with(&self.end)
     }
-    fn is_converged(&self, convergency: &mut dyn Convergency<F>) -> bool {
+    fn is_converged(&self, convergency: &mut (impl Convergency<F> + ?Sized)) -> bool {
         convergency.is_converged(self.begin.x, self.end.x)
     }
-    /// Check if the given X is inside the interval
-    fn contains_x(&self, x: &F) -> bool {
-        *x <= self.end.x && *x >= self.begin.x
-    }
     /// Returns a point somewhere in middle of the interval for narrowing this interval down.
     /// Rules are as follows:
     /// * If the interval is bracketed, use the secant to find the middle point.
@@ -99,32 +97,402 @@ where
 }
 
 /// Possible errors
+///
+/// Failures of the search carry the number of iterations performed, the last estimate of the root with its f(x),
+/// and the final bracket of the bracketing methods, when they are known. The values are converted to f64
+/// to keep the error independent of the float type.
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum SearchError {
     /// The algorithm could not converge within the given number of iterations
-    NoConvergency,
+    NoConvergency {
+        /// Number of iterations performed
+        iterations: usize,
+        /// The last estimate of the root and f(x)
+        last: Option<(f64, f64)>,
+        /// The final bracket of the root
+        bracket: Option<(f64, f64)>,
+    },
     /// Initial values do not bracket zero
-    NoBracketing,
+    NoBracketing {
+        /// Number of iterations performed
+        iterations: usize,
+        /// The last point examined and f(x)
+        last: Option<(f64, f64)>,
+        /// The interval which does not bracket zero
+        bracket: Option<(f64, f64)>,
+    },
     /// The algorithm cannot continue from the point where the derivative is zero
-    ZeroDerivative,
+    ZeroDerivative {
+        /// Number of iterations performed
+        iterations: usize,
+        /// The x where the derivative is zero and f(x)
+        last: Option<(f64, f64)>,
+        /// The bracket of the root, if the method keeps one
+        bracket: Option<(f64, f64)>,
+    },
+    /// The search range is empty or not finite, or the input does not suit the method
+    InvalidInput {
+        /// What is wrong with the input
+        reason: InvalidInputReason,
+    },
 }
 
-impl fmt::Display for SearchError {
+/// Reasons of SearchError::InvalidInput
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum InvalidInputReason {
+    /// An end of the bracket or the search range is NaN or infinite
+    NotFiniteBracket,
+    /// The starting point is NaN or infinite
+    NotFiniteStart,
+    /// The function is NaN or infinite at an end of the bracket or at the starting point
+    NotFiniteValue,
+    /// The search range is empty, or the ends of the bracket coincide
+    EmptyRange,
+    /// The input does not suit the method, e.g. the ends of the logarithmic bisection are not positive
+    Unsuitable,
+}
+
+impl fmt::Display for InvalidInputReason {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            SearchError::NoConvergency => write!(f, "Convergency Error"),
-            SearchError::NoBracketing => write!(f, "Bracketing Error"),
-            SearchError::ZeroDerivative => write!(f, "Zero Derivative Error"),
+            InvalidInputReason::NotFiniteBracket => write!(f, "the bracket is not finite"),
+            InvalidInputReason::NotFiniteStart => write!(f, "the starting point is not finite"),
+            InvalidInputReason::NotFiniteValue => write!(f, "the function is not finite at the initial points"),
+            InvalidInputReason::EmptyRange => write!(f, "the range is empty"),
+            InvalidInputReason::Unsuitable => write!(f, "the input does not suit the method"),
         }
     }
 }
-impl Error for SearchError {
-    fn description(&self) -> &str {
+
+impl SearchError {
+    /// The algorithm has not converged after the iterations, x and f(x) being the last estimate of the root
+    fn no_convergency<F: FloatType>(iterations: usize, x: F, y: F) -> Self {
+        SearchError::NoConvergency {
+            iterations,
+            last: Some((x.to_f64(), y.to_f64())),
+            bracket: None,
+        }
+    }
+
+    /// The algorithm has not converged after the iterations, and its estimate is not a real root
+    fn no_convergency_after(iterations: usize) -> Self {
+        SearchError::NoConvergency {
+            iterations,
+            last: None,
+            bracket: None,
+        }
+    }
+
+    /// The interval [a, b] does not bracket zero
+    fn no_bracketing<F: FloatType>(a: F, b: F) -> Self {
+        SearchError::NoBracketing {
+            iterations: 0,
+            last: None,
+            bracket: None,
+        }
+        .with_bracket(a, b)
+    }
+
+    /// The derivative is zero at x after the iterations
+    fn zero_derivative<F: FloatType>(iterations: usize, x: F, y: F) -> Self {
+        SearchError::ZeroDerivative {
+            iterations,
+            last: Some((x.to_f64(), y.to_f64())),
+            bracket: None,
+        }
+    }
+
+    /// The input is wrong for the reason
+    fn invalid_input(reason: InvalidInputReason) -> Self {
+        SearchError::InvalidInput { reason }
+    }
+
+    /// The same error with the given bracket of the root, its ends arranged in the ascending order
+    fn with_bracket<F: FloatType>(self, a: F, b: F) -> Self {
+        let (a, b) = (a.to_f64(), b.to_f64());
+        let (a, b) = if a <= b { (a, b) } else { (b, a) };
         match self {
-            SearchError::NoConvergency => "The algorithm could not converge within the given number of iterations",
-            SearchError::NoBracketing => "Initial values do not bracket zero",
-            SearchError::ZeroDerivative => "The algorithm cannot continue from the point where the derivative is zero",
+            SearchError::NoConvergency { iterations, last, .. } => SearchError::NoConvergency {
+                iterations,
+                last,
+                bracket: Some((a, b)),
+            },
+            SearchError::NoBracketing { iterations, last, .. } => SearchError::NoBracketing {
+                iterations,
+                last,
+                bracket: Some((a, b)),
+            },
+            SearchError::ZeroDerivative { iterations, last, .. } => SearchError::ZeroDerivative {
+                iterations,
+                last,
+                bracket: Some((a, b)),
+            },
+            SearchError::InvalidInput { reason } => SearchError::InvalidInput { reason },
+        }
+    }
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, iterations, last, bracket) = match *self {
+            SearchError::NoConvergency {
+                iterations,
+                last,
+                bracket,
+            } => ("Convergency Error", iterations, last, bracket),
+            SearchError::NoBracketing {
+                iterations,
+                last,
+                bracket,
+            } => ("Bracketing Error", iterations, last, bracket),
+            SearchError::ZeroDerivative {
+                iterations,
+                last,
+                bracket,
+            } => ("Zero Derivative Error", iterations, last, bracket),
+            SearchError::InvalidInput { reason } => return write!(f, "Invalid Input Error: {}", reason),
+        };
+        write!(f, "{}", name)?;
+        if iterations > 0 {
+            write!(f, " after {} iterations", iterations)?;
         }
+        if let Some((x, y)) = last {
+            write!(f, ", last x = {}, f(x) = {}", x, y)?;
+        }
+        if let Some((a, b)) = bracket {
+            write!(f, ", bracket [{}, {}]", a, b)?;
+        }
+        Ok(())
+    }
+}
+impl Error for SearchError {}
+
+/// Errors of the search for a root of the function which can fail
+#[derive(Debug, PartialEq, Clone)]
+pub enum TrySearchError<E> {
+    /// The search has failed
+    Search(SearchError),
+    /// The function has failed with the given error
+    Function(E),
+}
+
+impl<E> From<SearchError> for TrySearchError<E> {
+    fn from(error: SearchError) -> Self {
+        TrySearchError::Search(error)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for TrySearchError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TrySearchError::Search(error) => error.fmt(f),
+            TrySearchError::Function(error) => write!(f, "Function Error: {}", error),
+        }
+    }
+}
+impl<E: Error> Error for TrySearchError<E> {}
+
+/// Convergency which stops the search as soon as the function fails
+struct Stopping<'a, C: ?Sized> {
+    convergency: &'a mut C,
+    failed: &'a Cell<bool>,
+}
+
+impl<'a, F: FloatType, C: Convergency<F> + ?Sized> Convergency<F> for Stopping<'a, C> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        self.failed.get() || self.convergency.is_root_found(y)
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        self.failed.get() || self.convergency.is_converged(x1, x2)
+    }
+    fn is_converged_at(&mut self, x1: F, x2: F, y: F) -> bool {
+        self.failed.get() || self.convergency.is_converged_at(x1, x2, y)
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        self.failed.get() || self.convergency.is_iteration_limit_reached(iter)
+    }
+}
+
+/// Run the search by the infallible function, which stops at the first error of the fallible one.
+/// The fallible function is not called after the error; its result is returned instead of the result of the search.
+fn try_search<F, E, Func, Search>(
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    search: Search,
+) -> Result<F, TrySearchError<E>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> Result<F, E>,
+    Search: FnOnce(&mut dyn FnMut(F) -> F, &mut dyn Convergency<F>) -> Result<F, SearchError>,
+{
+    let failed = Cell::new(false);
+    let mut error = None;
+    let result = {
+        let mut infallible = |x: F| {
+            if failed.get() {
+                return F::zero();
+            }
+            match f(x) {
+                Ok(y) => y,
+                Err(e) => {
+                    error = Some(e);
+                    failed.set(true);
+                    F::zero()
+                }
+            }
+        };
+        let mut stopping = Stopping {
+            convergency,
+            failed: &failed,
+        };
+        search(&mut infallible, &mut stopping)
+    };
+    match error {
+        Some(e) => Err(TrySearchError::Function(e)),
+        None => result.map_err(TrySearchError::Search),
+    }
+}
+
+/// Observer of the iterations of the solvers, called once per accepted approximation of the root
+pub trait IterationObserver<F: FloatType> {
+    /// Called with the number of the iteration, starting from 0, the approximation and the value of the function there
+    fn on_iteration(&mut self, _iter: usize, _x: F, _y: F) {}
+}
+
+impl<F: FloatType, Func: FnMut(usize, F, F)> IterationObserver<F> for Func {
+    fn on_iteration(&mut self, iter: usize, x: F, y: F) {
+        self(iter, x, y)
+    }
+}
+
+/// Observer which ignores all iterations
+struct NoObserver;
+
+impl<F: FloatType> IterationObserver<F> for NoObserver {}
+
+/// Statistics of the search, returned by the *_with_stats functions
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct SolverStats<F>
+where
+    F: FloatType,
+{
+    /// The last iteration checked against the limit of the convergency
+    pub iterations: usize,
+    /// Number of evaluations of the function
+    pub fn_evaluations: usize,
+    /// Number of evaluations of the derivative
+    pub derivative_evaluations: usize,
+    /// Width of the last bracketing interval, if the method keeps one and has found the root
+    pub final_bracket_width: Option<F>,
+}
+
+/// Convergency which remembers the last iteration checked against the limit
+struct Counting<'a, C: ?Sized> {
+    convergency: &'a mut C,
+    iterations: usize,
+}
+
+impl<'a, F: FloatType, C: Convergency<F> + ?Sized> Convergency<F> for Counting<'a, C> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        self.convergency.is_root_found(y)
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        self.convergency.is_converged(x1, x2)
+    }
+    fn is_converged_at(&mut self, x1: F, x2: F, y: F) -> bool {
+        self.convergency.is_converged_at(x1, x2, y)
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        self.iterations = iter;
+        self.convergency.is_iteration_limit_reached(iter)
+    }
+}
+
+/// Run the search counting the evaluations of the function and the iterations
+fn search_with_stats<F, T, Func, Search>(
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    search: Search,
+) -> (Result<T, SearchError>, SolverStats<F>)
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Search: FnOnce(&mut dyn FnMut(F) -> F, &mut dyn Convergency<F>) -> Result<T, SearchError>,
+{
+    let mut fn_evaluations = 0;
+    let mut counting = Counting {
+        convergency,
+        iterations: 0,
+    };
+    let result = {
+        let mut counted = |x: F| {
+            fn_evaluations += 1;
+            f(x)
+        };
+        search(&mut counted, &mut counting)
+    };
+    let stats = SolverStats {
+        iterations: counting.iterations,
+        fn_evaluations,
+        derivative_evaluations: 0,
+        final_bracket_width: None,
+    };
+    (result, stats)
+}
+
+/// Kind of the point where the function changes its sign
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum RootKind<F>
+where
+    F: FloatType,
+{
+    /// The function is close to zero at the point
+    Root(F),
+    /// The function stays far from zero on both sides of the point, e.g. at a pole or a jump
+    Discontinuity(F),
+}
+
+/// Check if the value is neither infinite nor NaN
+fn is_finite<F: FloatType>(x: F) -> bool {
+    // Infinite and NaN values give NaN
+    x * F::zero() == F::zero()
+}
+
+/// Fail with InvalidInput if an end of the bracket is not finite
+fn check_bracket<F: FloatType>(a: F, b: F) -> Result<(), SearchError> {
+    if is_finite(a) && is_finite(b) {
+        Ok(())
+    } else {
+        Err(SearchError::invalid_input(InvalidInputReason::NotFiniteBracket))
+    }
+}
+
+/// Fail with InvalidInput if the starting point is not finite
+fn check_start<F: FloatType>(x: F) -> Result<(), SearchError> {
+    if is_finite(x) {
+        Ok(())
+    } else {
+        Err(SearchError::invalid_input(InvalidInputReason::NotFiniteStart))
+    }
+}
+
+/// Fail with InvalidInput if the initial value of the function is not finite
+fn check_value<F: FloatType>(y: F) -> Result<(), SearchError> {
+    if is_finite(y) {
+        Ok(())
+    } else {
+        Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue))
+    }
+}
+
+/// Ends of the search range; the range must be finite and not empty
+fn range_ends<F: FloatType>(range: &RangeInclusive<F>) -> Result<(F, F), SearchError> {
+    let (start, end) = (*range.start(), *range.end());
+    check_bracket(start, end)?;
+    if start <= end {
+        Ok((start, end))
+    } else {
+        Err(SearchError::invalid_input(InvalidInputReason::EmptyRange))
     }
 }
 
@@ -137,6 +505,14 @@ pub trait Convergency<F: FloatType> {
     fn is_converged(&mut self, x1: F, x2: F) -> bool;
     /// Return true if no more iterations desired
     fn is_iteration_limit_reached(&mut self, iter: usize) -> bool;
+    /// Return true if given x values are close enough to each other, knowing the value y
+    /// of the function at the current approximation of the root.
+    /// Called instead of `is_converged` by the methods having the value at hand (brent, regula falsi),
+    /// so that the rules can combine X and Y, e.g. by estimating the error from both.
+    /// By default, y is ignored and `is_converged` decides.
+    fn is_converged_at(&mut self, x1: F, x2: F, _y: F) -> bool {
+        self.is_converged(x1, x2)
+    }
 }
 
 impl<F: FloatType> Convergency<F> for F {
@@ -154,21 +530,146 @@ impl<F: FloatType> Convergency<F> for F {
     }
 }
 
+#[cfg(feature = "num-complex")]
+pub mod aberth;
+pub mod bairstow;
+pub mod bernstein;
+pub mod bisection;
+pub mod bracket;
 pub mod brent;
+pub mod cache;
+pub mod chandrupatla;
+pub mod deflation;
+pub mod dekker;
+pub mod derivative;
 pub mod eigen;
+pub mod extremum;
+pub mod finder;
+pub mod graeffe;
+pub mod halley;
+pub mod inverse;
 pub mod inverse_quadratic;
+pub mod jenkins_traub;
+pub mod laguerre;
+pub mod method;
 pub mod newton_raphson;
+pub mod ostrowski;
 pub mod polynom;
+pub mod refine;
 pub mod regula_falsi;
+pub mod ridders;
+pub mod routh;
 pub mod secant;
+pub mod solver;
+pub mod steffensen;
+pub mod subdivision;
+pub mod suppression;
+pub mod tabulated;
 
+pub mod adaptive_convergency;
+pub mod composite_convergency;
 pub mod debug_convergency;
+pub mod recording_convergency;
+pub mod relative_convergency;
+pub mod residual_relative_convergency;
 pub mod simple_convergency;
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn convergency_concrete_and_dyn() {
+        let f = |x| 1f64 * x * x - 1f64;
+
+        // Concrete types, including the blanket implementation for floats
+        let mut simple = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 30,
+        };
+        let expected = brent::find_root_brent(0f64, 10f64, &f, &mut simple);
+        assert_eq!(expected, brent::find_root_brent(0f64, 10f64, &f, &mut 1e-15f64));
+        assert_eq!(expected, brent::find_root_brent::<f64, _>(0f64, 10f64, &f, &mut simple));
+
+        // Trait objects
+        let dynamic: &mut dyn Convergency<f64> = &mut simple;
+        assert_eq!(expected, brent::find_root_brent(0f64, 10f64, &f, dynamic));
+        let mut boxed: Box<dyn Convergency<f64>> = Box::new(1e-15f64);
+        assert_eq!(expected, brent::find_root_brent(0f64, 10f64, &f, &mut *boxed));
+        assert_eq!(
+            polynom::find_roots_sturm(&[0f64, -1f64], &mut *boxed),
+            polynom::find_roots_sturm(&[0f64, -1f64], &mut 1e-15f64)
+        );
+        assert_eq!(
+            brent::try_find_root_brent(0f64, 10f64, |x| Ok::<f64, ()>(1f64 * x * x - 1f64), &mut *boxed),
+            Ok(expected.unwrap())
+        );
+    }
+
+    /// Stops when the error estimated by |y| / |f'| is small; X or Y alone never stop the search
+    struct ErrorEstimate {
+        slope: f64,
+        eps: f64,
+        combined: bool,
+    }
+
+    impl Convergency<f64> for ErrorEstimate {
+        fn is_root_found(&mut self, _: f64) -> bool {
+            false
+        }
+        fn is_converged(&mut self, _: f64, _: f64) -> bool {
+            false
+        }
+        fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+            iter >= 100
+        }
+        fn is_converged_at(&mut self, x1: f64, x2: f64, y: f64) -> bool {
+            if self.combined {
+                (y / self.slope).abs() < self.eps
+            } else {
+                self.is_converged(x1, x2)
+            }
+        }
+    }
+
+    #[test]
+    fn convergency_combined() {
+        let f = |x: f64| x * x - 2f64;
+        // f'(x) = 2x is about 2.8 near the root
+        let mut convergency = ErrorEstimate {
+            slope: 2.8f64,
+            eps: 1e-12f64,
+            combined: true,
+        };
+        assert_float_eq!(
+            1e-12f64,
+            brent::find_root_brent(0f64, 10f64, &f, &mut convergency).ok().unwrap(),
+            2f64.sqrt()
+        );
+        assert_float_eq!(
+            1e-12f64,
+            regula_falsi::find_root_regula_falsi(0f64, 10f64, &f, &mut convergency)
+                .ok()
+                .unwrap(),
+            2f64.sqrt()
+        );
+        // The wrappers pass the value through
+        let (root, stats) = brent::find_root_brent_with_stats(0f64, 10f64, &f, &mut convergency);
+        assert_float_eq!(1e-12f64, root.ok().unwrap(), 2f64.sqrt());
+        assert!(stats.iterations < 100);
+
+        // The default implementation ignores the value
+        convergency.combined = false;
+        assert!(matches!(
+            brent::find_root_brent(0f64, 10f64, &f, &mut convergency),
+            Err(SearchError::NoConvergency { .. })
+        ));
+        assert!(matches!(
+            regula_falsi::find_root_regula_falsi(0f64, 10f64, &f, &mut convergency),
+            Err(SearchError::NoConvergency { .. })
+        ));
+    }
+
     #[test]
     fn sample_bracketed() {
         let sample1 = Sample { x: 0f64, y: 0f64 };
@@ -254,4 +755,46 @@ mod test {
         assert_eq!(0.5f64, sut1.middle());
         assert_eq!(0f64, sut2.middle());
     }
+
+    #[test]
+    fn search_error_context() {
+        let f = |x| 1f64 * x * x - 1f64;
+
+        let error = brent::find_root_brent(10f64, 20f64, &f, &mut 1e-15f64).unwrap_err();
+        assert_eq!(
+            error,
+            SearchError::NoBracketing {
+                iterations: 0,
+                last: None,
+                bracket: Some((10f64, 20f64)),
+            }
+        );
+        assert_eq!(error.to_string(), "Bracketing Error, bracket [10, 20]");
+
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 3,
+        };
+        // Bisection stops at the bracket [0, 2] of the root, where |f(0)| < |f(2)|
+        let error = bisection::find_root_bisection(0f64, 16f64, &f, &mut conv).unwrap_err();
+        assert_eq!(
+            error,
+            SearchError::NoConvergency {
+                iterations: 3,
+                last: Some((0f64, -1f64)),
+                bracket: Some((0f64, 2f64)),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Convergency Error after 3 iterations, last x = 0, f(x) = -1, bracket [0, 2]"
+        );
+
+        let error = newton_raphson::find_root_newton_raphson(1000f64, &f, |x| 2f64 * x, &mut conv).unwrap_err();
+        assert!(matches!(error, SearchError::NoConvergency { iterations: 3, .. }));
+        assert_eq!(
+            SearchError::invalid_input(InvalidInputReason::EmptyRange).to_string(),
+            "Invalid Input Error: the range is empty"
+        );
+    }
 }
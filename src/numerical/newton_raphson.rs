@@ -22,12 +22,51 @@
 // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+#[cfg(feature = "num-complex")]
+use super::super::analytical::quartic_complex::complex_div;
 use super::super::FloatType;
+use super::check_bracket;
+use super::check_start;
+use super::check_value;
 use super::Convergency;
+use super::IterationObserver;
+use super::NoObserver;
 use super::SearchError;
+use super::SolverStats;
+
+/// What to do when the derivative is zero at the starting point of the Newton-Raphson method
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ZeroDerivativeStrategy<F>
+where
+    F: FloatType,
+{
+    /// Fail with `SearchError::ZeroDerivative`
+    Fail,
+    /// Move the starting point by the given value. The default is to move it by 1.
+    NudgeBy(F),
+    /// Bisect the given interval bracketing the root until the derivative at the middle is not zero
+    Bisect(F, F),
+}
+
+impl<F: FloatType> Default for ZeroDerivativeStrategy<F> {
+    fn default() -> Self {
+        ZeroDerivativeStrategy::NudgeBy(F::one())
+    }
+}
 
 /// Find a root of the function f(x) = 0 using the Newton-Raphson method.
 ///
+/// With the debug-derivative feature, debug builds check the derivative at the starting point by check_derivative
+/// and panic if it is wrong.
+/// If the derivative is zero at the starting point, the point is moved by 1;
+/// use `find_root_newton_raphson_with_strategy` to choose another behavior.
+/// If the iterations return to the previous but one point, they would cycle between two points forever.
+/// The cycle is broken by bisection if the function changes its sign between the points,
+/// or by the damped half-step otherwise.
+///
 /// Pro
 ///
 /// + Simple
@@ -42,6 +81,8 @@ use super::SearchError;
 /// - Cannot continue when derivative is zero
 ///
 /// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
 /// ## ZeroDerivative
 /// The stationary point of the function is encountered. Algorithm cannot continue.
 /// ## NoConvergency
@@ -65,72 +106,1368 @@ pub fn find_root_newton_raphson<F, Func, Deriv>(
     start: F,
     mut f: Func,
     mut d: Deriv,
-    convergency: &mut dyn Convergency<F>,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    #[cfg(all(feature = "debug-derivative", debug_assertions))]
+    {
+        // Invalid input is reported before the derivative is checked
+        check_start(start)?;
+        check_value(f(start))?;
+        let mismatches = super::derivative::check_derivative(&mut f, &mut d, &[start], F::epsilon().cbrt());
+        if let Some(mismatch) = mismatches.first() {
+            panic!(
+                "find_root_newton_raphson: derivative {:?} differs from the finite difference {:?} at {:?}",
+                mismatch.derivative, mismatch.estimate, mismatch.x
+            );
+        }
+    }
+    find_root_newton_raphson_combined(start, |x| (f(x), d(x)), convergency)
+}
+
+/// Find a root of the function f(x) = 0 using the Newton-Raphson method and count the work done.
+///
+/// The same as `find_root_newton_raphson`, but the result comes with `SolverStats`: the last iteration,
+/// and the numbers of evaluations of the function and its derivative.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_newton_raphson_with_stats;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let d = |x| { 2f64*x };
+///
+/// let (root, stats) = find_root_newton_raphson_with_stats(10f64, &f, &d, &mut 1e-15f64);
+/// // Returns approximately Ok(1) and SolverStats { iterations: 8, fn_evaluations: 9, derivative_evaluations: 9, .. }
+/// ```
+pub fn find_root_newton_raphson_with_stats<F, Func, Deriv>(
+    start: F,
+    f: Func,
+    mut d: Deriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> (Result<F, SearchError>, SolverStats<F>)
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    let mut derivative_evaluations = 0;
+    let (result, mut stats) = {
+        let mut counted = |x: F| {
+            derivative_evaluations += 1;
+            d(x)
+        };
+        super::search_with_stats(f, convergency, |f, convergency| {
+            find_root_newton_raphson(start, f, &mut counted, convergency)
+        })
+    };
+    stats.derivative_evaluations = derivative_evaluations;
+    (result, stats)
+}
+
+/// Find a root of the function f(x) = 0 using the Newton-Raphson method and report every iteration to the observer.
+///
+/// The same as `find_root_newton_raphson`, but `observer.on_iteration` is called once per iteration
+/// with the current approximation, starting from the starting point.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_newton_raphson_observed;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let d = |x| { 2f64*x };
+/// let mut trajectory = Vec::new();
+///
+/// let root = find_root_newton_raphson_observed(10f64, &f, &d, &mut 1e-15f64, &mut |_: usize, x: f64, y: f64| {
+///     trajectory.push((x, y))
+/// });
+/// // Returns approximately Ok(1), and trajectory starts with (10, 99)
+/// ```
+pub fn find_root_newton_raphson_observed<F, Func, Deriv>(
+    start: F,
+    mut f: Func,
+    mut d: Deriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    observer: &mut (impl IterationObserver<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    match newton_raphson_or_best(
+        start,
+        |x| (f(x), d(x)),
+        ZeroDerivativeStrategy::default(),
+        convergency,
+        observer,
+    )? {
+        (x, None) => Ok(x),
+        (_, Some(error)) => Err(error),
+    }
+}
+
+/// Find a root of the function f(x) = 0 using the Newton-Raphson method.
+///
+/// The same as `find_root_newton_raphson`, but the function and its derivative are calculated by one closure
+/// returning the tuple (f(x), f'(x)). Use it when they share most of the calculations.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::find_root_newton_raphson_combined;
+///
+/// let fd = |x: f64| { let e = x.exp(); (e - 2f64, e) };
+///
+/// let root = find_root_newton_raphson_combined(10f64, &fd, &mut 1e-15f64);
+/// // Returns approximately Ok(0.6931471805599453);
+/// ```
+pub fn find_root_newton_raphson_combined<F, FuncDeriv>(
+    start: F,
+    fd: FuncDeriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    FuncDeriv: FnMut(F) -> (F, F),
+{
+    match newton_raphson_or_best(start, fd, ZeroDerivativeStrategy::default(), convergency, &mut NoObserver)? {
+        (x, None) => Ok(x),
+        (_, Some(error)) => Err(error),
+    }
+}
+
+/// Find a root of the function f(x) = 0 using the Newton-Raphson method.
+///
+/// The same as `find_root_newton_raphson`, but the zero derivative at the starting point is handled by `strategy`:
+/// the search fails, the starting point is moved, or the given bracket is bisected until the derivative
+/// at its middle is not zero. If the moved point is stationary as well, the search fails.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point, the function value at it or the interval of `ZeroDerivativeStrategy::Bisect` is not finite.
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// ## NoBracketing
+/// The interval of `ZeroDerivativeStrategy::Bisect` does not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::find_root_newton_raphson_with_strategy;
+/// use roots::ZeroDerivativeStrategy;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let d = |x| { 2f64*x };
+///
+/// let root1 = find_root_newton_raphson_with_strategy(0f64, &f, &d, ZeroDerivativeStrategy::Fail, &mut 1e-15f64);
+/// // Returns Err(SearchError::ZeroDerivative { .. });
+///
+/// let root2 = find_root_newton_raphson_with_strategy(0f64, &f, &d, ZeroDerivativeStrategy::NudgeBy(-0.5f64), &mut 1e-15f64);
+/// // Returns approximately Ok(-1);
+///
+/// let root3 = find_root_newton_raphson_with_strategy(0f64, &f, &d, ZeroDerivativeStrategy::Bisect(-3f64, 0f64), &mut 1e-15f64);
+/// // Returns approximately Ok(-1);
+/// ```
+pub fn find_root_newton_raphson_with_strategy<F, Func, Deriv>(
+    start: F,
+    mut f: Func,
+    mut d: Deriv,
+    strategy: ZeroDerivativeStrategy<F>,
+    convergency: &mut (impl Convergency<F> + ?Sized),
 ) -> Result<F, SearchError>
 where
     F: FloatType,
     Func: FnMut(F) -> F,
     Deriv: FnMut(F) -> F,
 {
+    match newton_raphson_or_best(start, |x| (f(x), d(x)), strategy, convergency, &mut NoObserver)? {
+        (x, None) => Ok(x),
+        (_, Some(error)) => Err(error),
+    }
+}
+
+/// Find a root of the function f(x) = 0 using the Newton-Raphson method.
+///
+/// The same as `find_root_newton_raphson`, but the result is the tuple (x, converged).
+/// If the iteration limit is reached, x is the point with the least |f(x)| evaluated so far
+/// and converged is false, which can be good enough when the precision has been set too high.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// # Examples
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_newton_raphson_or_best;
+///
+/// let f = |x| { 1f64*x*x - 2f64 };
+/// let d = |x| { 2f64*x };
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:7 };
+///
+/// let root = find_root_newton_raphson_or_best(10f64, &f, &d, &mut convergency);
+/// // Returns approximately Ok((1.4142135968, false)) instead of Err(SearchError::NoConvergency { .. });
+/// ```
+pub fn find_root_newton_raphson_or_best<F, Func, Deriv>(
+    start: F,
+    mut f: Func,
+    mut d: Deriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<(F, bool), SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    newton_raphson_or_best(
+        start,
+        |x| (f(x), d(x)),
+        ZeroDerivativeStrategy::default(),
+        convergency,
+        &mut NoObserver,
+    )
+    .map(|(x, error)| (x, error.is_none()))
+}
+
+/// Newton-Raphson iterations returning the best point evaluated along with the error when the iteration limit is reached
+fn newton_raphson_or_best<F, FuncDeriv>(
+    start: F,
+    mut fd: FuncDeriv,
+    strategy: ZeroDerivativeStrategy<F>,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    observer: &mut (impl IterationObserver<F> + ?Sized),
+) -> Result<(F, Option<SearchError>), SearchError>
+where
+    F: FloatType,
+    FuncDeriv: FnMut(F) -> (F, F),
+{
+    let _2 = F::from(2i16);
+    check_start(start)?;
+    if let ZeroDerivativeStrategy::Bisect(lo, hi) = strategy {
+        check_bracket(lo, hi)?;
+    }
     let mut x = start;
+    let (mut best_x, mut best_y) = (start, F::zero());
+    // The previous point and the value of the function there
+    let mut previous: Option<(F, F)> = None;
+    // No Newton step has been made yet
+    let mut starting = true;
+    // The bracket (a, f(a), b) of ZeroDerivativeStrategy::Bisect
+    let mut bracket: Option<(F, F, F)> = None;
 
     let mut iter = 0;
     loop {
-        let f = f(x);
-        let d = d(x);
+        let (f, d) = fd(x);
+        if iter == 0 {
+            check_value(f)?;
+        }
+        observer.on_iteration(iter, x, f);
         if convergency.is_root_found(f) {
-            return Ok(x);
+            return Ok((x, None));
+        }
+        if iter == 0 || f.abs() < best_y.abs() {
+            best_x = x;
+            best_y = f;
         }
         // Derivative is 0; try to correct the bad starting point
         if convergency.is_root_found(d) {
-            if iter == 0 {
-                x = x + F::one();
-                iter = iter + 1;
-                continue;
-            } else {
-                return Err(SearchError::ZeroDerivative);
+            if !starting {
+                return Err(SearchError::zero_derivative(iter, x, f));
             }
+            match strategy {
+                ZeroDerivativeStrategy::Fail => return Err(SearchError::zero_derivative(iter, x, f)),
+                ZeroDerivativeStrategy::NudgeBy(h) => {
+                    x = x + h;
+                    starting = false;
+                }
+                ZeroDerivativeStrategy::Bisect(lo, hi) => {
+                    let (mut a, mut ya, mut b) = match bracket {
+                        Some(bracket) => bracket,
+                        None => {
+                            let (ylo, _) = fd(lo);
+                            if convergency.is_root_found(ylo) {
+                                return Ok((lo, None));
+                            }
+                            let (yhi, _) = fd(hi);
+                            if convergency.is_root_found(yhi) {
+                                return Ok((hi, None));
+                            }
+                            if (ylo < F::zero()) == (yhi < F::zero()) {
+                                return Err(SearchError::no_bracketing(lo, hi));
+                            }
+                            (lo, ylo, hi)
+                        }
+                    };
+                    // The stationary point inside the bracket splits it
+                    if (x - a) * (x - b) < F::zero() {
+                        if (f < F::zero()) == (ya < F::zero()) {
+                            a = x;
+                            ya = f;
+                        } else {
+                            b = x;
+                        }
+                    }
+                    if convergency.is_converged(a, b) {
+                        return Ok(((a + b) / _2, None));
+                    }
+                    bracket = Some((a, ya, b));
+                    x = (a + b) / _2;
+                }
+            }
+            iter = iter + 1;
+            if convergency.is_iteration_limit_reached(iter) {
+                return Ok((best_x, Some(SearchError::no_convergency(iter, best_x, best_y))));
+            }
+            continue;
         }
+        starting = false;
 
-        let x1 = x - f / d;
+        let mut x1 = x - f / d;
         if convergency.is_converged(x, x1) {
-            return Ok(x1);
+            return Ok((x1, None));
+        }
+
+        // The iterations returned to the previous point and would cycle between it and x forever
+        if let Some((x0, f0)) = previous {
+            if convergency.is_converged(x0, x1) {
+                if (f0 < F::zero()) != (f < F::zero()) {
+                    return match bisect(x0, f0, x, fd, convergency, iter) {
+                        Some(root) => Ok((root, None)),
+                        None => Ok((
+                            best_x,
+                            Some(SearchError::no_convergency(iter, best_x, best_y).with_bracket(x0, x)),
+                        )),
+                    };
+                }
+                // Take the damped step to leave the cycle
+                x1 = x - f / (_2 * d);
+            }
         }
 
+        previous = Some((x, f));
         x = x1;
         iter = iter + 1;
 
         if convergency.is_iteration_limit_reached(iter) {
-            return Err(SearchError::NoConvergency);
+            return Ok((best_x, Some(SearchError::no_convergency(iter, best_x, best_y))));
         }
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::super::*;
-    use super::*;
+/// Bisect the interval [a, b], where y is the value of the function at a having the opposite sign to one at b
+fn bisect<F, FuncDeriv>(
+    mut a: F,
+    mut ya: F,
+    mut b: F,
+    mut fd: FuncDeriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    mut iter: usize,
+) -> Option<F>
+where
+    F: FloatType,
+    FuncDeriv: FnMut(F) -> (F, F),
+{
+    let _2 = F::from(2i16);
+    loop {
+        let mid = (a + b) / _2;
+        if convergency.is_converged(a, b) {
+            return Some(mid);
+        }
+        let (y, _) = fd(mid);
+        if convergency.is_root_found(y) {
+            return Some(mid);
+        }
+        if (y < F::zero()) == (ya < F::zero()) {
+            a = mid;
+            ya = y;
+        } else {
+            b = mid;
+        }
 
-    #[test]
-    fn test_find_root_newton_raphson() {
-        let f = |x| 1f64 * x * x - 1f64;
-        let d = |x| 2f64 * x;
-        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return None;
+        }
+    }
+}
 
-        conv.reset();
-        assert_float_eq!(
-            1e-15f64,
-            find_root_newton_raphson(10f64, &f, &d, &mut conv).ok().unwrap(),
-            1f64
-        );
-        assert_eq!(8, conv.get_iter_count());
+/// Find a root of the function f(x) = 0 and estimate its multiplicity using the Newton-Raphson method.
+///
+/// Near the root of multiplicity m each Newton-Raphson correction is (m - 1)/m of the previous one,
+/// so m is estimated from the ratio of the last two corrections. The result is (root, multiplicity).
+/// Multiple roots need more iterations, as the convergency is only linear.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_newton_with_multiplicity;
+///
+/// let f = |x| { 1f64*(x - 1f64)*(x - 1f64) };
+/// let d = |x| { 2f64*(x - 1f64) };
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:100 };
+///
+/// let root = find_root_newton_with_multiplicity(10f64, &f, &d, &mut convergency);
+/// // Returns approximately Ok((1, 2));
+/// ```
+pub fn find_root_newton_with_multiplicity<F, Func, Deriv>(
+    start: F,
+    mut f: Func,
+    mut d: Deriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<(F, usize), SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    let mut iterates = Vec::new();
+    let root = find_root_newton_raphson_combined(
+        start,
+        |x| {
+            iterates.push(x);
+            (f(x), d(x))
+        },
+        convergency,
+    )?;
+    if iterates.last() != Some(&root) {
+        iterates.push(root);
+    }
 
-        conv.reset();
-        assert_float_eq!(
-            1e-15f64,
-            find_root_newton_raphson(-10f64, &f, &d, &mut conv).ok().unwrap(),
-            -1f64
-        );
-        assert_eq!(8, conv.get_iter_count());
+    let multiplicity = match iterates.len() {
+        0..=2 => 1,
+        n => {
+            let ratio = (iterates[n - 1] - iterates[n - 2]) / (iterates[n - 2] - iterates[n - 3]);
+            let m = (F::one() / (F::one() - ratio)).to_f64().round();
+            if m >= 1f64 {
+                m as usize
+            } else {
+                1
+            }
+        }
+    };
+    Ok((root, multiplicity))
+}
+
+/// Find a root of the function f(x) = 0 using the Newton-Raphson method with the numerical derivative.
+///
+/// The derivative is approximated by the central difference (f(x + h) - f(x - h))/2h with h = sqrt(eps)*max(|x|, 1).
+/// Its relative error is about sqrt(eps) for well-scaled functions, and can be much worse when f(x) has large
+/// rounding errors or varies quickly within h. A worse derivative slows down the convergency, but does not move the root.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::find_root_newton_numeric;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let root = find_root_newton_numeric(10f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1);
+/// ```
+pub fn find_root_newton_numeric<F, Func>(
+    start: F,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    check_start(start)?;
+    let mut x = start;
+
+    let mut iter = 0;
+    loop {
+        let y = f(x);
+        if iter == 0 {
+            check_value(y)?;
+        }
+        if convergency.is_root_found(y) {
+            return Ok(x);
+        }
+        let h = F::epsilon().sqrt() * if x.abs() > F::one() { x.abs() } else { F::one() };
+        let d = (f(x + h) - f(x - h)) / (_2 * h);
+        // Derivative is 0; try to correct the bad starting point
+        if convergency.is_root_found(d) {
+            if iter == 0 {
+                x = x + F::one();
+                iter += 1;
+                continue;
+            } else {
+                return Err(SearchError::zero_derivative(iter, x, y));
+            }
+        }
+
+        let x1 = x - y / d;
+        if convergency.is_converged(x, x1) {
+            return Ok(x1);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency(iter, x, y));
+        }
+        x = x1;
+    }
+}
+
+/// Find a root of the function f(x) = 0 using the Newton-Raphson method constrained to the interval [lo, hi].
+///
+/// The function is never evaluated outside of the interval. The first Newton-Raphson step that leaves the interval
+/// is clamped to its bound; any further such step bisects the narrowest known sub-interval with the sign change.
+/// If no sign change is known yet, the iterate moves from one bound to the other.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point, the bounds or the function value at the starting point are not finite.
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::find_root_newton_raphson_bounded;
+///
+/// let f = |x: f64| { x.ln() - 1f64 };
+/// let d = |x: f64| { 1f64 / x };
+///
+/// // The first unbounded step goes to -3.03, where the logarithm is not defined
+/// let root = find_root_newton_raphson_bounded(10f64, 0.5f64, 20f64, &f, &d, &mut 1e-15f64);
+/// // Returns approximately Ok(2.718281828459045);
+/// ```
+pub fn find_root_newton_raphson_bounded<F, Func, Deriv>(
+    start: F,
+    lo: F,
+    hi: F,
+    mut f: Func,
+    mut d: Deriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    check_start(start)?;
+    check_bracket(lo, hi)?;
+    let (lo, hi) = if lo > hi { (hi, lo) } else { (lo, hi) };
+    let clamp = |x: F| {
+        if x < lo {
+            lo
+        } else if x > hi {
+            hi
+        } else {
+            x
+        }
+    };
+    let mut x = clamp(start);
+    // Points with negative and positive values of the function, as close to each other as known
+    let mut negative: Option<F> = None;
+    let mut positive: Option<F> = None;
+    let mut clamped = false;
+
+    let mut iter = 0;
+    loop {
+        let y = f(x);
+        if iter == 0 {
+            check_value(y)?;
+        }
+        if convergency.is_root_found(y) {
+            return Ok(x);
+        }
+        {
+            let (same, other) = if y < F::zero() {
+                (&mut negative, positive)
+            } else {
+                (&mut positive, negative)
+            };
+            let closer = match (*same, other) {
+                (Some(s), Some(o)) => (x - o).abs() < (s - o).abs(),
+                _ => true,
+            };
+            if closer {
+                *same = Some(x);
+            }
+        }
+
+        let dy = d(x);
+        // Derivative is 0; try to correct the bad starting point
+        if convergency.is_root_found(dy) {
+            if iter == 0 {
+                x = clamp(x + F::one());
+                iter += 1;
+                continue;
+            } else {
+                return Err(SearchError::zero_derivative(iter, x, y));
+            }
+        }
+
+        let mut x1 = x - y / dy;
+        if x1 < lo || x1 > hi {
+            x1 = if !clamped {
+                clamped = true;
+                clamp(x1)
+            } else if let (Some(n), Some(p)) = (negative, positive) {
+                (n + p) / _2
+            } else if x == lo || x == hi {
+                lo + hi - x
+            } else {
+                clamp(x1)
+            };
+        }
+        if convergency.is_converged(x, x1) {
+            return Ok(x1);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            let error = SearchError::no_convergency(iter, x, y);
+            return Err(match (negative, positive) {
+                (Some(n), Some(p)) => error.with_bracket(n, p),
+                _ => error,
+            });
+        }
+        x = x1;
+    }
+}
+
+/// Find a root of the function f(x) = 0 using the Newton-Raphson method safeguarded by bisection.
+///
+/// The root stays bracketed. The Newton-Raphson step is taken when it stays within the bracket
+/// and reduces the bracket fast enough, otherwise the bracket is bisected.
+///
+/// Pro
+///
+/// + Fast convergency for well-behaved functions
+/// + Converges at least as fast as bisection
+/// + Stationary points are not a problem
+///
+/// Contra
+///
+/// - Needs derivative function
+/// - Needs initial bracketing
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::find_root_newton_bracketed;
+///
+/// let f = |x: f64| { x.atan() };
+/// let d = |x: f64| { 1f64 / (1f64 + x*x) };
+///
+/// // The plain Newton-Raphson method diverges from the midpoint 2.5
+/// let root = find_root_newton_bracketed(-5f64, 10f64, &f, &d, &mut 1e-15f64);
+/// // Returns approximately Ok(0);
+/// ```
+pub fn find_root_newton_bracketed<F, Func, Deriv>(
+    a: F,
+    b: F,
+    mut f: Func,
+    mut d: Deriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    check_bracket(a, b)?;
+    let ya = f(a);
+    check_value(ya)?;
+    if convergency.is_root_found(ya) {
+        return Ok(a);
+    }
+    let yb = f(b);
+    check_value(yb)?;
+    if convergency.is_root_found(yb) {
+        return Ok(b);
+    }
+    if ya * yb > F::zero() {
+        return Err(SearchError::no_bracketing(a, b));
+    }
+    // f(low) < 0 < f(high)
+    let (mut low, mut high) = if ya < F::zero() { (a, b) } else { (b, a) };
+
+    let mut x = (a + b) / _2;
+    let mut step = (b - a).abs();
+    let mut previous_step = step;
+    let mut y = f(x);
+    let mut dy = d(x);
+
+    let mut iter = 0;
+    loop {
+        if convergency.is_root_found(y) {
+            return Ok(x);
+        }
+        if y < F::zero() {
+            low = x;
+        } else {
+            high = x;
+        }
+
+        let x0 = x;
+        // Bisect if the Newton-Raphson step leaves the bracket, or does not halve the step size
+        if ((x - high) * dy - y) * ((x - low) * dy - y) > F::zero() || (_2 * y).abs() > (previous_step * dy).abs() {
+            previous_step = step;
+            step = (high - low) / _2;
+            x = low + step;
+        } else {
+            previous_step = step;
+            step = y / dy;
+            x = x - step;
+        }
+        if convergency.is_converged(x0, x) || convergency.is_converged(low, high) {
+            return Ok(x);
+        }
+
+        y = f(x);
+        dy = d(x);
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency(iter, x, y).with_bracket(low, high));
+        }
+    }
+}
+
+/// Find a complex root of the analytic function f(z) = 0 using the Newton-Raphson method.
+///
+/// The convergency checks are applied to the modulus of f(z) and of the step.
+/// Real functions keep the iterates on the real axis, where they cannot reach complex roots.
+/// If a real iterate hits the zero derivative or does not decrease |f(z)|, it is shifted
+/// by 0.001*max(1, |z|) along the imaginary axis, and the search continues in the upper half-plane.
+/// Requires the num-complex feature.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// extern crate num_complex;
+/// extern crate roots;
+///
+/// use num_complex::Complex;
+/// use roots::find_root_newton_complex;
+///
+/// // z^2 + 1 has no real roots
+/// let f = |z: Complex<f64>| { z * z + 1f64 };
+/// let d = |z: Complex<f64>| { z * 2f64 };
+///
+/// let root = find_root_newton_complex(Complex::new(1f64, 0f64), &f, &d, &mut 1e-15f64);
+/// // Returns approximately Ok(i);
+/// ```
+#[cfg(feature = "num-complex")]
+pub fn find_root_newton_complex<F, Func, Deriv>(
+    start: Complex<F>,
+    mut f: Func,
+    mut d: Deriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<Complex<F>, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(Complex<F>) -> Complex<F>,
+    Deriv: FnMut(Complex<F>) -> Complex<F>,
+{
+    let _1000 = F::from(1000i16);
+    let modulus = |z: Complex<F>| (z.re * z.re + z.im * z.im).sqrt();
+    check_start(start.re)?;
+    check_start(start.im)?;
+    let mut x = start;
+    // Modulus of the function at the previous point
+    let mut previous: Option<F> = None;
+
+    let mut iter = 0;
+    loop {
+        let y = f(x);
+        if iter == 0 {
+            check_value(y.re)?;
+            check_value(y.im)?;
+        }
+        let residual = modulus(y);
+        if convergency.is_root_found(residual) {
+            return Ok(x);
+        }
+        let dy = d(x);
+        let zero_derivative = convergency.is_root_found(modulus(dy));
+
+        let real = x.im == F::zero() && y.im == F::zero() && dy.im == F::zero();
+        let stuck = zero_derivative
+            || match previous {
+                Some(r) => residual >= r,
+                None => false,
+            };
+        if zero_derivative && iter == 0 {
+            // Derivative is 0; try to correct the bad starting point
+            x = Complex::new(x.re + F::one(), x.im);
+        } else if real && stuck {
+            // Leave the real axis
+            let scale = if x.re.abs() > F::one() { x.re.abs() } else { F::one() };
+            x = Complex::new(x.re, scale / _1000);
+            previous = None;
+        } else if zero_derivative {
+            // The complex point does not fit into the error
+            return Err(SearchError::ZeroDerivative {
+                iterations: iter,
+                last: None,
+                bracket: None,
+            });
+        } else {
+            let step = complex_div(y, dy);
+            let x1 = Complex::new(x.re - step.re, x.im - step.im);
+            if convergency.is_converged(F::zero(), modulus(step)) {
+                return Ok(x1);
+            }
+            x = x1;
+            previous = Some(residual);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency_after(iter));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_find_root_newton_raphson() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let d = |x| 2f64 * x;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_raphson(10f64, &f, &d, &mut conv).ok().unwrap(),
+            1f64
+        );
+        assert_eq!(8, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_raphson(-10f64, &f, &d, &mut conv).ok().unwrap(),
+            -1f64
+        );
+        assert_eq!(8, conv.get_iter_count());
+    }
+
+    #[test]
+    #[cfg(not(feature = "debug-derivative"))]
+    fn test_find_root_newton_raphson_with_stats() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let d = |x| 2f64 * x;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        let (root, stats) = find_root_newton_raphson_with_stats(10f64, &f, &d, &mut conv);
+        assert_eq!(root, find_root_newton_raphson(10f64, &f, &d, &mut conv));
+        assert_eq!(stats.iterations, conv.get_iter_count());
+        assert_eq!(stats.iterations, 8);
+        assert_eq!(stats.fn_evaluations, 9);
+        assert_eq!(stats.derivative_evaluations, 9);
+        assert_eq!(stats.final_bracket_width, None);
+
+        let (root, stats) = find_root_newton_raphson_with_stats(0f64, &|x| 1f64 * x * x + 1f64, &d, &mut conv);
+        assert!(matches!(root, Err(SearchError::ZeroDerivative { .. })));
+        assert_eq!(stats.derivative_evaluations, 3);
+    }
+
+    #[test]
+    fn test_find_root_newton_raphson_observed() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let d = |x| 2f64 * x;
+        let mut trajectory = Vec::new();
+
+        let root = find_root_newton_raphson_observed(10f64, &f, &d, &mut 1e-15f64, &mut |iter: usize, x: f64, y: f64| {
+            trajectory.push((iter, x, y))
+        });
+        assert_eq!(root, Ok(1f64));
+        assert_eq!(trajectory.len(), 9);
+        assert_eq!(trajectory[0], (0, 10f64, 99f64));
+        assert_eq!(trajectory[8], (8, 1f64, 0f64));
+        // Quadratic convergency from the right
+        for window in trajectory.windows(2) {
+            assert!(window[1].1 < window[0].1 && window[1].2 < window[0].2);
+        }
+    }
+
+    #[test]
+    fn test_find_root_newton_raphson_with_strategy() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let d = |x| 2f64 * x;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        // The default strategy moves the start by 1
+        conv.reset();
+        assert_eq!(
+            find_root_newton_raphson_with_strategy(0f64, &f, &d, ZeroDerivativeStrategy::default(), &mut conv),
+            find_root_newton_raphson(0f64, &f, &d, &mut 1e-15f64)
+        );
+        assert_eq!(1, conv.get_iter_count());
+
+        assert!(matches!(
+            find_root_newton_raphson_with_strategy(0f64, &f, &d, ZeroDerivativeStrategy::Fail, &mut 1e-15f64),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+        // Only the starting point is affected
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_raphson_with_strategy(10f64, &f, &d, ZeroDerivativeStrategy::Fail, &mut 1e-15f64)
+                .ok()
+                .unwrap(),
+            1f64
+        );
+
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_raphson_with_strategy(0f64, &f, &d, ZeroDerivativeStrategy::NudgeBy(-0.5f64), &mut 1e-15f64)
+                .ok()
+                .unwrap(),
+            -1f64
+        );
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_raphson_with_strategy(0f64, &f, &d, ZeroDerivativeStrategy::Bisect(-3f64, 0f64), &mut 1e-15f64)
+                .ok()
+                .unwrap(),
+            -1f64
+        );
+        assert!(matches!(
+            find_root_newton_raphson_with_strategy(0f64, &f, &d, ZeroDerivativeStrategy::Bisect(-3f64, 3f64), &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(
+            find_root_newton_raphson_with_strategy(0f64, &f, &d, ZeroDerivativeStrategy::Bisect(1f64, 3f64), &mut 1e-15f64),
+            Ok(1f64)
+        );
+
+        // x^3 - 3x + 3 is stationary at -1 and 1
+        let f = |x| 1f64 * x * x * x - 3f64 * x + 3f64;
+        let d = |x| 3f64 * x * x - 3f64;
+        // The nudge lands on the other stationary point
+        assert!(matches!(
+            find_root_newton_raphson_with_strategy(1f64, &f, &d, ZeroDerivativeStrategy::NudgeBy(-2f64), &mut 1e-15f64),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+        // The middle of [-3, 1] is stationary as well, and it is bisected again
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_raphson_with_strategy(1f64, &f, &d, ZeroDerivativeStrategy::Bisect(-3f64, 5f64), &mut conv)
+                .ok()
+                .unwrap(),
+            -2.1038034027355366f64
+        );
+        assert_eq!(6, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_newton_raphson_cycle() {
+        // The classic example: the iterations from 0 cycle between 0 and 1
+        let f = |x: f64| x * x * x - 2f64 * x + 2f64;
+        let d = |x: f64| 3f64 * x * x - 2f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_raphson(0f64, &f, &d, &mut conv).ok().unwrap(),
+            -1.7692923542386314f64
+        );
+        assert_eq!(11, conv.get_iter_count());
+
+        // The function changes its sign between the points of the cycle
+        let g = |x: f64| x.signum() * x.abs().sqrt();
+        let gd = |x: f64| 0.5f64 / x.abs().sqrt();
+        conv.reset();
+        assert_eq!(find_root_newton_raphson(1f64, &g, &gd, &mut conv), Ok(0f64));
+        assert_eq!(1, conv.get_iter_count());
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_raphson(0.7f64, |x| g(x - 0.2f64), |x| gd(x - 0.2f64), &mut conv)
+                .ok()
+                .unwrap(),
+            0.2f64
+        );
+    }
+
+    #[test]
+    fn test_find_root_newton_bracketed() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let d = |x| 2f64 * x;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_bracketed(10f64, 0f64, &f, &d, &mut conv).ok().unwrap(),
+            1f64
+        );
+        assert_eq!(7, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_bracketed(-10f64, 0f64, &f, &d, &mut conv).ok().unwrap(),
+            -1f64
+        );
+        assert_eq!(7, conv.get_iter_count());
+
+        conv.reset();
+        assert!(matches!(
+            find_root_newton_bracketed(10f64, 20f64, &f, &d, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(0, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_newton_bracketed_divergence() {
+        // Newton-Raphson steps from the midpoint 2.5 overshoot the root further and further
+        let f = |x: f64| x.atan();
+        let d = |x: f64| 1f64 / (1f64 + x * x);
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert!(matches!(
+            find_root_newton_raphson(2.5f64, &f, &d, &mut conv),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_bracketed(-5f64, 10f64, &f, &d, &mut conv).ok().unwrap(),
+            0f64
+        );
+        assert_eq!(7, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_newton_raphson_bounded() {
+        // The logarithm is defined for positive values only
+        let outside = Cell::new(false);
+        let f = |x: f64| {
+            if x <= 0f64 {
+                outside.set(true);
+            }
+            x.ln() - 1f64
+        };
+        let d = |x: f64| 1f64 / x;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        let _ = find_root_newton_raphson(10f64, &f, &d, &mut conv);
+        assert!(outside.get());
+
+        outside.set(false);
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_raphson_bounded(10f64, 0.5f64, 20f64, &f, &d, &mut conv)
+                .ok()
+                .unwrap(),
+            ::std::f64::consts::E
+        );
+        assert_eq!(7, conv.get_iter_count());
+        assert!(!outside.get());
+    }
+
+    #[test]
+    fn test_find_root_newton_raphson_bounded_bisection() {
+        // Newton-Raphson steps alternate between -1 and 4.5 after the first clamping
+        let outside = Cell::new(false);
+        let f = |x: f64| {
+            if !(-1f64..=8f64).contains(&x) {
+                outside.set(true);
+            }
+            (x - 1f64).atan()
+        };
+        let d = |x: f64| 1f64 / (1f64 + (x - 1f64) * (x - 1f64));
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_raphson_bounded(8f64, -1f64, 8f64, &f, &d, &mut conv)
+                .ok()
+                .unwrap(),
+            1f64
+        );
+        assert_eq!(7, conv.get_iter_count());
+        assert!(!outside.get());
+
+        // The root is outside of the interval
+        let f = |x: f64| x - 10f64;
+        let d = |_| 1f64;
+        conv.reset();
+        assert!(matches!(
+            find_root_newton_raphson_bounded(1f64, 0f64, 5f64, &f, &d, &mut conv),
+            Err(SearchError::NoConvergency { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_newton_numeric() {
+        let polynomials: [(fn(f64) -> f64, fn(f64) -> f64); 2] = [
+            (|x| x * x - 1f64, |x| 2f64 * x),
+            (|x| x * x * x * x - 1f64, |x| 4f64 * x * x * x),
+        ];
+        for &(f, d) in polynomials.iter() {
+            for &start in [0.5f64, 10f64, -10f64].iter() {
+                let analytic = find_root_newton_raphson(start, f, d, &mut 1e-15f64).ok().unwrap();
+                let numeric = find_root_newton_numeric(start, f, &mut 1e-15f64).ok().unwrap();
+                assert_float_eq!(1e-15f64, numeric, analytic);
+            }
+        }
+
+        let f = |x| 1f64 * x * x - 1f64;
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_numeric(0f64, &f, &mut 1e-15f64).ok().unwrap(),
+            1f64
+        );
+    }
+
+    // The check of the derivative makes additional evaluations
+    #[cfg(not(feature = "debug-derivative"))]
+    #[test]
+    fn test_find_root_newton_raphson_combined() {
+        use std::cell::RefCell;
+
+        let iterates = RefCell::new(Vec::new());
+        let f = |x: f64| {
+            iterates.borrow_mut().push(x);
+            x * x * x * x - 1f64
+        };
+        let d = |x: f64| 4f64 * x * x * x;
+        let fd = |x: f64| (f(x), d(x));
+
+        for &start in [0.5f64, 10f64, -10f64, 0f64].iter() {
+            let root = find_root_newton_raphson(start, &f, &d, &mut 1e-15f64);
+            let separate = iterates.replace(Vec::new());
+            assert_eq!(find_root_newton_raphson_combined(start, &fd, &mut 1e-15f64), root);
+            assert_eq!(iterates.replace(Vec::new()), separate);
+        }
+    }
+
+    #[test]
+    fn test_find_root_newton_with_multiplicity() {
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 200,
+        };
+        for m in 1..4 {
+            // (x - 1)^m * (x + 2)
+            let f = |x: f64| (x - 1f64).powi(m) * (x + 2f64);
+            let d = |x: f64| (x - 1f64).powi(m - 1) * (f64::from(m) * (x + 2f64) + (x - 1f64));
+            for &start in [3f64, 1.5f64, 0.5f64].iter() {
+                let (root, multiplicity) = find_root_newton_with_multiplicity(start, &f, &d, &mut conv).ok().unwrap();
+                assert_float_eq!(1e-5f64, root, 1f64);
+                assert_eq!(m as usize, multiplicity);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_root_newton_raphson_or_best() {
+        let f = |x| 1f64 * x * x - 2f64;
+        let d = |x| 2f64 * x;
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 7,
+        };
+        assert!(matches!(
+            find_root_newton_raphson(10f64, &f, &d, &mut conv),
+            Err(SearchError::NoConvergency { .. })
+        ));
+        let (root, converged) = find_root_newton_raphson_or_best(10f64, &f, &d, &mut conv).ok().unwrap();
+        assert!(!converged);
+        assert_float_eq!(1e-7f64, root, 2f64.sqrt());
+
+        let root = find_root_newton_raphson(10f64, &f, &d, &mut 1e-15f64);
+        assert_eq!(
+            find_root_newton_raphson_or_best(10f64, &f, &d, &mut 1e-15f64),
+            root.map(|x| (x, true))
+        );
+
+        // Iterations diverge; the starting point is the closest to the root
+        let f = |x: f64| x.atan();
+        let d = |x: f64| 1f64 / (1f64 + x * x);
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 5,
+        };
+        assert!(matches!(
+            find_root_newton_raphson(2f64, &f, &d, &mut conv),
+            Err(SearchError::NoConvergency { .. })
+        ));
+        assert_eq!(find_root_newton_raphson_or_best(2f64, &f, &d, &mut conv), Ok((2f64, false)));
+
+        // Other errors are not affected
+        let f = |x| 1f64 * x * x + 1f64;
+        let d = |x| 2f64 * x;
+        assert!(matches!(
+            find_root_newton_raphson_or_best(0f64, &f, &d, &mut 1e-15f64),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_find_root_newton_complex() {
+        // z^2 + 1 has no real roots; real iterates leave the real axis to the upper half-plane
+        let f = |z: Complex<f64>| z * z + 1f64;
+        let d = |z: Complex<f64>| z * 2f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+        for &(start, iter) in [(1f64, 17), (0f64, 18), (-3f64, 20), (100f64, 25)].iter() {
+            conv.reset();
+            let root = find_root_newton_complex(Complex::new(start, 0f64), &f, &d, &mut conv)
+                .ok()
+                .unwrap();
+            assert_float_eq!(1e-15f64, root.re, 0f64);
+            assert_float_eq!(1e-15f64, root.im, 1f64);
+            assert_eq!(iter, conv.get_iter_count());
+        }
+        // Complex starts find the root in their half-plane
+        for &(start, root) in [
+            ((1f64, 1f64), 1f64),
+            ((1f64, -1f64), -1f64),
+            ((-1f64, -0.5f64), -1f64),
+            ((0f64, 5f64), 1f64),
+        ]
+        .iter()
+        {
+            let found = find_root_newton_complex(Complex::new(start.0, start.1), &f, &d, &mut 1e-15f64)
+                .ok()
+                .unwrap();
+            assert_float_eq!(1e-15f64, found.re, 0f64);
+            assert_float_eq!(1e-15f64, found.im, root);
+        }
+
+        // z^3 - 1
+        let f = |z: Complex<f64>| z * z * z - 1f64;
+        let d = |z: Complex<f64>| z * z * 3f64;
+        let half_sqrt3 = 3f64.sqrt() / 2f64;
+        for &(start, root) in [
+            ((0f64, 0f64), (1f64, 0f64)),
+            ((-3f64, 0f64), (1f64, 0f64)),
+            ((1f64, 1f64), (1f64, 0f64)),
+            ((-1f64, -0.5f64), (-0.5f64, -half_sqrt3)),
+            ((0f64, 5f64), (-0.5f64, half_sqrt3)),
+        ]
+        .iter()
+        {
+            let found = find_root_newton_complex(Complex::new(start.0, start.1), &f, &d, &mut 1e-15f64)
+                .ok()
+                .unwrap();
+            assert_float_eq!(1e-15f64, found.re, root.0);
+            assert_float_eq!(1e-15f64, found.im, root.1);
+        }
+    }
+
+    #[test]
+    fn test_find_root_newton_raphson_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let d = |x: f64| 1f64 / x;
+        let start = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteStart));
+        assert_eq!(find_root_newton_raphson(f64::NAN, &f, &d, &mut 1e-15f64), start);
+        assert_eq!(find_root_newton_raphson(f64::INFINITY, &f, &d, &mut 1e-15f64), start);
+        assert_eq!(find_root_newton_raphson(f64::NEG_INFINITY, &f, &d, &mut 1e-15f64), start);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_newton_raphson(0f64, &f, &d, &mut 1e-15f64), value);
+        assert_eq!(find_root_newton_raphson(-1f64, &f, &d, &mut 1e-15f64), value);
+        assert_eq!(find_root_newton_numeric(f64::NAN, &f, &mut 1e-15f64), start);
+        assert_eq!(find_root_newton_numeric(0f64, &f, &mut 1e-15f64), value);
+        assert_eq!(
+            find_root_newton_raphson_bounded(f64::NAN, 0.5f64, 10f64, &f, &d, &mut 1e-15f64),
+            start
+        );
+        assert_eq!(
+            find_root_newton_raphson_bounded(0f64, 0f64, 10f64, &f, &d, &mut 1e-15f64),
+            value
+        );
+
+        let bracket = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteBracket));
+        assert_eq!(
+            find_root_newton_raphson_bounded(2f64, f64::NAN, 10f64, &f, &d, &mut 1e-15f64),
+            bracket
+        );
+        assert_eq!(
+            find_root_newton_raphson_bounded(2f64, 0.5f64, f64::INFINITY, &f, &d, &mut 1e-15f64),
+            bracket
+        );
+        assert_eq!(find_root_newton_bracketed(f64::NAN, 10f64, &f, &d, &mut 1e-15f64), bracket);
+        assert_eq!(
+            find_root_newton_bracketed(0.5f64, f64::INFINITY, &f, &d, &mut 1e-15f64),
+            bracket
+        );
+        assert_eq!(find_root_newton_bracketed(0f64, 10f64, &f, &d, &mut 1e-15f64), value);
+        assert_eq!(
+            find_root_newton_raphson_with_strategy(2f64, &f, &d, ZeroDerivativeStrategy::Bisect(f64::NAN, 10f64), &mut 1e-15f64),
+            bracket
+        );
     }
 }
@@ -0,0 +1,192 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::check_start;
+use super::check_value;
+use super::Convergency;
+use super::SearchError;
+
+/// Find a root of the function f(x) = 0 using the Ostrowski's method.
+///
+/// Each iteration makes the Newton-Raphson step to y and corrects it by
+/// x - f(x)/f'(x) * (f(x) - f(y))/(f(x) - 2f(y)).
+///
+/// Pro
+///
+/// + Fourth order convergency for well-behaved functions
+/// + Only one derivative evaluation per iteration
+/// + No need for initial bracketing
+///
+/// Contra
+///
+/// - Needs derivative function
+/// - Impossible to predict which root will be found when many roots exist
+/// - Unstable convergency for non-trivial functions
+/// - Cannot continue when derivative is zero
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered, or the correction cannot be computed. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_ostrowski;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let d = |x| { 2f64*x };
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:30 };
+///
+/// let root1 = find_root_ostrowski(10f64, &f, &d, &mut convergency);
+/// // Returns approximately Ok(1);
+///
+/// let root2 = find_root_ostrowski(-10f64, &f, &d, &mut 1e-15f64);
+/// // Returns approximately Ok(-1);
+/// ```
+pub fn find_root_ostrowski<F, Func, Deriv>(
+    start: F,
+    mut f: Func,
+    mut d: Deriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    check_start(start)?;
+    let mut x = start;
+
+    let mut iter = 0;
+    loop {
+        let fx = f(x);
+        if iter == 0 {
+            check_value(fx)?;
+        }
+        if convergency.is_root_found(fx) {
+            return Ok(x);
+        }
+        let dx = d(x);
+        // Derivative is 0; try to correct the bad starting point
+        if convergency.is_root_found(dx) {
+            if iter == 0 {
+                x = x + F::one();
+                iter += 1;
+                continue;
+            } else {
+                return Err(SearchError::zero_derivative(iter, x, fx));
+            }
+        }
+
+        let y = x - fx / dx;
+        let fy = f(y);
+        if convergency.is_root_found(fy) {
+            return Ok(y);
+        }
+        let denominator = fx - _2 * fy;
+        if convergency.is_root_found(denominator) {
+            return Err(SearchError::zero_derivative(iter, x, fx));
+        }
+
+        let x1 = y - fy * fx / (dx * denominator);
+        if convergency.is_converged(x, x1) {
+            return Ok(x1);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency(iter, x, fx));
+        }
+        x = x1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_find_root_ostrowski() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let d = |x| 2f64 * x;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_ostrowski(10f64, &f, &d, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(4, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_ostrowski(-10f64, &f, &d, &mut conv).ok().unwrap(), -1f64);
+        assert_eq!(4, conv.get_iter_count());
+    }
+
+    // The check of the derivative makes additional evaluations
+    #[cfg(not(feature = "debug-derivative"))]
+    #[test]
+    fn test_find_root_ostrowski_evaluations() {
+        use std::cell::Cell;
+
+        // Both function and derivative evaluations are counted
+        let evaluations = Cell::new(0);
+        let f = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            x * x * x * x - 1f64
+        };
+        let d = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            4f64 * x * x * x
+        };
+
+        let root = find_root_ostrowski(10f64, &f, &d, &mut 1e-15f64).ok().unwrap();
+        let ostrowski_evaluations = evaluations.replace(0);
+        let newton = newton_raphson::find_root_newton_raphson(10f64, &f, &d, &mut 1e-15f64)
+            .ok()
+            .unwrap();
+        let newton_evaluations = evaluations.get();
+        assert_float_eq!(1e-15f64, root, 1f64);
+        assert_float_eq!(1e-15f64, newton, 1f64);
+        assert_eq!(19, ostrowski_evaluations);
+        assert_eq!(28, newton_evaluations);
+    }
+
+    #[test]
+    fn test_find_root_ostrowski_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let d = |x: f64| 1f64 / x;
+        let start = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteStart));
+        assert_eq!(find_root_ostrowski(f64::NAN, &f, &d, &mut 1e-15f64), start);
+        assert_eq!(find_root_ostrowski(f64::INFINITY, &f, &d, &mut 1e-15f64), start);
+        assert_eq!(find_root_ostrowski(f64::NEG_INFINITY, &f, &d, &mut 1e-15f64), start);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_ostrowski(0f64, &f, &d, &mut 1e-15f64), value);
+        assert_eq!(find_root_ostrowski(-1f64, &f, &d, &mut 1e-15f64), value);
+    }
+}
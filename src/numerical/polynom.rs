@@ -26,6 +26,8 @@ use super::super::find_roots_cubic;
 use super::super::find_roots_linear;
 use super::super::find_roots_quadratic;
 use super::super::FloatType;
+use super::bracket::Direction;
+use super::extremum::Extremum;
 use super::Convergency;
 use super::Interval;
 use super::Sample;
@@ -46,7 +48,11 @@ where
 {
     fn value(&self, x: &F) -> F;
     fn value_and_derivative(&self, x: &F) -> ValueAndDerivative<F>;
-    fn find_root(&self, bracketed_start: &mut Interval<F>, convergency: &mut dyn Convergency<F>) -> Result<F, SearchError>;
+    fn find_root(
+        &self,
+        bracketed_start: &mut Interval<F>,
+        convergency: &mut (impl Convergency<F> + ?Sized),
+    ) -> Result<F, SearchError>;
     fn derivative_polynom(&self) -> Vec<F>;
     fn to_string(&self) -> String;
 }
@@ -70,30 +76,27 @@ where
     }
 
     fn value_and_derivative(&self, x: &F) -> ValueAndDerivative<F> {
-        let mut xn = F::one(); // x^n for SUM(A(n)*x^(n))
-        let mut value = F::zero();
-
-        let mut xn1 = F::zero(); // x^n-1 for SUM(n*A(n-1)*x^(n-1))
+        // The highest coefficient of the normalized polynom is 1
+        let mut value = F::one();
         let mut derivative = F::zero();
-        let mut n = F::zero();
 
-        // Sum starting with a0
-        for a in self.iter().rev() {
-            value = value + *a * xn;
-            derivative = derivative + *a * n * xn1;
-            xn1 = xn;
-            xn = xn * *x;
-            n = n + F::one();
+        // Horner's scheme; the derivative is the value of the quotient of the division by (t - x)
+        for a in self.iter() {
+            derivative = derivative * *x + value;
+            value = value * *x + *a;
         }
 
-        // The highest coefficient of the normalized polynom is 1
         ValueAndDerivative {
-            value: Sample { x: *x, y: value + xn },
-            derivative: derivative + n * xn1,
+            value: Sample { x: *x, y: value },
+            derivative,
         }
     }
 
-    fn find_root(&self, bracketed_start: &mut Interval<F>, convergency: &mut dyn Convergency<F>) -> Result<F, SearchError> {
+    fn find_root(
+        &self,
+        bracketed_start: &mut Interval<F>,
+        convergency: &mut (impl Convergency<F> + ?Sized),
+    ) -> Result<F, SearchError> {
         if bracketed_start.is_bracketed() {
             let interval = bracketed_start;
             let mut iter = 0;
@@ -138,11 +141,16 @@ where
                 }
                 iter = iter + 1;
                 if convergency.is_iteration_limit_reached(iter) {
-                    break Err(SearchError::NoConvergency);
+                    let best = if interval.begin.y.abs() < interval.end.y.abs() {
+                        &interval.begin
+                    } else {
+                        &interval.end
+                    };
+                    break Err(SearchError::no_convergency(iter, best.x, best.y).with_bracket(interval.begin.x, interval.end.x));
                 }
             }
         } else {
-            Err(SearchError::NoBracketing)
+            Err(SearchError::no_bracketing(bracketed_start.begin.x, bracketed_start.end.x))
         }
     }
 
@@ -198,25 +206,20 @@ where
     Middle(Interval<F>),
 }
 
-enum BracketingDirection {
-    TowardsPositive,
-    TowardsNegative,
-}
-
 fn initial_bracket<F>(
     initial_sample: &Sample<F>,
-    direction: &BracketingDirection,
+    direction: &Direction,
     polynom: &[F],
     derivative_polynom: &[F],
-    convergency: &mut dyn Convergency<F>,
+    convergency: &mut (impl Convergency<F> + ?Sized),
 ) -> Result<Interval<F>, SearchError>
 where
     F: FloatType,
 {
     let mut iter = 0;
     let towards_positive = match direction {
-        &BracketingDirection::TowardsPositive => true,
-        &BracketingDirection::TowardsNegative => false,
+        &Direction::TowardsPositive => true,
+        &Direction::TowardsNegative => false,
     };
     let mut step = if towards_positive { F::one() } else { -F::one() };
     let initial_copy = Sample {
@@ -263,7 +266,7 @@ where
             next_x = next_x + step;
             iter = iter + 1;
             if convergency.is_iteration_limit_reached(iter) {
-                break Err(SearchError::NoConvergency);
+                break Err(SearchError::no_convergency(iter, next_sample.x, next_sample.y));
             };
         }
     };
@@ -274,7 +277,7 @@ fn narrow_down<F>(
     initial_interval: &SearchInterval<F>,
     polynom: &[F],
     derivative_polynom: &[F],
-    convergency: &mut dyn Convergency<F>,
+    convergency: &mut (impl Convergency<F> + ?Sized),
 ) -> Result<Interval<F>, SearchError>
 where
     F: FloatType,
@@ -292,20 +295,12 @@ where
             };
             narrow_down(&zero_interval, polynom, derivative_polynom, convergency)
         }
-        &SearchInterval::First(ref end) => initial_bracket(
-            &end,
-            &BracketingDirection::TowardsNegative,
-            polynom,
-            derivative_polynom,
-            convergency,
-        ),
-        &SearchInterval::Last(ref begin) => initial_bracket(
-            &begin,
-            &BracketingDirection::TowardsPositive,
-            polynom,
-            derivative_polynom,
-            convergency,
-        ),
+        &SearchInterval::First(ref end) => {
+            initial_bracket(&end, &Direction::TowardsNegative, polynom, derivative_polynom, convergency)
+        }
+        &SearchInterval::Last(ref begin) => {
+            initial_bracket(&begin, &Direction::TowardsPositive, polynom, derivative_polynom, convergency)
+        }
         &SearchInterval::Middle(ref interval) => {
             if interval.is_bracketed() {
                 let middle_x = if interval.begin.y == interval.end.y {
@@ -345,7 +340,7 @@ where
                     })
                 }
             } else {
-                Err(SearchError::NoBracketing)
+                Err(SearchError::no_bracketing(interval.begin.x, interval.end.x))
             }
         }
     }
@@ -354,7 +349,7 @@ where
 fn find_root_intervals<F>(
     polynom: &[F],
     derivative_polynom: &[F],
-    convergency: &mut dyn Convergency<F>,
+    convergency: &mut (impl Convergency<F> + ?Sized),
 ) -> Result<Vec<SearchInterval<F>>, SearchError>
 where
     F: FloatType,
@@ -411,7 +406,11 @@ where
             }
             Ok(result)
         }
-        _ => Err(SearchError::NoBracketing),
+        _ => Err(SearchError::NoBracketing {
+            iterations: 0,
+            last: None,
+            bracket: None,
+        }),
     }
 }
 
@@ -438,7 +437,7 @@ where
 ///             .collect();
 /// // Returns vector of roots filterin out all search errors;
 /// ```
-pub fn find_roots_sturm<F>(a: &[F], convergency: &mut dyn Convergency<F>) -> Vec<Result<F, SearchError>>
+pub fn find_roots_sturm<F>(a: &[F], convergency: &mut (impl Convergency<F> + ?Sized)) -> Vec<Result<F, SearchError>>
 where
     F: FloatType,
 {
@@ -475,8 +474,224 @@ where
     }
 }
 
+/// Refines an approximate root of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// using guarded Newton-Raphson steps of refine_root.
+///
+/// Roots found by find_roots_eigen or by analytical solvers may be imprecise,
+/// and a few steps usually restore the full precision of simple roots.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_eigen;
+/// use roots::refine_polynomial_root;
+///
+/// let a3 = -0.000000000000000040410628481035f64;
+/// let polynom = [0.0126298310280606f64 / a3, -0.100896606408756f64 / a3, 0.0689539597036461f64 / a3];
+/// let roots: Vec<f64> = find_roots_eigen(&polynom)
+///     .map(|x| refine_polynomial_root(&polynom, x, &mut 1e-15f64).unwrap_or(x))
+///     .collect();
+/// // Returns approximately [0.7547108770537f64, 7.23404258961f64, 312537357195212.8f64]
+/// // while find_roots_eigen alone returns [0f64, 8f64, 312537357195212.8f64]
+/// ```
+pub fn refine_polynomial_root<F>(a: &[F], x0: F, convergency: &mut (impl Convergency<F> + ?Sized)) -> Result<F, SearchError>
+where
+    F: FloatType,
+{
+    super::refine::refine_root(
+        x0,
+        |x| a.value_and_derivative(&x).value.y,
+        |x| a.value_and_derivative(&x).derivative,
+        convergency,
+    )
+}
+
+/// Find a root of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// using the Newton-Raphson method, where the value and the derivative are calculated
+/// by one pass of the Horner's scheme.
+///
+/// Pro
+///
+/// + About 4n flops per iteration
+/// + No need for derivative function
+///
+/// Contra
+///
+/// - Polynomials only
+/// - Real roots only
+/// - Impossible to predict which root will be found when many roots exist
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The stationary point of the polynomial is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_newton_horner;
+///
+/// // x^3 - 6x^2 + 11x - 6 = (x - 1)(x - 2)(x - 3)
+/// let polynom = [-6f64, 11f64, -6f64];
+///
+/// let root = find_root_newton_horner(&polynom, 0f64, &mut 1e-15f64);
+/// // Returns approximately Ok(1);
+/// ```
+pub fn find_root_newton_horner<F>(a: &[F], start: F, convergency: &mut (impl Convergency<F> + ?Sized)) -> Result<F, SearchError>
+where
+    F: FloatType,
+{
+    super::newton_raphson::find_root_newton_raphson_combined(
+        start,
+        |x| {
+            let result = a.value_and_derivative(&x);
+            (result.value.y, result.derivative)
+        },
+        convergency,
+    )
+}
+
+/// Find all roots of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// by find_root_newton_horner, one by one.
+///
+/// Each root found is divided out by deflate_stable, and the next root is searched in the quotient.
+/// Finally, all roots are polished by the Newton-Raphson method on the original polynomial
+/// to remove the errors accumulated by deflation.
+///
+/// Pro
+///
+/// + O(n^2) flops compared to O(n^3) of find_roots_eigen
+///
+/// Contra
+///
+/// - Polynomials with real roots only
+/// - Multiple roots converge linearly and lose precision
+///
+/// # Failures
+/// ## ZeroDerivative
+/// The stationary point of a deflated polynomial is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations, e.g. the remaining roots are complex.
+/// # Examples
+///
+/// ```
+/// use roots::find_all_roots_newton_horner;
+///
+/// // (x + 2)(x - 1)(x - 3)(x - 4) = x^4 - 6x^3 + 3x^2 + 26x - 24
+/// let polynom = [-6f64, 3f64, 26f64, -24f64];
+///
+/// let roots = find_all_roots_newton_horner(&polynom, &mut 1e-15f64);
+/// // Returns approximately Ok(vec![-2f64, 1f64, 3f64, 4f64]);
+/// ```
+pub fn find_all_roots_newton_horner<F>(a: &[F], convergency: &mut (impl Convergency<F> + ?Sized)) -> Result<Vec<F>, SearchError>
+where
+    F: FloatType,
+{
+    let mut roots = Vec::with_capacity(a.len());
+    let mut deflated = a.to_vec();
+    while !deflated.is_empty() {
+        let root = find_root_newton_horner(&deflated, F::zero(), convergency)?;
+        deflated = super::deflation::deflate_stable(&deflated, root);
+        roots.push(root);
+    }
+    for root in roots.iter_mut() {
+        *root = find_root_newton_horner(a, *root, convergency).unwrap_or(*root);
+    }
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Ok(roots)
+}
+
+/// Find a local extremum of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1]
+/// between a and b as the root of its derivative by find_extremum_brent.
+///
+/// The derivative is calculated from the coefficients, so the caller does not need to write it.
+///
+/// # Failures
+/// ## NoBracketing
+/// The derivative does not change its sign between a and b, or it is zero at both ends.
+/// ## NoConvergency
+/// Algorithm cannot find a root of the derivative within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_extremum_polynomial;
+///
+/// // x^4 - 2x^2 has minima at -1 and 1 and the maximum at 0
+/// let polynom = [0f64, -2f64, 0f64, 0f64];
+///
+/// let extremum = find_extremum_polynomial(&polynom, 0.5f64, 2f64, &mut 1e-15f64);
+/// // Returns approximately Ok(Extremum { x: 1, kind: ExtremumKind::Minimum });
+/// ```
+pub fn find_extremum_polynomial<F>(
+    a: &[F],
+    lo: F,
+    hi: F,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<Extremum<F>, SearchError>
+where
+    F: FloatType,
+{
+    if a.is_empty() {
+        // The derivative of x is 1
+        return Err(SearchError::no_bracketing(lo, hi));
+    }
+    // The normalized derivative has the same sign as the derivative itself
+    let derivative_polynom = a.derivative_polynom();
+    super::extremum::find_extremum_brent(lo, hi, |x| derivative_polynom.value(&x), convergency)
+}
+
+/// Find real zeros of the rational function p(x) / q(x) of the normalized polynomials
+/// p(x) = x^n + p[0]*x^(n-1) + ... + p[n-1] and q(x) = x^m + q[0]*x^(m-1) + ... + q[m-1].
+///
+/// Generic bracketing methods report the poles of the rational function as roots, because the sign changes
+/// there as well. Here the candidates are the real roots of p found by find_roots_sturm. The candidates
+/// closer than the precision of `convergency` to a real root of q come from common factors of p and q
+/// and are removed, even if their multiplicity in p is higher. The other candidates are verified by evaluating
+/// the rational function: p(x) must be zero within the precision, and p(x) / q(x) must be finite.
+/// The zeros are sorted in the ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_rational;
+///
+/// // (x - 1)(x - 2) / ((x - 1)(x + 3)) has the zero 2 only
+/// let p = [-3f64, 2f64];
+/// let q = [2f64, -3f64];
+///
+/// let zeros = find_roots_rational(&p, &q, &mut 1e-12f64);
+/// // Returns approximately [2f64]
+/// ```
+pub fn find_roots_rational<F>(p: &[F], q: &[F], convergency: &mut (impl Convergency<F> + ?Sized)) -> Vec<F>
+where
+    F: FloatType,
+{
+    let is_finite = |x: F| x * F::zero() == F::zero();
+    let poles: Vec<F> = find_roots_sturm(q, convergency).into_iter().filter_map(|r| r.ok()).collect();
+    let mut zeros: Vec<F> = Vec::new();
+    for x in find_roots_sturm(p, convergency).into_iter().filter_map(|r| r.ok()) {
+        if poles.iter().any(|&pole| convergency.is_converged(x, pole)) {
+            continue;
+        }
+        let (y, d) = (p.value(&x), q.value(&x));
+        if convergency.is_root_found(y) && d != F::zero() && is_finite(y / d) {
+            zeros.push(x);
+        }
+    }
+    zeros.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    zeros
+}
+
 #[cfg(test)]
 mod test {
+    use super::super::brent::find_root_brent;
+    use super::super::extremum::ExtremumKind;
     use super::super::*;
     use super::*;
 
@@ -571,7 +786,182 @@ mod test {
             .collect();
 
         // these roots cannot be found
-        assert_float_array_eq!(1e-5, roots, []);
+        assert_float_array_eq!(1e-5, roots, [0f64; 0]);
         //assert_float_array_eq!(1e-5, roots, [-1.1016116464173349f64, 0.9682783130840016f64]);
     }
+
+    #[test]
+    fn test_refine_polynomial_root() {
+        // The normalized cubic polynomial where the highest coefficient was very small
+        // (as reported by Andrew Hunter in July 2019)
+        let polynom = [
+            0.0126298310280606f64 / -0.000000000000000040410628481035f64,
+            -0.100896606408756f64 / -0.000000000000000040410628481035f64,
+            0.0689539597036461f64 / -0.000000000000000040410628481035f64,
+        ];
+        // find_roots_eigen returns 0 and 8 instead of 0.7547108770537 and 7.23404258961
+        let expected = [0.75471087705369017054f64, 7.2340425896070389699f64];
+        for (x0, e) in [0f64, 8f64].iter().zip(expected.iter()) {
+            assert_float_eq!(1e-14f64, refine_polynomial_root(&polynom, *x0, &mut 1e-15f64).unwrap(), *e);
+        }
+    }
+
+    /// Counts the checks of the value and the derivative, i.e. about two per evaluation of the polynomial
+    struct CheckCounter {
+        eps: f64,
+        checks: usize,
+    }
+
+    impl Convergency<f64> for CheckCounter {
+        fn is_root_found(&mut self, y: f64) -> bool {
+            self.checks += 1;
+            y.abs() < self.eps
+        }
+        fn is_converged(&mut self, x1: f64, x2: f64) -> bool {
+            (x1 - x2).abs() < self.eps
+        }
+        fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+            iter >= 30
+        }
+    }
+
+    #[test]
+    fn test_find_root_newton_horner() {
+        // (x - 1)(x - 2)(x - 3)
+        let polynom = [-6f64, 11f64, -6f64];
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_horner(&polynom, 0f64, &mut conv).ok().unwrap(),
+            1f64
+        );
+        assert_eq!(7, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_newton_horner(&polynom, 10f64, &mut conv).ok().unwrap(),
+            3f64
+        );
+        assert_eq!(10, conv.get_iter_count());
+
+        // x^2 + 1 has no real roots; iterates reach the stationary point 0
+        assert!(matches!(
+            find_root_newton_horner(&[0f64, 1f64], 0f64, &mut 1e-15f64),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_all_roots_newton_horner() {
+        let polynoms: [&[f64]; 4] = [
+            // (x + 3)(x + 1)(x - 0.5)(x - 2)(x - 4)
+            &[-2.5f64, -12f64, 20.5f64, 17f64, -12f64],
+            // (x + 4)(x + 2)(x + 0.5)(x - 1)(x - 3)(x - 7)
+            &[-4.5f64, -29.5f64, 63.5f64, 160.5f64, -107f64, -84f64],
+            // (x - 1)(x - 2)(x - 3)(x - 4)(x - 5)(x - 6)(x - 7)
+            &[-28f64, 322f64, -1960f64, 6769f64, -13132f64, 13068f64, -5040f64],
+            // (x + 3.5)(x + 2)(x + 1)(x - 0.25)(x - 1.5)(x - 2)(x - 5)(x - 6)
+            &[
+                -8.25f64,
+                -8.25f64,
+                155.0625f64,
+                -52.875f64,
+                -635.8125f64,
+                318.875f64,
+                590.25f64,
+                -157.5f64,
+            ],
+        ];
+        let checks = [56usize, 82, 133, 149];
+        for (polynom, expected_checks) in polynoms.iter().zip(checks.iter()) {
+            let mut counter = CheckCounter {
+                eps: 1e-12f64,
+                checks: 0,
+            };
+            let roots = find_all_roots_newton_horner(polynom, &mut counter).unwrap();
+            let mut expected: Vec<f64> = eigen::find_roots_eigen(polynom).collect();
+            expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_float_array_eq!(1e-10f64, roots, expected);
+            // Each evaluation takes about 4n flops, while the eigenvalues of the companion matrix take about 10n^3
+            let n = polynom.len();
+            assert_eq!(*expected_checks, counter.checks);
+            assert!(2 * n * counter.checks < 10 * n * n * n);
+        }
+    }
+
+    #[test]
+    fn test_find_extremum_polynomial() {
+        // x^4 - 2x^2
+        let polynom = [0f64, -2f64, 0f64, 0f64];
+        let extrema: Vec<_> = [(-2f64, -0.5f64), (-0.5f64, 0.5f64), (0.5f64, 2f64)]
+            .iter()
+            .map(|&(lo, hi)| find_extremum_polynomial(&polynom, lo, hi, &mut 1e-15f64).unwrap())
+            .collect();
+        assert_float_eq!(1e-15f64, extrema[0].x, -1f64);
+        assert_eq!(extrema[0].kind, ExtremumKind::Minimum);
+        assert_float_eq!(1e-15f64, extrema[1].x, 0f64);
+        assert_eq!(extrema[1].kind, ExtremumKind::Maximum);
+        assert_float_eq!(1e-15f64, extrema[2].x, 1f64);
+        assert_eq!(extrema[2].kind, ExtremumKind::Minimum);
+
+        // (x + 2)(x - 1)(x - 3)(x - 4) has one extremum between each pair of the neighbouring roots
+        let polynom = [-6f64, 3f64, 26f64, -24f64];
+        let stationary = find_roots_cubic(4f64, -18f64, 6f64, 26f64);
+        let kinds = [ExtremumKind::Minimum, ExtremumKind::Maximum, ExtremumKind::Minimum];
+        for (i, &(lo, hi)) in [(-2f64, 1f64), (1f64, 3f64), (3f64, 4f64)].iter().enumerate() {
+            let extremum = find_extremum_polynomial(&polynom, lo, hi, &mut 1e-15f64).unwrap();
+            assert_float_eq!(1e-14f64, extremum.x, stationary.as_ref()[i]);
+            assert_eq!(extremum.kind, kinds[i]);
+        }
+        assert!(matches!(
+            find_extremum_polynomial(&polynom, 4f64, 5f64, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+
+        // The derivative of a linear polynomial does not change its sign
+        assert!(matches!(
+            find_extremum_polynomial(&[1f64], -1f64, 1f64, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert!(matches!(
+            find_extremum_polynomial(&[] as &[f64], -1f64, 1f64, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_roots_rational() {
+        // (x - 1)(x - 2) / ((x - 1)(x + 3)): the common factor is removed
+        let zeros = find_roots_rational(&[-3f64, 2f64], &[2f64, -3f64], &mut 1e-12f64);
+        assert_eq!(zeros.len(), 1);
+        assert_float_eq!(1e-12f64, zeros[0], 2f64);
+
+        // (x - 1)(x - 3) / (x - 1.000001): the zero 1 is very close to the pole, but distinct
+        let p = [-4f64, 3f64];
+        let q = [-1.000001f64];
+        let zeros = find_roots_rational(&p, &q, &mut 1e-12f64);
+        assert_eq!(zeros.len(), 2);
+        assert_float_eq!(1e-12f64, zeros[0], 1f64);
+        assert_float_eq!(1e-12f64, zeros[1], 3f64);
+        // A bracketing method finds the pole instead
+        let mut convergency = simple_convergency::SimpleConvergency {
+            eps: 1e-12f64,
+            max_iter: 100,
+        };
+        let pole = find_root_brent(1.0000005f64, 2f64, |x| p.value(&x) / q.value(&x), &mut convergency).unwrap();
+        assert_float_eq!(1e-9f64, pole, 1.000001f64);
+
+        // (x^2 - 1) / (x^2 + 1) has no poles
+        let zeros = find_roots_rational(&[0f64, -1f64], &[0f64, 1f64], &mut 1e-12f64);
+        assert_eq!(zeros.len(), 2);
+        assert_float_eq!(1e-12f64, zeros[0], -1f64);
+        assert_float_eq!(1e-12f64, zeros[1], 1f64);
+
+        // Constant numerator or denominator
+        assert_eq!(find_roots_rational(&[], &[-1f64], &mut 1e-12f64), Vec::<f64>::new());
+        assert_eq!(find_roots_rational(&[-1f64], &[], &mut 1e-12f64), vec![1f64]);
+    }
 }
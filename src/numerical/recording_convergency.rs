@@ -0,0 +1,195 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::Convergency;
+
+/// Check made by the search, recorded by `RecordingConvergency`
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Event<F>
+where
+    F: FloatType,
+{
+    /// `is_root_found(y)` has returned `found`
+    RootFound { y: F, found: bool },
+    /// `is_converged(x1, x2)` has returned `converged`
+    Converged { x1: F, x2: F, converged: bool },
+    /// `is_converged_at(x1, x2, y)` has returned `converged`
+    ConvergedAt { x1: F, x2: F, y: F, converged: bool },
+    /// `is_iteration_limit_reached(iter)` has returned `reached`
+    IterationLimit { iter: usize, reached: bool },
+}
+
+/// Convergency provider which records the convergence history.
+/// Every check is passed to the inner convergency, and the arguments and the result are recorded
+/// in the order of the checks. Unlike `DebugConvergency`, nothing is printed.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent;
+/// use roots::Event;
+/// use roots::RecordingConvergency;
+/// use roots::SimpleConvergency;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let mut convergency = RecordingConvergency::new(SimpleConvergency { eps: 1e-15f64, max_iter: 30 });
+///
+/// let root = find_root_brent(10f64, 0f64, &f, &mut convergency);
+/// // Returns approximately Ok(1);
+///
+/// let iterations = convergency.history().iter().filter(|event| match event {
+///     Event::IterationLimit { .. } => true,
+///     _ => false,
+/// }).count();
+/// // Returns 10
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecordingConvergency<F, C>
+where
+    F: FloatType,
+    C: Convergency<F>,
+{
+    /// Convergency making the decisions
+    inner: C,
+    /// Checks made since the creation or the last reset
+    history: Vec<Event<F>>,
+}
+
+impl<F, C> RecordingConvergency<F, C>
+where
+    F: FloatType,
+    C: Convergency<F>,
+{
+    pub fn new(inner: C) -> RecordingConvergency<F, C> {
+        RecordingConvergency {
+            inner,
+            history: Vec::new(),
+        }
+    }
+
+    /// Forget the recorded checks
+    pub fn reset(&mut self) {
+        self.history.clear();
+    }
+
+    /// Checks made since the creation or the last reset, in their order
+    pub fn history(&self) -> &[Event<F>] {
+        &self.history
+    }
+
+    /// The inner convergency
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<F, C> Convergency<F> for RecordingConvergency<F, C>
+where
+    F: FloatType,
+    C: Convergency<F>,
+{
+    /// Records the value being checked
+    fn is_root_found(&mut self, y: F) -> bool {
+        let found = self.inner.is_root_found(y);
+        self.history.push(Event::RootFound { y, found });
+        found
+    }
+    /// Records the values being checked
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        let converged = self.inner.is_converged(x1, x2);
+        self.history.push(Event::Converged { x1, x2, converged });
+        converged
+    }
+    /// Records the values being checked
+    fn is_converged_at(&mut self, x1: F, x2: F, y: F) -> bool {
+        let converged = self.inner.is_converged_at(x1, x2, y);
+        self.history.push(Event::ConvergedAt { x1, x2, y, converged });
+        converged
+    }
+    /// Records the iteration being checked
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        let reached = self.inner.is_iteration_limit_reached(iter);
+        self.history.push(Event::IterationLimit { iter, reached });
+        reached
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_recording_convergency() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = RecordingConvergency::new(simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 30,
+        });
+
+        for &(a, b, root) in [(10f64, 0f64, 1f64), (-10f64, 0f64, -1f64)].iter() {
+            conv.reset();
+            assert_float_eq!(1e-15f64, brent::find_root_brent(a, b, &f, &mut conv).ok().unwrap(), root);
+
+            // Each iteration ends with the check of the limit
+            let iterations: Vec<usize> = conv
+                .history()
+                .iter()
+                .filter_map(|event| match *event {
+                    Event::IterationLimit { iter, reached } => {
+                        assert!(!reached);
+                        Some(iter)
+                    }
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(iterations, (1..=10).collect::<Vec<usize>>());
+
+            // Each iteration checks the bracket, with the value at its best end, after the values at its ends.
+            // The search stops at the check of the values, so there is no bracket check after the last iteration
+            let widths: Vec<f64> = conv
+                .history()
+                .iter()
+                .filter_map(|event| match *event {
+                    Event::ConvergedAt { x1, x2, .. } => Some((x1 - x2).abs()),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(widths.len(), iterations.len());
+            assert!(widths.windows(2).all(|pair| pair[1] <= pair[0]));
+            assert_eq!(widths[0], 10f64);
+
+            match conv.history().last() {
+                Some(&Event::RootFound { found, .. }) => assert!(found),
+                Some(&Event::ConvergedAt { converged, .. }) => assert!(converged),
+                event => panic!("unexpected last check {:?}", event),
+            }
+        }
+
+        conv.reset();
+        assert!(conv.history().is_empty());
+        assert_eq!(conv.inner().max_iter, 30);
+    }
+}
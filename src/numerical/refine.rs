@@ -0,0 +1,264 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::Convergency;
+use super::SearchError;
+
+/// Refines an approximate root of the function f(x) = 0 using guarded Newton-Raphson steps.
+///
+/// Unlike find_root_newton_raphson, a step which increases |f(x)| is not accepted:
+/// it is halved until |f(x)| does not grow. This keeps the root found by other methods
+/// (analytical solvers, eigenvalues etc.) from jumping to another root or diverging.
+/// When no step can decrease |f(x)|, x is as precise as the function evaluation allows, and it is returned.
+///
+/// # Failures
+/// ## ZeroDerivative
+/// The stationary point of the function is encountered. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot refine the root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::find_roots_cubic;
+/// use roots::refine_root;
+///
+/// // The leading coefficient is tiny, so the analytical solution is imprecise
+/// let (a3, a2, a1, a0) = (-0.000000000000000040410628481035f64, 0.0126298310280606f64, -0.100896606408756f64, 0.0689539597036461f64);
+/// let x0 = find_roots_cubic(a3, a2, a1, a0).as_ref()[0];
+/// // x0 is 0.7583841816097057 while the root is 0.75471087705369017
+///
+/// let f = |x: f64| ((a3 * x + a2) * x + a1) * x + a0;
+/// let d = |x: f64| (3f64 * a3 * x + 2f64 * a2) * x + a1;
+/// let root = refine_root(x0, &f, &d, &mut 1e-15f64);
+/// // Returns approximately Ok(0.75471087705369017)
+/// ```
+pub fn refine_root<F, Func, Deriv>(
+    x0: F,
+    mut f: Func,
+    mut d: Deriv,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    let mut x = x0;
+    let mut y = f(x);
+
+    let mut iter = 0;
+    loop {
+        if convergency.is_root_found(y) {
+            return Ok(x);
+        }
+        let derivative = d(x);
+        if derivative == F::zero() {
+            return Err(SearchError::zero_derivative(iter, x, y));
+        }
+
+        let mut step = y / derivative;
+        let mut x1 = x - step;
+        let mut y1 = f(x1);
+        // Halve the step while it makes the value greater
+        while y1.abs() > y.abs() {
+            step = step / _2;
+            x1 = x - step;
+            if x1 == x {
+                return Ok(x);
+            }
+            y1 = f(x1);
+        }
+        if x1 == x || convergency.is_converged(x, x1) {
+            return Ok(x1);
+        }
+
+        x = x1;
+        y = y1;
+        iter += 1;
+
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency(iter, x, y));
+        }
+    }
+}
+
+/// Polishes approximate real roots of the normalized polynomial
+/// x^n + a[0]*x^(n-1) + a[1]*x^(n-2) + ... + a[n-1] = 0
+/// simultaneously by the Weierstrass (Durand-Kerner) corrections.
+///
+/// Each sweep corrects all roots at once: x[i] -= p(x[i]) / (q(x[i]) * product of (x[i] - x[j]) over j != i),
+/// where q is the quotient of p by the factors of all given roots. When all n roots are given,
+/// q is 1 and the sum of the roots is kept equal to -a[0]. Fewer roots are allowed, e.g. the real ones
+/// of a polynomial with complex roots; then q takes the place of the missing factors.
+/// Sweeps stop when all roots are found or converged, or after max_sweeps.
+/// Returns the number of sweeps used.
+///
+/// # Examples
+/// ```
+/// use roots::polish_roots_weierstrass;
+///
+/// // x^3 - x = (x + 1)x(x - 1)
+/// let mut roots = [-1.01f64, 0.02f64, 0.99f64];
+/// let sweeps = polish_roots_weierstrass(&[0f64, -1f64, 0f64], &mut roots, 10, &mut 1e-15f64);
+/// // roots are approximately [-1, 0, 1]
+/// ```
+pub fn polish_roots_weierstrass<F>(
+    a: &[F],
+    roots: &mut [F],
+    max_sweeps: usize,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> usize
+where
+    F: FloatType,
+{
+    let evaluate = |p: &[F], x: F| p.iter().fold(F::one(), |value, c| value * x + *c);
+
+    for sweep in 0..max_sweeps {
+        let old = roots.to_vec();
+        // Quotient of the polynomial by the factors of the roots, without the leading 1
+        let mut q = a.to_vec();
+        for root in old.iter() {
+            let mut value = F::one();
+            for c in q.iter_mut() {
+                value = value * *root + *c;
+                *c = value;
+            }
+            q.pop();
+        }
+
+        let mut converged = true;
+        for (i, root) in roots.iter_mut().enumerate() {
+            let x = old[i];
+            let y = evaluate(a, x);
+            if convergency.is_root_found(y) {
+                continue;
+            }
+            let mut denominator = evaluate(&q, x);
+            for (j, other) in old.iter().enumerate() {
+                if j != i {
+                    denominator = denominator * (x - *other);
+                }
+            }
+            // Coinciding roots cannot be corrected
+            if denominator == F::zero() {
+                continue;
+            }
+            let x1 = x - y / denominator;
+            if !convergency.is_converged(x, x1) {
+                converged = false;
+            }
+            *root = x1;
+        }
+        if converged {
+            return sweep + 1;
+        }
+    }
+    max_sweeps
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::find_roots_cubic;
+    use super::super::super::Roots;
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_refine_root() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let d = |x| 2f64 * x;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, refine_root(1.1f64, &f, &d, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(4, conv.get_iter_count());
+
+        // Newton-Raphson jumps from 0.01 to 50; the guarded step stays close to the root 1
+        conv.reset();
+        assert_float_eq!(1e-15f64, refine_root(0.01f64, &f, &d, &mut conv).ok().unwrap(), 1f64);
+
+        assert!(matches!(
+            refine_root(0f64, &f, &d, &mut conv),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+
+        // Stops when steps do not change x even if the convergency is never satisfied
+        assert_float_eq!(
+            1e-15f64,
+            refine_root(1.000000000000001f64, &f, &d, &mut 0f64).ok().unwrap(),
+            1f64
+        );
+    }
+
+    #[test]
+    fn test_refine_root_andrew_hunter() {
+        // The cubic polynomial where the highest coefficient is very small
+        // (as reported by Andrew Hunter in July 2019)
+        let (a3, a2, a1, a0) = (
+            -0.000000000000000040410628481035f64,
+            0.0126298310280606f64,
+            -0.100896606408756f64,
+            0.0689539597036461f64,
+        );
+        let f = |x: f64| ((a3 * x + a2) * x + a1) * x + a0;
+        let d = |x: f64| (3f64 * a3 * x + 2f64 * a2) * x + a1;
+        let expected = [0.75471087705369017054f64, 7.2340425896070389699f64];
+        match find_roots_cubic(a3, a2, a1, a0) {
+            Roots::Three(x) => {
+                for (x, e) in x.iter().zip(expected.iter()) {
+                    // Three correct digits become fourteen
+                    assert!((x - e).abs() > 1e-4f64);
+                    assert_float_eq!(1e-14f64, refine_root(*x, &f, &d, &mut 1e-15f64).unwrap(), *e);
+                }
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_polish_roots_weierstrass() {
+        // x^3 - x = (x + 1)x(x - 1)
+        let polynom = [0f64, -1f64, 0f64];
+        let mut roots = [-1.01f64, 0.02f64, 0.99f64];
+        assert_eq!(4, polish_roots_weierstrass(&polynom, &mut roots, 30, &mut 1e-15f64));
+        assert_float_array_eq!(1e-15f64, roots, [-1f64, 0f64, 1f64]);
+
+        // The sum of the roots is kept after the first sweep
+        let mut roots = [-1.2f64, 0.1f64, 0.9f64];
+        polish_roots_weierstrass(&polynom, &mut roots, 1, &mut 1e-15f64);
+        assert_float_eq!(1e-15f64, roots.iter().sum::<f64>(), 0f64);
+    }
+
+    #[test]
+    fn test_polish_roots_weierstrass_incomplete() {
+        // (x - 2)(x^2 + x + 1) = x^3 - x^2 - x - 2: the complex roots are not given
+        let polynom = [-1f64, -1f64, -2f64];
+        let mut roots = [2.1f64];
+        assert_eq!(5, polish_roots_weierstrass(&polynom, &mut roots, 30, &mut 1e-15f64));
+        assert_float_eq!(1e-15f64, roots[0], 2f64);
+    }
+}
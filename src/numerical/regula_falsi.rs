@@ -23,10 +23,47 @@
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use super::super::FloatType;
+use super::check_bracket;
+use super::check_value;
 use super::Convergency;
+use super::IterationObserver;
+use super::NoObserver;
 use super::SearchError;
+use super::SolverStats;
+use super::TrySearchError;
+use std::ops::RangeInclusive;
 
-/// Illinois modification to the classical method
+/// Modification of the classical method that scales the weight of the stale interval end
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RegulaFalsiVariant {
+    /// The weight is halved
+    Illinois,
+    /// The weight is scaled by y_k/(y_k + y_k+1)
+    Pegasus,
+    /// The weight is scaled by 1 - y_k+1/y_k, or halved if this factor is not positive
+    AndersonBjorck,
+}
+
+impl RegulaFalsiVariant {
+    /// Scale factor of the stale end, given the previous and the new values at the other end
+    fn scale<F: FloatType>(self, y_old: F, y_new: F) -> F {
+        let half = F::one() / F::from(2i16);
+        match self {
+            RegulaFalsiVariant::Illinois => half,
+            RegulaFalsiVariant::Pegasus => y_old / (y_old + y_new),
+            RegulaFalsiVariant::AndersonBjorck => {
+                let m = F::one() - y_new / y_old;
+                if m > F::zero() {
+                    m
+                } else {
+                    half
+                }
+            }
+        }
+    }
+}
+
+/// Tracks which end of the interval has been moved lately
 #[derive(Debug, PartialEq)]
 enum Edge {
     /// Value is close to X1, reduce the Y1 weight
@@ -51,6 +88,8 @@ enum Edge {
 /// - Needs initial bracketing
 ///
 /// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
 /// ## NoBracketing
 /// Initial values do not bracket the root.
 /// ## NoConvergency
@@ -70,57 +109,309 @@ enum Edge {
 /// let root2 = find_root_regula_falsi(-10f64, 0f64, &f, &mut 1e-15f64);
 /// // Returns approximately Ok(-1);
 /// ```
-pub fn find_root_regula_falsi<F, Func>(a: F, b: F, mut f: Func, convergency: &mut dyn Convergency<F>) -> Result<F, SearchError>
+pub fn find_root_regula_falsi<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    find_root_regula_falsi_variant(a, b, f, RegulaFalsiVariant::Illinois, convergency)
+}
+
+/// Find a root of the function f(x) = 0 using the regula falsi method (Illinois modification) and count the work done.
+///
+/// The same as `find_root_regula_falsi`, but the result comes with `SolverStats`: the last iteration,
+/// the numbers of evaluations of the function, and the width of the last
+/// bracketing interval if the root is found.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_regula_falsi_with_stats;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let (root, stats) = find_root_regula_falsi_with_stats(10f64, 0f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1) and SolverStats { iterations: 11, fn_evaluations: 14, .. }
+/// ```
+pub fn find_root_regula_falsi_with_stats<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> (Result<F, SearchError>, SolverStats<F>)
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (result, mut stats) = super::search_with_stats(f, convergency, |f, convergency| {
+        find_root_regula_falsi_enclosure(a, b, f, convergency)
+    });
+    stats.final_bracket_width = result.ok().map(|(_, lo, hi)| hi - lo);
+    (result.map(|(root, _, _)| root), stats)
+}
+
+/// Find a root of the function f(x) = 0 using the regula falsi method (Illinois modification)
+/// and report every iteration to the observer.
+///
+/// The same as `find_root_regula_falsi`, but `observer.on_iteration` is called once per iteration
+/// with the new approximation, which replaces one end of the bracketing interval.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_regula_falsi_observed;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let mut trajectory = Vec::new();
+///
+/// let root = find_root_regula_falsi_observed(10f64, 0f64, &f, &mut 1e-15f64, &mut |_: usize, x: f64, y: f64| {
+///     trajectory.push((x, y))
+/// });
+/// // Returns approximately Ok(1), and trajectory has 12 points
+/// ```
+pub fn find_root_regula_falsi_observed<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    observer: &mut (impl IterationObserver<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    regula_falsi_with(a, b, f, RegulaFalsiVariant::Illinois, convergency, observer).map(|(root, _, _)| root)
+}
+
+/// Find a root of the function f(x) = 0 which can fail using the regula falsi method (Illinois modification).
+///
+/// The same as `find_root_regula_falsi`, but f returns Result. The first error of f stops the search
+/// and is returned as `TrySearchError::Function`; f is not called after it.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## Function
+/// The function has failed.
+/// ## Search
+/// The search has failed as `find_root_regula_falsi` does.
+/// # Examples
+///
+/// ```
+/// use roots::try_find_root_regula_falsi;
+///
+/// // ln is defined for positive x only
+/// let f = |x: f64| if x > 0f64 { Ok(x.ln() - 1f64) } else { Err("not positive") };
+///
+/// let root = try_find_root_regula_falsi(1f64, 10f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(2.718281828459045);
+///
+/// let error = try_find_root_regula_falsi(0f64, 10f64, &f, &mut 1e-15f64);
+/// // Returns Err(TrySearchError::Function("not positive"));
+/// ```
+pub fn try_find_root_regula_falsi<F, Func, E>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, TrySearchError<E>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> Result<F, E>,
+{
+    super::try_search(f, convergency, |f, convergency| find_root_regula_falsi(a, b, f, convergency))
+}
+
+/// Find a root of the function f(x) = 0 within the given range using the regula falsi method (Illinois modification).
+///
+/// The same as `find_root_regula_falsi` with the ends of the range.
+///
+/// # Failures
+/// ## InvalidInput
+/// The range is empty or its ends are not finite.
+/// ## NoBracketing
+/// The ends of the range do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_regula_falsi_in;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let root = find_root_regula_falsi_in(0f64..=10f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1);
+/// ```
+pub fn find_root_regula_falsi_in<F, Func>(
+    range: RangeInclusive<F>,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (start, end) = super::range_ends(&range)?;
+    find_root_regula_falsi(start, end, f, convergency)
+}
+
+/// Find a root of the function f(x) = 0 using the given modification of the regula falsi method.
+///
+/// Pegasus and Anderson-Björck variants usually converge faster than Illinois on convex functions,
+/// but Anderson-Björck can be slow if the function is flat near one of the interval ends.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::RegulaFalsiVariant;
+/// use roots::find_root_regula_falsi_variant;
+///
+/// let f = |x| { 1f64*x*x*x*x - 1f64 };
+///
+/// let root = find_root_regula_falsi_variant(10f64, 0f64, &f, RegulaFalsiVariant::AndersonBjorck, &mut 1e-15f64);
+/// // Returns approximately Ok(1);
+/// ```
+pub fn find_root_regula_falsi_variant<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    variant: RegulaFalsiVariant,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    regula_falsi_with(a, b, f, variant, convergency, &mut NoObserver).map(|(root, _, _)| root)
+}
+
+/// Find a root of the function f(x) = 0 using the regula falsi method and the interval enclosing it.
+///
+/// The same as `find_root_regula_falsi`, but the result is the tuple (root, lo, hi), where [lo, hi] is the last
+/// bracketing interval: f(lo) and f(hi) have opposite signs, or one of them satisfies `is_root_found`.
+/// If the root is not found by its value, the interval is narrower than the precision of `convergency`.
+/// Unlike the root, which can be close to one end, the interval shows how far the other end is left behind.
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_regula_falsi_enclosure;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let result = find_root_regula_falsi_enclosure(10f64, 0f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok((1, 0.99999999999, 1.00000000001));
+/// ```
+pub fn find_root_regula_falsi_enclosure<F, Func>(
+    a: F,
+    b: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<(F, F, F), SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    regula_falsi_with(a, b, f, RegulaFalsiVariant::Illinois, convergency, &mut NoObserver)
+}
+
+/// Regula falsi iterations returning the root and the last interval [x1, x2] bracketing it
+fn regula_falsi_with<F, Func>(
+    a: F,
+    b: F,
+    mut f: Func,
+    variant: RegulaFalsiVariant,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    observer: &mut (impl IterationObserver<F> + ?Sized),
+) -> Result<(F, F, F), SearchError>
 where
     F: FloatType,
     Func: FnMut(F) -> F,
 {
-    let _2 = F::from(2i16);
+    check_bracket(a, b)?;
     let (mut x1, mut x2) = if a > b { (b, a) } else { (a, b) };
     let mut y1 = f(x1);
+    check_value(y1)?;
     if convergency.is_root_found(y1) {
-        return Ok(x1);
+        return Ok((x1, x1, x2));
     }
     let mut y2 = f(x2);
+    check_value(y2)?;
     if convergency.is_root_found(y2) {
-        return Ok(x2);
+        return Ok((x2, x1, x2));
     }
     if y1 * y2 > F::zero() {
-        return Err(SearchError::NoBracketing);
+        return Err(SearchError::no_bracketing(x1, x2));
     }
     let mut edge = Edge::NoEdge;
     let mut iter = 0;
     loop {
         let x = (x1 * y2 - x2 * y1) / (y2 - y1);
-        if convergency.is_converged(x1, x2) {
-            return Ok(x);
+        let y_best = if y1.abs() < y2.abs() { y1 } else { y2 };
+        if convergency.is_converged_at(x1, x2, y_best) {
+            return Ok((x, x1, x2));
         }
         let y = f(x);
+        observer.on_iteration(iter, x, y);
         if convergency.is_root_found(y) {
-            return Ok(x);
+            return Ok((x, x1, x2));
         }
 
         if y * y1 > F::zero() {
-            x1 = x;
-            y1 = y;
             if edge == Edge::EdgeX1 {
-                y2 = y2 / _2;
+                y2 = y2 * variant.scale(y1, y);
             }
+            x1 = x;
+            y1 = y;
             edge = Edge::EdgeX1;
         } else if y * y2 > F::zero() {
-            x2 = x;
-            y2 = y;
             if edge == Edge::EdgeX2 {
-                y1 = y1 / _2;
+                y1 = y1 * variant.scale(y2, y);
             }
+            x2 = x;
+            y2 = y;
             edge = Edge::EdgeX2;
         } else {
-            return Ok(x);
+            return Ok((x, x1, x2));
         }
 
         iter = iter + 1;
         if convergency.is_iteration_limit_reached(iter) {
-            return Err(SearchError::NoConvergency);
+            return Err(SearchError::no_convergency(iter, x, y).with_bracket(x1, x2));
         }
     }
 }
@@ -152,12 +443,235 @@ mod test {
         assert_eq!(11, conv.get_iter_count());
 
         conv.reset();
-        assert_eq!(
+        assert!(matches!(
             find_root_regula_falsi(10f64, 20f64, &f, &mut conv),
-            Err(SearchError::NoBracketing)
-        );
+            Err(SearchError::NoBracketing { .. })
+        ));
         let result = find_root_regula_falsi(10f64, 20f64, &f, &mut conv);
-        assert_eq!(result.unwrap_err().to_string(), "Bracketing Error");
+        assert_eq!(result.unwrap_err().to_string(), "Bracketing Error, bracket [10, 20]");
         assert_eq!(0, conv.get_iter_count());
     }
+
+    #[test]
+    fn test_find_root_regula_falsi_variant() {
+        let f = |x| 1f64 * x * x * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_regula_falsi_variant(10f64, 0.5f64, &f, RegulaFalsiVariant::Illinois, &mut conv)
+                .ok()
+                .unwrap(),
+            1f64
+        );
+        assert_eq!(17, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_regula_falsi_variant(10f64, 0.5f64, &f, RegulaFalsiVariant::Pegasus, &mut conv)
+                .ok()
+                .unwrap(),
+            1f64
+        );
+        assert_eq!(15, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_regula_falsi_variant(10f64, 0.5f64, &f, RegulaFalsiVariant::AndersonBjorck, &mut conv)
+                .ok()
+                .unwrap(),
+            1f64
+        );
+        assert_eq!(12, conv.get_iter_count());
+
+        conv.reset();
+        assert!(matches!(
+            find_root_regula_falsi_variant(10f64, 20f64, &f, RegulaFalsiVariant::Pegasus, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(0, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_regula_falsi_with_stats() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        let (root, stats) = find_root_regula_falsi_with_stats(10f64, 0f64, &f, &mut conv);
+        assert_eq!(root, find_root_regula_falsi(10f64, 0f64, &f, &mut conv));
+        assert_eq!(stats.iterations, conv.get_iter_count());
+        assert_eq!(stats.iterations, 11);
+        assert_eq!(stats.fn_evaluations, 14);
+        assert_eq!(stats.derivative_evaluations, 0);
+        let (_, lo, hi) = find_root_regula_falsi_enclosure(10f64, 0f64, &f, &mut conv).unwrap();
+        assert_eq!(stats.final_bracket_width, Some(hi - lo));
+
+        let (root, stats) = find_root_regula_falsi_with_stats(
+            10f64,
+            0f64,
+            &f,
+            &mut simple_convergency::SimpleConvergency {
+                eps: 1e-15f64,
+                max_iter: 3,
+            },
+        );
+        assert!(matches!(root, Err(SearchError::NoConvergency { .. })));
+        assert_eq!(stats.iterations, 3);
+        assert_eq!(stats.final_bracket_width, None);
+    }
+
+    #[test]
+    fn test_find_root_regula_falsi_observed() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut trajectory = Vec::new();
+
+        let root = find_root_regula_falsi_observed(10f64, 0f64, &f, &mut 1e-15f64, &mut |iter: usize, x: f64, y: f64| {
+            trajectory.push((iter, x, y))
+        });
+        assert_eq!(root, find_root_regula_falsi(10f64, 0f64, &f, &mut 1e-15f64));
+        assert_eq!(trajectory.len(), 12);
+        // Every approximation is inside the initial bracket
+        for &(_, x, _) in trajectory.iter() {
+            assert!(x > 0f64 && x < 10f64);
+        }
+        assert_eq!(trajectory.last().unwrap().1, root.unwrap());
+    }
+
+    #[test]
+    fn test_try_find_root_regula_falsi() {
+        let f = |x: f64| if x < 0f64 { Err("negative") } else { Ok(x * x - 2f64) };
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+
+        // The root lies in the valid half of the domain
+        assert_float_eq!(
+            1e-15f64,
+            try_find_root_regula_falsi(0f64, 4f64, &f, &mut conv).ok().unwrap(),
+            2f64.sqrt()
+        );
+        // The function fails at a bracket endpoint
+        assert_eq!(
+            try_find_root_regula_falsi(-4f64, 4f64, &f, &mut conv),
+            Err(TrySearchError::Function("negative"))
+        );
+        assert!(matches!(
+            try_find_root_regula_falsi(2f64, 4f64, &f, &mut conv),
+            Err(TrySearchError::Search(SearchError::NoBracketing { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_find_root_regula_falsi_in() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = 1e-15f64;
+
+        assert_eq!(
+            find_root_regula_falsi_in(0f64..=10f64, &f, &mut conv),
+            find_root_regula_falsi(0f64, 10f64, &f, &mut conv)
+        );
+        assert_float_eq!(
+            1e-15f64,
+            find_root_regula_falsi_in(-10f64..=0f64, &f, &mut conv).ok().unwrap(),
+            -1f64
+        );
+        assert!(matches!(
+            find_root_regula_falsi_in(2f64..=3f64, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+
+        // Empty and infinite ranges
+        assert_eq!(
+            find_root_regula_falsi_in(10f64..=0f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::EmptyRange
+            })
+        );
+        assert_eq!(
+            find_root_regula_falsi_in(0f64..=f64::INFINITY, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            find_root_regula_falsi_in(f64::NEG_INFINITY..=0f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            find_root_regula_falsi_in(f64::NAN..=10f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            find_root_regula_falsi_in(10f64..=0f64, &f, &mut conv)
+                .unwrap_err()
+                .to_string(),
+            "Invalid Input Error: the range is empty"
+        );
+    }
+
+    #[test]
+    fn test_find_root_regula_falsi_enclosure() {
+        type Case = (fn(f64) -> f64, f64, f64);
+        let cases: [Case; 4] = [
+            (|x| x * x - 1f64, 10f64, 0f64),
+            (|x| x * x * x * x - 1f64, 0f64, 10f64),
+            (|x| x * x * x - 2f64 * x - 5f64, 2f64, 3f64),
+            // The step is never found as a root; the interval must shrink
+            (|x| if x < 1f64 / 3f64 { -1f64 } else { 1f64 }, -5f64, 7f64),
+        ];
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+        for (f, a, b) in cases.iter() {
+            let (root, lo, hi) = find_root_regula_falsi_enclosure(*a, *b, f, &mut conv).ok().unwrap();
+            assert_eq!(root, find_root_regula_falsi(*a, *b, f, &mut conv).ok().unwrap());
+            assert!(lo <= root && root <= hi);
+            let found = f(lo).abs() < 1e-15f64 || f(root).abs() < 1e-15f64 || f(hi).abs() < 1e-15f64;
+            assert!(f(lo).signum() * f(hi).signum() <= 0f64 || found);
+            assert!(hi - lo < 1e-15f64 || found);
+        }
+
+        // The end at 10 is left behind by 2e-8
+        let (root, lo, hi) = find_root_regula_falsi_enclosure(0f64, 10f64, cases[1].0, &mut conv)
+            .ok()
+            .unwrap();
+        assert_float_eq!(1e-15f64, root, 1f64);
+        assert_float_eq!(1e-15f64, lo, 1f64);
+        assert_float_eq!(1e-7f64, hi, 1f64);
+        assert!(hi - 1f64 > 1e-8f64);
+
+        let (_, lo, hi) = find_root_regula_falsi_enclosure(-5f64, 7f64, cases[3].0, &mut conv)
+            .ok()
+            .unwrap();
+        assert!(hi - lo < 1e-15f64);
+        assert!(lo <= 1f64 / 3f64 && 1f64 / 3f64 <= hi);
+
+        assert!(matches!(
+            find_root_regula_falsi_enclosure(10f64, 20f64, &|x| x * x - 1f64, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_regula_falsi_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let bracket = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteBracket));
+        assert_eq!(find_root_regula_falsi(f64::NAN, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_regula_falsi(0.5f64, f64::NAN, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_regula_falsi(f64::NEG_INFINITY, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_regula_falsi(0.5f64, f64::INFINITY, &f, &mut 1e-15f64), bracket);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_regula_falsi(0f64, 10f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_regula_falsi(10f64, -1f64, &f, &mut 1e-15f64), value);
+    }
 }
@@ -0,0 +1,128 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::Convergency;
+
+/// Convergency rules with the precision of X relative to the magnitude of X.
+///
+/// Two values of X are converged when |x1 - x2| <= rel_eps * max(|x1|, |x2|) + abs_floor,
+/// so the same rel_eps suits roots around 1e8, where an absolute precision of 1e-15 is finer
+/// than the spacing of f64 values, and roots around 1e-8, where it is too coarse.
+/// The floor keeps the precision finite for roots at zero. The function is considered zero
+/// only when |y| <= abs_floor.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent;
+/// use roots::RelativeConvergency;
+///
+/// let f = |x| { (x - 1e8f64)*(x + 1f64) + 0.5f64 };
+/// let mut convergency = RelativeConvergency { rel_eps: 1e-15f64, abs_floor: 1e-300f64, max_iter: 100 };
+///
+/// let root = find_root_brent(1e7f64, 1e9f64, &f, &mut convergency);
+/// // Returns approximately Ok(1e8), while the absolute eps 1e-15 gives Err(SearchError::NoConvergency { .. })
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RelativeConvergency<F: FloatType> {
+    /// Precision of X relative to the magnitude of X
+    pub rel_eps: F,
+    /// Absolute precision added to the relative one, and the precision of Y
+    pub abs_floor: F,
+    /// Maximum number of iterations
+    pub max_iter: usize,
+}
+
+impl<F: FloatType> Convergency<F> for RelativeConvergency<F> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        y.abs() <= self.abs_floor.abs()
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        let magnitude = if x1.abs() > x2.abs() { x1.abs() } else { x2.abs() };
+        (x1 - x2).abs() <= self.rel_eps.abs() * magnitude + self.abs_floor.abs()
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        iter >= self.max_iter
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_relative_convergency() {
+        // f64 values around 1e8 are 1.5e-8 apart; the absolute eps 1e-15 cannot be reached.
+        // The shift keeps the function from being exactly zero at any f64 value.
+        let f = |x: f64| (x - 1e8f64) * (x + 1f64) + 0.5f64;
+        let mut simple = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+        assert!(matches!(
+            brent::find_root_brent(1e7f64, 1e9f64, &f, &mut simple),
+            Err(SearchError::NoConvergency { .. })
+        ));
+        let mut convergency = RelativeConvergency {
+            rel_eps: 1e-15f64,
+            abs_floor: 1e-300f64,
+            max_iter: 100,
+        };
+        assert_float_eq!(
+            1e-6f64,
+            brent::find_root_brent(1e7f64, 1e9f64, &f, &mut convergency).ok().unwrap(),
+            1e8f64
+        );
+
+        // The absolute eps 1e-9 is coarser than the root 1e-8 itself
+        let f = |x: f64| (x - 1e-8f64) * (x + 1f64);
+        let mut simple = simple_convergency::SimpleConvergency {
+            eps: 1e-9f64,
+            max_iter: 100,
+        };
+        let root = brent::find_root_brent(-0.5f64, 0.5f64, &f, &mut simple).ok().unwrap();
+        assert!((root - 1e-8f64).abs() > 1e-8f64 * 1e-6f64);
+        let root = brent::find_root_brent(-0.5f64, 0.5f64, &f, &mut convergency).ok().unwrap();
+        assert_float_eq!(1e-8f64 * 1e-14f64, root, 1e-8f64);
+    }
+
+    #[test]
+    fn test_relative_convergency_floor() {
+        let mut convergency = RelativeConvergency {
+            rel_eps: 1e-6f64,
+            abs_floor: 1e-12f64,
+            max_iter: 100,
+        };
+        assert!(convergency.is_converged(1e6f64, 1e6f64 + 0.5f64));
+        assert!(!convergency.is_converged(1e6f64, 1e6f64 + 2f64));
+        assert!(convergency.is_converged(0f64, 1e-12f64));
+        assert!(!convergency.is_converged(0f64, 1e-11f64));
+        assert!(convergency.is_root_found(-1e-12f64));
+        assert!(!convergency.is_root_found(1e-11f64));
+        assert!(!convergency.is_iteration_limit_reached(99));
+        assert!(convergency.is_iteration_limit_reached(100));
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::Convergency;
+
+/// Convergency rules with the precision of Y relative to the magnitude of the function.
+///
+/// The magnitude is taken from the first nonzero value checked by `is_root_found`,
+/// which is the value at the starting point or at an end of the bracket for all find_root_* functions.
+/// A root is found when |y| < max(eps_rel * magnitude, eps_abs), so the same eps_rel suits
+/// functions with values around 1e12 and around 1e-12. The absolute floor eps_abs must be positive
+/// and smaller than the expected magnitude. The precision of X is absolute.
+///
+/// The magnitude is remembered between the searches; call `reset` before searching another function.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent;
+/// use roots::find_root_regula_falsi;
+/// use roots::ResidualRelativeConvergency;
+///
+/// let f = |x| { 1e-12f64*(x*x - 2f64) };
+/// let mut convergency = ResidualRelativeConvergency::new(1e-9f64, 1e-300f64, 1e-12f64, 30);
+///
+/// let root1 = find_root_brent(0f64, 10f64, &f, &mut convergency);
+/// // Returns approximately Ok(1.4142135623730951), while the absolute eps 1e-9 accepts 10
+///
+/// convergency.reset();
+/// let root2 = find_root_regula_falsi(0f64, 10f64, &f, &mut convergency);
+/// // Returns approximately Ok(1.4142135623730951), while the absolute eps 1e-9 accepts 0
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ResidualRelativeConvergency<F: FloatType> {
+    /// Precision of Y relative to the magnitude of the function
+    pub eps_rel: F,
+    /// Absolute precision of Y used when the relative one is smaller
+    pub eps_abs: F,
+    /// Precision of X
+    pub eps_x: F,
+    /// Maximum number of iterations
+    pub max_iter: usize,
+    /// Magnitude of the function; none until the first nonzero value is checked
+    y_scale: Option<F>,
+}
+
+impl<F: FloatType> ResidualRelativeConvergency<F> {
+    /// Convergency with the magnitude of the function not yet known
+    pub fn new(eps_rel: F, eps_abs: F, eps_x: F, max_iter: usize) -> ResidualRelativeConvergency<F> {
+        ResidualRelativeConvergency {
+            eps_rel,
+            eps_abs,
+            eps_x,
+            max_iter,
+            y_scale: None,
+        }
+    }
+
+    /// Forget the magnitude of the function
+    pub fn reset(&mut self) {
+        self.y_scale = None;
+    }
+
+    /// Magnitude of the function, if any nonzero value has been checked
+    pub fn y_scale(&self) -> Option<F> {
+        self.y_scale
+    }
+}
+
+impl<F: FloatType> Convergency<F> for ResidualRelativeConvergency<F> {
+    /// Remembers the magnitude of the first nonzero value
+    fn is_root_found(&mut self, y: F) -> bool {
+        if self.y_scale.is_none() && y != F::zero() {
+            self.y_scale = Some(y.abs());
+        }
+        let eps = self.eps_rel.abs() * self.y_scale.unwrap_or(F::zero());
+        let eps = if eps > self.eps_abs.abs() { eps } else { self.eps_abs.abs() };
+        y.abs() < eps
+    }
+    fn is_converged(&mut self, x1: F, x2: F) -> bool {
+        (x1 - x2).abs() < self.eps_x.abs()
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        iter >= self.max_iter
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_residual_relative_convergency() {
+        // |f| is about 1e12 near the ends; the absolute eps 1e-9 cannot be reached in 30 bisections
+        let f = |x: f64| 1e12f64 * (x * x - 1f64);
+        let mut simple = simple_convergency::SimpleConvergency {
+            eps: 1e-9f64,
+            max_iter: 30,
+        };
+        assert!(matches!(
+            bisection::find_root_bisection(0f64, 10f64, &f, &mut simple),
+            Err(SearchError::NoConvergency { .. })
+        ));
+        // |f| < 1e-6 * 1e12 near the root
+        let mut convergency = ResidualRelativeConvergency::new(1e-6f64, 1e-300f64, 1e-12f64, 30);
+        assert_float_eq!(
+            1e-6f64,
+            bisection::find_root_bisection(0f64, 10f64, &f, &mut convergency)
+                .ok()
+                .unwrap(),
+            1f64
+        );
+        assert_eq!(convergency.y_scale(), Some(1e12f64));
+
+        // |f| is about 1e-12; the absolute eps 1e-9 accepts the ends of the bracket
+        let f = |x: f64| 1e-12f64 * (x * x - 1f64);
+        assert_eq!(brent::find_root_brent(0.5f64, 10f64, &f, &mut simple), Ok(10f64));
+        assert_eq!(
+            regula_falsi::find_root_regula_falsi(0.5f64, 10f64, &f, &mut simple),
+            Ok(0.5f64)
+        );
+        convergency.reset();
+        assert_float_eq!(
+            1e-6f64,
+            brent::find_root_brent(0.5f64, 10f64, &f, &mut convergency).ok().unwrap(),
+            1f64
+        );
+        convergency.reset();
+        assert_float_eq!(
+            1e-6f64,
+            regula_falsi::find_root_regula_falsi(0.5f64, 10f64, &f, &mut convergency)
+                .ok()
+                .unwrap(),
+            1f64
+        );
+    }
+
+    #[test]
+    fn test_residual_relative_convergency_floor() {
+        let mut convergency = ResidualRelativeConvergency::new(1e-9f64, 1e-6f64, 1e-12f64, 30);
+        // The exact zero is not a magnitude
+        assert!(convergency.is_root_found(0f64));
+        assert_eq!(convergency.y_scale(), None);
+        assert!(!convergency.is_root_found(1f64));
+        assert_eq!(convergency.y_scale(), Some(1f64));
+        // The floor is greater than 1e-9 * 1
+        assert!(convergency.is_root_found(1e-7f64));
+        assert!(!convergency.is_root_found(1e-5f64));
+    }
+}
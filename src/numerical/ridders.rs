@@ -0,0 +1,234 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::check_bracket;
+use super::check_value;
+use super::Convergency;
+use super::SearchError;
+
+/// Find a root of the function f(x) = 0 using the Ridders' method.
+///
+/// Pro
+///
+/// + Simple
+/// + Robust: the root stays bracketed
+/// + Quadratic convergence
+/// + No need for derivative function
+///
+/// Contra
+///
+/// - Two function evaluations per iteration
+/// - Needs initial bracketing
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_ridders;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:30 };
+///
+/// let root1 = find_root_ridders(10f64, 0f64, &f, &mut convergency);
+/// // Returns approximately Ok(1);
+///
+/// let root2 = find_root_ridders(-10f64, 0f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(-1);
+/// ```
+pub fn find_root_ridders<F, Func>(
+    a: F,
+    b: F,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    check_bracket(a, b)?;
+    let (mut x1, mut x2) = if a > b { (b, a) } else { (a, b) };
+    let mut y1 = f(x1);
+    check_value(y1)?;
+    if convergency.is_root_found(y1) {
+        return Ok(x1);
+    }
+    let mut y2 = f(x2);
+    check_value(y2)?;
+    if convergency.is_root_found(y2) {
+        return Ok(x2);
+    }
+    if y1 * y2 > F::zero() {
+        return Err(SearchError::no_bracketing(x1, x2));
+    }
+
+    let mut iter = 0;
+    loop {
+        let xm = x1 + (x2 - x1) / _2;
+        let ym = f(xm);
+        if convergency.is_root_found(ym) {
+            return Ok(xm);
+        }
+
+        // Exponential fit through (x1, y1), (xm, ym), (x2, y2); y1 and y2 have different signs, so s >= |ym|
+        let s = (ym * ym - y1 * y2).sqrt();
+        let step = (xm - x1) * ym / s;
+        let x = if y1 > y2 { xm + step } else { xm - step };
+        let y = f(x);
+        if convergency.is_root_found(y) {
+            return Ok(x);
+        }
+
+        // Keep the root bracketed by the closest points
+        if ym * y < F::zero() {
+            if xm < x {
+                x1 = xm;
+                y1 = ym;
+                x2 = x;
+                y2 = y;
+            } else {
+                x1 = x;
+                y1 = y;
+                x2 = xm;
+                y2 = ym;
+            }
+        } else if y1 * y < F::zero() {
+            x2 = x;
+            y2 = y;
+        } else {
+            x1 = x;
+            y1 = y;
+        }
+        if convergency.is_converged(x1, x2) {
+            return Ok(x);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            let (x, y) = if y1.abs() < y2.abs() { (x1, y1) } else { (x2, y2) };
+            return Err(SearchError::no_convergency(iter, x, y).with_bracket(x1, x2));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_find_root_ridders() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_ridders(10f64, 0f64, &f, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(7, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_ridders(-10f64, 0f64, &f, &mut conv).ok().unwrap(), -1f64);
+        assert_eq!(7, conv.get_iter_count());
+
+        conv.reset();
+        assert!(matches!(
+            find_root_ridders(10f64, 20f64, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
+        assert_eq!(0, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_ridders_simple() {
+        let f = |x| 1f64 * x * x - 1f64;
+
+        assert_float_eq!(
+            1e-15f64,
+            find_root_ridders(10f64, 0f64, &f, &mut 1e-15f64).ok().unwrap(),
+            1f64
+        );
+
+        assert_float_eq!(
+            1e-15f64,
+            find_root_ridders(-10f64, 0f64, &f, &mut 1e-15f64).ok().unwrap(),
+            -1f64
+        );
+
+        assert!(matches!(
+            find_root_ridders(10f64, 20f64, &f, &mut 1e-15f64),
+            Err(SearchError::NoBracketing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_ridders_iterations() {
+        // Iterations of Ridders' method, Brent and regula falsi; Ridders' method evaluates the function twice per iteration
+        let x2_min_1 = |x| 1f64 * x * x - 1f64;
+        let x4_min_1 = |x| 1f64 * x * x * x * x - 1f64;
+        let cases: [(&dyn Fn(f64) -> f64, [usize; 3]); 2] = [(&x2_min_1, [7, 10, 11]), (&x4_min_1, [7, 12, 19])];
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+        for (f, iterations) in cases.iter() {
+            conv.reset();
+            assert_float_eq!(1e-15f64, find_root_ridders(0f64, 10f64, f, &mut conv).ok().unwrap(), 1f64);
+            assert_eq!(iterations[0], conv.get_iter_count());
+
+            conv.reset();
+            assert_float_eq!(
+                1e-15f64,
+                brent::find_root_brent(0f64, 10f64, f, &mut conv).ok().unwrap(),
+                1f64
+            );
+            assert_eq!(iterations[1], conv.get_iter_count());
+
+            conv.reset();
+            assert_float_eq!(
+                1e-15f64,
+                regula_falsi::find_root_regula_falsi(0f64, 10f64, f, &mut conv).ok().unwrap(),
+                1f64
+            );
+            assert_eq!(iterations[2], conv.get_iter_count());
+        }
+    }
+
+    #[test]
+    fn test_find_root_ridders_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let bracket = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteBracket));
+        assert_eq!(find_root_ridders(f64::NAN, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_ridders(0.5f64, f64::NAN, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_ridders(f64::NEG_INFINITY, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_ridders(0.5f64, f64::INFINITY, &f, &mut 1e-15f64), bracket);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_ridders(0f64, 10f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_ridders(10f64, -1f64, &f, &mut 1e-15f64), value);
+    }
+}
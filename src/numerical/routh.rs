@@ -0,0 +1,270 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::SearchError;
+
+/// Skips leading zero coefficients and removes trailing ones, which give roots at the origin.
+/// Returns None for the zero polynomial.
+fn strip_zeros<F: FloatType>(coeffs: &[F]) -> Option<(&[F], bool)> {
+    let first = coeffs.iter().position(|c| *c != F::zero())?;
+    let last = coeffs.iter().rposition(|c| *c != F::zero())?;
+    Some((&coeffs[first..=last], last + 1 < coeffs.len()))
+}
+
+/// Builds the Routh array of the polynomial c[0]*s^n + c[1]*s^(n-1) + ... + c[n] with non-zero c[0] and c[n].
+/// Returns the number of sign changes in the first column, and true if the array has a zero pivot or a zero row,
+/// which happens when some roots are symmetric around the origin or lie on the imaginary axis.
+fn routh_array<F: FloatType>(c: &[F]) -> Result<(usize, bool), SearchError> {
+    let _16 = F::from(16i16);
+    let n = c.len() - 1;
+    let mut upper: Vec<F> = c.iter().step_by(2).cloned().collect();
+    let mut lower: Vec<F> = c.iter().skip(1).step_by(2).cloned().collect();
+    let mut first_column = vec![upper[0]];
+    let mut degenerate = false;
+
+    for row in 1..=n {
+        let length = (n - row) / 2 + 1;
+        lower.resize(length, F::zero());
+        if lower.iter().all(|x| *x == F::zero()) {
+            // The upper row is the auxiliary polynomial of degree n-row+1 whose roots are symmetric around the origin;
+            // the zero row is replaced by its derivative
+            let degree = n - row + 1;
+            for (j, x) in lower.iter_mut().enumerate() {
+                *x = upper[j] * F::from((degree - 2 * j) as i16);
+            }
+            degenerate = true;
+        } else if lower[0] == F::zero() {
+            // The zero pivot is replaced by a small positive number
+            let scale = lower
+                .iter()
+                .fold(F::zero(), |max, x| if x.abs() > max { x.abs() } else { max });
+            lower[0] = F::epsilon() * scale;
+            degenerate = true;
+        }
+        first_column.push(lower[0]);
+
+        if row < n {
+            let pivot = lower[0];
+            let next: Vec<F> = (0..(n - row - 1) / 2 + 1)
+                .map(|j| {
+                    let (a, b) = (
+                        *upper.get(j + 1).unwrap_or(&F::zero()),
+                        *lower.get(j + 1).unwrap_or(&F::zero()),
+                    );
+                    let value = (pivot * a - upper[0] * b) / pivot;
+                    // Rounding errors are not allowed to hide zero rows and pivots
+                    let bound = ((pivot * a).abs() + (upper[0] * b).abs()) / pivot.abs();
+                    if value.abs() <= _16 * F::epsilon() * bound {
+                        F::zero()
+                    } else {
+                        value
+                    }
+                })
+                .collect();
+            if !next.iter().all(|x| x.to_f64().is_finite()) {
+                return Err(SearchError::no_convergency_after(row));
+            }
+            upper = lower;
+            lower = next;
+        }
+    }
+
+    let sign_changes = first_column
+        .windows(2)
+        .filter(|pair| (pair[0] < F::zero()) != (pair[1] < F::zero()))
+        .count();
+    Ok((sign_changes, degenerate))
+}
+
+/// Counts roots of the polynomial c[0]*s^n + c[1]*s^(n-1) + ... + c[n] in the right half-plane (Re(s) > 0)
+/// by the Routh-Hurwitz criterion, without finding roots.
+///
+/// The number of roots is the number of sign changes in the first column of the Routh array.
+/// A zero pivot is replaced by a small positive number, and a zero row is replaced by the derivative
+/// of the auxiliary polynomial, so roots on the imaginary axis are not counted.
+/// Leading zero coefficients are skipped, and the zero polynomial has no roots in the right half-plane.
+///
+/// # Failures
+/// ## NoConvergency
+/// Coefficients are not finite, or the Routh array overflows.
+///
+/// # Examples
+///
+/// ```
+/// use roots::count_right_half_plane_roots;
+///
+/// // (s - 1)(s + 2)(s^2 - 2s + 5) has roots 1, -2, 1-2i, 1+2i
+/// let count = count_right_half_plane_roots(&[1f64, -1f64, 1f64, 11f64, -10f64]);
+/// // Returns Ok(3)
+/// ```
+pub fn count_right_half_plane_roots<F: FloatType>(coeffs: &[F]) -> Result<usize, SearchError> {
+    if !coeffs.iter().all(|c| c.to_f64().is_finite()) {
+        return Err(SearchError::no_convergency_after(0));
+    }
+    match strip_zeros(coeffs) {
+        None => Ok(0),
+        Some((c, _)) => routh_array(c).map(|(sign_changes, _)| sign_changes),
+    }
+}
+
+/// Checks if all roots of the polynomial c[0]*s^n + c[1]*s^(n-1) + ... + c[n] lie in the left half-plane (Re(s) < 0)
+/// by the Routh-Hurwitz criterion, without finding roots.
+///
+/// Marginally stable polynomials, which have roots on the imaginary axis, are not stable.
+/// Leading zero coefficients are skipped; non-zero constants are stable, and the zero polynomial is not.
+///
+/// # Failures
+/// ## NoConvergency
+/// Coefficients are not finite, or the Routh array overflows.
+///
+/// # Examples
+///
+/// ```
+/// use roots::is_hurwitz_stable;
+///
+/// // (s + 1)(s + 2)(s + 3)
+/// let stable = is_hurwitz_stable(&[1f64, 6f64, 11f64, 6f64]);
+/// // Returns Ok(true)
+///
+/// // (s + 1)(s^2 + 4) has roots on the imaginary axis
+/// let stable = is_hurwitz_stable(&[1f64, 1f64, 4f64, 4f64]);
+/// // Returns Ok(false)
+/// ```
+pub fn is_hurwitz_stable<F: FloatType>(coeffs: &[F]) -> Result<bool, SearchError> {
+    if !coeffs.iter().all(|c| c.to_f64().is_finite()) {
+        return Err(SearchError::no_convergency_after(0));
+    }
+    match strip_zeros(coeffs) {
+        None => Ok(false),
+        Some((_, true)) => Ok(false),
+        Some((c, false)) => routh_array(c).map(|(sign_changes, degenerate)| sign_changes == 0 && !degenerate),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    /// Coefficients of the product of polynomials
+    fn multiply(factors: &[&[f64]]) -> Vec<f64> {
+        let mut c = vec![1f64];
+        for f in factors.iter() {
+            let mut product = vec![0f64; c.len() + f.len() - 1];
+            for (i, a) in c.iter().enumerate() {
+                for (j, b) in f.iter().enumerate() {
+                    product[i + j] += a * b;
+                }
+            }
+            c = product;
+        }
+        c
+    }
+
+    #[test]
+    fn test_is_hurwitz_stable() {
+        let stable: [&[&[f64]]; 5] = [
+            &[&[1f64, 1f64], &[2f64, 3f64]],
+            &[&[1f64, 1f64], &[1f64, 2f64, 5f64]],
+            &[&[1f64, 0.5f64], &[1f64, 0.1f64, 4f64], &[3f64, 1f64]],
+            &[&[1f64, 1f64], &[1f64, 2f64], &[1f64, 3f64], &[1f64, 0.2f64, 9f64]],
+            &[&[1f64, 2f64, 2f64], &[1f64, 0.01f64, 1f64], &[1f64, 10f64, 26f64]],
+        ];
+        for factors in stable.iter() {
+            let c = multiply(factors);
+            assert_eq!(is_hurwitz_stable(&c), Ok(true), "{:?}", c);
+            assert_eq!(count_right_half_plane_roots(&c), Ok(0), "{:?}", c);
+            // The sign of the polynomial does not matter
+            let negated: Vec<f64> = c.iter().map(|x| -x).collect();
+            assert_eq!(is_hurwitz_stable(&negated), Ok(true), "{:?}", negated);
+        }
+        assert_eq!(is_hurwitz_stable(&[0f32, 3f32, 6f32]), Ok(true));
+        assert_eq!(is_hurwitz_stable(&[5f32]), Ok(true));
+    }
+
+    #[test]
+    fn test_is_hurwitz_stable_marginal() {
+        // Roots on the imaginary axis, including the origin; none of them is in the right half-plane
+        let marginal: [&[&[f64]]; 6] = [
+            &[&[1f64, 0f64, 4f64]],
+            &[&[1f64, 0f64], &[1f64, 3f64]],
+            &[&[1f64, 0f64, 4f64], &[1f64, 1f64]],
+            &[&[1f64, 0f64, 4f64], &[1f64, 0f64, 9f64]],
+            &[&[1f64, 0f64, 1f64], &[1f64, 0f64, 1f64], &[1f64, 2f64]],
+            &[&[1f64, 0f64, 0.25f64], &[1f64, 1f64, 1f64], &[1f64, 3f64, 2f64]],
+        ];
+        for factors in marginal.iter() {
+            let c = multiply(factors);
+            assert_eq!(is_hurwitz_stable(&c), Ok(false), "{:?}", c);
+            assert_eq!(count_right_half_plane_roots(&c), Ok(0), "{:?}", c);
+        }
+        assert_eq!(is_hurwitz_stable(&[0f64, 0f64]), Ok(false));
+        assert_eq!(count_right_half_plane_roots(&[0f64, 0f64]), Ok(0));
+    }
+
+    #[test]
+    fn test_count_right_half_plane_roots() {
+        let unstable: [(&[&[f64]], usize); 8] = [
+            (&[&[1f64, -1f64], &[1f64, 2f64]], 1),
+            (&[&[1f64, -1f64, 5f64]], 2),
+            (&[&[1f64, -1f64], &[1f64, -2f64], &[1f64, 3f64]], 2),
+            (&[&[1f64, -2f64, 5f64], &[1f64, 2f64, 5f64]], 2),
+            (&[&[1f64, 1f64], &[1f64, -1f64], &[1f64, 0f64, 4f64], &[2f64, 1f64]], 1),
+            (
+                &[&[1f64, -1f64], &[1f64, -2f64], &[1f64, -3f64], &[1f64, -4f64], &[1f64, -5f64]],
+                5,
+            ),
+            (
+                &[&[1f64, 0.5f64], &[1f64, -0.2f64, 3f64], &[1f64, 4f64, 1f64], &[1f64, -3f64]],
+                3,
+            ),
+            (&[&[1f64, 0f64, -1f64], &[1f64, 0f64, 1f64], &[1f64, 1f64, 1f64]], 1),
+        ];
+        for (factors, count) in unstable.iter() {
+            let c = multiply(factors);
+            assert_eq!(is_hurwitz_stable(&c), Ok(false), "{:?}", c);
+            assert_eq!(count_right_half_plane_roots(&c), Ok(*count), "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn test_routh_array_zero_pivot() {
+        // s^3 + s^2 + 2s + 2 = (s + 1)(s^2 + 2) has the zero row, s^4 + s^3 + 2s^2 + 2s + 3 has the zero pivot
+        assert_eq!(is_hurwitz_stable(&[1f64, 1f64, 2f64, 2f64]), Ok(false));
+        assert_eq!(count_right_half_plane_roots(&[1f64, 1f64, 2f64, 2f64, 3f64]), Ok(2));
+        assert_eq!(count_right_half_plane_roots(&[1f32, 2f32, 3f32, 6f32, 5f32, 3f32]), Ok(2));
+    }
+
+    #[test]
+    fn test_is_hurwitz_stable_not_finite() {
+        assert!(matches!(
+            is_hurwitz_stable(&[1f64, f64::NAN, 1f64]),
+            Err(SearchError::NoConvergency { .. })
+        ));
+        assert!(matches!(
+            count_right_half_plane_roots(&[1f64, f64::INFINITY]),
+            Err(SearchError::NoConvergency { .. })
+        ));
+    }
+}
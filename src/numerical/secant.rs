@@ -23,8 +23,17 @@
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use super::super::FloatType;
+use super::check_bracket;
+use super::check_start;
+use super::check_value;
 use super::Convergency;
+use super::IterationObserver;
+use super::NoObserver;
 use super::SearchError;
+use super::SolverStats;
+use super::TrySearchError;
+use std::cmp::Ordering;
+use std::ops::RangeInclusive;
 
 /// Find a root of the function f(x) = 0 using the secant method.
 ///
@@ -41,6 +50,8 @@ use super::SearchError;
 /// - Cannot continue when two consecutive iterations have the same value
 ///
 /// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
 /// ## ZeroDerivative
 /// Two consecutive points have the same value. Algorithm cannot continue.
 /// ## NoConvergency
@@ -60,34 +71,185 @@ use super::SearchError;
 /// let root2 = find_root_secant(-10f64, 0f64, &f, &mut 1e-15f64);
 /// // Returns approximately Ok(-1);
 /// ```
-pub fn find_root_secant<F, Func>(first: F, second: F, mut f: Func, convergency: &mut dyn Convergency<F>) -> Result<F, SearchError>
+pub fn find_root_secant<F, Func>(
+    first: F,
+    second: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
 where
     F: FloatType,
     Func: FnMut(F) -> F,
 {
+    match secant_or_best(first, second, f, None, convergency, &mut NoObserver)? {
+        (x, None) => Ok(x),
+        (_, Some(error)) => Err(error),
+    }
+}
+
+/// Find a root of the function f(x) = 0 using the secant method starting from one point.
+///
+/// The same as `find_root_secant`, where the second point is start*(1 + d) + d with d = sqrt(epsilon),
+/// as in MINPACK. The first step is then close to the step of the Newton-Raphson method.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// Two consecutive points have the same value. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_secant_auto;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let root = find_root_secant_auto(10f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1);
+/// ```
+pub fn find_root_secant_auto<F, Func>(start: F, f: Func, convergency: &mut (impl Convergency<F> + ?Sized)) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let delta = F::epsilon().sqrt();
+    find_root_secant(start, start * (F::one() + delta) + delta, f, convergency)
+}
+
+/// Find a root of the function f(x) = 0 using the secant method.
+///
+/// The same as `find_root_secant`, but the result is the tuple (x, converged).
+/// If the iteration limit is reached, x is the point with the least |f(x)| evaluated so far
+/// and converged is false, which can be good enough when the precision has been set too high.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// Two consecutive points have the same value. Algorithm cannot continue.
+/// # Examples
+///
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_secant_or_best;
+///
+/// let f = |x| { 1f64*x*x - 2f64 };
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:11 };
+///
+/// let root = find_root_secant_or_best(10f64, 0f64, &f, &mut convergency);
+/// // Returns approximately Ok((1.4142135624, false)) instead of Err(SearchError::NoConvergency { .. });
+/// ```
+pub fn find_root_secant_or_best<F, Func>(
+    first: F,
+    second: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<(F, bool), SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    secant_or_best(first, second, f, None, convergency, &mut NoObserver).map(|(x, error)| (x, error.is_none()))
+}
+
+/// Find a root of the function f(x) = 0 using the secant method with limited steps.
+///
+/// The same as `find_root_secant`, but each step is limited by max_step and by 100 times the previous step.
+/// Longer steps, which happen when two points have almost the same value, are cut down to the limit
+/// in the same direction. This keeps the iterations from jumping to infinity where the function is flat.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// Two consecutive points have the same value. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_secant_guarded;
+///
+/// let f = |x: f64| { x.atan() - 1f64 };
+///
+/// let root = find_root_secant_guarded(10f64, 20f64, &f, 1f64, &mut 1e-15f64);
+/// // Returns approximately Ok(1.5574077246549023);
+/// ```
+pub fn find_root_secant_guarded<F, Func>(
+    first: F,
+    second: F,
+    f: Func,
+    max_step: F,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    match secant_or_best(first, second, f, Some(max_step), convergency, &mut NoObserver)? {
+        (x, None) => Ok(x),
+        (_, Some(error)) => Err(error),
+    }
+}
+
+/// Secant iterations with optionally limited steps, returning the best point evaluated along with the error when the iteration limit is reached
+fn secant_or_best<F, Func>(
+    first: F,
+    second: F,
+    mut f: Func,
+    max_step: Option<F>,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    observer: &mut (impl IterationObserver<F> + ?Sized),
+) -> Result<(F, Option<SearchError>), SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let _100 = F::from(100i16);
+    check_start(first)?;
+    check_start(second)?;
     let mut x1 = first;
     let mut y1 = f(x1);
+    check_value(y1)?;
     if convergency.is_root_found(y1) {
-        return Ok(x1);
+        return Ok((x1, None));
     }
     let mut x2 = second;
     let mut y2 = f(x2);
+    check_value(y2)?;
     if convergency.is_root_found(y2) {
-        return Ok(x2);
+        return Ok((x2, None));
     }
+    let (mut best_x, mut best_y) = if y1.abs() < y2.abs() { (x1, y1) } else { (x2, y2) };
 
     let mut iter = 0;
     loop {
         if convergency.is_root_found(y1 - y2) {
-            return Err(SearchError::ZeroDerivative);
+            return Err(SearchError::zero_derivative(iter, x2, y2));
+        }
+        let mut x = x2 - y2 * (x2 - x1) / (y2 - y1);
+        if let Some(max_step) = max_step {
+            let previous = _100 * (x2 - x1).abs();
+            let limit = if previous < max_step { previous } else { max_step };
+            // NaN steps, e.g. from infinite values, are limited too
+            match (x - x2).abs().partial_cmp(&limit) {
+                Some(Ordering::Less) | Some(Ordering::Equal) => {}
+                _ => x = if x < x2 { x2 - limit } else { x2 + limit },
+            }
         }
-        let x = x2 - y2 * (x2 - x1) / (y2 - y1);
         if convergency.is_converged(x, x2) {
-            return Ok(x);
+            return Ok((x, None));
         }
         let y = f(x);
+        observer.on_iteration(iter, x, y);
         if convergency.is_root_found(y) {
-            return Ok(x);
+            return Ok((x, None));
+        }
+        if y.abs() < best_y.abs() {
+            best_x = x;
+            best_y = y;
         }
 
         x1 = x2;
@@ -97,11 +259,267 @@ where
 
         iter = iter + 1;
         if convergency.is_iteration_limit_reached(iter) {
-            return Err(SearchError::NoConvergency);
+            return Ok((best_x, Some(SearchError::no_convergency(iter, best_x, best_y))));
+        }
+    }
+}
+
+/// Find a root of the function f(x) = 0 using the secant method safeguarded by bisection.
+///
+/// The secant step through the last two points is taken if it lands inside the bracket,
+/// otherwise the bracket is bisected. The bracket is updated at every iteration,
+/// so the iterations cannot run away from the root as in `find_root_secant`.
+///
+/// Pro
+///
+/// + Robust
+/// + Superlinear convergency for well-behaved functions
+/// + No need for derivative function
+///
+/// Contra
+///
+/// - Needs initial bracketing
+/// - Slower than the Brent method when the secant steps keep falling outside of the bracket
+///
+/// # Failures
+/// ## InvalidInput
+/// The ends or the function values at them are not finite.
+/// ## NoBracketing
+/// Initial values do not bracket the root.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_secant_bracketed;
+///
+/// let f = |x| { 1f64*x*x*x - 2f64*x + 2f64 };
+///
+/// let root = find_root_secant_bracketed(-3f64, 1.5f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(-1.7692923542386314);
+/// // while find_root_secant(-3f64, 1.5f64, &f, &mut 1e-15f64) returns Err(SearchError::NoConvergency { .. })
+/// ```
+pub fn find_root_secant_bracketed<F, Func>(
+    a: F,
+    b: F,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let _2 = F::from(2i16);
+    check_bracket(a, b)?;
+    let (mut x1, mut y1) = (a, f(a));
+    check_value(y1)?;
+    if convergency.is_root_found(y1) {
+        return Ok(x1);
+    }
+    let (mut x2, mut y2) = (b, f(b));
+    check_value(y2)?;
+    if convergency.is_root_found(y2) {
+        return Ok(x2);
+    }
+    if y1 * y2 > F::zero() {
+        return Err(SearchError::no_bracketing(x1, x2));
+    }
+
+    // The root is bracketed between lo and hi
+    let (mut lo, mut ylo, mut hi) = (x1, y1, x2);
+
+    let mut iter = 0;
+    loop {
+        if convergency.is_converged(lo, hi) {
+            return Ok((lo + hi) / _2);
+        }
+        let secant = if y1 != y2 { x2 - y2 * (x2 - x1) / (y2 - y1) } else { lo };
+        let x = if (secant - lo) * (secant - hi) < F::zero() {
+            if convergency.is_converged(secant, x2) {
+                return Ok(secant);
+            }
+            secant
+        } else {
+            (lo + hi) / _2
+        };
+        let y = f(x);
+        if convergency.is_root_found(y) {
+            return Ok(x);
+        }
+
+        if ylo * y < F::zero() {
+            hi = x;
+        } else {
+            lo = x;
+            ylo = y;
+        }
+        x1 = x2;
+        y1 = y2;
+        x2 = x;
+        y2 = y;
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency(iter, x2, y2).with_bracket(lo, hi));
         }
     }
 }
 
+/// Find a root of the function f(x) = 0 using the secant method and count the work done.
+///
+/// The same as `find_root_secant`, but the result comes with `SolverStats`: the last iteration,
+/// and the number of evaluations of the function.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// Two consecutive points have the same value. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_secant_with_stats;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let (root, stats) = find_root_secant_with_stats(10f64, 0f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1) and SolverStats { iterations: 12, fn_evaluations: 15, .. }
+/// ```
+pub fn find_root_secant_with_stats<F, Func>(
+    first: F,
+    second: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> (Result<F, SearchError>, SolverStats<F>)
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    super::search_with_stats(f, convergency, |f, convergency| {
+        find_root_secant(first, second, f, convergency)
+    })
+}
+
+/// Find a root of the function f(x) = 0 using the secant method and report every iteration to the observer.
+///
+/// The same as `find_root_secant`, but `observer.on_iteration` is called once per iteration
+/// with the new approximation.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// Two consecutive points have the same value. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_secant_observed;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let mut trajectory = Vec::new();
+///
+/// let root = find_root_secant_observed(10f64, 0f64, &f, &mut 1e-15f64, &mut |_: usize, x: f64, y: f64| {
+///     trajectory.push((x, y))
+/// });
+/// // Returns approximately Ok(1), and trajectory has 13 points
+/// ```
+pub fn find_root_secant_observed<F, Func>(
+    first: F,
+    second: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    observer: &mut (impl IterationObserver<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    match secant_or_best(first, second, f, None, convergency, observer)? {
+        (x, None) => Ok(x),
+        (_, Some(error)) => Err(error),
+    }
+}
+
+/// Find a root of the function f(x) = 0 which can fail using the secant method.
+///
+/// The same as `find_root_secant`, but f returns Result. The first error of f stops the search
+/// and is returned as `TrySearchError::Function`; f is not called after it.
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## Function
+/// The function has failed.
+/// ## Search
+/// The search has failed as `find_root_secant` does.
+/// # Examples
+///
+/// ```
+/// use roots::try_find_root_secant;
+///
+/// // ln is defined for positive x only
+/// let f = |x: f64| if x > 0f64 { Ok(x.ln() - 1f64) } else { Err("not positive") };
+///
+/// let root = try_find_root_secant(1f64, 10f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(2.718281828459045);
+///
+/// let error = try_find_root_secant(0f64, 10f64, &f, &mut 1e-15f64);
+/// // Returns Err(TrySearchError::Function("not positive"));
+/// ```
+pub fn try_find_root_secant<F, Func, E>(
+    first: F,
+    second: F,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, TrySearchError<E>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> Result<F, E>,
+{
+    super::try_search(f, convergency, |f, convergency| {
+        find_root_secant(first, second, f, convergency)
+    })
+}
+
+/// Find a root of the function f(x) = 0 within the given range using the secant method.
+///
+/// The same as `find_root_secant` with the ends of the range as the first two points.
+/// The range does not have to bracket the root, and the root may be found outside of it.
+///
+/// # Failures
+/// ## InvalidInput
+/// The range is empty or its ends are not finite.
+/// ## ZeroDerivative
+/// Two consecutive points have the same value. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_secant_in;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let root = find_root_secant_in(0f64..=10f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(1);
+/// ```
+pub fn find_root_secant_in<F, Func>(
+    range: RangeInclusive<F>,
+    f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (start, end) = super::range_ends(&range)?;
+    find_root_secant(start, end, f, convergency)
+}
+
 #[cfg(test)]
 mod test {
     use super::super::*;
@@ -121,10 +539,321 @@ mod test {
         assert_eq!(12, conv.get_iter_count());
 
         conv.reset();
-        assert_eq!(
+        assert!(matches!(
             find_root_secant(10f64, -10f64, &f, &mut conv),
-            Err(SearchError::ZeroDerivative)
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+        assert_eq!(0, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_secant_auto() {
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        let f = |x| 1f64 * x * x - 1f64;
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_secant_auto(10f64, &f, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(10, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_secant_auto(-0.5f64, &f, &mut conv).ok().unwrap(), -1f64);
+        assert_eq!(6, conv.get_iter_count());
+
+        let f = |x| 1f64 * x * x * x * x - 1f64;
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_secant_auto(10f64, &f, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(17, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_secant_auto(0.5f64, &f, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(13, conv.get_iter_count());
+
+        // The start at the root
+        assert_eq!(find_root_secant_auto(1f64, &f, &mut 1e-15f64), Ok(1f64));
+
+        // The same values at both points
+        assert_eq!(
+            find_root_secant_auto(0f64, &|_| 1f64, &mut 1e-15f64),
+            find_root_secant(0f64, 1.4901161193847656e-8f64, &|_| 1f64, &mut 1e-15f64)
+        );
+        assert!(matches!(
+            find_root_secant_auto(0f64, &|_| 1f64, &mut 1e-15f64),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_secant_or_best() {
+        let f = |x| 1f64 * x * x - 2f64;
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 11,
+        };
+        assert!(matches!(
+            find_root_secant(10f64, 0f64, &f, &mut conv),
+            Err(SearchError::NoConvergency { .. })
+        ));
+        let (root, converged) = find_root_secant_or_best(10f64, 0f64, &f, &mut conv).ok().unwrap();
+        assert!(!converged);
+        assert_float_eq!(1e-10f64, root, 2f64.sqrt());
+
+        let root = find_root_secant(10f64, 0f64, &f, &mut 1e-15f64);
+        assert_eq!(
+            find_root_secant_or_best(10f64, 0f64, &f, &mut 1e-15f64),
+            root.map(|x| (x, true))
         );
+
+        // Secant steps jump around; the best point is at least as good as the initial ones
+        let f = |x| 1f64 * x * x * x - 2f64 * x + 2f64;
+        let (root, converged) = find_root_secant_or_best(-3f64, 1.5f64, &f, &mut 1e-15f64).ok().unwrap();
+        assert!(!converged);
+        assert!(f(root).abs() <= f(1.5f64).abs());
+
+        assert!(matches!(
+            find_root_secant_or_best(10f64, -10f64, &|x| 1f64 * x * x - 1f64, &mut 1e-15f64),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+    }
+
+    #[test]
+    fn test_find_root_secant_guarded() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        // Steps are short enough; the same iterations as the unguarded method
+        conv.reset();
+        assert_eq!(
+            find_root_secant_guarded(10f64, 0f64, &f, 100f64, &mut conv),
+            find_root_secant(10f64, 0f64, &f, &mut 1e-15f64)
+        );
+        assert_eq!(12, conv.get_iter_count());
+
+        // Regression: the unguarded method jumps to 5e24 where the function is flat
+        let f = |x: f64| x.atan() - 1f64;
+        let mut farthest = 0f64;
+        let root = find_root_secant(
+            3f64,
+            20f64,
+            |x: f64| {
+                farthest = if x.abs() > farthest { x.abs() } else { farthest };
+                f(x)
+            },
+            &mut 1e-15f64,
+        );
+        assert!(matches!(root, Err(SearchError::ZeroDerivative { .. })));
+        assert!(farthest > 1e24f64);
+
+        let mut farthest = 0f64;
+        let root = find_root_secant_guarded(
+            3f64,
+            20f64,
+            |x: f64| {
+                farthest = if x.abs() > farthest { x.abs() } else { farthest };
+                f(x)
+            },
+            1f64,
+            &mut 1e-15f64,
+        );
+        assert_float_eq!(1e-15f64, root.ok().unwrap(), 1f64.tan());
+        assert_eq!(farthest, 20f64);
+    }
+
+    #[test]
+    fn test_find_root_secant_bracketed() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_secant_bracketed(10f64, 0f64, &f, &mut conv).ok().unwrap(),
+            1f64
+        );
+        assert_eq!(12, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_secant_bracketed(-10f64, 0f64, &f, &mut conv).ok().unwrap(),
+            -1f64
+        );
+        assert_eq!(12, conv.get_iter_count());
+
+        conv.reset();
+        assert!(matches!(
+            find_root_secant_bracketed(10f64, 20f64, &f, &mut conv),
+            Err(SearchError::NoBracketing { .. })
+        ));
         assert_eq!(0, conv.get_iter_count());
     }
+
+    #[test]
+    fn test_find_root_secant_bracketed_divergent() {
+        // Secant steps jump out of the bracket
+        let f = |x| 1f64 * x * x * x - 2f64 * x + 2f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert!(matches!(
+            find_root_secant(-3f64, 1.5f64, &f, &mut conv),
+            Err(SearchError::NoConvergency { .. })
+        ));
+
+        conv.reset();
+        assert_float_eq!(
+            1e-15f64,
+            find_root_secant_bracketed(-3f64, 1.5f64, &f, &mut conv).ok().unwrap(),
+            -1.7692923542386314f64
+        );
+        assert_eq!(10, conv.get_iter_count());
+
+        // Secant steps stagnate far from the root where the function is flat
+        let f = |x: f64| (x - 1f64).tanh();
+        assert!(matches!(
+            find_root_secant(-5f64, 10f64, &f, &mut 1e-15f64),
+            Err(SearchError::ZeroDerivative { .. })
+        ));
+        assert_float_eq!(
+            1e-15f64,
+            find_root_secant_bracketed(-5f64, 10f64, &f, &mut 1e-15f64).ok().unwrap(),
+            1f64
+        );
+    }
+
+    #[test]
+    fn test_find_root_secant_with_stats() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        let (root, stats) = find_root_secant_with_stats(10f64, 0f64, &f, &mut conv);
+        assert_eq!(root, find_root_secant(10f64, 0f64, &f, &mut conv));
+        assert_eq!(stats.iterations, conv.get_iter_count());
+        assert_eq!(stats.iterations, 12);
+        assert_eq!(stats.fn_evaluations, 15);
+        assert_eq!(stats.derivative_evaluations, 0);
+        assert_eq!(stats.final_bracket_width, None);
+
+        let (root, stats) = find_root_secant_with_stats(
+            10f64,
+            0f64,
+            &f,
+            &mut simple_convergency::SimpleConvergency {
+                eps: 1e-15f64,
+                max_iter: 3,
+            },
+        );
+        assert!(matches!(root, Err(SearchError::NoConvergency { .. })));
+        assert_eq!(stats.iterations, 3);
+        assert_eq!(stats.final_bracket_width, None);
+    }
+
+    #[test]
+    fn test_find_root_secant_observed() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut trajectory = Vec::new();
+
+        let root = find_root_secant_observed(10f64, 0f64, &f, &mut 1e-15f64, &mut |iter: usize, x: f64, y: f64| {
+            trajectory.push((iter, x, y))
+        });
+        assert_eq!(root, find_root_secant(10f64, 0f64, &f, &mut 1e-15f64));
+        assert_eq!(trajectory.len(), 13);
+        assert_eq!(trajectory[1], (1, 9.999999999999991f64, 98.99999999999983f64));
+        assert_eq!(trajectory.last().unwrap().1, root.unwrap());
+    }
+
+    #[test]
+    fn test_try_find_root_secant() {
+        let f = |x: f64| if x < 0f64 { Err("negative") } else { Ok(x * x - 2f64) };
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-15f64,
+            max_iter: 100,
+        };
+
+        // The root lies in the valid half of the domain
+        assert_float_eq!(
+            1e-15f64,
+            try_find_root_secant(0f64, 4f64, &f, &mut conv).ok().unwrap(),
+            2f64.sqrt()
+        );
+        // The function fails at a bracket endpoint
+        assert_eq!(
+            try_find_root_secant(-4f64, 4f64, &f, &mut conv),
+            Err(TrySearchError::Function("negative"))
+        );
+    }
+
+    #[test]
+    fn test_find_root_secant_in() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = 1e-15f64;
+
+        assert_eq!(
+            find_root_secant_in(0f64..=10f64, &f, &mut conv),
+            find_root_secant(0f64, 10f64, &f, &mut conv)
+        );
+        assert_float_eq!(
+            1e-15f64,
+            find_root_secant_in(-10f64..=0f64, &f, &mut conv).ok().unwrap(),
+            -1f64
+        );
+
+        // Empty and infinite ranges
+        assert_eq!(
+            find_root_secant_in(10f64..=0f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::EmptyRange
+            })
+        );
+        assert_eq!(
+            find_root_secant_in(0f64..=f64::INFINITY, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            find_root_secant_in(f64::NEG_INFINITY..=0f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+        assert_eq!(
+            find_root_secant_in(f64::NAN..=10f64, &f, &mut conv),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::NotFiniteBracket
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_root_secant_bracketed_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let bracket = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteBracket));
+        assert_eq!(find_root_secant_bracketed(f64::NAN, 10f64, &f, &mut 1e-15f64), bracket);
+        assert_eq!(find_root_secant_bracketed(0.5f64, f64::NAN, &f, &mut 1e-15f64), bracket);
+        assert_eq!(
+            find_root_secant_bracketed(f64::NEG_INFINITY, 10f64, &f, &mut 1e-15f64),
+            bracket
+        );
+        assert_eq!(find_root_secant_bracketed(0.5f64, f64::INFINITY, &f, &mut 1e-15f64), bracket);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_secant_bracketed(0f64, 10f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_secant_bracketed(10f64, -1f64, &f, &mut 1e-15f64), value);
+    }
+
+    #[test]
+    fn test_find_root_secant_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let start = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteStart));
+        assert_eq!(find_root_secant(f64::NAN, 10f64, &f, &mut 1e-15f64), start);
+        assert_eq!(find_root_secant(f64::INFINITY, 10f64, &f, &mut 1e-15f64), start);
+        assert_eq!(find_root_secant(f64::NEG_INFINITY, 10f64, &f, &mut 1e-15f64), start);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_secant(0f64, 10f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_secant(-1f64, 10f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_secant(10f64, f64::NAN, &f, &mut 1e-15f64), start);
+        assert_eq!(find_root_secant(10f64, -1f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_secant_auto(f64::NAN, &f, &mut 1e-15f64), start);
+    }
 }
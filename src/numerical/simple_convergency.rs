@@ -27,6 +27,7 @@ use super::Convergency;
 
 /// A very basic convergency rules that must be sufficient for many cases.
 /// The absolute precision is the same for x and y axes, no relative precision.
+#[derive(Debug, Clone, Copy)]
 pub struct SimpleConvergency<F: FloatType> {
     /// Precision for both X and Y axes
     pub eps: F,
@@ -34,6 +35,30 @@ pub struct SimpleConvergency<F: FloatType> {
     pub max_iter: usize,
 }
 
+/// Precision of 16 machine epsilons and 100 iterations, suitable for both f32 and f64.
+/// The precision is absolute, so it suits roots and values of the function of the order of 1.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent;
+/// use roots::SimpleConvergency;
+///
+/// let f = |x| { 1f32*x*x - 2f32 };
+/// let mut convergency = SimpleConvergency::default();
+///
+/// let root = find_root_brent(10f32, 0f32, &f, &mut convergency);
+/// // Returns approximately Ok(1.4142135), while eps 1e-15 would never be reached in f32
+/// ```
+impl<F: FloatType> Default for SimpleConvergency<F> {
+    fn default() -> Self {
+        SimpleConvergency {
+            eps: F::from(16i16) * F::epsilon(),
+            max_iter: 100,
+        }
+    }
+}
+
 impl<F: FloatType> Convergency<F> for SimpleConvergency<F> {
     fn is_root_found(&mut self, y: F) -> bool {
         y.abs() < self.eps.abs()
@@ -45,3 +70,33 @@ impl<F: FloatType> Convergency<F> for SimpleConvergency<F> {
         iter >= self.max_iter
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+    use std::f32;
+    use std::f64;
+
+    fn find_sqrt2<F: FloatType>() -> Result<F, SearchError> {
+        let f = |x: F| x * x - F::from(2i16);
+        brent::find_root_brent(F::from(10i16), F::zero(), &f, &mut SimpleConvergency::default())
+    }
+
+    #[test]
+    fn test_simple_convergency_default() {
+        assert_float_eq!(16f32 * f32::EPSILON, find_sqrt2::<f32>().ok().unwrap(), f32::consts::SQRT_2);
+        assert_float_eq!(16f64 * f64::EPSILON, find_sqrt2::<f64>().ok().unwrap(), f64::consts::SQRT_2);
+
+        // The precision of f64 is never reached in f32
+        let f = |x: f32| x * x - 2f32;
+        let mut convergency = SimpleConvergency {
+            eps: 1e-15f32,
+            max_iter: 100,
+        };
+        assert!(matches!(
+            brent::find_root_brent(10f32, 0f32, &f, &mut convergency),
+            Err(SearchError::NoConvergency { .. })
+        ));
+    }
+}
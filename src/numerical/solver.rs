@@ -0,0 +1,286 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::method::find_root;
+use super::method::Method;
+use super::method::Start;
+use super::simple_convergency::SimpleConvergency;
+use super::SearchError;
+use std::error::Error;
+use std::fmt;
+
+/// Errors of the Solver: either its configuration is not valid, or the search has failed
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum SolverError {
+    /// The bracket has not been given
+    MissingBracket,
+    /// The bracket is reversed or not finite
+    InvalidBracket,
+    /// The precision has not been given
+    MissingEps,
+    /// The precision is not positive
+    InvalidEps,
+    /// The search has failed
+    Search(SearchError),
+}
+
+impl From<SearchError> for SolverError {
+    fn from(error: SearchError) -> Self {
+        SolverError::Search(error)
+    }
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolverError::MissingBracket => write!(f, "Missing Bracket Error"),
+            SolverError::InvalidBracket => write!(f, "Invalid Bracket Error"),
+            SolverError::MissingEps => write!(f, "Missing Precision Error"),
+            SolverError::InvalidEps => write!(f, "Invalid Precision Error"),
+            SolverError::Search(error) => error.fmt(f),
+        }
+    }
+}
+impl Error for SolverError {}
+
+/// Builder of the search for a root with one of the methods of find_root.
+///
+/// The method, the precision, the maximal number of iterations and the bracket are set one by one,
+/// and the configuration is checked by `solve`. The search is done by `find_root`
+/// with `SimpleConvergency`. The maximal number of iterations is 100 unless set.
+///
+/// # Examples
+///
+/// ```
+/// use roots::Solver;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+///
+/// let root = Solver::brent().eps(1e-12).max_iter(64).bracket(0.0, 10.0).solve(&f);
+/// // Returns approximately Ok(1);
+///
+/// let solver = Solver::regula_falsi().eps(1e-12);
+/// let root1 = solver.bracket(-10.0, 0.0).solve(&f);
+/// // Returns approximately Ok(-1);
+///
+/// let root2 = solver.bracket(10.0, 0.0).solve(&f);
+/// // Returns Err(SolverError::InvalidBracket);
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Solver<F>
+where
+    F: FloatType,
+{
+    method: Method,
+    eps: Option<F>,
+    max_iter: usize,
+    bracket: Option<(F, F)>,
+}
+
+impl<F> Solver<F>
+where
+    F: FloatType,
+{
+    /// Solver with the given method
+    pub fn new(method: Method) -> Self {
+        Solver {
+            method,
+            eps: None,
+            max_iter: 100,
+            bracket: None,
+        }
+    }
+
+    /// Solver with the bisection method
+    pub fn bisection() -> Self {
+        Solver::new(Method::Bisection)
+    }
+
+    /// Solver with the Brent method
+    pub fn brent() -> Self {
+        Solver::new(Method::Brent)
+    }
+
+    /// Solver with the Chandrupatla's method
+    pub fn chandrupatla() -> Self {
+        Solver::new(Method::Chandrupatla)
+    }
+
+    /// Solver with the Dekker's method
+    pub fn dekker() -> Self {
+        Solver::new(Method::Dekker)
+    }
+
+    /// Solver with the inverse quadratic approximation
+    pub fn inverse_quadratic() -> Self {
+        Solver::new(Method::InverseQuadratic)
+    }
+
+    /// Solver with the regula falsi method
+    pub fn regula_falsi() -> Self {
+        Solver::new(Method::RegulaFalsi)
+    }
+
+    /// Solver with the Ridders' method
+    pub fn ridders() -> Self {
+        Solver::new(Method::Ridders)
+    }
+
+    /// Set the precision for both X and Y axes
+    pub fn eps(self, eps: F) -> Self {
+        Solver { eps: Some(eps), ..self }
+    }
+
+    /// Set the maximal number of iterations
+    pub fn max_iter(self, max_iter: usize) -> Self {
+        Solver { max_iter, ..self }
+    }
+
+    /// Set the bracket of the root, a <= b
+    pub fn bracket(self, a: F, b: F) -> Self {
+        Solver {
+            bracket: Some((a, b)),
+            ..self
+        }
+    }
+
+    /// Check the configuration and find a root of the function f(x) = 0
+    ///
+    /// # Failures
+    /// ## MissingBracket, MissingEps
+    /// The bracket or the precision has not been given.
+    /// ## InvalidBracket
+    /// The bracket is reversed or not finite.
+    /// ## InvalidEps
+    /// The precision is not positive.
+    /// ## Search
+    /// The method has failed, see find_root.
+    pub fn solve<Func>(&self, f: Func) -> Result<F, SolverError>
+    where
+        Func: FnMut(F) -> F,
+    {
+        let (a, b) = self.bracket.ok_or(SolverError::MissingBracket)?;
+        // Infinite and NaN values give NaN
+        if a * F::zero() != F::zero() || b * F::zero() != F::zero() || a > b {
+            return Err(SolverError::InvalidBracket);
+        }
+        let eps = self.eps.ok_or(SolverError::MissingEps)?;
+        if eps.partial_cmp(&F::zero()) != Some(std::cmp::Ordering::Greater) {
+            return Err(SolverError::InvalidEps);
+        }
+        let mut convergency = SimpleConvergency {
+            eps,
+            max_iter: self.max_iter,
+        };
+        Ok(find_root(self.method, Start::Bracket(a, b), f, &mut convergency)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_solver() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = simple_convergency::SimpleConvergency {
+            eps: 1e-12f64,
+            max_iter: 64,
+        };
+
+        assert_eq!(
+            Solver::brent().eps(1e-12).max_iter(64).bracket(0.0, 10.0).solve(&f),
+            Ok(brent::find_root_brent(0f64, 10f64, &f, &mut conv).unwrap())
+        );
+        for solver in [
+            Solver::bisection(),
+            Solver::brent(),
+            Solver::chandrupatla(),
+            Solver::dekker(),
+            Solver::inverse_quadratic(),
+            Solver::regula_falsi(),
+            Solver::ridders(),
+        ]
+        .iter()
+        {
+            assert_float_eq!(1e-12f64, solver.eps(1e-12).bracket(-10.0, 0.0).solve(&f).ok().unwrap(), -1f64);
+        }
+        // The same as the method
+        let solver = Solver::new(Method::Ridders).eps(1e-12f64).max_iter(64).bracket(0.0, 10.0);
+        assert_eq!(
+            solver.solve(&f),
+            Ok(find_root(Method::Ridders, Start::Bracket(0f64, 10f64), &f, &mut conv).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_solver_errors() {
+        let f = |x| 1f64 * x * x - 1f64;
+
+        assert_eq!(Solver::brent().eps(1e-12f64).solve(&f), Err(SolverError::MissingBracket));
+        assert_eq!(
+            Solver::brent().eps(1e-12f64).bracket(10.0, 0.0).solve(&f),
+            Err(SolverError::InvalidBracket)
+        );
+        assert_eq!(
+            Solver::brent().eps(1e-12f64).bracket(0.0, f64::INFINITY).solve(&f),
+            Err(SolverError::InvalidBracket)
+        );
+        assert_eq!(
+            Solver::brent().eps(1e-12f64).bracket(f64::NAN, 1.0).solve(&f),
+            Err(SolverError::InvalidBracket)
+        );
+        assert_eq!(Solver::brent().bracket(0f64, 10.0).solve(&f), Err(SolverError::MissingEps));
+        assert_eq!(
+            Solver::brent().eps(0f64).bracket(0.0, 10.0).solve(&f),
+            Err(SolverError::InvalidEps)
+        );
+        assert_eq!(
+            Solver::brent().eps(-1e-12f64).bracket(0.0, 10.0).solve(&f),
+            Err(SolverError::InvalidEps)
+        );
+        assert_eq!(
+            Solver::brent().eps(f64::NAN).bracket(0.0, 10.0).solve(&f),
+            Err(SolverError::InvalidEps)
+        );
+
+        // Errors of the search
+        assert!(matches!(
+            Solver::brent().eps(1e-12f64).bracket(2.0, 10.0).solve(&f),
+            Err(SolverError::Search(SearchError::NoBracketing { .. }))
+        ));
+        assert!(matches!(
+            Solver::bisection().eps(1e-12f64).max_iter(5).bracket(0.0, 10.0).solve(&f),
+            Err(SolverError::Search(SearchError::NoConvergency { .. }))
+        ));
+        assert_eq!(
+            Solver::new(Method::Steffensen).eps(1e-12f64).bracket(0.0, 10.0).solve(&f),
+            Err(SolverError::Search(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            }))
+        );
+    }
+}
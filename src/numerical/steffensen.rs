@@ -0,0 +1,182 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::check_start;
+use super::check_value;
+use super::Convergency;
+use super::SearchError;
+
+/// Find a root of the function f(x) = 0 using the Steffensen's method.
+///
+/// The derivative is replaced by the slope (f(x + h) - f(x))/h with the probe step h = f(x).
+/// For large values of f(x) the probe step is limited by sqrt(eps)*(|x| + 1),
+/// and the slope becomes the finite difference approximation of the derivative.
+///
+/// Pro
+///
+/// + Quadratic convergency for well-behaved functions
+/// + No need for derivative function
+/// + No need for initial bracketing
+/// + Only one starting point
+///
+/// Contra
+///
+/// - Two function evaluations per iteration
+/// - Impossible to predict which root will be found when many roots exist
+/// - Unstable convergency for non-trivial functions
+/// - Cannot continue when the slope is zero
+///
+/// # Failures
+/// ## InvalidInput
+/// The starting point or the function value at it is not finite.
+/// ## ZeroDerivative
+/// The slope of the function is zero. Algorithm cannot continue.
+/// ## NoConvergency
+/// Algorithm cannot find a root within the given number of iterations.
+/// # Examples
+/// ```
+/// use roots::SimpleConvergency;
+/// use roots::find_root_steffensen;
+///
+/// let f = |x| { 1f64*x*x - 1f64 };
+/// let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:30 };
+///
+/// let root1 = find_root_steffensen(10f64, &f, &mut convergency);
+/// // Returns approximately Ok(1);
+///
+/// let root2 = find_root_steffensen(-10f64, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(-1);
+/// ```
+pub fn find_root_steffensen<F, Func>(
+    start: F,
+    mut f: Func,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    check_start(start)?;
+    let mut x = start;
+
+    let mut iter = 0;
+    loop {
+        let y = f(x);
+        if iter == 0 {
+            check_value(y)?;
+        }
+        if convergency.is_root_found(y) {
+            return Ok(x);
+        }
+        let limit = F::epsilon().sqrt() * (x.abs() + F::one());
+        let h = if y > limit {
+            limit
+        } else if y < -limit {
+            -limit
+        } else {
+            y
+        };
+        let d = (f(x + h) - y) / h;
+        // Slope is 0; try to correct the bad starting point
+        if convergency.is_root_found(d) {
+            if iter == 0 {
+                x = x + F::one();
+                iter += 1;
+                continue;
+            } else {
+                return Err(SearchError::zero_derivative(iter, x, y));
+            }
+        }
+
+        let x1 = x - y / d;
+        if convergency.is_converged(x, x1) {
+            return Ok(x1);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::no_convergency(iter, x, y));
+        }
+        x = x1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_find_root_steffensen() {
+        let f = |x| 1f64 * x * x - 1f64;
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_steffensen(10f64, &f, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(8, conv.get_iter_count());
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_steffensen(-10f64, &f, &mut conv).ok().unwrap(), -1f64);
+        assert_eq!(8, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_steffensen_scaled() {
+        // The probe step f(x) would be absurdly large without the limit
+        let f = |x| 1e10f64 * (x * x - 1f64);
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert_float_eq!(1e-15f64, find_root_steffensen(10f64, &f, &mut conv).ok().unwrap(), 1f64);
+        assert_eq!(8, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_steffensen_divergence() {
+        // Each step overshoots the root of the cubic root function, like the Newton-Raphson method does
+        let f = |x: f64| x.cbrt();
+        let mut conv = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        conv.reset();
+        assert!(matches!(
+            find_root_steffensen(10f64, &f, &mut conv),
+            Err(SearchError::NoConvergency { .. })
+        ));
+        assert_eq!(30, conv.get_iter_count());
+    }
+
+    #[test]
+    fn test_find_root_steffensen_invalid_input() {
+        // The logarithm is -inf at 0 and NaN at -1
+        let f = |x: f64| x.ln();
+        let start = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteStart));
+        assert_eq!(find_root_steffensen(f64::NAN, &f, &mut 1e-15f64), start);
+        assert_eq!(find_root_steffensen(f64::INFINITY, &f, &mut 1e-15f64), start);
+        assert_eq!(find_root_steffensen(f64::NEG_INFINITY, &f, &mut 1e-15f64), start);
+        let value = Err(SearchError::invalid_input(InvalidInputReason::NotFiniteValue));
+        assert_eq!(find_root_steffensen(0f64, &f, &mut 1e-15f64), value);
+        assert_eq!(find_root_steffensen(-1f64, &f, &mut 1e-15f64), value);
+    }
+}
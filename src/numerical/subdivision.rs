@@ -0,0 +1,338 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::brent::find_root_brent;
+use super::Convergency;
+use super::Sample;
+use std::cmp::Ordering;
+
+/// Find all roots of the function f(x) = 0 on the interval [a, b] by adaptive subdivision.
+///
+/// The interval is split into `initial_subdivisions` equal segments. A segment is split in two
+/// while the values of the function there are not large compared to their change,
+/// i.e. while two close roots or a root without the sign change can hide inside. Each segment
+/// where the function changes its sign and is close to the straight line is passed to the Brent
+/// method. Roots closer than the precision of `convergency` are reported once.
+/// The roots are sorted in the ascending order.
+///
+/// Roots of even multiplicity, like the root of x^2, do not change the sign of the function.
+/// They are detected as local minima of |f(x)| where segments could not be resolved
+/// down to the precision of x, and reported only if the minimal value satisfies `is_root_found`,
+/// which needs a loose precision for f(x).
+/// The initial segments should be short enough to resolve oscillations of the function:
+/// a segment that looks close to the straight line is searched for one root only.
+/// Discontinuities where the function changes its sign are reported as roots;
+/// sign changes where the Brent method fails to converge are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_all_roots;
+///
+/// let f = |x: f64| x.sin();
+///
+/// let roots = find_all_roots(&f, 0f64, 30f64, 10, &mut 1e-12f64);
+/// // Returns approximately [0, 3.14159, 6.28318, ..., 28.27433]
+/// ```
+pub fn find_all_roots<F, Func>(
+    mut f: Func,
+    a: F,
+    b: F,
+    initial_subdivisions: usize,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Vec<F>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (a, b) = if a > b { (b, a) } else { (a, b) };
+    let n = if initial_subdivisions > 0 { initial_subdivisions } else { 1 };
+
+    let mut roots = Vec::new();
+    let mut touches = Vec::new();
+    let mut begin = Sample { x: a, y: f(a) };
+    for i in 1..=n {
+        let x = grid_point(a, b, n, i);
+        let end = Sample { x, y: f(x) };
+        scan(&mut f, &begin, &end, convergency, &mut roots, &mut touches);
+        begin = end;
+    }
+    unique_roots(roots, touches, convergency)
+}
+
+/// Find all roots of the function f(x) = 0 on the interval [a, b] by adaptive subdivision in parallel.
+///
+/// The interval is split into `starts` equal segments, which are searched on the thread pool
+/// of rayon exactly as `find_all_roots` does. Each task works with its own clone
+/// of `convergency`, so the results are the same as of `find_all_roots` with `starts`
+/// initial subdivisions as long as the convergency does not depend on its history.
+/// Available with the `rayon` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "rayon")]
+/// # {
+/// use roots::find_roots_multistart;
+///
+/// let f = |x: f64| x.sin();
+///
+/// let roots = find_roots_multistart(f, 0f64, 30f64, 10, &1e-12f64);
+/// // Returns approximately [0, 3.14159, 6.28318, ..., 28.27433]
+/// # }
+/// ```
+#[cfg(feature = "rayon")]
+pub fn find_roots_multistart<F, Func, C>(f: Func, a: F, b: F, starts: usize, convergency: &C) -> Vec<F>
+where
+    F: FloatType + Send + Sync,
+    Func: Fn(F) -> F + Sync,
+    C: Convergency<F> + Clone + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    let (a, b) = if a > b { (b, a) } else { (a, b) };
+    let n = if starts > 0 { starts } else { 1 };
+
+    let segments: Vec<(Vec<F>, Vec<Touch<F>>)> = (1..=n)
+        .into_par_iter()
+        .map(|i| {
+            let mut convergency = convergency.clone();
+            let mut f = &f;
+            let (x0, x1) = (grid_point(a, b, n, i - 1), grid_point(a, b, n, i));
+            let begin = Sample { x: x0, y: f(x0) };
+            let end = Sample { x: x1, y: f(x1) };
+            let mut roots = Vec::new();
+            let mut touches = Vec::new();
+            scan(&mut f, &begin, &end, &mut convergency, &mut roots, &mut touches);
+            (roots, touches)
+        })
+        .collect();
+
+    let mut roots = Vec::new();
+    let mut touches = Vec::new();
+    for (segment_roots, segment_touches) in segments {
+        roots.extend(segment_roots);
+        for touch in segment_touches {
+            push_touch(&mut touches, touch);
+        }
+    }
+    unique_roots(roots, touches, &mut convergency.clone())
+}
+
+/// The i-th of n+1 equally spaced points from a to b
+fn grid_point<F: FloatType>(a: F, b: F, n: usize, i: usize) -> F {
+    if i == n {
+        b
+    } else {
+        a + (b - a) / F::from_f64(n as f64) * F::from_f64(i as f64)
+    }
+}
+
+/// Run of the finest segments next to each other where the sign of the function does not change
+struct Touch<F: FloatType> {
+    /// Beginning of the run
+    begin: F,
+    /// End of the run
+    end: F,
+    /// Point of the run with the minimal |f(x)|
+    best: Sample<F>,
+}
+
+/// Add the touch to the list, joining it with the last one if they are next to each other
+fn push_touch<F: FloatType>(touches: &mut Vec<Touch<F>>, touch: Touch<F>) {
+    match touches.last_mut() {
+        Some(last) if last.end == touch.begin => {
+            last.end = touch.end;
+            if touch.best.y.abs() < last.best.y.abs() {
+                last.best = touch.best;
+            }
+        }
+        _ => touches.push(touch),
+    }
+}
+
+/// Add minima of |f(x)| close enough to zero, sort the roots and remove duplicates
+fn unique_roots<F: FloatType>(
+    mut roots: Vec<F>,
+    touches: Vec<Touch<F>>,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+) -> Vec<F> {
+    for touch in touches {
+        if convergency.is_root_found(touch.best.y) {
+            roots.push(touch.best.x);
+        }
+    }
+
+    roots.sort_by(|x1, x2| x1.partial_cmp(x2).unwrap_or(Ordering::Equal));
+    let mut unique: Vec<F> = Vec::with_capacity(roots.len());
+    for root in roots {
+        match unique.last() {
+            Some(&last) if convergency.is_converged(last, root) => {}
+            _ => unique.push(root),
+        }
+    }
+    unique
+}
+
+/// Collect roots of the segment [begin, end] and the finest segments where the sign does not change
+fn scan<F, Func>(
+    f: &mut Func,
+    begin: &Sample<F>,
+    end: &Sample<F>,
+    convergency: &mut (impl Convergency<F> + ?Sized),
+    roots: &mut Vec<F>,
+    touches: &mut Vec<Touch<F>>,
+) where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (x0, y0, x1, y1) = (begin.x, begin.y, end.x, end.y);
+    let changes_sign = (y0 < F::zero() && y1 > F::zero()) || (y0 > F::zero() && y1 < F::zero());
+    let xm = (x0 + x1) / F::from(2i16);
+    if convergency.is_converged(x0, x1) || xm == x0 || xm == x1 {
+        // The segment cannot be split any more
+        if changes_sign {
+            if let Ok(root) = find_root_brent(x0, x1, &mut *f, convergency) {
+                roots.push(root);
+            }
+        } else {
+            // Unresolved segments next to each other enclose a local minimum of |f(x)|
+            let best = if y0.abs() <= y1.abs() {
+                Sample { x: x0, y: y0 }
+            } else {
+                Sample { x: x1, y: y1 }
+            };
+            push_touch(
+                touches,
+                Touch {
+                    begin: x0,
+                    end: x1,
+                    best,
+                },
+            );
+        }
+        return;
+    }
+
+    let middle = Sample { x: xm, y: f(xm) };
+    let ym = middle.y;
+    // Close to the straight line, the function crosses the zero once
+    let linear = (ym - (y0 + y1) / F::from(2i16)).abs() * F::from(8i16) <= (y1 - y0).abs();
+    // Values large compared to their change cannot hide a root inside the segment
+    let change0 = (y0 - ym).abs();
+    let change1 = (y1 - ym).abs();
+    let change = if change0 > change1 { change0 } else { change1 };
+    let large = y0.abs() > change && y1.abs() > change && ym.abs() > change;
+    if changes_sign && linear {
+        if let Ok(root) = find_root_brent(x0, x1, &mut *f, convergency) {
+            roots.push(root);
+        }
+    } else if !large {
+        scan(f, begin, &middle, convergency, roots, touches);
+        scan(f, &middle, end, convergency, roots, touches);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_find_all_roots_sin() {
+        let f = |x: f64| x.sin();
+
+        for &subdivisions in &[10, 30, 1] {
+            let roots = find_all_roots(&f, 0f64, 30f64, subdivisions, &mut 1e-12f64);
+            assert_eq!(roots.len(), 10);
+            for (i, &root) in roots.iter().enumerate() {
+                assert_float_eq!(1e-12f64, root, i as f64 * std::f64::consts::PI);
+            }
+        }
+        // Reversed interval
+        assert_eq!(
+            find_all_roots(&f, 30f64, 0f64, 10, &mut 1e-12f64),
+            find_all_roots(&f, 0f64, 30f64, 10, &mut 1e-12f64)
+        );
+    }
+
+    #[test]
+    fn test_find_all_roots_close() {
+        let f = |x: f64| (x - 1f64) * (x - 1.0001f64);
+
+        let roots = find_all_roots(&f, 0f64, 3f64, 1, &mut 1e-12f64);
+        assert_eq!(roots.len(), 2);
+        // The slope is about 1e-4 at the roots
+        assert_float_eq!(1e-8f64, roots[0], 1f64);
+        assert_float_eq!(1e-8f64, roots[1], 1.0001f64);
+    }
+
+    #[test]
+    fn test_find_all_roots_none() {
+        let f = |x: f64| x * x + 1f64;
+        let mut convergency = debug_convergency::DebugConvergency::new(1e-15f64, 30);
+
+        assert_eq!(find_all_roots(&f, -10f64, 10f64, 4, &mut convergency), Vec::<f64>::new());
+        assert_eq!(find_all_roots(&f, 0f64, 0f64, 4, &mut 1e-12f64), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn test_find_all_roots_even_multiplicity() {
+        let f = |x: f64| (x - 1.3f64) * (x - 1.3f64);
+        let mut convergency = simple_convergency::SimpleConvergency {
+            eps: 1e-8f64,
+            max_iter: 30,
+        };
+
+        let roots = find_all_roots(&f, 0f64, 3.1f64, 3, &mut convergency);
+        assert_eq!(roots.len(), 1);
+        assert_float_eq!(1e-4f64, roots[0], 1.3f64);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_find_roots_multistart() {
+        let f = |x: f64| x.sin();
+        let g = |x: f64| (x - 1f64) * (x - 1.0001f64) * (x - 2f64);
+        let h = |x: f64| (x - 1.3f64) * (x - 1.3f64);
+        let convergency = simple_convergency::SimpleConvergency {
+            eps: 1e-8f64,
+            max_iter: 30,
+        };
+
+        for &starts in &[1, 3, 10, 100] {
+            let roots = find_roots_multistart(f, 0f64, 30f64, starts, &1e-12f64);
+            assert_eq!(roots, find_all_roots(&f, 0f64, 30f64, starts, &mut 1e-12f64));
+            let roots = find_roots_multistart(g, 3f64, 0f64, starts, &1e-12f64);
+            assert_eq!(roots, find_all_roots(&g, 3f64, 0f64, starts, &mut 1e-12f64));
+            assert_eq!(roots.len(), 3);
+            let roots = find_roots_multistart(h, 0f64, 3.1f64, starts, &convergency);
+            assert_eq!(roots, find_all_roots(&h, 0f64, 3.1f64, starts, &mut convergency.clone()));
+            assert_eq!(roots.len(), 1);
+        }
+        assert_eq!(find_roots_multistart(f, 0f64, 30f64, 30, &1e-12f64).len(), 10);
+        assert_eq!(find_roots_multistart(f, 0f64, 30f64, 0, &1e-12f64).len(), 10);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::brent::find_root_brent;
+use super::secant::find_root_secant_guarded;
+use super::Convergency;
+use super::Sample;
+use std::cmp::Ordering;
+
+/// Divide the known roots out of the function: f(x) / ((x - r[0]) * (x - r[1]) * ...).
+///
+/// The result keeps all other roots of f, while the known ones become regular points, so a solver
+/// started again does not converge back to them. The suppressed function at a known root is 0/0;
+/// closer than cbrt(epsilon)*max(1, |x|) to a known root, it is evaluated at that distance from the root
+/// instead, which approximates the limit f'(r) / (product of the other factors).
+///
+/// Numerical caveats:
+///
+/// * The roots must be known precisely: an error in a known root leaves a pole with a root right next to it.
+/// * Far from the known roots the suppressed function decays as x^-k for k known roots,
+///   so the absolute precision of f(x) in the convergency accepts more points there.
+/// * The suppressed function is flatter than f, so the same precision of f(x) gives less precise roots.
+/// * Multiple roots must be given as many times as their multiplicity.
+/// * The sign changes at every known root, so bracketing methods may need new brackets.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_secant;
+/// use roots::suppress_roots;
+///
+/// let f = |x: f64| x.sin();
+/// let known_roots = [0f64];
+///
+/// let root = find_root_secant(0f64, 1f64, suppress_roots(&f, &known_roots), &mut 1e-15f64);
+/// // Returns approximately Ok(6.283185307179586) instead of Ok(0)
+/// ```
+pub fn suppress_roots<'a, F, Func>(mut f: Func, known_roots: &'a [F]) -> impl FnMut(F) -> F + 'a
+where
+    F: FloatType + 'a,
+    Func: FnMut(F) -> F + 'a,
+{
+    move |x: F| {
+        let h = F::epsilon().cbrt() * if x.abs() > F::one() { x.abs() } else { F::one() };
+        // Move away from the known root which is too close
+        let x = match known_roots.iter().find(|&&r| (x - r).abs() < h) {
+            Some(&r) if x < r => r - h,
+            Some(&r) => r + h,
+            None => x,
+        };
+        known_roots.iter().fold(f(x), |y, &r| y / (x - r))
+    }
+}
+
+/// Find up to n roots of the function f(x) = 0 within the bracket one by one, suppressing the roots found.
+///
+/// Each root is searched on the function with all roots found before divided out by `suppress_roots`.
+/// The known roots split the bracket into intervals where the suppressed function is regular
+/// at the ends, and the first of them where it changes its sign is searched by the Brent method,
+/// so a root is never found twice. If the remaining roots come in pairs and the sign does not change
+/// anywhere, the secant method with limited steps of `find_root_secant_guarded` is started
+/// in the middle of the widest interval. The search stops when n roots are found, or the next root
+/// is not found within the bracket. The roots are returned in the order they are found;
+/// see `suppress_roots` for the numerical caveats.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_n_roots;
+///
+/// let f = |x: f64| x.sin();
+///
+/// let roots = find_n_roots(&f, (0.5f64, 10f64), 3, &mut 1e-12f64);
+/// // Returns approximately [3.14159, 6.28318, 9.42477]
+/// ```
+pub fn find_n_roots<F, Func>(mut f: Func, bracket: (F, F), n: usize, convergency: &mut (impl Convergency<F> + ?Sized)) -> Vec<F>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let (a, b) = if bracket.0 > bracket.1 {
+        (bracket.1, bracket.0)
+    } else {
+        bracket
+    };
+    let mut roots: Vec<F> = Vec::with_capacity(n);
+    while roots.len() < n {
+        let mut points: Vec<F> = roots.iter().cloned().filter(|&r| r > a && r < b).collect();
+        points.push(a);
+        points.push(b);
+        points.sort_by(|x1, x2| x1.partial_cmp(x2).unwrap_or(Ordering::Equal));
+
+        let found = {
+            let mut suppressed = suppress_roots(&mut f, &roots);
+            let mut found = None;
+            let mut widest: Option<(F, F)> = None;
+            let mut begin = Sample { x: a, y: suppressed(a) };
+            for &x in points.iter().skip(1) {
+                let end = Sample { x, y: suppressed(x) };
+                if begin.is_bracketed_with(&end) {
+                    found = Some(find_root_brent(begin.x, end.x, &mut suppressed, convergency));
+                    break;
+                }
+                widest = match widest {
+                    Some((x0, x1)) if x1 - x0 >= end.x - begin.x => Some((x0, x1)),
+                    _ => Some((begin.x, end.x)),
+                };
+                begin = end;
+            }
+            // Roots in pairs do not change the sign; start from the middle of the widest interval
+            found.unwrap_or_else(|| {
+                let widest = widest.unwrap_or((a, b));
+                let width = widest.1 - widest.0;
+                let _4 = F::from(4i16);
+                let x0 = widest.0 + width / F::from(2i16);
+                find_root_secant_guarded(x0, x0 + width / _4, &mut suppressed, width / _4, convergency)
+            })
+        };
+        match found {
+            Ok(root) if root >= a && root <= b && !roots.iter().any(|&r| convergency.is_converged(r, root)) => roots.push(root),
+            _ => break,
+        }
+    }
+    roots
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::secant::find_root_secant;
+    use super::*;
+
+    #[test]
+    fn test_suppress_roots() {
+        let f = |x: f64| x.sin();
+        let known_roots = [0f64, std::f64::consts::PI];
+        let mut suppressed = suppress_roots(&f, &known_roots);
+
+        assert_float_eq!(
+            1e-15f64,
+            suppressed(1f64),
+            1f64.sin() / (1f64 * (1f64 - std::f64::consts::PI))
+        );
+        // The limits at the known roots: 1 / (0 - pi) and -1 / (pi - 0)
+        assert_float_eq!(1e-5f64, suppressed(0f64), -1f64 / std::f64::consts::PI);
+        assert_float_eq!(1e-5f64, suppressed(std::f64::consts::PI), -1f64 / std::f64::consts::PI);
+        assert_float_eq!(1e-5f64, suppressed(1e-12f64), -1f64 / std::f64::consts::PI);
+        assert_float_eq!(1e-5f64, suppressed(-1e-12f64), -1f64 / std::f64::consts::PI);
+
+        // Nothing to suppress
+        let mut same = suppress_roots(&f, &[]);
+        assert_eq!(same(1f64), 1f64.sin());
+
+        // The secant method does not converge back to the known root
+        assert_eq!(find_root_secant(0f64, 1f64, &f, &mut 1e-15f64), Ok(0f64));
+        let root = find_root_secant(0f64, 1f64, suppress_roots(&f, &[0f64]), &mut 1e-15f64).unwrap();
+        assert!(root.abs() > 1f64);
+        assert_float_eq!(1e-15f64, root.sin(), 0f64);
+    }
+
+    #[test]
+    fn test_find_n_roots() {
+        let f = |x: f64| x.sin();
+        let pi = std::f64::consts::PI;
+
+        // One root at a time, without finding the same root again
+        for n in 1..=3 {
+            let roots = find_n_roots(&f, (0.5f64, 10f64), n, &mut 1e-12f64);
+            assert_eq!(roots.len(), n);
+            let mut sorted = roots.clone();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            sorted.dedup();
+            assert_eq!(sorted.len(), n);
+            for root in roots {
+                assert_float_eq!(1e-10f64, root, (root / pi).round() * pi);
+            }
+        }
+
+        // No more roots
+        let roots = find_n_roots(&f, (10f64, 0.5f64), 5, &mut 1e-12f64);
+        assert_eq!(roots.len(), 3);
+        let mut roots = find_n_roots(&f, (0f64, 10f64), 5, &mut 1e-12f64);
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(roots.len(), 4);
+        for (k, &root) in roots.iter().enumerate() {
+            assert_float_eq!(1e-10f64, root, k as f64 * pi);
+        }
+        assert_eq!(find_n_roots(&f, (0.5f64, 3f64), 5, &mut 1e-12f64), vec![]);
+        assert_eq!(find_n_roots(&f, (0.5f64, 10f64), 0, &mut 1e-12f64), vec![]);
+    }
+}
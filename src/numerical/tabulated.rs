@@ -0,0 +1,232 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::inverse_quadratic::Parabola;
+use super::InvalidInputReason;
+use super::SearchError;
+use std::cmp::Ordering;
+
+/// Find roots of a function given by the samples (x, f(x)) using the linear interpolation.
+///
+/// Every pair of neighbouring samples where the function changes its sign gives one root,
+/// where the straight line through them crosses zero. Samples where f(x) is exactly zero are
+/// roots themselves; each of them is returned once, so a plateau of zeros gives all its samples.
+/// The function is assumed to have no more than one root between the neighbouring samples.
+/// Samples where f(x) is NaN do not bracket any root. The roots are returned in the ascending order.
+///
+/// # Failures
+/// ## InvalidInput
+/// The values of x are not strictly increasing.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_from_samples;
+///
+/// let samples = [(0f64, -1f64), (1f64, 1f64), (2f64, 3f64)];
+///
+/// let roots = find_root_from_samples(&samples);
+/// // Returns Ok(vec![0.5f64]);
+/// ```
+pub fn find_root_from_samples<F>(samples: &[(F, F)]) -> Result<Vec<F>, SearchError>
+where
+    F: FloatType,
+{
+    find_crossings(samples, |i| linear_crossing(samples[i], samples[i + 1]))
+}
+
+/// Find roots of a function given by the samples (x, f(x)) using the quadratic interpolation.
+///
+/// The same as `find_root_from_samples`, but the root between two samples is taken from the parabola
+/// through them and the nearest of the adjacent samples, which is exact for quadratic functions
+/// and much more precise for smooth ones. If there are only two samples, or the parabola
+/// has no root between the samples (e.g. because of the noise), the linear interpolation is used.
+///
+/// # Failures
+/// ## InvalidInput
+/// The values of x are not strictly increasing.
+/// # Examples
+///
+/// ```
+/// use roots::find_root_from_samples_quadratic;
+///
+/// let samples = [(0f64, -1f64), (1f64, 0.5f64), (2f64, 3f64)];
+///
+/// let roots = find_root_from_samples_quadratic(&samples);
+/// // Returns approximately Ok(vec![0.7320508075688772f64]);
+/// ```
+pub fn find_root_from_samples_quadratic<F>(samples: &[(F, F)]) -> Result<Vec<F>, SearchError>
+where
+    F: FloatType,
+{
+    find_crossings(samples, |i| {
+        let (begin, end) = (samples[i], samples[i + 1]);
+        // The adjacent sample closest to the interval
+        let third = match (i.checked_sub(1).map(|j| samples[j]), samples.get(i + 2)) {
+            (Some(before), Some(&after)) => {
+                if begin.0 - before.0 <= after.0 - end.0 {
+                    Some(before)
+                } else {
+                    Some(after)
+                }
+            }
+            (Some(before), None) => Some(before),
+            (None, Some(&after)) => Some(after),
+            (None, None) => None,
+        };
+        third
+            .and_then(|third| Parabola::from_three_points(begin, end, third))
+            .and_then(|parabola| parabola.roots().as_ref().iter().cloned().find(|&x| x > begin.0 && x < end.0))
+            .unwrap_or_else(|| linear_crossing(begin, end))
+    })
+}
+
+/// Validate the samples and collect zeros at them and crossings between the neighbouring ones
+fn find_crossings<F, Crossing>(samples: &[(F, F)], mut crossing: Crossing) -> Result<Vec<F>, SearchError>
+where
+    F: FloatType,
+    Crossing: FnMut(usize) -> F,
+{
+    for pair in samples.windows(2) {
+        if pair[0].0.partial_cmp(&pair[1].0) != Some(Ordering::Less) {
+            return Err(SearchError::invalid_input(InvalidInputReason::Unsuitable));
+        }
+    }
+
+    let mut roots = Vec::new();
+    for (i, &(x, y)) in samples.iter().enumerate() {
+        if y == F::zero() {
+            roots.push(x);
+        } else if let Some(&(_, next)) = samples.get(i + 1) {
+            if (y < F::zero() && next > F::zero()) || (y > F::zero() && next < F::zero()) {
+                roots.push(crossing(i));
+            }
+        }
+    }
+    Ok(roots)
+}
+
+/// Zero of the straight line through two samples with the different signs
+fn linear_crossing<F: FloatType>(begin: (F, F), end: (F, F)) -> F {
+    begin.0 - begin.1 * (end.0 - begin.0) / (end.1 - begin.1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_root_from_samples() {
+        let samples = [(0f64, -1f64), (1f64, 1f64), (2f64, 3f64)];
+        assert_eq!(find_root_from_samples(&samples), Ok(vec![0.5f64]));
+        assert_eq!(find_root_from_samples(&samples[..1]), Ok(vec![]));
+        assert_eq!(find_root_from_samples::<f64>(&[]), Ok(vec![]));
+
+        // Exact zeros are returned once
+        let samples = [(0f64, -1f64), (1f64, 0f64), (2f64, 3f64), (3f64, 0f64)];
+        assert_eq!(find_root_from_samples(&samples), Ok(vec![1f64, 3f64]));
+
+        // A plateau touching zero
+        let samples = [(0f64, 1f64), (1f64, 0f64), (2f64, 0f64), (3f64, 0f64), (4f64, 1f64)];
+        assert_eq!(find_root_from_samples(&samples), Ok(vec![1f64, 2f64, 3f64]));
+        // A plateau crossing zero
+        let samples = [(0f64, 1f64), (1f64, 0f64), (2f64, 0f64), (3f64, -1f64)];
+        assert_eq!(find_root_from_samples(&samples), Ok(vec![1f64, 2f64]));
+
+        // NaN does not bracket
+        let samples = [(0f64, -1f64), (1f64, f64::NAN), (2f64, 3f64)];
+        assert_eq!(find_root_from_samples(&samples), Ok(vec![]));
+
+        // x must be strictly increasing
+        let samples = [(0f64, -1f64), (0f64, 1f64)];
+        assert_eq!(
+            find_root_from_samples(&samples),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            })
+        );
+        let samples = [(1f64, -1f64), (0f64, 1f64)];
+        assert_eq!(
+            find_root_from_samples(&samples),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            })
+        );
+        let samples = [(0f64, -1f64), (f64::NAN, 1f64)];
+        assert_eq!(
+            find_root_from_samples(&samples),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            })
+        );
+        assert_eq!(
+            find_root_from_samples_quadratic(&samples),
+            Err(SearchError::InvalidInput {
+                reason: InvalidInputReason::Unsuitable
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_root_from_samples_noisy_sine() {
+        // Deterministic noise of about 1e-3
+        let samples: Vec<(f64, f64)> = (0..50)
+            .map(|i| {
+                let x = 0.1f64 + 0.2f64 * f64::from(i);
+                (x, x.sin() + 1e-3f64 * (37f64 * x).sin())
+            })
+            .collect();
+
+        for roots in [find_root_from_samples(&samples), find_root_from_samples_quadratic(&samples)].iter() {
+            let roots = roots.as_ref().unwrap();
+            assert_eq!(roots.len(), 3);
+            for (k, &root) in roots.iter().enumerate() {
+                assert_float_eq!(2e-3f64, root, (k + 1) as f64 * std::f64::consts::PI);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_root_from_samples_quadratic() {
+        // Exact for quadratic functions
+        let samples: Vec<(f64, f64)> = (0..5).map(|i| f64::from(i)).map(|x| (x, x * x - 2f64)).collect();
+        let roots = find_root_from_samples_quadratic(&samples).unwrap();
+        assert_eq!(roots.len(), 1);
+        assert_float_eq!(1e-15f64, roots[0], 2f64.sqrt());
+
+        // Much more precise than the linear interpolation for smooth functions
+        let samples: Vec<(f64, f64)> = (0..11).map(|i| f64::from(i) / 10f64).map(|x| (x, x.exp() - 2f64)).collect();
+        let linear = find_root_from_samples(&samples).unwrap();
+        let quadratic = find_root_from_samples_quadratic(&samples).unwrap();
+        assert!((linear[0] - 2f64.ln()).abs() > 1e-4f64);
+        assert_float_eq!(1e-4f64, quadratic[0], 2f64.ln());
+
+        // Two samples only
+        let samples = [(0f64, -1f64), (1f64, 1f64)];
+        assert_eq!(find_root_from_samples_quadratic(&samples), Ok(vec![0.5f64]));
+        // Zeros at the samples are returned as they are
+        let samples = [(0f64, -1f64), (1f64, 0f64), (2f64, 3f64)];
+        assert_eq!(find_root_from_samples_quadratic(&samples), Ok(vec![1f64]));
+    }
+}